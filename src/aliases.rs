@@ -0,0 +1,364 @@
+//! Aliases for the narrow integer types at common widths.
+//!
+//! Naming `UInt<12>` everywhere gets noisy, so this module has an alias for each of the widths
+//! hardware tends to use: `u2` to `u63` and `i2` to `i63`. The widths of the primitives are left
+//! out, as are 24 and 48, which have their own byte-sized types in [`u24`](../struct.u24.html),
+//! [`i24`](../struct.i24.html), and [`u48`](../struct.u48.html). The aliases are also exported
+//! from the crate root.
+//!
+//! # Examples
+//!
+//! ```
+//! use quark::aliases::{i12, u12};
+//!
+//! let offset = i12::new(-4);
+//! let address = u12::masked(0x1ffc);
+//!
+//! assert_eq!(address.get() as i128 + offset.get(), 0xff8);
+//! assert_eq!(quark::u12::MAX.get(), 4095);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use crate::{Int, UInt};
+
+// rustfmt indents the `concat!` arguments further each time it runs, so the layout is kept by hand.
+#[rustfmt::skip]
+macro_rules! alias_impl {
+    ($unsigned:ident, $signed:ident, $bits:literal, $max:literal, $signed_min:literal, $signed_max:literal) => {
+        #[doc = concat!(
+                    "A ",
+                    stringify!($bits),
+                    "-bit unsigned integer, from 0 to ",
+                    $max,
+                    "."
+                )]
+        pub type $unsigned = UInt<$bits>;
+
+        #[doc = concat!(
+                    "A ",
+                    stringify!($bits),
+                    "-bit signed integer, from ",
+                    $signed_min,
+                    " to ",
+                    $signed_max,
+                    "."
+                )]
+        pub type $signed = Int<$bits>;
+    };
+}
+
+alias_impl!(u2, i2, 2, "3", "-2", "1");
+alias_impl!(u3, i3, 3, "7", "-4", "3");
+alias_impl!(u4, i4, 4, "15", "-8", "7");
+alias_impl!(u5, i5, 5, "31", "-16", "15");
+alias_impl!(u6, i6, 6, "63", "-32", "31");
+alias_impl!(u7, i7, 7, "127", "-64", "63");
+alias_impl!(u9, i9, 9, "511", "-256", "255");
+alias_impl!(u10, i10, 10, "1023", "-512", "511");
+alias_impl!(u11, i11, 11, "2047", "-1024", "1023");
+alias_impl!(u12, i12, 12, "4095", "-2048", "2047");
+alias_impl!(u13, i13, 13, "8191", "-4096", "4095");
+alias_impl!(u14, i14, 14, "16383", "-8192", "8191");
+alias_impl!(u15, i15, 15, "32767", "-16384", "16383");
+alias_impl!(u17, i17, 17, "131071", "-65536", "65535");
+alias_impl!(u18, i18, 18, "262143", "-131072", "131071");
+alias_impl!(u19, i19, 19, "524287", "-262144", "262143");
+alias_impl!(u20, i20, 20, "1048575", "-524288", "524287");
+alias_impl!(u21, i21, 21, "2097151", "-1048576", "1048575");
+alias_impl!(u22, i22, 22, "4194303", "-2097152", "2097151");
+alias_impl!(u23, i23, 23, "8388607", "-4194304", "4194303");
+alias_impl!(u25, i25, 25, "33554431", "-16777216", "16777215");
+alias_impl!(u26, i26, 26, "67108863", "-33554432", "33554431");
+alias_impl!(u27, i27, 27, "134217727", "-67108864", "67108863");
+alias_impl!(u28, i28, 28, "268435455", "-134217728", "134217727");
+alias_impl!(u29, i29, 29, "536870911", "-268435456", "268435455");
+alias_impl!(u30, i30, 30, "1073741823", "-536870912", "536870911");
+alias_impl!(u31, i31, 31, "2147483647", "-1073741824", "1073741823");
+alias_impl!(u33, i33, 33, "8589934591", "-4294967296", "4294967295");
+alias_impl!(u34, i34, 34, "17179869183", "-8589934592", "8589934591");
+alias_impl!(u35, i35, 35, "34359738367", "-17179869184", "17179869183");
+alias_impl!(u36, i36, 36, "68719476735", "-34359738368", "34359738367");
+alias_impl!(u37, i37, 37, "137438953471", "-68719476736", "68719476735");
+alias_impl!(
+    u38,
+    i38,
+    38,
+    "274877906943",
+    "-137438953472",
+    "137438953471"
+);
+alias_impl!(
+    u39,
+    i39,
+    39,
+    "549755813887",
+    "-274877906944",
+    "274877906943"
+);
+alias_impl!(
+    u40,
+    i40,
+    40,
+    "1099511627775",
+    "-549755813888",
+    "549755813887"
+);
+alias_impl!(
+    u41,
+    i41,
+    41,
+    "2199023255551",
+    "-1099511627776",
+    "1099511627775"
+);
+alias_impl!(
+    u42,
+    i42,
+    42,
+    "4398046511103",
+    "-2199023255552",
+    "2199023255551"
+);
+alias_impl!(
+    u43,
+    i43,
+    43,
+    "8796093022207",
+    "-4398046511104",
+    "4398046511103"
+);
+alias_impl!(
+    u44,
+    i44,
+    44,
+    "17592186044415",
+    "-8796093022208",
+    "8796093022207"
+);
+alias_impl!(
+    u45,
+    i45,
+    45,
+    "35184372088831",
+    "-17592186044416",
+    "17592186044415"
+);
+alias_impl!(
+    u46,
+    i46,
+    46,
+    "70368744177663",
+    "-35184372088832",
+    "35184372088831"
+);
+alias_impl!(
+    u47,
+    i47,
+    47,
+    "140737488355327",
+    "-70368744177664",
+    "70368744177663"
+);
+alias_impl!(
+    u49,
+    i49,
+    49,
+    "562949953421311",
+    "-281474976710656",
+    "281474976710655"
+);
+alias_impl!(
+    u50,
+    i50,
+    50,
+    "1125899906842623",
+    "-562949953421312",
+    "562949953421311"
+);
+alias_impl!(
+    u51,
+    i51,
+    51,
+    "2251799813685247",
+    "-1125899906842624",
+    "1125899906842623"
+);
+alias_impl!(
+    u52,
+    i52,
+    52,
+    "4503599627370495",
+    "-2251799813685248",
+    "2251799813685247"
+);
+alias_impl!(
+    u53,
+    i53,
+    53,
+    "9007199254740991",
+    "-4503599627370496",
+    "4503599627370495"
+);
+alias_impl!(
+    u54,
+    i54,
+    54,
+    "18014398509481983",
+    "-9007199254740992",
+    "9007199254740991"
+);
+alias_impl!(
+    u55,
+    i55,
+    55,
+    "36028797018963967",
+    "-18014398509481984",
+    "18014398509481983"
+);
+alias_impl!(
+    u56,
+    i56,
+    56,
+    "72057594037927935",
+    "-36028797018963968",
+    "36028797018963967"
+);
+alias_impl!(
+    u57,
+    i57,
+    57,
+    "144115188075855871",
+    "-72057594037927936",
+    "72057594037927935"
+);
+alias_impl!(
+    u58,
+    i58,
+    58,
+    "288230376151711743",
+    "-144115188075855872",
+    "144115188075855871"
+);
+alias_impl!(
+    u59,
+    i59,
+    59,
+    "576460752303423487",
+    "-288230376151711744",
+    "288230376151711743"
+);
+alias_impl!(
+    u60,
+    i60,
+    60,
+    "1152921504606846975",
+    "-576460752303423488",
+    "576460752303423487"
+);
+alias_impl!(
+    u61,
+    i61,
+    61,
+    "2305843009213693951",
+    "-1152921504606846976",
+    "1152921504606846975"
+);
+alias_impl!(
+    u62,
+    i62,
+    62,
+    "4611686018427387903",
+    "-2305843009213693952",
+    "2305843009213693951"
+);
+alias_impl!(
+    u63,
+    i63,
+    63,
+    "9223372036854775807",
+    "-4611686018427387904",
+    "4611686018427387903"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BitSize;
+
+    macro_rules! check {
+        ($($unsigned:ident $unsigned_bits:literal, $signed:ident $signed_bits:literal),* $(,)?) => {
+            $(
+                assert_eq!($unsigned::BIT_SIZE, $unsigned_bits);
+                assert_eq!($unsigned::MAX.get(), (1 << $unsigned_bits) - 1);
+                assert_eq!($unsigned::masked(0).get(), 0);
+                assert_eq!($signed::BIT_SIZE, $signed_bits);
+                assert_eq!($signed::MIN.get(), -1 << ($signed_bits - 1));
+                assert_eq!($signed::masked(-1).get(), -1);
+            )*
+        };
+    }
+
+    #[test]
+    fn every_alias_has_its_width() {
+        check!(
+            u2 2, i2 2,
+            u3 3, i3 3,
+            u4 4, i4 4,
+            u5 5, i5 5,
+            u6 6, i6 6,
+            u7 7, i7 7,
+            u9 9, i9 9,
+            u10 10, i10 10,
+            u11 11, i11 11,
+            u12 12, i12 12,
+            u13 13, i13 13,
+            u14 14, i14 14,
+            u15 15, i15 15,
+            u17 17, i17 17,
+            u18 18, i18 18,
+            u19 19, i19 19,
+            u20 20, i20 20,
+            u21 21, i21 21,
+            u22 22, i22 22,
+            u23 23, i23 23,
+            u25 25, i25 25,
+            u26 26, i26 26,
+            u27 27, i27 27,
+            u28 28, i28 28,
+            u29 29, i29 29,
+            u30 30, i30 30,
+            u31 31, i31 31,
+            u33 33, i33 33,
+            u34 34, i34 34,
+            u35 35, i35 35,
+            u36 36, i36 36,
+            u37 37, i37 37,
+            u38 38, i38 38,
+            u39 39, i39 39,
+            u40 40, i40 40,
+            u41 41, i41 41,
+            u42 42, i42 42,
+            u43 43, i43 43,
+            u44 44, i44 44,
+            u45 45, i45 45,
+            u46 46, i46 46,
+            u47 47, i47 47,
+            u49 49, i49 49,
+            u50 50, i50 50,
+            u51 51, i51 51,
+            u52 52, i52 52,
+            u53 53, i53 53,
+            u54 54, i54 54,
+            u55 55, i55 55,
+            u56 56, i56 56,
+            u57 57, i57 57,
+            u58 58, i58 58,
+            u59 59, i59 59,
+            u60 60, i60 60,
+            u61 61, i61 61,
+            u62 62, i62 62,
+            u63 63, i63 63,
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{i24, i256, u24, u256, u48, Field, Int, UInt};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// Each value takes the fewest whole bytes that cover its width, read in little-endian order, and
+// the bits past the width are dropped, so every input gives a value in range and a fuzzer mutating
+// a byte changes the value it lands in. Like the primitives, values read as zero bits once the
+// input runs out.
+
+/// Reads `N` little-endian bytes, padding with zeros once the input runs out.
+fn take<const N: usize>(u: &mut Unstructured) -> Result<[u8; N]> {
+    let mut bytes = [0; N];
+    u.fill_buffer(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads the bytes covering `bits` bits into the low end of a `u128`.
+fn take_bits(u: &mut Unstructured, bits: usize) -> Result<u128> {
+    let mut bytes = [0; 16];
+    u.fill_buffer(&mut bytes[..bits.div_ceil(8)])?;
+    Ok(u128::from_le_bytes(bytes))
+}
+
+impl<'a, const BITS: usize> Arbitrary<'a> for UInt<BITS> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(UInt::masked(take_bits(u, BITS)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (BITS.div_ceil(8), Some(BITS.div_ceil(8)))
+    }
+}
+
+impl<'a, const BITS: usize> Arbitrary<'a> for Int<BITS> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Int::masked(take_bits(u, BITS)? as i128))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (BITS.div_ceil(8), Some(BITS.div_ceil(8)))
+    }
+}
+
+macro_rules! arbitrary_bytes_impl {
+    ($($type:ident, $bytes:literal;)*) => {
+        $(
+            impl<'a> Arbitrary<'a> for $type {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok($type::from_le_bytes(take::<$bytes>(u)?))
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    ($bytes, Some($bytes))
+                }
+            }
+        )*
+    };
+}
+
+arbitrary_bytes_impl! {
+    u24, 3;
+    i24, 3;
+    u48, 6;
+    u256, 32;
+    i256, 32;
+}
+
+/// Fields are generated within the bits of a `u128`, since a field that lies entirely past the
+/// end of every type only ever reads zeros. The first byte is the offset, and the second is the
+/// width, wrapped to fit in the bits above the offset.
+impl<'a> Arbitrary<'a> for Field {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let [offset, width] = take::<2>(u)?;
+        let offset = (offset & 0x7f) as usize;
+        Ok(Field::new(offset, width as usize % (129 - offset)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    /// Generates a value from the start of `data`, checking it takes exactly the hinted bytes.
+    fn generate<'a, T: Arbitrary<'a>>(data: &'a [u8]) -> T {
+        let mut u = Unstructured::new(data);
+        let value = T::arbitrary(&mut u).unwrap();
+        let (size, _) = T::size_hint(0);
+
+        assert_eq!(data.len() - u.len(), size.min(data.len()));
+        value
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut random = random_u64s();
+        let mut data = [0; 32];
+
+        for _ in 0..1000 {
+            for byte in data.iter_mut() {
+                *byte = random.next().unwrap() as u8;
+            }
+
+            macro_rules! check {
+                ($($type:ty, $bytes:literal, $to_bytes:expr;)*) => {
+                    $(
+                        let value: $type = generate(&data);
+                        let bytes: Vec<u8> = $to_bytes(value);
+
+                        asserting!("values take the fewest bytes and re-parse from them")
+                            .that(&(<$type>::size_hint(0), bytes.len(), generate::<$type>(&bytes)))
+                            .is_equal_to(((($bytes), Some($bytes)), $bytes, value));
+                    )*
+                };
+            }
+
+            check! {
+                UInt<1>, 1, |value: UInt<1>| vec![value.get() as u8];
+                UInt<12>, 2, |value: UInt<12>| value.get().to_le_bytes()[..2].to_vec();
+                UInt<128>, 16, |value: UInt<128>| value.get().to_le_bytes().to_vec();
+                Int<5>, 1, |value: Int<5>| vec![value.get() as u8];
+                Int<33>, 5, |value: Int<33>| value.get().to_le_bytes()[..5].to_vec();
+                u24, 3, |value: u24| value.to_le_bytes().to_vec();
+                i24, 3, |value: i24| value.to_le_bytes().to_vec();
+                u48, 6, |value: u48| value.to_le_bytes().to_vec();
+                u256, 32, |value: u256| value.to_le_bytes().to_vec();
+                i256, 32, |value: i256| value.to_le_bytes().to_vec();
+                Field, 2, |value: Field| vec![value.offset() as u8, value.width() as u8];
+            }
+
+            let field: Field = generate(&data);
+            asserting!("fields lie within 128 bits")
+                .that(&(field.offset() + field.width() <= 128))
+                .is_true();
+        }
+    }
+
+    #[test]
+    fn values_are_in_range() {
+        asserting!("bits past the width are dropped")
+            .that(&(
+                generate::<UInt<12>>(&[0xff, 0xff, 0xff]),
+                generate::<Int<12>>(&[0xff, 0x07]),
+                generate::<Int<12>>(&[0x00, 0x08]),
+                generate::<Int<1>>(&[0x01]),
+            ))
+            .is_equal_to((UInt::MAX, Int::MAX, Int::MIN, Int::new(-1)));
+
+        asserting!("short inputs read as zeros")
+            .that(&(
+                generate::<UInt<12>>(&[0xab]),
+                generate::<u48>(&[]),
+                generate::<Field>(&[0x7f]),
+            ))
+            .is_equal_to((UInt::new(0xab), u48::MIN, Field::new(127, 0)));
+
+        let mut u = Unstructured::new(&[0x34, 0x12, 0x81, 0x05, 0x10, 0xff][..]);
+        asserting!("structured inputs consume their parts in order")
+            .that(&<(UInt<12>, Int<5>, Field)>::arbitrary(&mut u).ok())
+            .is_equal_to(Some((UInt::new(0x234), Int::new(1), Field::new(5, 16))));
+    }
+}
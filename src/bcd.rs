@@ -0,0 +1,321 @@
+use crate::BitIndex;
+
+/// Converts values to and from packed binary-coded decimal.
+///
+/// Packed BCD stores one decimal digit in each nibble, so `42` is `0x42`. It's how real-time
+/// clocks and many older protocols store numbers. A value holds as many digits as it has nibbles,
+/// two for a `u8` up to thirty-two for a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Bcd;
+///
+/// assert_eq!(42_u8.to_bcd(), Some(0x42));
+/// assert_eq!(0x1959_u16.from_bcd(), Some(1959));
+/// assert!(!0x1a_u8.is_valid_bcd());
+/// ```
+pub trait Bcd: Sized {
+    /// Returns the packed BCD digits of a value, or `None` if it has more digits than fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Bcd;
+    ///
+    /// assert_eq!(99_u8.to_bcd(), Some(0x99));
+    /// assert_eq!(100_u8.to_bcd(), None);
+    /// assert_eq!(1_234_567_u32.to_bcd(), Some(0x0123_4567));
+    /// ```
+    fn to_bcd(&self) -> Option<Self>;
+
+    /// Returns the value of packed BCD digits, or `None` if any nibble isn't a decimal digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Bcd;
+    ///
+    /// assert_eq!(0x99_u8.from_bcd(), Some(99));
+    /// assert_eq!(0x0123_4567_u32.from_bcd(), Some(1_234_567));
+    /// assert_eq!(0x9a_u8.from_bcd(), None);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bcd(&self) -> Option<Self>;
+
+    /// Returns whether every nibble of a value is a decimal digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Bcd;
+    ///
+    /// assert!(0x1959_u16.is_valid_bcd());
+    /// assert!(!0xf959_u16.is_valid_bcd());
+    /// ```
+    fn is_valid_bcd(&self) -> bool;
+}
+
+macro_rules! bcd_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Bcd for $type {
+                fn to_bcd(&self) -> Option<Self> {
+                    let mut value = *self;
+                    let mut bcd: Self = 0;
+                    for digit in 0..Self::BITS as usize / 4 {
+                        bcd = bcd.with_bits(digit * 4..digit * 4 + 4, value % 10);
+                        value /= 10;
+                    }
+
+                    if value == 0 {
+                        Some(bcd)
+                    } else {
+                        None
+                    }
+                }
+
+                fn from_bcd(&self) -> Option<Self> {
+                    // Every value of the digits fits, since 10^N is less than 16^N
+                    let mut value: Self = 0;
+                    for digit in (0..Self::BITS as usize / 4).rev() {
+                        let digit = self.bits(digit * 4..digit * 4 + 4);
+                        if digit > 9 {
+                            return None;
+                        }
+                        value = value * 10 + digit;
+                    }
+                    Some(value)
+                }
+
+                fn is_valid_bcd(&self) -> bool {
+                    (0..Self::BITS as usize / 4).all(|digit| self.bits(digit * 4..digit * 4 + 4) <= 9)
+                }
+            }
+        )*
+    };
+}
+
+bcd_impl!(u8, u16, u32, u64, u128, usize);
+
+/// Adjusts the binary sum of two packed BCD bytes back into packed BCD, like the Z80's `DAA`
+/// instruction after an addition.
+///
+/// `half_carry` and `carry` are the carries out of bits 3 and 7 of the addition, as from
+/// [`WidthArith::carry_out_of_bit`](crate::WidthArith::carry_out_of_bit). Returns the adjusted
+/// byte and the decimal carry out. This matches the Z80 for every input, including sums that
+/// aren't valid BCD: a digit over 9 or a set flag adds 6 to that digit, and the carry out is set
+/// when the upper digit was adjusted.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{bcd_adjust_add, WidthArith};
+///
+/// // 15 + 27 = 42
+/// let (a, b) = (0x15_u8, 0x27);
+/// let half_carry = a.carry_out_of_bit(b, 3);
+/// let carry = a.carry_out_of_bit(b, 7);
+/// assert_eq!(bcd_adjust_add(a.wrapping_add(b), half_carry, carry), (0x42, false));
+///
+/// // 99 + 1 = 100
+/// assert_eq!(bcd_adjust_add(0x9a, false, false), (0x00, true));
+/// ```
+pub const fn bcd_adjust_add(value: u8, half_carry: bool, carry: bool) -> (u8, bool) {
+    let (correction, carry) = bcd_correction(value, half_carry, carry);
+    (value.wrapping_add(correction), carry)
+}
+
+/// Adjusts the binary difference of two packed BCD bytes back into packed BCD, like the Z80's
+/// `DAA` instruction after a subtraction.
+///
+/// `half_carry` and `carry` are the borrows out of bits 3 and 7 of the subtraction, as from
+/// [`WidthArith::borrow_out_of_bit`](crate::WidthArith::borrow_out_of_bit). Returns the adjusted
+/// byte and the decimal borrow out. The corrections are the same as for
+/// [`bcd_adjust_add`], subtracted instead of added.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{bcd_adjust_sub, WidthArith};
+///
+/// // 42 - 15 = 27
+/// let (a, b) = (0x42_u8, 0x15);
+/// let half_borrow = a.borrow_out_of_bit(b, 3);
+/// let borrow = a.borrow_out_of_bit(b, 7);
+/// assert_eq!(bcd_adjust_sub(a.wrapping_sub(b), half_borrow, borrow), (0x27, false));
+///
+/// // 0 - 1 = 99, borrowing 100
+/// assert_eq!(bcd_adjust_sub(0xff, true, true), (0x99, true));
+/// ```
+pub const fn bcd_adjust_sub(value: u8, half_carry: bool, carry: bool) -> (u8, bool) {
+    let (correction, carry) = bcd_correction(value, half_carry, carry);
+    (value.wrapping_sub(correction), carry)
+}
+
+/// Returns the correction `DAA` applies to a byte, and the carry out.
+const fn bcd_correction(value: u8, half_carry: bool, carry: bool) -> (u8, bool) {
+    let low = if half_carry || value & 0x0f > 9 {
+        0x06
+    } else {
+        0
+    };
+    if carry || value > 0x99 {
+        (low | 0x60, true)
+    } else {
+        (low, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WidthArith;
+    use spectral::prelude::*;
+
+    #[test]
+    fn digits_round_trip() {
+        for value in 0..=u16::MAX {
+            let expected = if value <= 9999 {
+                Some(u16::from_str_radix(&value.to_string(), 16).unwrap())
+            } else {
+                None
+            };
+            asserting!("u16 values have a BCD form up to 9999")
+                .that(&value.to_bcd())
+                .is_equal_to(expected);
+
+            let digits = format!("{:04x}", value);
+            let valid = digits.bytes().all(|digit| digit.is_ascii_digit());
+            asserting!("u16 digits are valid BCD when every nibble is a decimal digit")
+                .that(&(value.is_valid_bcd(), value.from_bcd()))
+                .is_equal_to((valid, digits.parse().ok().filter(|_| valid)));
+
+            if let Some(bcd) = value.to_bcd() {
+                asserting!("u16 values come back from their digits")
+                    .that(&bcd.from_bcd())
+                    .is_equal_to(Some(value));
+            }
+        }
+
+        for value in 0..=u8::MAX {
+            asserting!("u8 values round-trip up to 99")
+                .that(&(
+                    value.to_bcd().and_then(|bcd| bcd.from_bcd()),
+                    value.from_bcd().and_then(|decimal| decimal.to_bcd()),
+                ))
+                .is_equal_to((
+                    Some(value).filter(|&value| value <= 99),
+                    Some(value).filter(|value| value.is_valid_bcd()),
+                ));
+        }
+    }
+
+    #[test]
+    fn decimal_adjust_matches_the_z80() {
+        // The DAA table from The Undocumented Z80 Documented: the carry flag, the ranges of the
+        // upper and lower digits and the half-carry flag (None for either), and the correction
+        let table = [
+            (false, (0x0, 0x9), Some(false), (0x0, 0x9), 0x00),
+            (false, (0x0, 0x9), Some(true), (0x0, 0x9), 0x06),
+            (false, (0x0, 0x8), None, (0xa, 0xf), 0x06),
+            (false, (0xa, 0xf), Some(false), (0x0, 0x9), 0x60),
+            (true, (0x0, 0xf), Some(false), (0x0, 0x9), 0x60),
+            (true, (0x0, 0xf), Some(true), (0x0, 0x9), 0x66),
+            (true, (0x0, 0xf), None, (0xa, 0xf), 0x66),
+            (false, (0x9, 0xf), None, (0xa, 0xf), 0x66),
+            (false, (0xa, 0xf), Some(true), (0x0, 0x9), 0x66),
+        ];
+
+        for value in 0..=u8::MAX {
+            for &(half_carry, carry) in
+                &[(false, false), (false, true), (true, false), (true, true)]
+            {
+                let (upper, lower) = (value >> 4, value & 0xf);
+                let rows = table
+                    .iter()
+                    .filter(|&&(row_carry, high, row_half_carry, low, _)| {
+                        row_carry == carry
+                            && (high.0..=high.1).contains(&upper)
+                            && row_half_carry
+                                .map_or(true, |row_half_carry| row_half_carry == half_carry)
+                            && (low.0..=low.1).contains(&lower)
+                    });
+                let correction = rows.map(|row| row.4).collect::<Vec<_>>();
+                // The carry out is clear only in the rows for values that are already valid BCD,
+                // or need only the lower digit adjusted
+                let carry_out = carry || upper > 9 || (upper == 9 && lower > 9);
+
+                asserting!("exactly one row of the table applies")
+                    .that(&correction.len())
+                    .is_equal_to(1);
+                asserting!("the adjustments match the table")
+                    .that(&(
+                        bcd_adjust_add(value, half_carry, carry),
+                        bcd_adjust_sub(value, half_carry, carry),
+                    ))
+                    .is_equal_to((
+                        (value.wrapping_add(correction[0]), carry_out),
+                        (value.wrapping_sub(correction[0]), carry_out),
+                    ));
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_arithmetic() {
+        for a in 0..100_u8 {
+            for b in 0..100_u8 {
+                let (x, y) = (a.to_bcd().unwrap(), b.to_bcd().unwrap());
+
+                let sum = bcd_adjust_add(
+                    x.wrapping_add(y),
+                    x.carry_out_of_bit(y, 3),
+                    x.carry_out_of_bit(y, 7),
+                );
+                let difference = bcd_adjust_sub(
+                    x.wrapping_sub(y),
+                    x.borrow_out_of_bit(y, 3),
+                    x.borrow_out_of_bit(y, 7),
+                );
+
+                asserting!("adjusted BCD arithmetic matches decimal arithmetic")
+                    .that(&(sum, difference))
+                    .is_equal_to((
+                        (((a + b) % 100).to_bcd().unwrap(), a + b >= 100),
+                        (((a + 100 - b) % 100).to_bcd().unwrap(), a < b),
+                    ));
+            }
+        }
+    }
+    #[test]
+    fn wide_values() {
+        asserting!("the widest values have as many digits as nibbles")
+            .that(&(
+                99_999_999_u32.to_bcd(),
+                100_000_000_u32.to_bcd(),
+                u64::MAX.to_bcd(),
+                9_999_999_999_999_999_u64.to_bcd(),
+                u128::MAX.to_bcd(),
+                (10_u128.pow(32) - 1).to_bcd(),
+            ))
+            .is_equal_to((
+                Some(0x9999_9999),
+                None,
+                None,
+                Some(0x9999_9999_9999_9999),
+                None,
+                Some(u128::from_str_radix(&"9".repeat(32), 16).unwrap()),
+            ));
+
+        asserting!("a non-decimal nibble anywhere makes digits invalid")
+            .that(&(
+                0xa000_0000_0000_0000_u64.from_bcd(),
+                0x0000_0000_0000_000f_u64.from_bcd(),
+                0x0999_0000_b000_0999_u64.is_valid_bcd(),
+                (0xc_u128 << 124).is_valid_bcd(),
+                0x9999_9999_9999_9999_usize.is_valid_bcd(),
+            ))
+            .is_equal_to((None, None, false, false, true));
+    }
+}
@@ -1,7 +1,8 @@
 #![allow(unused_comparisons)]
 
-use crate::{BitMask, BitSize};
-use std::ops::RangeBounds;
+use crate::bit_mask::range_bounds;
+use crate::{i256, u256, BitMask, BitSize, Int, UInt};
+use std::ops::{BitAnd, BitOr, Not, RangeBounds};
 
 /// Provides bit indexing operations.
 ///
@@ -26,12 +27,73 @@ use std::ops::RangeBounds;
 /// let rm = value.bits(0..4);
 /// assert_eq!(rm, 7);
 /// ```
+///
+/// Only [`bit`](#tymethod.bit) and [`bits`](#tymethod.bits) have to be implemented. The other
+/// methods are built on them and [`BitMask`](trait.BitMask.html) for any type with the bitwise
+/// operators they need, and the types in this crate override them with faster versions.
 pub trait BitIndex: BitSize + BitMask {
     /// Returns whether the specified bit is set.
     fn bit(&self, index: usize) -> bool;
 
     /// Returns the bits contained in the specified bit range.
     fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self;
+
+    /// Returns the value with the specified bit range replaced by the low bits of `value`.
+    ///
+    /// Bits of `value` that don't fit in the range are discarded, as are bits of the range that lie
+    /// past the end of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitIndex;
+    ///
+    /// let value: u32 = 0xe01a3497;
+    /// assert_eq!(value.with_bits(16..20, 0x5), 0xe0153497);
+    /// assert_eq!(value.with_bits(16..20, 0x15), 0xe0153497);
+    /// ```
+    fn with_bits<Idx: RangeBounds<usize>>(&self, index: Idx, value: Self) -> Self
+    where
+        Self: Sized + Copy + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        let (start, length) = range_bounds(&index);
+        let end = length
+            .map_or(Self::BIT_SIZE, |length| start.saturating_add(length))
+            .min(Self::BIT_SIZE);
+
+        (start..end)
+            .filter(|&bit| value.bit(bit - start))
+            .fold(*self & !Self::mask_range(index), |result, bit| {
+                result | Self::mask_range(bit..=bit)
+            })
+    }
+
+    /// Splits the value at bit N into the bits above it, shifted down, and the bits below it.
+    ///
+    /// This is the pair `(self.bits(n..), self.bits(..n))`, so shifting the high part back up and
+    /// or-ing in the low part gives the original value. Splitting at 0 gives the whole value and
+    /// 0, and splitting at the bit size or beyond gives the sign fill and the whole value: 0 for
+    /// unsigned and non-negative values, and -1 for negative ones. The high part of a signed value
+    /// keeps its sign, while the low part is always the raw bits, without a sign of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitIndex;
+    ///
+    /// let address: u32 = 0x0804_8a3c;
+    /// let (page, offset) = address.split_at_bit(12);
+    /// assert_eq!((page, offset), (0x08048, 0xa3c));
+    ///
+    /// assert_eq!((-0x1234_i32).split_at_bit(8), (-0x13, 0xcc));
+    /// assert_eq!(0x1234_u16.split_at_bit(16), (0, 0x1234));
+    /// ```
+    fn split_at_bit(&self, index: usize) -> (Self, Self)
+    where
+        Self: Sized,
+    {
+        (self.bits(index..), self.bits(..index))
+    }
 }
 
 macro_rules! bit_index_impl {
@@ -95,6 +157,13 @@ macro_rules! bit_index_impl {
                     (None, None) => *self,
                 }
             }
+
+            fn with_bits<Idx: RangeBounds<usize>>(&self, index: Idx, value: Self) -> Self {
+                let (start, _) = range_bounds(&index);
+                let mask = Self::mask_range(index);
+
+                self & !mask | value.checked_shl(start as _).unwrap_or(0) & mask
+            }
         }
     };
 }
@@ -112,9 +181,72 @@ bit_index_impl!(i64);
 bit_index_impl!(i128);
 bit_index_impl!(isize);
 
+// The narrow types keep their values zero- or sign-extended to the full width of a `u128` or an
+// `i128`, so indexing the stored value reads past the width the same way the primitives do: as
+// zeros for `UInt`, and as copies of the sign bit for `Int`.
+macro_rules! narrow_bit_index_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<const BITS: usize> BitIndex for $type<BITS> {
+                fn bit(&self, index: usize) -> bool {
+                    self.get().bit(index)
+                }
+
+                fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self {
+                    Self::masked(self.get().bits(index))
+                }
+
+                fn with_bits<Idx: RangeBounds<usize>>(&self, index: Idx, value: Self) -> Self {
+                    let (start, _) = range_bounds(&index);
+                    let mask = Self::mask_range(index);
+
+                    *self & !mask | (value << start) & mask
+                }
+            }
+        )*
+    };
+}
+
+narrow_bit_index_impl!(UInt, Int);
+
+// Shifting the 256-bit types past their width fills with zeros for `u256` and with the sign for
+// `i256`, so reading bits past the top matches the primitives without any special cases.
+macro_rules! wide_bit_index_impl {
+    ($($type:ident),*) => {
+        $(
+            impl BitIndex for $type {
+                fn bit(&self, index: usize) -> bool {
+                    *self >> index & $type::ONE == $type::ONE
+                }
+
+                fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self {
+                    let (start, length) = range_bounds(&index);
+                    let shifted = *self >> start;
+
+                    match length {
+                        Some(length) => shifted.mask_to(length),
+                        None => shifted,
+                    }
+                }
+
+                fn with_bits<Idx: RangeBounds<usize>>(&self, index: Idx, value: Self) -> Self {
+                    let (start, _) = range_bounds(&index);
+                    let mask = Self::mask_range(index);
+
+                    *self & !mask | (value << start) & mask
+                }
+            }
+        )*
+    };
+}
+
+wide_bit_index_impl!(u256, i256);
+
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod test {
     use super::*;
+    use crate::test_util::random_u64s;
     use spectral::prelude::*;
 
     struct RangeEE(usize, usize);
@@ -327,6 +459,41 @@ mod test {
             .is_equal_to(4);
     }
 
+    #[test]
+    fn replacing_bits() {
+        let byte: u8 = 90;
+
+        asserting!("with_bits() replaces only the bits in the range")
+            .that(&[
+                byte.with_bits(0..4, 0x5),
+                byte.with_bits(4..8, 0x0),
+                byte.with_bits(2..=5, 0xf),
+            ])
+            .is_equal_to([0x55, 0x0a, 0x7e]);
+
+        asserting!("with_bits() discards value bits that don't fit in the range")
+            .that(&byte.with_bits(0..4, 0xf5))
+            .is_equal_to(0x55);
+
+        asserting!("with_bits() ignores range bits past the last bit")
+            .that(&[byte.with_bits(4..12, 0xff), byte.with_bits(8..16, 0xff)])
+            .is_equal_to([0xfa, 0x5a]);
+
+        asserting!("with_bits(RangeFull) replaces the whole value")
+            .that(&byte.with_bits(.., 0x33))
+            .is_equal_to(0x33);
+
+        let byte: i8 = -90;
+
+        asserting!("with_bits() replaces the sign bit of signed values")
+            .that(&[byte.with_bits(4..8, 0x5), byte.with_bits(0..4, 0x0)])
+            .is_equal_to([0x56, -96]);
+
+        asserting!("with_bits() round-trips with bits()")
+            .that(&byte.with_bits(2..6, byte.bits(2..6)))
+            .is_equal_to(byte);
+    }
+
     #[test]
     fn signed_extra_high_bits() {
         let byte: i8 = -90;
@@ -385,4 +552,295 @@ mod test {
             .that(&byte.bits(RangeEI(8, 16)))
             .is_equal_to(-1);
     }
+
+    #[test]
+    fn narrow_bit_index() {
+        let value = UInt::<12>::new(0xa5c);
+
+        asserting!("bits are read within the width")
+            .that(&(
+                value.bit(11),
+                value.bit(12),
+                value.bits(4..8).get(),
+                value.bits(8..).get(),
+                value.bits(8..16).get(),
+                value.bits(12..).get(),
+            ))
+            .is_equal_to((true, false, 0x5, 0xa, 0xa, 0));
+
+        let value = Int::<12>::new(-0x5a4);
+
+        asserting!("bits past the width of a negative value are copies of the sign")
+            .that(&(
+                value.bit(11),
+                value.bit(12),
+                value.bit(200),
+                value.bits(4..8).get(),
+                value.bits(8..).get(),
+                value.bits(8..11).get(),
+                value.bits(12..).get(),
+                value.bits(8..16).get(),
+            ))
+            .is_equal_to((true, true, true, 0x5, -6, 0x2, -1, 0xfa));
+
+        asserting!("with_bits discards bits past the width")
+            .that(&(
+                UInt::<12>::new(0xa5c)
+                    .with_bits(8..16, UInt::new(0x3f))
+                    .get(),
+                Int::<12>::new(0x25c).with_bits(8.., Int::new(-1)).get(),
+            ))
+            .is_equal_to((0xf5c, -0xa4));
+    }
+
+    #[test]
+    fn narrow_bit_index_matches_primitives() {
+        macro_rules! check {
+            ($narrow:ty, $prim:ty) => {
+                for value in <$prim>::MIN..=<$prim>::MAX {
+                    let narrow = <$narrow>::new(value as _);
+
+                    for start in [0, 1, 4, 7, 8, 9] {
+                        for end in [start, start + 1, start + 4, 8, 12] {
+                            asserting!("bits match")
+                                .that(&(
+                                    narrow.bit(start),
+                                    narrow.bits(start..end.max(start)).get() as $prim,
+                                    narrow.bits(start..).get() as $prim,
+                                    narrow
+                                        .with_bits(start..end.max(start), narrow.rotate_left(3))
+                                        .get() as $prim,
+                                ))
+                                .is_equal_to((
+                                    value.bit(start),
+                                    value.bits(start..end.max(start)),
+                                    value.bits(start..),
+                                    value.with_bits(start..end.max(start), value.rotate_left(3)),
+                                ));
+                        }
+                    }
+                }
+            };
+        }
+
+        check!(UInt<8>, u8);
+        check!(Int<8>, i8);
+    }
+
+    #[test]
+    fn wide_bit_ranges_cross_limbs() {
+        let value = u256::from_limbs([0xfedc_ba98_7654_3210, 0x0123_4567_89ab_cdef, 0xab, 0]);
+
+        asserting!("ranges read across limb boundaries")
+            .that(&(
+                value.bit(63),
+                value.bit(64),
+                value.bit(200),
+                value.bits(60..68),
+                value.bits(120..136),
+                value.bits(124..),
+                value.bits(255..300),
+            ))
+            .is_equal_to((
+                true,
+                true,
+                false,
+                u256::from(0xff_u8),
+                u256::from(0xab01_u16),
+                u256::from(0xab0_u16),
+                u256::ZERO,
+            ));
+
+        asserting!("ranges write across limb boundaries")
+            .that(&(
+                u256::ZERO.with_bits(60..70, u256::MAX).to_limbs(),
+                u256::MAX.with_bits(120..136, u256::ZERO).to_limbs(),
+                u256::ZERO.with_bits(192.., u256::MAX).to_limbs(),
+            ))
+            .is_equal_to((
+                [0xf << 60, 0x3f, 0, 0],
+                [!0, !0 >> 8, !0xff, !0],
+                [0, 0, 0, !0],
+            ));
+
+        let value = i256::from(i64::MIN);
+
+        asserting!("bits past the top of a negative value are copies of the sign")
+            .that(&(
+                value.bit(255),
+                value.bits(60..70),
+                value.bits(250..),
+                value.bits(250..260),
+                i256::ZERO.with_bits(250.., i256::from(-1)),
+            ))
+            .is_equal_to((
+                true,
+                i256::from(0x3f8),
+                i256::from(-1),
+                i256::from(0x3ff),
+                i256::from(-1) << 250_u32,
+            ));
+    }
+
+    #[test]
+    fn wide_bit_index_matches_primitives() {
+        let values = [0, 1, -1, 0x1234_5678_9abc_def0, i128::MIN, i128::MAX];
+
+        for &value in values.iter() {
+            let wide = i256::from(value);
+
+            for start in [0, 1, 60, 63, 64, 65, 120, 123] {
+                for end in [start, start + 1, start + 4, 64, 100, 127] {
+                    let range = start..end.max(start);
+
+                    asserting!("bits match")
+                        .that(&(
+                            wide.bit(start),
+                            wide.bits(range.clone()),
+                            wide.bits(start..),
+                            wide.with_bits(range.clone(), i256::from(value.rotate_left(3))),
+                        ))
+                        .is_equal_to((
+                            value.bit(start),
+                            i256::from(value.bits(range.clone())),
+                            i256::from(value.bits(start..)),
+                            i256::from(value.with_bits(range, value.rotate_left(3))),
+                        ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn splitting_at_a_bit() {
+        asserting!("the ends of the value split into the whole value and zero")
+            .that(&(
+                0x1234_u16.split_at_bit(0),
+                0x1234_u16.split_at_bit(16),
+                0x1234_u16.split_at_bit(100),
+                (-2_i8).split_at_bit(0),
+                (-2_i8).split_at_bit(8),
+                (-2_i8).split_at_bit(9),
+                2_i8.split_at_bit(8),
+            ))
+            .is_equal_to((
+                (0x1234, 0),
+                (0, 0x1234),
+                (0, 0x1234),
+                (-2, 0),
+                (-1, 0xfe_u8 as i8),
+                (-1, -2),
+                (0, 2),
+            ));
+
+        asserting!("narrow and wide values split the same way")
+            .that(&(
+                UInt::<20>::new(0xabcde).split_at_bit(12),
+                Int::<20>::new(-0x12345).split_at_bit(12),
+                u256::MAX.split_at_bit(200),
+            ))
+            .is_equal_to((
+                (UInt::new(0xab), UInt::new(0xcde)),
+                (Int::new(-0x13), Int::new(0xcbb)),
+                (u256::from(0xff_ffff_ffff_ffff_u64), u256::MAX >> 56_u32),
+            ));
+
+        for seed in random_u64s().take(1000) {
+            let index = (seed >> 58) as usize;
+
+            let unsigned = seed as u32;
+            let (high, low) = unsigned.split_at_bit(index);
+            asserting!("unsigned halves reassemble")
+                .that(&(high.checked_shl(index as u32).unwrap_or(0) | low))
+                .is_equal_to(unsigned);
+
+            let signed = seed as i32;
+            let (high, low) = signed.split_at_bit(index);
+            asserting!("signed halves reassemble, with the low half never negative")
+                .that(&(
+                    high.checked_shl(index as u32).unwrap_or(0) | low.mask_to(index),
+                    low < 0 && index < 32,
+                    high < 0,
+                ))
+                .is_equal_to((signed, false, signed < 0));
+        }
+    }
+
+    #[test]
+    fn provided_methods_match_primitives() {
+        // Only `bit` and `bits` are implemented, so `with_bits` uses the provided method.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Reg(i16);
+
+        impl BitSize for Reg {
+            const BIT_SIZE: usize = 16;
+        }
+
+        impl BitMask for Reg {
+            fn mask(size: usize) -> Self {
+                Reg(i16::mask(size))
+            }
+
+            fn mask_to(&self, size: usize) -> Self {
+                Reg(self.0.mask_to(size))
+            }
+
+            fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                Reg(i16::mask_range(range))
+            }
+        }
+
+        impl BitIndex for Reg {
+            fn bit(&self, index: usize) -> bool {
+                self.0.bit(index)
+            }
+
+            fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self {
+                Reg(self.0.bits(index))
+            }
+        }
+
+        impl Not for Reg {
+            type Output = Reg;
+
+            fn not(self) -> Reg {
+                Reg(!self.0)
+            }
+        }
+
+        impl BitAnd for Reg {
+            type Output = Reg;
+
+            fn bitand(self, rhs: Reg) -> Reg {
+                Reg(self.0 & rhs.0)
+            }
+        }
+
+        impl BitOr for Reg {
+            type Output = Reg;
+
+            fn bitor(self, rhs: Reg) -> Reg {
+                Reg(self.0 | rhs.0)
+            }
+        }
+
+        for seed in random_u64s().take(200) {
+            let (container, value) = (seed as i16, (seed >> 16) as i16);
+            for start in 0..20 {
+                for end in start..20 {
+                    asserting!("writing bits matches the primitive")
+                        .that(&(
+                            Reg(container).with_bits(start..end, Reg(value)).0,
+                            Reg(container).with_bits(start.., Reg(value)).0,
+                            Reg(container).with_bits(RangeEE(start, end), Reg(value)).0,
+                        ))
+                        .is_equal_to((
+                            container.with_bits(start..end, value),
+                            container.with_bits(start.., value),
+                            container.with_bits(RangeEE(start, end), value),
+                        ));
+                }
+            }
+        }
+    }
 }
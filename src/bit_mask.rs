@@ -1,4 +1,5 @@
-use crate::BitSize;
+use crate::{i256, u256, BitConsts, BitSize, Int, UInt};
+use std::ops::{BitAnd, BitOr, Bound, Not, Range, RangeBounds};
 
 /// Provides bit mask calculation and masking on values.
 ///
@@ -16,12 +17,376 @@ use crate::BitSize;
 /// let value: u32 = 0x1234_5678;
 /// assert_eq!(value.mask_to(12), 0x678);
 /// ```
+///
+/// Only [`mask`](#tymethod.mask), [`mask_to`](#tymethod.mask_to), and
+/// [`mask_range`](#tymethod.mask_range) have to be implemented. The other methods are built on
+/// them for any type with the bitwise operators they need, and the types in this crate override
+/// them with faster versions.
 pub trait BitMask: BitSize {
     /// Returns a mask with the requested number of bits set.
     fn mask(size: usize) -> Self;
 
     /// Masks the value to the requested number of bits.
     fn mask_to(&self, size: usize) -> Self;
+
+    /// Returns a mask with the bits in the requested range set.
+    ///
+    /// Bits of the range that lie past the end of the type are ignored, so a range that starts
+    /// past the last bit produces an empty mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::mask_range(16..20), 0x000f_0000);
+    /// assert_eq!(u8::mask_range(4..), 0xf0);
+    /// ```
+    fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self;
+
+    /// Returns a mask with the bits in any of the requested ranges set.
+    ///
+    /// Overlapping ranges are allowed, and bits past the end of the type are ignored like they are
+    /// for [`mask_range`](#tymethod.mask_range). No ranges produce an empty mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// let writable = u32::mask_union(vec![0..4, 8..12, 31..32]);
+    /// assert_eq!(writable, 0x8000_0f0f);
+    /// ```
+    fn mask_union<I: IntoIterator<Item = R>, R: RangeBounds<usize>>(ranges: I) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        ranges
+            .into_iter()
+            .fold(Self::mask(0), |mask, range| mask | Self::mask_range(range))
+    }
+
+    /// Returns a mask of alternating groups of `period` set bits and `period` clear bits.
+    ///
+    /// The first group of set bits starts at bit 0. When the period doesn't divide the bit size,
+    /// the last group is truncated, so a period of at least the bit size sets every bit. A period
+    /// of 0 produces an empty mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::mask_alternating(1), 0x5555_5555);
+    /// assert_eq!(u32::mask_alternating(4), 0x0f0f_0f0f);
+    /// ```
+    fn mask_alternating(period: usize) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        if period == 0 {
+            return Self::mask(0);
+        }
+
+        (0..Self::BIT_SIZE)
+            .step_by(period.saturating_mul(2))
+            .fold(Self::mask(0), |mask, start| {
+                mask | Self::mask_range(start..start.saturating_add(period))
+            })
+    }
+
+    /// Returns the inverse of [`mask_alternating`](#method.mask_alternating), with the first
+    /// group of clear bits starting at bit 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::mask_alternating_inverted(1), 0xaaaa_aaaa);
+    /// assert_eq!(u32::mask_alternating_inverted(4), 0xf0f0_f0f0);
+    /// ```
+    fn mask_alternating_inverted(period: usize) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        !Self::mask_alternating(period)
+    }
+
+    /// Returns a mask with the requested bits set.
+    ///
+    /// Duplicate indices are allowed, and indices past the end of the type are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::mask_from_indices(vec![3, 7, 19]), 0x0008_0088);
+    /// ```
+    fn mask_from_indices<I: IntoIterator<Item = usize>>(indices: I) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        indices.into_iter().fold(Self::mask(0), |mask, index| {
+            mask | Self::mask_range(index..=index)
+        })
+    }
+
+    /// Returns a mask with the requested bits set, or `None` if any index is past the end of the
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u8::try_mask_from_indices(vec![1, 7]), Some(0x82));
+    /// assert_eq!(u8::try_mask_from_indices(vec![1, 8]), None);
+    /// ```
+    fn try_mask_from_indices<I: IntoIterator<Item = usize>>(indices: I) -> Option<Self>
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>,
+    {
+        indices.into_iter().try_fold(Self::mask(0), |mask, index| {
+            if index < Self::BIT_SIZE {
+                Some(mask | Self::mask_range(index..=index))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns an iterator over the indices of the set bits in the value, from lowest to highest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// let indices: Vec<usize> = 0x0008_0088_u32.indices_of_mask().collect();
+    /// assert_eq!(indices, vec![3, 7, 19]);
+    /// ```
+    fn indices_of_mask(&self) -> MaskIndices<Self>
+    where
+        Self: Sized + Copy,
+    {
+        MaskIndices { remaining: *self }
+    }
+
+    /// Returns the value with only its lowest `n` set bits kept.
+    ///
+    /// All of the set bits are kept if there are fewer than `n` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(0b1011_0110_u8.keep_lowest_set(2), 0b0000_0110);
+    /// ```
+    fn keep_lowest_set(&self, n: usize) -> Self
+    where
+        Self: Sized
+            + Copy
+            + PartialEq
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        (0..Self::BIT_SIZE)
+            .map(|index| Self::mask_range(index..=index))
+            .filter(|&bit| *self & bit != Self::mask(0))
+            .take(n)
+            .fold(Self::mask(0), |kept, bit| kept | bit)
+    }
+
+    /// Returns the value with only its highest `n` set bits kept.
+    ///
+    /// All of the set bits are kept if there are fewer than `n` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(0b1011_0110_u8.keep_highest_set(2), 0b1010_0000);
+    /// ```
+    fn keep_highest_set(&self, n: usize) -> Self
+    where
+        Self: Sized
+            + Copy
+            + PartialEq
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        (0..Self::BIT_SIZE)
+            .rev()
+            .map(|index| Self::mask_range(index..=index))
+            .filter(|&bit| *self & bit != Self::mask(0))
+            .take(n)
+            .fold(Self::mask(0), |kept, bit| kept | bit)
+    }
+
+    /// Rounds the value down to a multiple of `2^bits`.
+    ///
+    /// Aligning to 0 bits returns the value unchanged and aligning to the bit size of the type or
+    /// more returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(0x1234_u32.align_down_bits(8), 0x1200);
+    /// assert_eq!((-5_i32).align_down_bits(2), -8);
+    /// ```
+    fn align_down_bits(&self, bits: usize) -> Self
+    where
+        Self: Sized + Copy + Not<Output = Self> + BitAnd<Output = Self>,
+    {
+        *self & !Self::mask(bits)
+    }
+
+    /// Rounds the value up to a multiple of `2^bits`, returning `None` if the result overflows.
+    ///
+    /// Aligning to 0 bits returns the value unchanged. Aligning to the bit size of the type or
+    /// more leaves 0 as the only representable multiple of `2^bits`, so it rounds negative values
+    /// up to 0 and returns `None` for positive ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(0x1234_u32.align_up_bits(8), Some(0x1300));
+    /// assert_eq!(0xffff_ff01_u32.align_up_bits(8), None);
+    /// assert_eq!((-5_i8).align_up_bits(8), Some(0));
+    /// ```
+    fn align_up_bits(&self, bits: usize) -> Option<Self>
+    where
+        Self: Sized
+            + Copy
+            + PartialOrd
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        if self.is_aligned_bits(bits) {
+            return Some(*self);
+        }
+
+        // Adding one to the value with its low bits filled carries into the lowest clear bit above
+        // them, or out of the type if there isn't one. Either way, the result only overflowed if it
+        // didn't go up.
+        let filled = *self | Self::mask(bits);
+        let aligned = (bits..Self::BIT_SIZE)
+            .find(|&index| filled & Self::mask_range(index..=index) == Self::mask(0))
+            .map_or(Self::mask(0), |index| {
+                filled & !Self::mask(index + 1) | Self::mask_range(index..=index)
+            });
+
+        if aligned > *self {
+            Some(aligned)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the value is a multiple of `2^bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert!(0x1200_u32.is_aligned_bits(8));
+    /// assert!(!0x1234_u32.is_aligned_bits(8));
+    /// ```
+    fn is_aligned_bits(&self, bits: usize) -> bool
+    where
+        Self: Sized + PartialEq,
+    {
+        self.mask_to(bits) == Self::mask(0)
+    }
+
+    /// Returns a mask with the bits of the requested byte set.
+    ///
+    /// Bytes are indexed from the least significant byte, and indices past the end of the type
+    /// produce an empty mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::byte_mask(1), 0x0000_ff00);
+    /// assert_eq!(u32::byte_mask(4), 0);
+    /// ```
+    fn byte_mask(index: usize) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self>,
+    {
+        Self::byte_mask_range(index..index.saturating_add(1))
+    }
+
+    /// Returns a mask with the bits of the requested range of bytes set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(u32::byte_mask_range(1..3), 0x00ff_ff00);
+    /// ```
+    fn byte_mask_range(range: Range<usize>) -> Self
+    where
+        Self: Sized + Not<Output = Self> + BitAnd<Output = Self>,
+    {
+        Self::mask_range(range.start.saturating_mul(8)..range.end.saturating_mul(8))
+    }
+
+    /// Masks the value to the requested number of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMask;
+    ///
+    /// assert_eq!(0x1234_5678_u32.mask_bytes_to(3), 0x0034_5678);
+    /// ```
+    fn mask_bytes_to(&self, size: usize) -> Self
+    where
+        Self: Sized,
+    {
+        self.mask_to(size.saturating_mul(8))
+    }
+}
+
+/// An iterator over the indices of the set bits in a value.
+///
+/// This `struct` is created by the [`indices_of_mask`](trait.BitMask.html#method.indices_of_mask)
+/// method on [`BitMask`](trait.BitMask.html).
+#[derive(Clone, Debug)]
+pub struct MaskIndices<T> {
+    pub(crate) remaining: T,
+}
+
+/// Converts a range of bit indices into its starting index and, if bounded, its length.
+pub(crate) fn range_bounds<Idx: RangeBounds<usize>>(range: &Idx) -> (usize, Option<usize>) {
+    let start = match range.start_bound() {
+        Bound::Excluded(e) => e.saturating_add(1),
+        Bound::Included(i) => *i,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Excluded(e) => Some(*e),
+        Bound::Included(i) => Some(i.saturating_add(1)),
+        Bound::Unbounded => None,
+    };
+
+    (start, end.map(|e| e.saturating_sub(start)))
 }
 
 macro_rules! bit_mask_impl {
@@ -47,7 +412,75 @@ macro_rules! bit_mask_impl {
             fn mask_to(&self, size: usize) -> Self {
                 self & Self::mask(size)
             }
+
+            fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                let (start, length) = range_bounds(&range);
+                Self::mask(length.unwrap_or(Self::BIT_SIZE))
+                    .checked_shl(start as _)
+                    .unwrap_or(0)
+            }
+
+            fn keep_lowest_set(&self, n: usize) -> Self {
+                let mut remaining = *self;
+                let mut kept = 0;
+                for _ in 0..n {
+                    if remaining == 0 {
+                        break;
+                    }
+                    let lowest = remaining & remaining.wrapping_neg();
+                    kept |= lowest;
+                    remaining ^= lowest;
+                }
+                kept
+            }
+
+            fn keep_highest_set(&self, n: usize) -> Self {
+                let mut remaining = *self;
+                let mut kept = 0;
+                for _ in 0..n {
+                    if remaining == 0 {
+                        break;
+                    }
+                    let highest = 1 << (Self::BIT_SIZE - 1 - remaining.leading_zeros() as usize);
+                    kept |= highest;
+                    remaining ^= highest;
+                }
+                kept
+            }
+
+            fn align_up_bits(&self, bits: usize) -> Option<Self> {
+                if self.is_aligned_bits(bits) {
+                    Some(*self)
+                } else {
+                    // Filling a signed value to its full width gives -1, which rounds up to 0, and
+                    // that's only a valid result for negative values.
+                    (self | Self::mask(bits))
+                        .checked_add(1)
+                        .filter(|aligned| aligned > self)
+                }
+            }
+        }
+
+        impl Iterator for MaskIndices<$type> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                if self.remaining == 0 {
+                    None
+                } else {
+                    let index = self.remaining.trailing_zeros() as usize;
+                    self.remaining &= self.remaining.wrapping_sub(1);
+                    Some(index)
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let count = self.remaining.count_ones() as usize;
+                (count, Some(count))
+            }
         }
+
+        impl ExactSizeIterator for MaskIndices<$type> {}
     };
 }
 
@@ -64,7 +497,174 @@ bit_mask_impl!(i64, u64, _);
 bit_mask_impl!(i128, u128, _);
 bit_mask_impl!(isize, usize, _);
 
+// The narrow types work on the bits within their width, as a `u128`, and read the result back in
+// the same way, so bits past the width are dropped from masks and from the values they're applied
+// to. Signed values get the sign filled back in, so a mask covering the whole width is -1, as it is
+// for the signed primitives.
+macro_rules! narrow_bit_mask_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<const BITS: usize> BitMask for $type<BITS> {
+                fn mask(size: usize) -> Self {
+                    Self::from_bits(u128::mask(size))
+                }
+
+                fn mask_to(&self, size: usize) -> Self {
+                    *self & Self::mask(size)
+                }
+
+                fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                    Self::from_bits(u128::mask_range(range))
+                }
+
+                fn mask_union<I: IntoIterator<Item = R>, R: RangeBounds<usize>>(ranges: I) -> Self {
+                    Self::from_bits(u128::mask_union(ranges))
+                }
+
+                fn mask_alternating(period: usize) -> Self {
+                    Self::from_bits(u128::mask_alternating(period))
+                }
+
+                fn mask_from_indices<I: IntoIterator<Item = usize>>(indices: I) -> Self {
+                    Self::from_bits(u128::mask_from_indices(indices))
+                }
+
+                fn keep_lowest_set(&self, n: usize) -> Self {
+                    Self::from_bits(self.to_bits().keep_lowest_set(n))
+                }
+
+                fn keep_highest_set(&self, n: usize) -> Self {
+                    Self::from_bits(self.to_bits().keep_highest_set(n))
+                }
+
+                fn align_up_bits(&self, bits: usize) -> Option<Self> {
+                    if self.is_aligned_bits(bits) {
+                        Some(*self)
+                    } else {
+                        (*self | Self::mask(bits))
+                            .checked_add(Self::from_bits(1))
+                            .filter(|aligned| aligned > self)
+                    }
+                }
+            }
+
+            impl<const BITS: usize> Iterator for MaskIndices<$type<BITS>> {
+                type Item = usize;
+
+                fn next(&mut self) -> Option<usize> {
+                    let bits = self.remaining.to_bits();
+
+                    if bits == 0 {
+                        None
+                    } else {
+                        self.remaining = $type::from_bits(bits & (bits - 1));
+                        Some(bits.trailing_zeros() as usize)
+                    }
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let count = self.remaining.to_bits().count_ones() as usize;
+                    (count, Some(count))
+                }
+            }
+
+            impl<const BITS: usize> ExactSizeIterator for MaskIndices<$type<BITS>> {}
+        )*
+    };
+}
+
+narrow_bit_mask_impl!(UInt, Int);
+
+// The 256-bit types build their masks from shifts across the whole width, so a mask can cover
+// any range of limbs.
+macro_rules! wide_bit_mask_impl {
+    ($($type:ident),*) => {
+        $(
+            impl BitMask for $type {
+                fn mask(size: usize) -> Self {
+                    if size < 256 {
+                        ($type::ONE << size).wrapping_sub($type::ONE)
+                    } else {
+                        Self::ALL_SET
+                    }
+                }
+
+                fn mask_to(&self, size: usize) -> Self {
+                    *self & Self::mask(size)
+                }
+
+                fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                    let (start, length) = range_bounds(&range);
+                    Self::mask(length.unwrap_or(256)) << start
+                }
+
+                fn keep_lowest_set(&self, n: usize) -> Self {
+                    let mut remaining = *self;
+                    let mut kept = Self::NONE_SET;
+                    for _ in 0..n {
+                        if remaining == Self::NONE_SET {
+                            break;
+                        }
+                        let lowest = remaining & remaining.wrapping_neg();
+                        kept |= lowest;
+                        remaining ^= lowest;
+                    }
+                    kept
+                }
+
+                fn keep_highest_set(&self, n: usize) -> Self {
+                    let mut remaining = *self;
+                    let mut kept = Self::NONE_SET;
+                    for _ in 0..n {
+                        if remaining == Self::NONE_SET {
+                            break;
+                        }
+                        let highest = $type::ONE << (255 - remaining.leading_zeros());
+                        kept |= highest;
+                        remaining ^= highest;
+                    }
+                    kept
+                }
+
+                fn align_up_bits(&self, bits: usize) -> Option<Self> {
+                    if self.is_aligned_bits(bits) {
+                        Some(*self)
+                    } else {
+                        (*self | Self::mask(bits))
+                            .checked_add($type::ONE)
+                            .filter(|aligned| aligned > self)
+                    }
+                }
+            }
+
+            impl Iterator for MaskIndices<$type> {
+                type Item = usize;
+
+                fn next(&mut self) -> Option<usize> {
+                    if self.remaining == $type::NONE_SET {
+                        None
+                    } else {
+                        let index = self.remaining.trailing_zeros() as usize;
+                        self.remaining &= self.remaining.wrapping_sub($type::ONE);
+                        Some(index)
+                    }
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let count = self.remaining.count_ones() as usize;
+                    (count, Some(count))
+                }
+            }
+
+            impl ExactSizeIterator for MaskIndices<$type> {}
+        )*
+    };
+}
+
+wide_bit_mask_impl!(u256, i256);
+
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod test {
     use super::*;
     use spectral::prelude::*;
@@ -99,10 +699,779 @@ mod test {
             .is_equal_to(204);
     }
 
+    #[test]
+    fn calculating_range_masks() {
+        asserting!("range masks are positioned at the start of the range")
+            .that(&[
+                u32::mask_range(0..4),
+                u32::mask_range(8..12),
+                u32::mask_range(4..=7),
+            ])
+            .is_equal_to([0x0000_000f, 0x0000_0f00, 0x0000_00f0]);
+
+        asserting!("unbounded range masks extend to the end of the type")
+            .that(&(u16::mask_range(12..), u16::mask_range(..)))
+            .is_equal_to((0xf000, 0xffff));
+
+        asserting!("range masks are empty for empty ranges")
+            .that(&u32::mask_range(8..8))
+            .is_equal_to(0);
+
+        asserting!("range masks ignore bits past the end of the type")
+            .that(&[u8::mask_range(4..12), u8::mask_range(8..16)])
+            .is_equal_to([0xf0, 0x00]);
+
+        asserting!("signed range masks have the same bits as unsigned range masks")
+            .that(&i8::mask_range(4..8))
+            .is_equal_to(0xf0_u8 as i8);
+    }
+
+    #[test]
+    fn calculating_union_masks() {
+        asserting!("union masks are the union of the range masks")
+            .that(&u32::mask_union(vec![0..4, 8..12, 31..32]))
+            .is_equal_to(u32::mask_range(0..4) | u32::mask_range(8..12) | u32::mask_range(31..32));
+
+        asserting!("union masks accept any kind of range")
+            .that(&u16::mask_union(vec![
+                (Bound::Included(0), Bound::Excluded(4)),
+                (Bound::Excluded(7), Bound::Included(11)),
+                (Bound::Included(14), Bound::Unbounded),
+            ]))
+            .is_equal_to(0xcf0f);
+
+        asserting!("union masks of overlapping ranges combine the ranges")
+            .that(&u32::mask_union(vec![0..8, 4..12, 6..7]))
+            .is_equal_to(u32::mask_range(0..12));
+
+        asserting!("union masks ignore bits past the end of the type")
+            .that(&u8::mask_union(vec![4..12, 16..20]))
+            .is_equal_to(0xf0);
+
+        asserting!("union masks of no ranges are empty")
+            .that(&u32::mask_union(Vec::<Range<usize>>::new()))
+            .is_equal_to(0);
+
+        asserting!("signed union masks have the same bits as unsigned union masks")
+            .that(&i8::mask_union(vec![0..2, 6..8]))
+            .is_equal_to(0xc3_u8 as i8);
+    }
+
+    #[test]
+    fn calculating_alternating_masks() {
+        asserting!("alternating masks match the well-known constants")
+            .that(&[
+                u8::mask_alternating(1),
+                u8::mask_alternating(2),
+                u8::mask_alternating(4),
+            ])
+            .is_equal_to([0x55, 0x33, 0x0f]);
+        asserting!("alternating masks match the well-known constants")
+            .that(&[
+                u16::mask_alternating(1),
+                u16::mask_alternating(2),
+                u16::mask_alternating(4),
+                u16::mask_alternating(8),
+            ])
+            .is_equal_to([0x5555, 0x3333, 0x0f0f, 0x00ff]);
+        asserting!("alternating masks match the well-known constants")
+            .that(&[
+                u32::mask_alternating(1),
+                u32::mask_alternating(2),
+                u32::mask_alternating(4),
+                u32::mask_alternating(8),
+                u32::mask_alternating(16),
+            ])
+            .is_equal_to([
+                0x5555_5555,
+                0x3333_3333,
+                0x0f0f_0f0f,
+                0x00ff_00ff,
+                0x0000_ffff,
+            ]);
+        asserting!("alternating masks match the well-known constants")
+            .that(&[
+                u64::mask_alternating(1),
+                u64::mask_alternating(4),
+                u64::mask_alternating(32),
+            ])
+            .is_equal_to([
+                0x5555_5555_5555_5555,
+                0x0f0f_0f0f_0f0f_0f0f,
+                0x0000_0000_ffff_ffff,
+            ]);
+        asserting!("alternating masks match the well-known constants")
+            .that(&[
+                u128::mask_alternating(1),
+                u128::mask_alternating(8),
+                u128::mask_alternating(64),
+            ])
+            .is_equal_to([
+                0x5555_5555_5555_5555_5555_5555_5555_5555,
+                0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff,
+                0x0000_0000_0000_0000_ffff_ffff_ffff_ffff,
+            ]);
+
+        asserting!("alternating masks truncate the last group")
+            .that(&[u8::mask_alternating(3), u8::mask_alternating(6)])
+            .is_equal_to([0xc7, 0x3f]);
+        asserting!("alternating masks truncate the last group")
+            .that(&u16::mask_alternating(5))
+            .is_equal_to(0b0111_1100_0001_1111);
+
+        asserting!("alternating masks with a period of at least the bit size are full")
+            .that(&[
+                u8::mask_alternating(8),
+                u8::mask_alternating(9),
+                u8::mask_alternating(usize::MAX),
+            ])
+            .is_equal_to([0xff, 0xff, 0xff]);
+
+        asserting!("alternating masks with a period of 0 are empty")
+            .that(&u32::mask_alternating(0))
+            .is_equal_to(0);
+
+        asserting!("signed alternating masks have the same bits as unsigned alternating masks")
+            .that(&i16::mask_alternating(4))
+            .is_equal_to(0x0f0f);
+    }
+
+    #[test]
+    fn calculating_inverted_alternating_masks() {
+        asserting!("inverted alternating masks start with clear bits")
+            .that(&[
+                u8::mask_alternating_inverted(1),
+                u8::mask_alternating_inverted(2),
+                u8::mask_alternating_inverted(4),
+            ])
+            .is_equal_to([0xaa, 0xcc, 0xf0]);
+        asserting!("inverted alternating masks start with clear bits")
+            .that(&u64::mask_alternating_inverted(16))
+            .is_equal_to(0xffff_0000_ffff_0000);
+
+        asserting!("inverted alternating masks with a period of 0 are full")
+            .that(&u32::mask_alternating_inverted(0))
+            .is_equal_to(u32::MAX);
+
+        asserting!("signed inverted alternating masks have the same bits as unsigned ones")
+            .that(&i8::mask_alternating_inverted(1))
+            .is_equal_to(0xaa_u8 as i8);
+    }
+
+    #[test]
+    fn calculating_masks_from_indices() {
+        asserting!("index masks have the requested bits set")
+            .that(&u32::mask_from_indices(vec![3, 7, 19]))
+            .is_equal_to(1 << 3 | 1 << 7 | 1 << 19);
+
+        asserting!("index masks of no indices are empty")
+            .that(&u32::mask_from_indices(vec![]))
+            .is_equal_to(0);
+
+        asserting!("index masks ignore duplicate indices")
+            .that(&u8::mask_from_indices(vec![1, 1, 4, 1, 4]))
+            .is_equal_to(0x12);
+
+        asserting!("index masks ignore indices past the end of the type")
+            .that(&u8::mask_from_indices(vec![0, 8, 7, usize::MAX]))
+            .is_equal_to(0x81);
+
+        asserting!("signed index masks have the same bits as unsigned index masks")
+            .that(&i8::mask_from_indices(vec![0, 7]))
+            .is_equal_to(0x81_u8 as i8);
+    }
+
+    #[test]
+    fn trying_masks_from_indices() {
+        asserting!("checked index masks have the requested bits set")
+            .that(&u32::try_mask_from_indices(vec![3, 7, 19, 7]))
+            .is_equal_to(Some(0x0008_0088));
+
+        asserting!("checked index masks of no indices are empty")
+            .that(&u32::try_mask_from_indices(vec![]))
+            .is_equal_to(Some(0));
+
+        asserting!("checked index masks reject indices past the end of the type")
+            .that(&[
+                u8::try_mask_from_indices(vec![0, 8]),
+                u8::try_mask_from_indices(vec![usize::MAX]),
+            ])
+            .is_equal_to([None, None]);
+    }
+
+    #[test]
+    fn iterating_mask_indices() {
+        asserting!("mask indices are the set bits from lowest to highest")
+            .that(&0x8000_0f01_u32.indices_of_mask().collect::<Vec<_>>())
+            .is_equal_to(vec![0, 8, 9, 10, 11, 31]);
+
+        asserting!("empty masks have no indices")
+            .that(&0_u64.indices_of_mask().next())
+            .is_equal_to(None);
+
+        asserting!("mask indices of signed values include the sign bit")
+            .that(&(-128_i8).indices_of_mask().collect::<Vec<_>>())
+            .is_equal_to(vec![7]);
+        asserting!("mask indices of signed values include the sign bit")
+            .that(&(-1_i16).indices_of_mask().count())
+            .is_equal_to(16);
+
+        asserting!("mask indices know how many indices remain")
+            .that(&0x8000_0f01_u32.indices_of_mask().skip(2).len())
+            .is_equal_to(4);
+
+        let indices = vec![1, 5, 63, 64, 100, 127];
+        asserting!("mask indices round-trip with index masks")
+            .that(
+                &u128::mask_from_indices(indices.clone())
+                    .indices_of_mask()
+                    .collect::<Vec<_>>(),
+            )
+            .is_equal_to(indices);
+    }
+
+    #[test]
+    fn keeping_lowest_set_bits() {
+        asserting!("keeping the lowest set bits clears the higher set bits")
+            .that(&[
+                0b1011_0110_u8.keep_lowest_set(1),
+                0b1011_0110_u8.keep_lowest_set(3),
+            ])
+            .is_equal_to([0b0000_0010, 0b0001_0110]);
+
+        asserting!("keeping no set bits returns 0")
+            .that(&0b1011_0110_u8.keep_lowest_set(0))
+            .is_equal_to(0);
+
+        asserting!("keeping more set bits than are set returns the value")
+            .that(&[
+                0b1011_0110_u8.keep_lowest_set(5),
+                0b1011_0110_u8.keep_lowest_set(usize::MAX),
+            ])
+            .is_equal_to([0b1011_0110, 0b1011_0110]);
+
+        asserting!("keeping the lowest set bits of all ones keeps a low mask")
+            .that(&(u32::MAX.keep_lowest_set(12), u128::MAX.keep_lowest_set(100)))
+            .is_equal_to((u32::mask(12), u128::mask(100)));
+
+        asserting!("keeping the lowest set bits of signed values includes the sign bit")
+            .that(&[
+                (-1_i8).keep_lowest_set(3),
+                (-128_i8).keep_lowest_set(1),
+                (-1_i8).keep_lowest_set(8),
+            ])
+            .is_equal_to([0x07, -128, -1]);
+    }
+
+    #[test]
+    fn keeping_highest_set_bits() {
+        asserting!("keeping the highest set bits clears the lower set bits")
+            .that(&[
+                0b1011_0110_u8.keep_highest_set(1),
+                0b1011_0110_u8.keep_highest_set(3),
+            ])
+            .is_equal_to([0b1000_0000, 0b1011_0000]);
+
+        asserting!("keeping no set bits returns 0")
+            .that(&0b1011_0110_u8.keep_highest_set(0))
+            .is_equal_to(0);
+
+        asserting!("keeping more set bits than are set returns the value")
+            .that(&[
+                0b1011_0110_u8.keep_highest_set(5),
+                0b1011_0110_u8.keep_highest_set(usize::MAX),
+            ])
+            .is_equal_to([0b1011_0110, 0b1011_0110]);
+
+        asserting!("keeping the highest set bits of all ones keeps a high mask")
+            .that(&(
+                u32::MAX.keep_highest_set(12),
+                u128::MAX.keep_highest_set(100),
+            ))
+            .is_equal_to((u32::mask_range(20..), u128::mask_range(28..)));
+
+        asserting!("keeping the highest set bits of signed values starts at the sign bit")
+            .that(&[
+                (-1_i8).keep_highest_set(3),
+                0x7f_i8.keep_highest_set(1),
+                (-1_i8).keep_highest_set(8),
+            ])
+            .is_equal_to([0xe0_u8 as i8, 0x40, -1]);
+    }
+
+    #[test]
+    fn calculating_byte_masks() {
+        asserting!("byte masks cover the requested byte")
+            .that(&[u16::byte_mask(0), u16::byte_mask(1)])
+            .is_equal_to([0x00ff, 0xff00]);
+        asserting!("byte masks cover the requested byte")
+            .that(&[u32::byte_mask(0), u32::byte_mask(1), u32::byte_mask(3)])
+            .is_equal_to([0x0000_00ff, 0x0000_ff00, 0xff00_0000]);
+        asserting!("byte masks cover the requested byte")
+            .that(&[u64::byte_mask(4), u64::byte_mask(7)])
+            .is_equal_to([0x0000_00ff_0000_0000, 0xff00_0000_0000_0000]);
+        asserting!("byte masks cover the requested byte")
+            .that(&[u128::byte_mask(8), u128::byte_mask(15)])
+            .is_equal_to([0xff << 64, 0xff << 120]);
+
+        asserting!("byte masks past the end of the type are empty")
+            .that(&(u16::byte_mask(2), u32::byte_mask(4), u64::byte_mask(8)))
+            .is_equal_to((0, 0, 0));
+        asserting!("byte masks past the end of the type are empty")
+            .that(&u128::byte_mask(usize::MAX))
+            .is_equal_to(0);
+
+        asserting!("signed byte masks have the same bits as unsigned byte masks")
+            .that(&i16::byte_mask(1))
+            .is_equal_to(0xff00_u16 as i16);
+    }
+
+    #[test]
+    fn calculating_byte_range_masks() {
+        asserting!("byte range masks cover the requested bytes")
+            .that(&[
+                u32::byte_mask_range(1..3),
+                u32::byte_mask_range(0..4),
+                u32::byte_mask_range(2..2),
+            ])
+            .is_equal_to([0x00ff_ff00, 0xffff_ffff, 0x0000_0000]);
+
+        asserting!("byte range masks ignore bytes past the end of the type")
+            .that(&(u16::byte_mask_range(1..4), u64::byte_mask_range(6..10)))
+            .is_equal_to((0xff00, 0xffff_0000_0000_0000));
+
+        asserting!("byte range masks match the byte masks they cover")
+            .that(&u128::byte_mask_range(3..6))
+            .is_equal_to(u128::byte_mask(3) | u128::byte_mask(4) | u128::byte_mask(5));
+    }
+
+    #[test]
+    fn masking_values_to_bytes() {
+        asserting!("masking to 0 bytes returns 0")
+            .that(&0x1234_u16.mask_bytes_to(0))
+            .is_equal_to(0);
+
+        asserting!("masking to a number of bytes truncates the value")
+            .that(&(
+                0x1234_u16.mask_bytes_to(1),
+                0x1234_5678_u32.mask_bytes_to(2),
+            ))
+            .is_equal_to((0x34, 0x5678));
+        asserting!("masking to a number of bytes truncates the value")
+            .that(&(u64::MAX.mask_bytes_to(5), u128::MAX.mask_bytes_to(9)))
+            .is_equal_to((0xff_ffff_ffff, u128::MAX >> 56));
+
+        asserting!("masking to more bytes than the byte length returns the number")
+            .that(&(
+                0x1234_u16.mask_bytes_to(3),
+                u32::MAX.mask_bytes_to(usize::MAX),
+            ))
+            .is_equal_to((0x1234, u32::MAX));
+    }
+
+    #[test]
+    fn aligning_values() {
+        asserting!("aligning down clears the low bits")
+            .that(&[0x1234_u16.align_down_bits(4), 0x1234_u16.align_down_bits(8)])
+            .is_equal_to([0x1230, 0x1200]);
+
+        asserting!("aligning up rounds to the next multiple")
+            .that(&[0x1234_u16.align_up_bits(4), 0x1234_u16.align_up_bits(8)])
+            .is_equal_to([Some(0x1240), Some(0x1300)]);
+
+        asserting!("aligning aligned values returns the value")
+            .that(&(0x1200_u16.align_down_bits(8), 0x1200_u16.align_up_bits(8)))
+            .is_equal_to((0x1200, Some(0x1200)));
+
+        asserting!("aligning to 0 bits returns the value")
+            .that(&(0x1234_u16.align_down_bits(0), 0x1234_u16.align_up_bits(0)))
+            .is_equal_to((0x1234, Some(0x1234)));
+
+        asserting!("aligning up just below the maximum overflows")
+            .that(&[
+                u8::MAX.align_up_bits(1),
+                0xf1_u8.align_up_bits(4),
+                0xfe_u8.align_up_bits(2),
+            ])
+            .is_equal_to([None, None, None]);
+
+        asserting!("aligning up to the top aligned value just below the maximum succeeds")
+            .that(&[
+                0xef_u8.align_up_bits(4),
+                0xf0_u8.align_up_bits(4),
+                u8::MAX.align_up_bits(0),
+            ])
+            .is_equal_to([Some(0xf0), Some(0xf0), Some(0xff)]);
+
+        asserting!("aligning to the bit length or more only keeps 0")
+            .that(&[0x12_u8.align_down_bits(8), 0x12_u8.align_down_bits(9)])
+            .is_equal_to([0, 0]);
+        asserting!("aligning up to the bit length or more only succeeds for 0")
+            .that(&[
+                0x12_u8.align_up_bits(8),
+                0x00_u8.align_up_bits(8),
+                0x00_u8.align_up_bits(9),
+            ])
+            .is_equal_to([None, Some(0), Some(0)]);
+
+        asserting!("checking alignment looks at the low bits")
+            .that(&[
+                0x1200_u16.is_aligned_bits(8),
+                0x1234_u16.is_aligned_bits(2),
+                0x1234_u16.is_aligned_bits(3),
+            ])
+            .is_equal_to([true, true, false]);
+        asserting!("only 0 is aligned to the bit length or more")
+            .that(&[
+                0x8000_u16.is_aligned_bits(16),
+                0_u16.is_aligned_bits(16),
+                0_u16.is_aligned_bits(17),
+            ])
+            .is_equal_to([false, true, true]);
+    }
+
+    #[test]
+    fn aligning_signed_values() {
+        asserting!("aligning negative values rounds toward the same direction as positive values")
+            .that(&[
+                (-5_i8).align_down_bits(2),
+                (-5_i8).align_up_bits(2).unwrap(),
+            ])
+            .is_equal_to([-8, -4]);
+
+        asserting!("aligning up just below the signed maximum overflows")
+            .that(&[i8::MAX.align_up_bits(2), 0x71_i8.align_up_bits(4)])
+            .is_equal_to([None, None]);
+
+        asserting!("aligning the signed minimum succeeds")
+            .that(&(i8::MIN.align_down_bits(4), i8::MIN.align_up_bits(4)))
+            .is_equal_to((i8::MIN, Some(i8::MIN)));
+
+        asserting!("aligning up to the bit length or more rounds only negative values up to 0")
+            .that(&[
+                (-5_i8).align_up_bits(8),
+                i8::MIN.align_up_bits(9),
+                5_i8.align_up_bits(8),
+                i8::MAX.align_up_bits(9),
+                0_i8.align_up_bits(8),
+            ])
+            .is_equal_to([Some(0), Some(0), None, None, Some(0)]);
+    }
+
+    #[test]
+    fn provided_methods_match_primitives() {
+        // Only `mask`, `mask_to`, and `mask_range` are implemented, so everything else uses the
+        // provided methods.
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct Reg(i16);
+
+        impl BitSize for Reg {
+            const BIT_SIZE: usize = 16;
+        }
+
+        impl BitMask for Reg {
+            fn mask(size: usize) -> Self {
+                Reg(i16::mask(size))
+            }
+
+            fn mask_to(&self, size: usize) -> Self {
+                Reg(self.0.mask_to(size))
+            }
+
+            fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                Reg(i16::mask_range(range))
+            }
+        }
+
+        impl Not for Reg {
+            type Output = Reg;
+
+            fn not(self) -> Reg {
+                Reg(!self.0)
+            }
+        }
+
+        impl BitAnd for Reg {
+            type Output = Reg;
+
+            fn bitand(self, rhs: Reg) -> Reg {
+                Reg(self.0 & rhs.0)
+            }
+        }
+
+        impl BitOr for Reg {
+            type Output = Reg;
+
+            fn bitor(self, rhs: Reg) -> Reg {
+                Reg(self.0 | rhs.0)
+            }
+        }
+
+        for size in 0..20 {
+            asserting!("masks match the primitive")
+                .that(&(
+                    Reg::mask_range(size..size + 3).0,
+                    Reg::mask_range(size..).0,
+                    Reg::mask_union(vec![0..2, size..size + 5]).0,
+                    Reg::mask_alternating(size).0,
+                    Reg::mask_alternating_inverted(size).0,
+                    Reg::mask_from_indices(vec![1, size]).0,
+                    Reg::try_mask_from_indices(vec![1, size]).map(|mask| mask.0),
+                    Reg::byte_mask(size).0,
+                ))
+                .is_equal_to((
+                    i16::mask_range(size..size + 3),
+                    i16::mask_range(size..),
+                    i16::mask_union(vec![0..2, size..size + 5]),
+                    i16::mask_alternating(size),
+                    i16::mask_alternating_inverted(size),
+                    i16::mask_from_indices(vec![1, size]),
+                    i16::try_mask_from_indices(vec![1, size]),
+                    i16::byte_mask(size),
+                ));
+        }
+
+        for &value in &[0, 1, 5, -5, 0x1234, -0x1234, 0x7ff1, i16::MAX, i16::MIN, -1] {
+            for bits in 0..20 {
+                let reg = Reg(value);
+
+                asserting!("masking values matches the primitive")
+                    .that(&(
+                        reg.keep_lowest_set(bits).0,
+                        reg.keep_highest_set(bits).0,
+                        reg.align_down_bits(bits).0,
+                        reg.align_up_bits(bits).map(|aligned| aligned.0),
+                        reg.is_aligned_bits(bits),
+                        reg.mask_bytes_to(bits).0,
+                    ))
+                    .is_equal_to((
+                        value.keep_lowest_set(bits),
+                        value.keep_highest_set(bits),
+                        value.align_down_bits(bits),
+                        value.align_up_bits(bits),
+                        value.is_aligned_bits(bits),
+                        value.mask_bytes_to(bits),
+                    ));
+            }
+        }
+    }
+
     #[test]
     fn signed_masks() {
         asserting!("signed masks are the same as unsigned masks")
             .that(&(u8::mask(4), i8::mask(4)))
             .is_equal_to((0x0f, 0x0f));
     }
+
+    #[test]
+    fn narrow_masks() {
+        asserting!("masks stop at the width of the type")
+            .that(&(
+                UInt::<12>::mask(4).get(),
+                UInt::<12>::mask(20),
+                UInt::<12>::mask_range(8..16).get(),
+                UInt::<12>::mask_alternating(4).get(),
+                UInt::<12>::try_mask_from_indices(vec![0, 12]),
+            ))
+            .is_equal_to((0xf, UInt::MAX, 0xf00, 0x0f0f, None));
+
+        asserting!("signed masks covering the top bit are negative")
+            .that(&(
+                Int::<12>::mask(4).get(),
+                Int::<12>::mask(12).get(),
+                Int::<12>::mask_range(8..16).get(),
+                Int::<12>::mask_range(11..).get(),
+            ))
+            .is_equal_to((0xf, -1, -0x100, -0x800));
+
+        asserting!("mask indices stop at the width of the type")
+            .that(&Int::<12>::new(-2).indices_of_mask().collect::<Vec<_>>())
+            .is_equal_to((1..12).collect::<Vec<_>>());
+
+        asserting!("aligning up past the maximum fails")
+            .that(&(
+                UInt::<12>::new(0xff1).align_up_bits(4),
+                UInt::<12>::new(0xef1).align_up_bits(4),
+                Int::<12>::new(0x7f1).align_up_bits(4),
+            ))
+            .is_equal_to((None, Some(UInt::new(0xf00)), None));
+
+        asserting!("aligning up to the width rounds only negative values up to 0")
+            .that(&(
+                Int::<12>::new(-5).align_up_bits(12),
+                Int::<12>::new(5).align_up_bits(12),
+            ))
+            .is_equal_to((Some(Int::new(0)), None));
+    }
+
+    #[test]
+    fn narrow_masks_match_primitives() {
+        macro_rules! check {
+            ($narrow:ty, $prim:ty) => {
+                for size in 0..12 {
+                    asserting!("masks match")
+                        .that(&(
+                            <$narrow>::mask(size).get() as $prim,
+                            <$narrow>::mask_range(size..size + 3).get() as $prim,
+                            <$narrow>::mask_alternating(size).get() as $prim,
+                            <$narrow>::byte_mask(size).get() as $prim,
+                        ))
+                        .is_equal_to((
+                            <$prim>::mask(size),
+                            <$prim>::mask_range(size..size + 3),
+                            <$prim>::mask_alternating(size),
+                            <$prim>::byte_mask(size),
+                        ));
+                }
+
+                for value in <$prim>::MIN..=<$prim>::MAX {
+                    let narrow = <$narrow>::new(value as _);
+
+                    for n in [0, 1, 3, 7, 8, 9] {
+                        asserting!("masking values matches")
+                            .that(&(
+                                narrow.mask_to(n).get() as $prim,
+                                narrow.keep_lowest_set(n).get() as $prim,
+                                narrow.keep_highest_set(n).get() as $prim,
+                                narrow.align_down_bits(n).get() as $prim,
+                                narrow.align_up_bits(n).map(|value| value.get() as $prim),
+                            ))
+                            .is_equal_to((
+                                value.mask_to(n),
+                                value.keep_lowest_set(n),
+                                value.keep_highest_set(n),
+                                value.align_down_bits(n),
+                                value.align_up_bits(n),
+                            ));
+                    }
+
+                    asserting!("mask indices match")
+                        .that(&narrow.indices_of_mask().collect::<Vec<_>>())
+                        .is_equal_to(value.indices_of_mask().collect::<Vec<_>>());
+                }
+            };
+        }
+
+        check!(UInt<8>, u8);
+        check!(Int<8>, i8);
+    }
+
+    #[test]
+    fn wide_masks_cross_limbs() {
+        asserting!("masks fill across limb boundaries")
+            .that(&(
+                u256::mask(130).to_limbs(),
+                u256::mask(300),
+                u256::mask_range(60..70).to_limbs(),
+                u256::mask_union(vec![0..1, 127..129]).to_limbs(),
+                u256::mask_alternating(64).to_limbs(),
+                u256::mask_from_indices(vec![63, 64, 255]).to_limbs(),
+                u256::try_mask_from_indices(vec![256]),
+                u256::byte_mask_range(7..9).to_limbs(),
+            ))
+            .is_equal_to((
+                [!0, !0, 3, 0],
+                u256::MAX,
+                [0xf << 60, 0x3f, 0, 0],
+                [1, 1 << 63, 1, 0],
+                [!0, 0, !0, 0],
+                [1 << 63, 1, 0, 1 << 63],
+                None,
+                [0xff << 56, 0xff, 0, 0],
+            ));
+
+        asserting!("signed masks covering the top bit are negative")
+            .that(&(i256::mask(256), i256::mask_range(255..), i256::mask(255)))
+            .is_equal_to((i256::from(-1), i256::MIN, i256::MAX));
+
+        let value = u256::from_limbs([0b1011 << 60, 0b110, 0, 1 << 63]);
+
+        asserting!("masking values works across limbs")
+            .that(&(
+                value.mask_to(66).to_limbs(),
+                value.keep_lowest_set(3).to_limbs(),
+                value.keep_highest_set(3).to_limbs(),
+                value.align_down_bits(64).to_limbs(),
+                value.align_up_bits(128).map(u256::to_limbs),
+                value.align_up_bits(255),
+                value.is_aligned_bits(60),
+                value.mask_bytes_to(9).to_limbs(),
+            ))
+            .is_equal_to((
+                [0b1011 << 60, 0b10, 0, 0],
+                [0b1011 << 60, 0, 0, 0],
+                [0, 0b110, 0, 1 << 63],
+                [0, 0b110, 0, 1 << 63],
+                Some([0, 0, 1, 1 << 63]),
+                None,
+                true,
+                [0b1011 << 60, 0b110, 0, 0],
+            ));
+
+        asserting!("mask indices cross limbs")
+            .that(&value.indices_of_mask().collect::<Vec<_>>())
+            .is_equal_to(vec![60, 61, 63, 65, 66, 255]);
+
+        asserting!("signed mask indices include the sign bit")
+            .that(&i256::MIN.indices_of_mask().len())
+            .is_equal_to(1);
+
+        asserting!("aligning up to the full width rounds only negative values up to 0")
+            .that(&(
+                i256::from(-5).align_up_bits(256),
+                i256::from(5).align_up_bits(256),
+            ))
+            .is_equal_to((Some(i256::ZERO), None));
+    }
+
+    #[test]
+    fn wide_masks_match_primitives() {
+        for size in 0..=128 {
+            asserting!("masks match")
+                .that(&(
+                    u256::mask(size),
+                    u256::mask_range(size / 2..size),
+                    u256::mask_alternating(size.max(1)).mask_to(128),
+                ))
+                .is_equal_to((
+                    u256::from(u128::mask(size)),
+                    u256::from(u128::mask_range(size / 2..size)),
+                    u256::from(u128::mask_alternating(size.max(1))),
+                ));
+        }
+
+        let values = [
+            0,
+            1,
+            0x8000_0001,
+            u128::MAX,
+            0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        ];
+
+        for &value in values.iter() {
+            let wide = u256::from(value);
+
+            for n in [0, 1, 3, 8, 63, 64, 65, 100] {
+                asserting!("masking values matches")
+                    .that(&(
+                        wide.mask_to(n),
+                        wide.keep_lowest_set(n),
+                        wide.keep_highest_set(n),
+                        wide.align_down_bits(n),
+                        wide.is_aligned_bits(n),
+                    ))
+                    .is_equal_to((
+                        u256::from(value.mask_to(n)),
+                        u256::from(value.keep_lowest_set(n)),
+                        u256::from(value.keep_highest_set(n)),
+                        u256::from(value.align_down_bits(n)),
+                        value.is_aligned_bits(n),
+                    ));
+            }
+
+            asserting!("mask indices match")
+                .that(&wide.indices_of_mask().collect::<Vec<_>>())
+                .is_equal_to(value.indices_of_mask().collect::<Vec<_>>());
+        }
+    }
 }
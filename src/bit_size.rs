@@ -11,9 +11,68 @@
 /// assert_eq!(u8::BIT_SIZE, 8);
 /// assert_eq!(u128::BIT_SIZE, 128);
 /// ```
+///
+/// Arrays and tuples (of up to four elements) report the combined size of their elements, which
+/// makes it possible to describe values wider than any primitive:
+///
+/// ```
+/// use quark::BitSize;
+///
+/// assert_eq!(<[u64; 4]>::BIT_SIZE, 256);
+/// assert_eq!(<(u32, u32)>::BIT_SIZE, 64);
+/// ```
+///
+/// Sizes are counted in bits rather than measured in memory, so the byte size of a tuple doesn't
+/// include any padding between its elements.
+///
+/// The derived constants make it easy to write code that adapts to the size of the type, like
+/// formatting a value with one hex digit for every four bits:
+///
+/// ```
+/// use quark::BitSize;
+/// use std::fmt::LowerHex;
+///
+/// fn hex<T: BitSize + LowerHex>(value: T) -> String {
+///     format!("{:0width$x}", value, width = T::BYTE_SIZE * 2)
+/// }
+///
+/// assert_eq!(hex(0x1f_u8), "1f");
+/// assert_eq!(hex(0x1f_u32), "0000001f");
+/// assert_eq!(hex(-1_i16), "ffff");
+/// ```
 pub trait BitSize {
     /// The size of the type in bits.
     const BIT_SIZE: usize;
+
+    /// The size of the type in bytes, rounded up to a whole byte.
+    const BYTE_SIZE: usize = Self::BIT_SIZE.div_ceil(8);
+
+    /// The index of the highest bit in the type.
+    const MAX_BIT_INDEX: usize = Self::BIT_SIZE - 1;
+}
+
+/// Provides constants for the values with every bit set and with no bits set.
+///
+/// These are kept out of [`BitSize`](trait.BitSize.html) so that implementing it still only takes
+/// the size of the type. Every type in this crate that implements `BitSize` implements this too.
+///
+/// # Examples
+///
+/// ```
+/// use quark::BitConsts;
+///
+/// assert_eq!(u8::ALL_SET, 0xff);
+/// assert_eq!(i16::ALL_SET, -1);
+/// assert_eq!(<[u8; 2]>::NONE_SET, [0, 0]);
+/// ```
+pub trait BitConsts: BitSize {
+    /// A value with every bit set.
+    ///
+    /// This is the maximum value for unsigned types and -1 for signed ones.
+    const ALL_SET: Self;
+
+    /// A value with no bits set.
+    const NONE_SET: Self;
 }
 
 macro_rules! bit_size_impl {
@@ -21,6 +80,11 @@ macro_rules! bit_size_impl {
         impl BitSize for $type {
             const BIT_SIZE: usize = $sz;
         }
+
+        impl BitConsts for $type {
+            const ALL_SET: Self = !0;
+            const NONE_SET: Self = 0;
+        }
     };
 }
 
@@ -42,3 +106,116 @@ bit_size_impl!(i128, 128);
 bit_size_impl!(isize, 32);
 #[cfg(target_pointer_width = "64")]
 bit_size_impl!(isize, 64);
+
+impl<T: BitSize, const N: usize> BitSize for [T; N] {
+    const BIT_SIZE: usize = T::BIT_SIZE * N;
+}
+
+impl<T: BitConsts + Copy, const N: usize> BitConsts for [T; N] {
+    const ALL_SET: Self = [T::ALL_SET; N];
+    const NONE_SET: Self = [T::NONE_SET; N];
+}
+
+macro_rules! bit_size_tuple_impl {
+    ($($name:ident),*) => {
+        impl<$($name: BitSize),*> BitSize for ($($name,)*) {
+            const BIT_SIZE: usize = 0 $(+ $name::BIT_SIZE)*;
+        }
+
+        impl<$($name: BitConsts),*> BitConsts for ($($name,)*) {
+            const ALL_SET: Self = ($($name::ALL_SET,)*);
+            const NONE_SET: Self = ($($name::NONE_SET,)*);
+        }
+    };
+}
+
+bit_size_tuple_impl!(A, B);
+bit_size_tuple_impl!(A, B, C);
+bit_size_tuple_impl!(A, B, C, D);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn constants() {
+        macro_rules! check {
+            ($($type:ty => $bytes:expr),*) => {
+                $(
+                    asserting!("constants are consistent with the bit size")
+                        .that(&(
+                            <$type>::BYTE_SIZE,
+                            <$type>::MAX_BIT_INDEX,
+                            <$type>::ALL_SET,
+                            <$type>::NONE_SET,
+                        ))
+                        .is_equal_to((
+                            $bytes,
+                            $bytes * 8 - 1,
+                            <$type>::MAX.wrapping_add(<$type>::MIN),
+                            <$type>::MIN & <$type>::MAX,
+                        ));
+                    asserting!("byte size matches the size in memory")
+                        .that(&<$type>::BYTE_SIZE)
+                        .is_equal_to(std::mem::size_of::<$type>());
+                )*
+            };
+        }
+
+        check!(u8 => 1, u16 => 2, u32 => 4, u64 => 8, u128 => 16);
+        check!(i8 => 1, i16 => 2, i32 => 4, i64 => 8, i128 => 16);
+        check!(
+            usize => std::mem::size_of::<usize>(),
+            isize => std::mem::size_of::<isize>()
+        );
+
+        asserting!("all set values are all ones")
+            .that(&(u32::ALL_SET, i32::ALL_SET, u128::ALL_SET, isize::ALL_SET))
+            .is_equal_to((0xffff_ffff, -1, u128::MAX, -1));
+    }
+
+    #[test]
+    fn arrays() {
+        asserting!("arrays are as wide as all of their elements")
+            .that(&(
+                <[u8; 3]>::BIT_SIZE,
+                <[u64; 4]>::BIT_SIZE,
+                <[u64; 8]>::BIT_SIZE,
+                <[i16; 1]>::BIT_SIZE,
+                <[u32; 0]>::BIT_SIZE,
+            ))
+            .is_equal_to((24, 256, 512, 16, 0));
+
+        asserting!("nested arrays are as wide as all of their elements")
+            .that(&(<[[u16; 2]; 2]>::BIT_SIZE, <[[u16; 2]; 2]>::BYTE_SIZE))
+            .is_equal_to((64, 8));
+
+        asserting!("array constants are set in every element")
+            .that(&(<[u8; 3]>::ALL_SET, <[[i16; 2]; 2]>::NONE_SET))
+            .is_equal_to(([0xff; 3], [[0; 2]; 2]));
+
+        asserting!("derived constants use the combined size")
+            .that(&(<[u8; 3]>::BYTE_SIZE, <[u8; 3]>::MAX_BIT_INDEX))
+            .is_equal_to((3, 23));
+    }
+
+    #[test]
+    fn tuples() {
+        asserting!("tuples are as wide as all of their elements")
+            .that(&(
+                <(u32, u32)>::BIT_SIZE,
+                <(u8, u16, u32)>::BIT_SIZE,
+                <(u8, i8, [u8; 2], (u16, u16))>::BIT_SIZE,
+            ))
+            .is_equal_to((64, 56, 64));
+
+        asserting!("byte sizes don't include padding")
+            .that(&<(u8, u32)>::BYTE_SIZE)
+            .is_equal_to(5);
+
+        asserting!("tuple constants are set in every element")
+            .that(&(<(u32, i8)>::ALL_SET, <(u32, i8)>::NONE_SET))
+            .is_equal_to(((u32::MAX, -1), (0, 0)));
+    }
+}
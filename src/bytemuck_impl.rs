@@ -0,0 +1,97 @@
+use crate::{i24, u24, u48};
+use bytemuck::{Pod, Zeroable};
+
+// The byte-exact types are transparent wrappers around their little-endian bytes, so they have the
+// size of their width, an alignment of 1, and no padding, and every pattern of bytes is a valid
+// value. Casting a buffer therefore decodes the same values as `from_le_bytes` on every target.
+// The narrow types and the 256-bit types hold their values in native-endian primitives with unused
+// bits, so they can't be cast.
+
+// SAFETY: each type is `#[repr(transparent)]` over a byte array, and all zeros is the value 0.
+unsafe impl Zeroable for u24 {}
+unsafe impl Zeroable for i24 {}
+unsafe impl Zeroable for u48 {}
+
+// SAFETY: as above, and every byte pattern is a valid value with no padding or interior
+// mutability.
+unsafe impl Pod for u24 {}
+unsafe impl Pod for i24 {}
+unsafe impl Pod for u48 {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+    use std::mem::{align_of, size_of};
+
+    #[test]
+    fn layouts_are_packed_bytes() {
+        asserting!("values take exactly their bytes with no alignment")
+            .that(&(
+                (size_of::<u24>(), align_of::<u24>()),
+                (size_of::<i24>(), align_of::<i24>()),
+                (size_of::<u48>(), align_of::<u48>()),
+                size_of::<[i24; 5]>(),
+            ))
+            .is_equal_to(((3, 1), (3, 1), (6, 1), 15));
+
+        asserting!("zeroed values are zero")
+            .that(&(u24::zeroed(), i24::zeroed(), u48::zeroed()))
+            .is_equal_to((u24::MIN, i24::new(0), u48::MIN));
+    }
+
+    #[test]
+    fn casting_byte_buffers() {
+        let bytes: Vec<u8> = (0..48u8).map(|byte| byte.wrapping_mul(0x9d)).collect();
+
+        let samples: &[i24] = bytemuck::cast_slice(&bytes);
+        asserting!("signed samples decode like from_le_bytes")
+            .that(&samples.to_vec())
+            .is_equal_to(
+                bytes
+                    .chunks(3)
+                    .map(|chunk| i24::from_le_bytes([chunk[0], chunk[1], chunk[2]]))
+                    .collect::<Vec<_>>(),
+            );
+
+        let values: &[u24] = bytemuck::cast_slice(&bytes);
+        asserting!("unsigned values decode like from_le_bytes")
+            .that(&values.to_vec())
+            .is_equal_to(
+                bytes
+                    .chunks(3)
+                    .map(|chunk| u24::from_le_bytes([chunk[0], chunk[1], chunk[2]]))
+                    .collect::<Vec<_>>(),
+            );
+
+        let counters: &[u48] = bytemuck::cast_slice(&bytes);
+        asserting!("48-bit values decode like from_le_bytes")
+            .that(&counters.to_vec())
+            .is_equal_to(
+                bytes
+                    .chunks(6)
+                    .map(|chunk| {
+                        let mut array = [0; 6];
+                        array.copy_from_slice(chunk);
+                        u48::from_le_bytes(array)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+        asserting!("casting back gives the same bytes")
+            .that(&bytemuck::cast_slice::<i24, u8>(samples))
+            .is_equal_to(&bytes[..]);
+
+        asserting!("buffers of the wrong length are rejected")
+            .that(&bytemuck::try_cast_slice::<u8, u24>(&bytes[..47]).is_err())
+            .is_true();
+    }
+
+    #[test]
+    fn casting_a_sample_buffer() {
+        let bytes = [0x00, 0x00, 0x80, 0xff, 0xff, 0x7f, 0x01, 0x00, 0x00];
+        asserting!("samples are sign-extended from their top byte")
+            .that(&bytemuck::cast_slice::<u8, i24>(&bytes).to_vec())
+            .is_equal_to(vec![i24::MIN, i24::MAX, i24::new(1)]);
+    }
+}
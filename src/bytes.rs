@@ -0,0 +1,240 @@
+use crate::{consts, Int, UInt};
+
+// The byte conversions use as few bytes as the width needs, so a `UInt<20>` takes 3 bytes rather
+// than the 16 of the `u128` it's held in. The array length can't be computed from `BITS` on stable
+// Rust, so it's a parameter of its own that's checked at compile time, and it's usually inferred
+// from the array the bytes are written to or read from.
+
+/// Checks at compile time that `BYTES` is the number of bytes needed to hold `BITS` bits.
+struct ByteLength<const BITS: usize, const BYTES: usize>;
+
+impl<const BITS: usize, const BYTES: usize> ByteLength<BITS, BYTES> {
+    const VALID: () = assert!(
+        BYTES == BITS.div_ceil(8),
+        "the byte array must have exactly as many bytes as the width needs"
+    );
+}
+
+macro_rules! bytes_impl {
+    ($($type:ident, $sign_note:literal, $value:literal, $le:literal, $be:literal;)*) => {
+        $(
+            impl<const BITS: usize> $type<BITS> {
+                #[doc = concat!(
+                    "Returns the value as the fewest bytes that can hold `BITS` bits, in ",
+                    "little-endian order.\n",
+                    "\n",
+                    "The array has `(BITS + 7) / 8` bytes, and using any other length fails to ",
+                    "compile. The bits of the last byte above the width are always zero",
+                    $sign_note, ".\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let value = ", stringify!($type), "::<20>::new(", $value, ");\n",
+                    "assert_eq!(value.to_le_bytes(), ", $le, ");\n",
+                    "```\n",
+                    "\n",
+                    "```compile_fail\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let bytes: [u8; 4] = ", stringify!($type), "::<20>::MAX.to_le_bytes();\n",
+                    "```",
+                )]
+                pub const fn to_le_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+                    #[allow(clippy::let_unit_value)]
+                    let () = ByteLength::<BITS, BYTES>::VALID;
+
+                    let all = self.to_bits().to_le_bytes();
+                    let mut bytes = [0; BYTES];
+                    let mut index = 0;
+                    while index < BYTES {
+                        bytes[index] = all[index];
+                        index += 1;
+                    }
+
+                    bytes
+                }
+
+                #[doc = concat!(
+                    "Returns the value as the fewest bytes that can hold `BITS` bits, in ",
+                    "big-endian order.\n",
+                    "\n",
+                    "This is the same as [`to_le_bytes`](#method.to_le_bytes) with the bytes in ",
+                    "the opposite order, so the bits above the width are at the top of the first ",
+                    "byte.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let value = ", stringify!($type), "::<20>::new(", $value, ");\n",
+                    "assert_eq!(value.to_be_bytes(), ", $be, ");\n",
+                    "```",
+                )]
+                pub const fn to_be_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+                    let le = self.to_le_bytes::<BYTES>();
+                    let mut bytes = [0; BYTES];
+                    let mut index = 0;
+                    while index < BYTES {
+                        bytes[index] = le[BYTES - 1 - index];
+                        index += 1;
+                    }
+
+                    bytes
+                }
+
+                #[doc = concat!(
+                    "Creates a value from the fewest bytes that can hold `BITS` bits, in ",
+                    "little-endian order.\n",
+                    "\n",
+                    "This reverses [`to_le_bytes`](#method.to_le_bytes). It returns `None` if any ",
+                    "bit of the last byte above the width is set, rather than dropping it, since ",
+                    "that usually means the bytes weren't written for this type. When the width ",
+                    "is a whole number of bytes, every array is accepted.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let value = ", stringify!($type), "::<20>::new(", $value, ");\n",
+                    "assert_eq!(", stringify!($type), "::from_le_bytes(", $le, "), Some(value));\n",
+                    "assert_eq!(", stringify!($type), "::<20>::from_le_bytes([0, 0, 0x10]), None);",
+                    "\n",
+                    "```",
+                )]
+                pub const fn from_le_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Option<Self> {
+                    #[allow(clippy::let_unit_value)]
+                    let () = ByteLength::<BITS, BYTES>::VALID;
+
+                    let mut all = [0; 16];
+                    let mut index = 0;
+                    while index < BYTES {
+                        all[index] = bytes[index];
+                        index += 1;
+                    }
+
+                    let bits = u128::from_le_bytes(all);
+                    if bits <= consts::mask_u128(BITS) {
+                        Some(Self::from_bits(bits))
+                    } else {
+                        None
+                    }
+                }
+
+                #[doc = concat!(
+                    "Creates a value from the fewest bytes that can hold `BITS` bits, in ",
+                    "big-endian order.\n",
+                    "\n",
+                    "This reverses [`to_be_bytes`](#method.to_be_bytes), and like ",
+                    "[`from_le_bytes`](#method.from_le_bytes) it returns `None` if any bit above ",
+                    "the width is set.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let value = ", stringify!($type), "::<20>::new(", $value, ");\n",
+                    "assert_eq!(", stringify!($type), "::from_be_bytes(", $be, "), Some(value));\n",
+                    "```",
+                )]
+                pub const fn from_be_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Option<Self> {
+                    let mut le = [0; BYTES];
+                    let mut index = 0;
+                    while index < BYTES {
+                        le[index] = bytes[BYTES - 1 - index];
+                        index += 1;
+                    }
+
+                    Self::from_le_bytes(le)
+                }
+            }
+        )*
+    };
+}
+
+bytes_impl! {
+    UInt, "", "0xa_bcde", "[0xde, 0xbc, 0x0a]", "[0x0a, 0xbc, 0xde]";
+    Int,
+    ", so negative values aren't sign-extended into them",
+    "-2",
+    "[0xfe, 0xff, 0x0f]",
+    "[0x0f, 0xff, 0xfe]";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn round_trips() {
+        macro_rules! check {
+            ($($bits:literal => $bytes:literal),*) => {
+                $(
+                    for value in [UInt::<$bits>::MIN, UInt::new(1), UInt::MAX >> 1_u32, UInt::MAX] {
+                        let (le, be): ([u8; $bytes], [u8; $bytes]) =
+                            (value.to_le_bytes(), value.to_be_bytes());
+
+                        asserting!("unsigned values round trip")
+                            .that(&(UInt::from_le_bytes(le), UInt::from_be_bytes(be)))
+                            .is_equal_to((Some(value), Some(value)));
+                    }
+
+                    for value in [Int::<$bits>::MIN, Int::new(0), Int::new(-1), Int::MAX] {
+                        let (le, be): ([u8; $bytes], [u8; $bytes]) =
+                            (value.to_le_bytes(), value.to_be_bytes());
+
+                        asserting!("signed values round trip")
+                            .that(&(Int::from_le_bytes(le), Int::from_be_bytes(be)))
+                            .is_equal_to((Some(value), Some(value)));
+                    }
+                )*
+            };
+        }
+
+        check!(1 => 1, 7 => 1, 8 => 1, 9 => 2, 20 => 3, 24 => 3, 48 => 6, 128 => 16);
+    }
+
+    #[test]
+    fn padding() {
+        asserting!("bits above the width are written as zeros")
+            .that(&(
+                UInt::<9>::MAX.to_le_bytes(),
+                Int::<9>::new(-1).to_be_bytes(),
+                Int::<1>::new(-1).to_le_bytes(),
+            ))
+            .is_equal_to(([0xff, 0x01], [0x01, 0xff], [0x01]));
+
+        asserting!("bits above the width are rejected")
+            .that(&(
+                UInt::<9>::from_le_bytes([0xff, 0x03]),
+                Int::<9>::from_be_bytes([0xff, 0xff]),
+                UInt::<1>::from_le_bytes([0x02]),
+            ))
+            .is_equal_to((None, None, None));
+
+        asserting!("every array is accepted for whole bytes")
+            .that(&(
+                UInt::<24>::from_be_bytes([0xff; 3]),
+                Int::<8>::from_le_bytes([0x80]).map(Int::get),
+            ))
+            .is_equal_to((Some(UInt::MAX), Some(-128)));
+    }
+
+    #[test]
+    fn matching_dedicated_types() {
+        let value = UInt::<24>::new(0x12_3456);
+
+        asserting!("bytes match the 24-bit type")
+            .that(&(value.to_le_bytes(), value.to_be_bytes()))
+            .is_equal_to((
+                crate::u24::from(value).to_le_bytes(),
+                crate::u24::from(value).to_be_bytes(),
+            ));
+    }
+}
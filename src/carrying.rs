@@ -0,0 +1,185 @@
+/// Adds and subtracts with a carry or borrow in and out, for chaining operations across limbs.
+///
+/// These are stable versions of the `carrying_add` and `borrowing_sub` methods the standard
+/// library has on nightly, with the same results. For unsigned types, the flag that comes out is
+/// the carry or borrow out of the top bit, ready to pass to the next limb up. For signed types,
+/// which are only used for the most significant limb, it's whether the signed result overflowed.
+///
+/// Since the standard library methods have the same names, calling these with method syntax gives
+/// an `unstable_name_collisions` warning. Calling them through the trait avoids it, and once the
+/// standard methods are stable, removing the import switches to them.
+///
+/// # Examples
+///
+/// ```
+/// use quark::CarryingArith;
+///
+/// // Adding two 128-bit numbers held as little-endian u64 limbs
+/// let (a, b) = ([u64::MAX, 1], [1, 2]);
+/// let (low, carry) = CarryingArith::carrying_add(a[0], b[0], false);
+/// let (high, carry) = CarryingArith::carrying_add(a[1], b[1], carry);
+/// assert_eq!((low, high, carry), (0, 4, false));
+///
+/// assert_eq!(CarryingArith::borrowing_sub(0_u8, 0, true), (0xff, true));
+/// assert_eq!(CarryingArith::carrying_add(i8::MAX, 0, true), (i8::MIN, true));
+/// ```
+pub trait CarryingArith: Sized {
+    /// Returns `self + rhs + carry` and whether the sum overflowed.
+    ///
+    /// For unsigned types, the sum can overflow at most once even when `self` and `rhs` are both
+    /// the maximum and the carry is set, so the flag is the carry out.
+    fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool);
+
+    /// Returns `self - rhs - borrow` and whether the difference overflowed.
+    ///
+    /// For unsigned types, the flag is the borrow out.
+    fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool);
+}
+
+macro_rules! carrying_arith_impl {
+    ($($type:ident),* => $combine:tt) => {
+        $(
+            impl CarryingArith for $type {
+                #[inline]
+                fn carrying_add(self, rhs: $type, carry: bool) -> ($type, bool) {
+                    let (sum, first) = self.overflowing_add(rhs);
+                    let (sum, second) = sum.overflowing_add(carry as $type);
+                    (sum, first $combine second)
+                }
+
+                #[inline]
+                fn borrowing_sub(self, rhs: $type, borrow: bool) -> ($type, bool) {
+                    let (difference, first) = self.overflowing_sub(rhs);
+                    let (difference, second) = difference.overflowing_sub(borrow as $type);
+                    (difference, first $combine second)
+                }
+            }
+        )*
+    };
+}
+
+// An unsigned sum overflows when either step does, and both can't. A signed sum that overflows in
+// the first step is pulled back into range by the second if it overflows back the other way.
+carrying_arith_impl!(u8, u16, u32, u64, u128, usize => |);
+carrying_arith_impl!(i8, i16, i32, i64, i128, isize => !=);
+
+#[cfg(test)]
+#[allow(unstable_name_collisions)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use crate::{i256, u256};
+    use spectral::prelude::*;
+
+    #[test]
+    fn carries_at_the_extremes() {
+        asserting!("unsigned carries and borrows come out of the top bit")
+            .that(&(
+                u128::MAX.carrying_add(u128::MAX, true),
+                u128::MAX.carrying_add(0, true),
+                u128::MAX.carrying_add(u128::MAX, false),
+                0_u128.borrowing_sub(u128::MAX, true),
+                0_u8.borrowing_sub(0, true),
+                5_u32.borrowing_sub(5, false),
+            ))
+            .is_equal_to((
+                (u128::MAX, true),
+                (0, true),
+                (u128::MAX - 1, true),
+                (0, true),
+                (u8::MAX, true),
+                (0, false),
+            ));
+
+        asserting!("signed flags are overflows of the signed result")
+            .that(&(
+                i8::MAX.carrying_add(0, true),
+                i8::MAX.carrying_add(-1, true),
+                i8::MIN.carrying_add(-1, true),
+                i8::MIN.carrying_add(i8::MIN, true),
+                (-1_i8).carrying_add(-1, true),
+                i128::MIN.borrowing_sub(0, true),
+                i128::MIN.borrowing_sub(-1, true),
+                0_i16.borrowing_sub(i16::MIN, false),
+            ))
+            .is_equal_to((
+                (i8::MIN, true),
+                (i8::MAX, false),
+                (i8::MIN, false),
+                (1, true),
+                (-1, false),
+                (i128::MAX, true),
+                (i128::MIN, false),
+                (i16::MIN, true),
+            ));
+    }
+
+    #[test]
+    fn chains_match_wide_arithmetic() {
+        let mut random = random_u64s();
+        let mut next = || {
+            let seed = random.next().unwrap();
+            // Mostly all-ones or all-zeros limbs, so carries ripple through several of them
+            match seed % 4 {
+                0 => u64::MAX,
+                1 => 0,
+                _ => seed,
+            }
+        };
+
+        for _ in 0..1000 {
+            let a = [next(), next(), next(), next()];
+            let b = [next(), next(), next(), next()];
+            let (wide_a, wide_b) = (u256::from_limbs(a), u256::from_limbs(b));
+
+            let mut sum = [0; 4];
+            let mut difference = [0; 4];
+            let (mut carry, mut borrow) = (false, false);
+            for i in 0..4 {
+                (sum[i], carry) = a[i].carrying_add(b[i], carry);
+                (difference[i], borrow) = a[i].borrowing_sub(b[i], borrow);
+            }
+
+            asserting!("64-bit limbs add and subtract like a u256")
+                .that(&(sum, carry, difference, borrow))
+                .is_equal_to((
+                    wide_a.wrapping_add(wide_b).to_limbs(),
+                    wide_a.checked_add(wide_b).is_none(),
+                    wide_a.wrapping_sub(wide_b).to_limbs(),
+                    wide_a.checked_sub(wide_b).is_none(),
+                ));
+
+            let to_u128 = |low: u64, high: u64| (high as u128) << 64 | low as u128;
+            let (low, carry) = to_u128(a[0], a[1]).carrying_add(to_u128(b[0], b[1]), false);
+            let (high, carry) = to_u128(a[2], a[3]).carrying_add(to_u128(b[2], b[3]), carry);
+            asserting!("128-bit limbs add like a u256")
+                .that(&(low, high, carry))
+                .is_equal_to((
+                    to_u128(sum[0], sum[1]),
+                    to_u128(sum[2], sum[3]),
+                    wide_a.checked_add(wide_b).is_none(),
+                ));
+
+            let (mut signed_sum, mut signed_difference) = ([0; 4], [0; 4]);
+            let (mut carry, mut borrow) = (false, false);
+            for i in 0..3 {
+                (signed_sum[i], carry) = a[i].carrying_add(b[i], carry);
+                (signed_difference[i], borrow) = a[i].borrowing_sub(b[i], borrow);
+            }
+            let (top, overflow) = (a[3] as i64).carrying_add(b[3] as i64, carry);
+            signed_sum[3] = top as u64;
+            let (top, underflow) = (a[3] as i64).borrowing_sub(b[3] as i64, borrow);
+            signed_difference[3] = top as u64;
+
+            let (wide_a, wide_b) = (i256::from_limbs(a), i256::from_limbs(b));
+            asserting!("a signed top limb overflows like an i256")
+                .that(&(signed_sum, overflow, signed_difference, underflow))
+                .is_equal_to((
+                    wide_a.wrapping_add(wide_b).to_limbs(),
+                    wide_a.checked_add(wide_b).is_none(),
+                    wide_a.wrapping_sub(wide_b).to_limbs(),
+                    wide_a.checked_sub(wide_b).is_none(),
+                ));
+        }
+    }
+}
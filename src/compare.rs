@@ -0,0 +1,147 @@
+use crate::{Int, UInt};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+// Narrow types compare with every primitive, in both directions, by numeric value. That holds
+// across signedness too: a negative `i32` is less than every `UInt`, and a negative `Int` is less
+// than every `u32`, where casting one side to the other's type would wrap and get it backwards. A
+// primitive outside the range of the width is never equal to a value and orders past the end of the
+// range it's outside of.
+
+/// A value of any of the integer types, widened so it can be compared with any other.
+#[derive(Clone, Copy)]
+enum Wide {
+    Unsigned(u128),
+    Signed(i128),
+}
+
+impl Wide {
+    fn compare(self, other: Wide) -> Ordering {
+        match (self, other) {
+            (Wide::Unsigned(a), Wide::Unsigned(b)) => a.cmp(&b),
+            (Wide::Signed(a), Wide::Signed(b)) => a.cmp(&b),
+            (Wide::Unsigned(a), Wide::Signed(b)) => match u128::try_from(b) {
+                Ok(b) => a.cmp(&b),
+                Err(_) => Ordering::Greater,
+            },
+            (Wide::Signed(_), Wide::Unsigned(_)) => other.compare(self).reverse(),
+        }
+    }
+}
+
+macro_rules! compare_impl {
+    ($($type:ident, $variant:ident; unsigned $($unsigned:ty),*; signed $($signed:ty),*;)*) => {
+        $(
+            compare_impl!(@each $type, $variant, Unsigned, u128, $($unsigned),*);
+            compare_impl!(@each $type, $variant, Signed, i128, $($signed),*);
+        )*
+    };
+    (@each $type:ident, $variant:ident, $primitive_variant:ident, $wide:ty, $($primitive:ty),*) => {
+        $(
+            impl<const BITS: usize> PartialEq<$primitive> for $type<BITS> {
+                fn eq(&self, other: &$primitive) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl<const BITS: usize> PartialEq<$type<BITS>> for $primitive {
+                fn eq(&self, other: &$type<BITS>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<const BITS: usize> PartialOrd<$primitive> for $type<BITS> {
+                fn partial_cmp(&self, other: &$primitive) -> Option<Ordering> {
+                    let this = Wide::$variant(self.get());
+                    Some(this.compare(Wide::$primitive_variant(*other as $wide)))
+                }
+            }
+
+            impl<const BITS: usize> PartialOrd<$type<BITS>> for $primitive {
+                fn partial_cmp(&self, other: &$type<BITS>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+compare_impl! {
+    UInt, Unsigned;
+        unsigned u8, u16, u32, u64, u128, usize;
+        signed i8, i16, i32, i64, i128, isize;
+    Int, Signed;
+        unsigned u8, u16, u32, u64, u128, usize;
+        signed i8, i16, i32, i64, i128, isize;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn equality() {
+        asserting!("values equal the primitives with the same number")
+            .that(&(
+                UInt::<12>::new(5) == 5_u8,
+                5_u64 == UInt::<12>::new(5),
+                Int::<12>::new(-5) == -5_i32,
+                -5_i8 == Int::<12>::new(-5),
+                UInt::<12>::new(5) == 5_i16,
+                Int::<12>::new(5) == 5_usize,
+            ))
+            .is_equal_to((true, true, true, true, true, true));
+
+        asserting!("primitives outside the range are never equal")
+            .that(&(
+                UInt::<4>::masked(0x15) == 0x15_u32,
+                UInt::<4>::masked(0x15) == 0x05_u32,
+                UInt::<4>::MAX == -1_i32,
+                Int::<4>::new(-1) == u128::MAX,
+                Int::<4>::new(-1) == 0x0f_u8,
+            ))
+            .is_equal_to((false, true, false, false, false));
+    }
+
+    #[test]
+    fn ordering() {
+        asserting!("primitives past the maximum are greater, and don't wrap")
+            .that(&(
+                UInt::<4>::MAX < 16_u32,
+                UInt::<4>::MAX < 0x110_u32,
+                0x110_u32 > UInt::<4>::MAX,
+                Int::<4>::MAX < 8_i64,
+                Int::<4>::MIN > -9_i64,
+                UInt::<128>::MAX > i128::MAX,
+            ))
+            .is_equal_to((true, true, true, true, true, true));
+
+        asserting!("ordering follows the numbers across signedness")
+            .that(&(
+                UInt::<4>::ZERO > -1_i8,
+                -1_i8 < UInt::<4>::ZERO,
+                Int::<4>::new(-1) < 0_u8,
+                Int::<4>::new(-1) < u128::MAX,
+                Int::<128>::MIN < 0_u128,
+                Int::<4>::new(7) > 6_usize,
+            ))
+            .is_equal_to((true, true, true, true, true, true));
+
+        for value in -20..20 {
+            let narrow = Int::<5>::masked(value);
+
+            asserting!("ordering matches the numbers")
+                .that(&(
+                    narrow.partial_cmp(&value),
+                    value.partial_cmp(&narrow),
+                    UInt::<5>::masked(value as u128).partial_cmp(&value),
+                ))
+                .is_equal_to((
+                    narrow.get().partial_cmp(&value),
+                    value.partial_cmp(&narrow.get()),
+                    (UInt::<5>::masked(value as u128).get() as i128).partial_cmp(&value),
+                ));
+        }
+    }
+}
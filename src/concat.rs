@@ -0,0 +1,520 @@
+use crate::{i256, u256, BitSize, Quark, SignCast, Widen};
+use std::marker::PhantomData;
+
+/// Joins two values into a value twice as wide.
+///
+/// The high half lands in the upper bits of the result and the low half in the lower bits, so
+/// `u32::concat(high, low)` is the same as `(high as u64) << 32 | low as u64`. Concatenating signed
+/// values gives the signed wide type: the high half keeps its sign, and the low half is taken as
+/// raw bits, the way the lower half of a wider two's complement number has no sign of its own.
+///
+/// # Examples
+///
+/// ```
+/// use quark::BitConcat;
+///
+/// assert_eq!(u8::concat(0x12, 0x34), 0x1234_u16);
+/// assert_eq!(u32::concat(0xdead_beef, 0x0bad_f00d), 0xdead_beef_0bad_f00d_u64);
+///
+/// // The sign comes from the high half
+/// assert_eq!(i8::concat(-1, 0x00), -256_i16);
+/// assert_eq!(i8::concat(0, -1), 0x00ff);
+/// ```
+pub trait BitConcat: Sized {
+    /// The type twice as wide as this one.
+    type Wide;
+
+    /// Joins the bits of two values, with `high` in the upper half of the result.
+    fn concat(high: Self, low: Self) -> Self::Wide;
+}
+
+macro_rules! bit_concat_impl {
+    ($($type:ident as $unsigned:ident => $wide:ident;)*) => {
+        $(
+            impl BitConcat for $type {
+                type Wide = $wide;
+
+                #[inline]
+                fn concat(high: $type, low: $type) -> $wide {
+                    (high as $wide) << <$type>::BITS | low as $unsigned as $wide
+                }
+            }
+        )*
+    };
+}
+
+bit_concat_impl! {
+    u8 as u8 => u16;
+    u16 as u16 => u32;
+    u32 as u32 => u64;
+    u64 as u64 => u128;
+    i8 as u8 => i16;
+    i16 as u16 => i32;
+    i32 as u32 => i64;
+    i64 as u64 => i128;
+}
+
+impl BitConcat for u128 {
+    type Wide = u256;
+
+    fn concat(high: u128, low: u128) -> u256 {
+        u256::from_limbs([
+            low as u64,
+            (low >> 64) as u64,
+            high as u64,
+            (high >> 64) as u64,
+        ])
+    }
+}
+
+impl BitConcat for i128 {
+    type Wide = i256;
+
+    fn concat(high: i128, low: i128) -> i256 {
+        i256::from_limbs(u128::concat(high as u128, low as u128).to_limbs())
+    }
+}
+
+/// Splits a value into halves of the type half as wide, and builds it back from them.
+///
+/// This is the typed counterpart of splitting the value at its middle bit with
+/// [`BitIndex::split_at_bit`](trait.BitIndex.html#method.split_at_bit), and the inverse of
+/// [`BitConcat`](trait.BitConcat.html): `from_halves` is the same as `concat` on the half type. The halves of a signed value are
+/// signed: the high half keeps the sign of the value, and the low half holds the raw bits, so it's
+/// negative whenever its top bit is set.
+///
+/// # Examples
+///
+/// ```
+/// use quark::HalfBits;
+///
+/// // The BC register pair of an 8080
+/// let bc = u16::from_halves(0x12, 0x34);
+/// assert_eq!((bc.high(), bc.low()), (0x12_u8, 0x34_u8));
+///
+/// assert_eq!((-2_i32).high(), -1_i16);
+/// assert_eq!((-2_i32).low(), -2_i16);
+/// ```
+pub trait HalfBits: Sized {
+    /// The type half as wide as this one.
+    type Half: BitConcat<Wide = Self>;
+
+    /// Returns the upper half of the value.
+    fn high(self) -> Self::Half;
+
+    /// Returns the lower half of the value.
+    fn low(self) -> Self::Half;
+
+    /// Builds a value from its upper and lower halves.
+    fn from_halves(high: Self::Half, low: Self::Half) -> Self {
+        Self::Half::concat(high, low)
+    }
+}
+
+macro_rules! half_bits_impl {
+    ($($type:ident => $half:ident;)*) => {
+        $(
+            impl HalfBits for $type {
+                type Half = $half;
+
+                #[inline]
+                fn high(self) -> $half {
+                    (self >> <$half>::BITS) as $half
+                }
+
+                #[inline]
+                fn low(self) -> $half {
+                    self as $half
+                }
+            }
+        )*
+    };
+}
+
+half_bits_impl! {
+    u16 => u8;
+    u32 => u16;
+    u64 => u32;
+    u128 => u64;
+    i16 => i8;
+    i32 => i16;
+    i64 => i32;
+    i128 => i64;
+}
+
+impl HalfBits for u256 {
+    type Half = u128;
+
+    fn high(self) -> u128 {
+        let [_, _, low, high] = self.to_limbs();
+        u128::from_halves(high, low)
+    }
+
+    fn low(self) -> u128 {
+        let [low, high, _, _] = self.to_limbs();
+        u128::from_halves(high, low)
+    }
+}
+
+impl HalfBits for i256 {
+    type Half = i128;
+
+    fn high(self) -> i128 {
+        self.cast_unsigned().high() as i128
+    }
+
+    fn low(self) -> i128 {
+        self.cast_unsigned().low() as i128
+    }
+}
+
+/// Builds a value by joining a sequence of parts, most significant first.
+///
+/// The first part lands in the highest bits of the result and each later part goes below it, the
+/// order the parts are written in a datasheet or read off the wire in big-endian order. When the
+/// parts don't fill the type, they fill its lowest bits and the bits above them are zero. Bits of
+/// a part above its width are ignored, so signed parts contribute their raw bits.
+///
+/// This is implemented for every type that implements [`Quark`](trait.Quark.html).
+///
+/// # Examples
+///
+/// ```
+/// use quark::ConcatParts;
+///
+/// assert_eq!(u32::concat_all([0x12_u8, 0x34, 0x56, 0x78]), 0x1234_5678);
+/// assert_eq!(u32::concat_all([0x12_u8, 0x34, 0x56]), 0x12_3456);
+///
+/// // An RGB565 pixel
+/// let pixel = u16::concat_fields([(0x1f, 5), (0x00, 6), (0x10, 5)]);
+/// assert_eq!(pixel, 0b11111_000000_10000);
+/// ```
+pub trait ConcatParts: Quark {
+    /// Joins parts of equal width, with the first part in the highest bits.
+    ///
+    /// The number of parts is checked at compile time, so parts that don't fit in the type fail
+    /// to compile.
+    ///
+    /// ```compile_fail
+    /// use quark::ConcatParts;
+    ///
+    /// let value = u16::concat_all([1_u8, 2, 3]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::ConcatParts;
+    ///
+    /// assert_eq!(u64::concat_all([0xdead_u16, 0xbeef]), 0xdead_beef);
+    /// assert_eq!(i16::concat_all([-1_i8, -1]), -1);
+    /// ```
+    fn concat_all<P: BitSize + Widen<Self>, const N: usize>(parts: [P; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = PartsFit::<Self, P, N>::VALID;
+
+        let mut value = Self::ZERO;
+        for part in parts {
+            value = push_bits(value, part.widen(), P::BIT_SIZE);
+        }
+        value
+    }
+
+    /// Joins parts given with their widths, with the first part in the highest bits.
+    ///
+    /// Each part is a value and the number of bits it takes; bits of the value above its width are
+    /// ignored, and a width of 0 adds nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widths add up to more than the size of the type. Use
+    /// [`try_concat_fields`](#method.try_concat_fields) to check instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::ConcatParts;
+    ///
+    /// // A RISC-V R-type instruction: funct7, rs2, rs1, funct3, rd, opcode
+    /// let add = u32::concat_fields([(0, 7), (3, 5), (2, 5), (0, 3), (1, 5), (0x33, 7)]);
+    /// assert_eq!(add, 0x0031_00b3);
+    /// ```
+    fn concat_fields<I: IntoIterator<Item = (Self, usize)>>(parts: I) -> Self {
+        match Self::try_concat_fields(parts) {
+            Some(value) => value,
+            None => panic!("the fields are wider than the type"),
+        }
+    }
+
+    /// Joins parts given with their widths, or returns `None` if the widths add up to more than
+    /// the size of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::ConcatParts;
+    ///
+    /// assert_eq!(u8::try_concat_fields([(0x3, 2), (0x3f, 6)]), Some(0xff));
+    /// assert_eq!(u8::try_concat_fields([(0x3, 3), (0x3f, 6)]), None);
+    /// ```
+    fn try_concat_fields<I: IntoIterator<Item = (Self, usize)>>(parts: I) -> Option<Self> {
+        let mut value = Self::ZERO;
+        let mut total = 0_usize;
+
+        for (part, width) in parts {
+            total = total
+                .checked_add(width)
+                .filter(|&total| total <= Self::BIT_SIZE)?;
+            value = push_bits(value, part, width);
+        }
+
+        Some(value)
+    }
+}
+
+impl<T: Quark> ConcatParts for T {}
+
+/// Checks at compile time that `N` parts of type `P` fit in a `W`.
+struct PartsFit<W, P, const N: usize>(PhantomData<(W, P)>);
+
+impl<W: BitSize, P: BitSize, const N: usize> PartsFit<W, P, N> {
+    const VALID: () = assert!(
+        N * P::BIT_SIZE <= W::BIT_SIZE,
+        "the parts must fit in the type they're joined into"
+    );
+}
+
+/// Shifts `value` up by `width` bits and puts the lowest `width` bits of `part` below it.
+fn push_bits<T: Quark>(value: T, part: T, width: usize) -> T {
+    let shifted = if width < T::BIT_SIZE {
+        value << width
+    } else {
+        T::ZERO
+    };
+
+    shifted | part.mask_to(width)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use crate::{BitIndex, BitMask};
+    use spectral::prelude::*;
+
+    #[test]
+    fn high_halves_land_in_the_upper_bits() {
+        asserting!("unsigned halves concatenate in order")
+            .that(&(
+                u8::concat(0xab, 0xcd),
+                u16::concat(0x0001, 0x0000),
+                u32::concat(0, u32::MAX),
+                u64::concat(u64::MAX, 0),
+            ))
+            .is_equal_to((0xabcd, 0x0001_0000, 0xffff_ffff, (u64::MAX as u128) << 64));
+
+        asserting!("128-bit halves fill the limbs of a u256")
+            .that(&u128::concat(1 << 64 | 2, 3 << 64 | 4).to_limbs())
+            .is_equal_to([4, 3, 2, 1]);
+
+        asserting!("signed values take their sign from the high half")
+            .that(&(
+                i8::concat(-1, -1),
+                i8::concat(0, -1),
+                i8::concat(-128, 0),
+                i16::concat(0x7fff, -1),
+                i64::concat(-2, 1),
+                i128::concat(-1, 5),
+            ))
+            .is_equal_to((
+                -1,
+                0xff,
+                i16::MIN,
+                i32::MAX,
+                -(1 << 65) + 1,
+                i256::from_limbs([5, 0, u64::MAX, u64::MAX]),
+            ));
+    }
+
+    #[test]
+    fn round_trips() {
+        for seed in random_u64s().take(1000) {
+            let (high, low) = ((seed >> 32) as u32, seed as u32);
+
+            let (wide, narrow) = (
+                u16::concat(high as u16, low as u16),
+                u8::concat(high as u8, low as u8),
+            );
+            asserting!("unsigned halves come back out of the wide value")
+                .that(&(
+                    u32::concat(high, low),
+                    (wide >> 16) as u16,
+                    wide as u16,
+                    narrow.to_be_bytes(),
+                ))
+                .is_equal_to((seed, high as u16, low as u16, [high as u8, low as u8]));
+
+            let (wide, narrow) = (
+                i32::concat(high as i32, low as i32),
+                i8::concat(high as i8, low as i8),
+            );
+            asserting!("signed halves come back out of the wide value")
+                .that(&(wide, (narrow >> 8) as i8, narrow as u8, narrow < 0))
+                .is_equal_to((seed as i64, high as i8, low as u8, (high as i8) < 0));
+        }
+    }
+
+    #[test]
+    fn joining_parts() {
+        asserting!("equal parts fill the type from the top")
+            .that(&(
+                u32::concat_all([0xde_u8, 0xad, 0xbe, 0xef]),
+                u64::concat_all([0x1234_u16, 0x5678]),
+                u128::concat_all([u64::MAX, 0]),
+                i32::concat_all([-1_i8, 0, 0, 0]),
+                u16::concat_all([i8::MIN, -1]),
+                u32::concat_all::<u8, 0>([]),
+            ))
+            .is_equal_to((
+                0xdead_beef,
+                0x1234_5678,
+                u128::MAX << 64,
+                -1 << 24,
+                0x80ff,
+                0,
+            ));
+
+        asserting!("fields ignore the bits above their width")
+            .that(&(
+                u16::concat_fields([(0xff, 4), (0xff, 0), (0x0, 4), (0xff, 8)]),
+                i8::concat_fields([(-1, 1), (0, 7)]),
+                u256::concat_fields(vec![(u256::ONE, 1), (u256::ZERO, 255)]),
+                u8::concat_fields([(0xab, 8)]),
+            ))
+            .is_equal_to((
+                0xf0ff_u16,
+                i8::MIN,
+                u256::from_limbs([0, 0, 0, 1 << 63]),
+                0xab,
+            ));
+
+        asserting!("fields wider than the type are rejected")
+            .that(&(
+                u8::try_concat_fields([(0, 9)]),
+                u8::try_concat_fields([(0, 4), (0, 5)]),
+                u8::try_concat_fields([(0, usize::MAX), (0, 2)]),
+                u8::try_concat_fields([(1, 8), (1, 0)]),
+            ))
+            .is_equal_to((None, None, None, Some(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "the fields are wider than the type")]
+    fn joining_too_many_fields() {
+        u32::concat_fields([(0, 16), (0, 16), (0, 1)]);
+    }
+
+    #[test]
+    fn joining_then_reading_fields() {
+        let mut random = random_u64s();
+        let mut next = || random.next().unwrap();
+
+        for _ in 0..1000 {
+            let mut fields = Vec::new();
+            let mut total = 0;
+            loop {
+                let width = (next() % 17) as usize;
+                if total + width > 64 {
+                    break;
+                }
+                fields.push((next(), width));
+                total += width;
+            }
+
+            let value = u64::concat_fields(fields.iter().copied());
+            let mut end = total;
+            for &(field, width) in &fields {
+                asserting!("each field reads back from its place")
+                    .that(&value.bits(end - width..end))
+                    .is_equal_to(field.mask_to(width));
+                end -= width;
+            }
+
+            asserting!("the bits above the fields are clear")
+                .that(&value.bits(total..))
+                .is_equal_to(0);
+        }
+    }
+
+    #[test]
+    fn halves() {
+        asserting!("unsigned values split into their upper and lower halves")
+            .that(&(
+                0xabcd_u16.high(),
+                0xabcd_u16.low(),
+                0x1234_5678_u32.high(),
+                0x1234_5678_u32.low(),
+                u64::MAX.high(),
+                (1_u128 << 64).low(),
+                u256::from_limbs([1, 2, 3, 4]).high(),
+                u256::from_limbs([1, 2, 3, 4]).low(),
+            ))
+            .is_equal_to((
+                0xab,
+                0xcd,
+                0x1234,
+                0x5678,
+                u32::MAX,
+                0,
+                4 << 64 | 3,
+                2 << 64 | 1,
+            ));
+
+        asserting!("the high half keeps the sign and the low half holds the raw bits")
+            .that(&(
+                (-1_i16).high(),
+                (-1_i16).low(),
+                0x0080_i16.high(),
+                0x0080_i16.low(),
+                i32::MIN.high(),
+                i32::MIN.low(),
+                (-1_i128).high(),
+                i256::from_limbs([0, 1 << 63, !0, !0]).high(),
+                i256::from_limbs([0, 1 << 63, !0, !0]).low(),
+            ))
+            .is_equal_to((-1, -1, 0, i8::MIN, i16::MIN, 0, -1, -1, i128::MIN));
+
+        asserting!("halves with their top bit set don't change the sign of the whole")
+            .that(&(
+                i16::from_halves(0, -1),
+                i16::from_halves(-1, 0),
+                i64::from_halves(i32::MAX, i32::MIN),
+                i256::from_halves(-1, 0).is_negative(),
+            ))
+            .is_equal_to((0x00ff, -256, i64::MAX - i32::MAX as i64, true));
+    }
+
+    #[test]
+    fn halves_round_trip() {
+        for seed in random_u64s().take(1000) {
+            let wide = (seed as u128) << 64 | seed.rotate_left(17) as u128;
+
+            asserting!("values rebuild from their halves")
+                .that(&(
+                    u64::from_halves(seed.high(), seed.low()),
+                    i64::from_halves((seed as i64).high(), (seed as i64).low()),
+                    u16::from_halves((seed as u16).high(), (seed as u16).low()),
+                    i128::from_halves((wide as i128).high(), (wide as i128).low()),
+                    u256::from_halves(seed as u128, wide).low(),
+                ))
+                .is_equal_to((seed, seed as i64, seed as u16, wide as i128, wide));
+
+            asserting!("halves rebuild from their value")
+                .that(&(
+                    u32::from_halves(seed as u16, (seed >> 16) as u16).high(),
+                    i32::from_halves(seed as i16, (seed >> 16) as i16).low(),
+                ))
+                .is_equal_to((seed as u16, (seed >> 16) as i16));
+        }
+    }
+}
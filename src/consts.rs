@@ -0,0 +1,192 @@
+//! Constant functions for bit operations on primitives.
+//!
+//! Trait methods can't be called in constant expressions, so this module provides `const fn`
+//! versions of the most common operations for each primitive. Each function produces exactly the
+//! same result as the trait method it mirrors, which makes them useful for building lookup tables,
+//! declaring register layouts, and asserting field layouts at compile time.
+//!
+//! # Examples
+//!
+//! ```
+//! use quark::consts;
+//!
+//! const RD_MASK: u32 = consts::mask_u32(4) << 16;
+//! const RD: u32 = consts::bits_u32(0xe01a_3497, 16, 4);
+//!
+//! assert_eq!(RD_MASK, 0x000f_0000);
+//! assert_eq!(RD, 0xa);
+//! ```
+
+#![allow(unused_comparisons)]
+
+use crate::BitSize;
+
+macro_rules! consts_impl {
+    ($type:ty, $u_ty:ty, $s_ty:ty, $mask:ident, $bits:ident, $sign_extend:ident) => {
+        #[doc = concat!(
+            "Returns a mask with the requested number of bits set, like [`BitMask::mask`](../trait.BitMask.html#tymethod.mask) on `",
+            stringify!($type),
+            "`."
+        )]
+        pub const fn $mask(size: usize) -> $type {
+            const BIT_SIZE: usize = <$type as BitSize>::BIT_SIZE;
+
+            if size == 0 {
+                0
+            } else if size < BIT_SIZE {
+                ((1 as $u_ty) << size).wrapping_sub(1) as $type
+            } else {
+                !0
+            }
+        }
+
+        #[doc = concat!(
+            "Returns the `length` bits starting at bit `start`, like [`BitIndex::bits`](../trait.BitIndex.html#tymethod.bits) on `",
+            stringify!($type),
+            "` with the range `start..start + length`."
+        )]
+        pub const fn $bits(value: $type, start: usize, length: usize) -> $type {
+            const BIT_SIZE: usize = <$type as BitSize>::BIT_SIZE;
+
+            let shifted = if start < BIT_SIZE {
+                value >> start
+            } else if value < 0 {
+                !0
+            } else {
+                0
+            };
+
+            shifted & $mask(length)
+        }
+
+        #[doc = concat!(
+            "Fills the upper `bits` bits of a value with the next bit down, like [`Signs::sign_extend`](../trait.Signs.html#tymethod.sign_extend) on `",
+            stringify!($type),
+            "`."
+        )]
+        pub const fn $sign_extend(value: $type, bits: usize) -> $type {
+            const BIT_SIZE: usize = <$type as BitSize>::BIT_SIZE;
+
+            let bits = if bits < BIT_SIZE { bits } else { BIT_SIZE - 1 };
+            (((value << bits) as $s_ty) >> bits) as $type
+        }
+    };
+}
+
+consts_impl!(u8, u8, i8, mask_u8, bits_u8, sign_extend_u8);
+consts_impl!(u16, u16, i16, mask_u16, bits_u16, sign_extend_u16);
+consts_impl!(u32, u32, i32, mask_u32, bits_u32, sign_extend_u32);
+consts_impl!(u64, u64, i64, mask_u64, bits_u64, sign_extend_u64);
+consts_impl!(u128, u128, i128, mask_u128, bits_u128, sign_extend_u128);
+consts_impl!(
+    usize,
+    usize,
+    isize,
+    mask_usize,
+    bits_usize,
+    sign_extend_usize
+);
+consts_impl!(i8, u8, i8, mask_i8, bits_i8, sign_extend_i8);
+consts_impl!(i16, u16, i16, mask_i16, bits_i16, sign_extend_i16);
+consts_impl!(i32, u32, i32, mask_i32, bits_i32, sign_extend_i32);
+consts_impl!(i64, u64, i64, mask_i64, bits_i64, sign_extend_i64);
+consts_impl!(i128, u128, i128, mask_i128, bits_i128, sign_extend_i128);
+consts_impl!(
+    isize,
+    usize,
+    isize,
+    mask_isize,
+    bits_isize,
+    sign_extend_isize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BitIndex, BitMask, Signs};
+    use spectral::prelude::*;
+
+    #[test]
+    fn exhaustive_u8() {
+        for size in 0..=10 {
+            asserting!("const masks match trait masks")
+                .that(&(mask_u8(size), mask_i8(size)))
+                .is_equal_to((u8::mask(size), i8::mask(size)));
+        }
+
+        for value in 0..=u8::MAX {
+            for start in 0..=10 {
+                for length in 0..=10 {
+                    asserting!("const bits match trait bits")
+                        .that(&bits_u8(value, start, length))
+                        .is_equal_to(value.bits(start..start + length));
+                    asserting!("const bits match trait bits")
+                        .that(&bits_i8(value as i8, start, length))
+                        .is_equal_to((value as i8).bits(start..start + length));
+                }
+            }
+
+            for bits in 0..=10 {
+                asserting!("const sign extension matches trait sign extension")
+                    .that(&sign_extend_u8(value, bits))
+                    .is_equal_to(value.sign_extend(bits));
+                asserting!("const sign extension matches trait sign extension")
+                    .that(&sign_extend_i8(value as i8, bits))
+                    .is_equal_to((value as i8).sign_extend(bits));
+            }
+        }
+    }
+
+    #[test]
+    fn wider_types() {
+        for &size in &[0, 1, 12, 31, 32, 33, 63, 64, 65, 127, 128, 129] {
+            asserting!("const masks match trait masks")
+                .that(&(mask_u32(size), mask_u64(size), mask_u128(size)))
+                .is_equal_to((u32::mask(size), u64::mask(size), u128::mask(size)));
+            asserting!("const masks match trait masks")
+                .that(&(mask_i32(size), mask_i64(size), mask_i128(size)))
+                .is_equal_to((i32::mask(size), i64::mask(size), i128::mask(size)));
+            asserting!("const masks match trait masks")
+                .that(&(mask_usize(size), mask_isize(size)))
+                .is_equal_to((usize::mask(size), isize::mask(size)));
+        }
+
+        let value: u32 = 0xe01a_3497;
+        for &(start, length) in &[(0, 4), (16, 4), (28, 8), (31, 1), (32, 4), (4, 32)] {
+            asserting!("const bits match trait bits")
+                .that(&bits_u32(value, start, length))
+                .is_equal_to(value.bits(start..start + length));
+            asserting!("const bits match trait bits")
+                .that(&bits_i64(value as i32 as i64, start, length))
+                .is_equal_to((value as i32 as i64).bits(start..start + length));
+            asserting!("const bits match trait bits")
+                .that(&bits_i128(-(value as i128), start, length))
+                .is_equal_to((-(value as i128)).bits(start..start + length));
+        }
+
+        for &bits in &[0, 1, 15, 16, 17, 31, 32, 33, 64, 127, 128] {
+            asserting!("const sign extension matches trait sign extension")
+                .that(&(
+                    sign_extend_u32(0x0000_8000, bits),
+                    sign_extend_i32(0x0000_8000, bits),
+                ))
+                .is_equal_to((
+                    0x0000_8000_u32.sign_extend(bits),
+                    0x0000_8000_i32.sign_extend(bits),
+                ));
+            asserting!("const sign extension matches trait sign extension")
+                .that(&sign_extend_u128(0x8000, bits))
+                .is_equal_to(0x8000_u128.sign_extend(bits));
+        }
+    }
+
+    #[test]
+    fn constant_expressions() {
+        const RD_MASK: u32 = mask_u32(4) << 16;
+        const IMM: i16 = sign_extend_i16(bits_i16(0x0ff0, 4, 8), 8);
+
+        asserting!("const functions can be used in constant expressions")
+            .that(&(RD_MASK, IMM))
+            .is_equal_to((0x000f_0000, -1));
+    }
+}
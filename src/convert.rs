@@ -0,0 +1,1038 @@
+use crate::{Int, TryFromIntError, UInt};
+use std::convert::TryFrom;
+
+// A conversion is `From` when every value of the source fits in the destination and `TryFrom`
+// otherwise. `usize` and `isize` are only assumed to have 16 bits, as in the standard library, so
+// the conversions don't depend on the target.
+
+/// Expands to `$callback!($($args)* BITS; from ...; try_from ...; into ...; try_into ...)`, listing
+/// the primitives that convert into and out of a `UInt` or `Int` of the width with `From` and with
+/// `TryFrom`.
+///
+/// The conversions of `UInt` and `Int` are generated from this table, and `define_uint!` and
+/// `define_int!` use it to give named types the same conversions as the type they wrap.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __conversions {
+    (uint 1, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 1; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 2, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 2; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 3, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 3; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 4, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 4; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 5, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 5; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 6, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 6; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 7, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 7; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; try_into );
+    };
+    (uint 8, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 8; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into i8);
+    };
+    (uint 9, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 9; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 10, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 10; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 11, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 11; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 12, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 12; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 13, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 13; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 14, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 14; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 15, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 15; from u8; try_from u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; try_into u8, i8);
+    };
+    (uint 16, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 16; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u16, u32, u64, u128, usize, i32, i64, i128; try_into u8, i8, i16, isize);
+    };
+    (uint 17, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 17; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 18, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 18; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 19, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 19; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 20, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 20; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 21, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 21; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 22, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 22; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 23, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 23; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 24, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 24; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 25, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 25; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 26, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 26; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 27, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 27; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 28, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 28; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 29, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 29; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 30, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 30; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 31, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 31; from u8, u16; try_from u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i32, i64, i128; try_into u8, u16, usize, i8, i16, isize);
+    };
+    (uint 32, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 32; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u32, u64, u128, i64, i128; try_into u8, u16, usize, i8, i16, i32, isize);
+    };
+    (uint 33, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 33; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 34, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 34; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 35, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 35; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 36, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 36; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 37, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 37; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 38, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 38; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 39, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 39; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 40, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 40; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 41, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 41; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 42, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 42; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 43, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 43; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 44, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 44; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 45, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 45; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 46, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 46; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 47, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 47; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 48, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 48; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 49, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 49; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 50, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 50; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 51, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 51; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 52, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 52; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 53, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 53; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 54, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 54; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 55, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 55; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 56, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 56; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 57, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 57; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 58, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 58; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 59, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 59; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 60, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 60; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 61, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 61; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 62, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 62; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 63, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 63; from u8, u16, u32; try_from u64, u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i64, i128; try_into u8, u16, u32, usize, i8, i16, i32, isize);
+    };
+    (uint 64, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 64; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u64, u128, i128; try_into u8, u16, u32, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 65, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 65; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 66, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 66; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 67, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 67; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 68, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 68; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 69, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 69; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 70, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 70; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 71, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 71; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 72, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 72; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 73, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 73; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 74, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 74; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 75, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 75; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 76, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 76; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 77, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 77; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 78, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 78; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 79, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 79; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 80, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 80; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 81, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 81; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 82, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 82; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 83, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 83; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 84, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 84; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 85, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 85; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 86, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 86; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 87, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 87; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 88, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 88; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 89, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 89; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 90, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 90; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 91, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 91; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 92, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 92; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 93, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 93; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 94, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 94; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 95, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 95; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 96, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 96; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 97, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 97; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 98, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 98; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 99, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 99; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 100, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 100; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 101, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 101; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 102, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 102; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 103, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 103; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 104, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 104; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 105, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 105; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 106, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 106; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 107, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 107; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 108, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 108; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 109, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 109; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 110, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 110; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 111, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 111; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 112, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 112; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 113, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 113; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 114, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 114; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 115, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 115; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 116, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 116; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 117, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 117; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 118, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 118; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 119, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 119; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 120, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 120; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 121, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 121; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 122, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 122; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 123, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 123; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 124, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 124; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 125, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 125; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 126, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 126; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 127, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 127; from u8, u16, u32, u64; try_from u128, usize, i8, i16, i32, i64, i128, isize; into u128, i128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    };
+    (uint 128, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 128; from u8, u16, u32, u64, u128; try_from usize, i8, i16, i32, i64, i128, isize; into u128; try_into u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+    };
+    (int 1, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 1; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 2, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 2; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 3, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 3; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 4, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 4; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 5, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 5; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 6, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 6; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 7, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 7; from ; try_from u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 8, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 8; from i8; try_from u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i8, i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize);
+    };
+    (int 9, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 9; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 10, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 10; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 11, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 11; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 12, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 12; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 13, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 13; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 14, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 14; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 15, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 15; from u8, i8; try_from u16, u32, u64, u128, usize, i16, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 16, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 16; from u8, i8, i16; try_from u16, u32, u64, u128, usize, i32, i64, i128, isize; into i16, i32, i64, i128, isize; try_into u8, u16, u32, u64, u128, usize, i8);
+    };
+    (int 17, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 17; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 18, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 18; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 19, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 19; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 20, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 20; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 21, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 21; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 22, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 22; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 23, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 23; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 24, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 24; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 25, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 25; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 26, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 26; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 27, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 27; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 28, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 28; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 29, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 29; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 30, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 30; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 31, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 31; from u8, u16, i8, i16; try_from u32, u64, u128, usize, i32, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 32, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 32; from u8, u16, i8, i16, i32; try_from u32, u64, u128, usize, i64, i128, isize; into i32, i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, isize);
+    };
+    (int 33, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 33; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 34, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 34; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 35, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 35; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 36, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 36; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 37, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 37; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 38, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 38; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 39, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 39; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 40, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 40; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 41, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 41; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 42, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 42; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 43, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 43; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 44, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 44; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 45, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 45; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 46, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 46; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 47, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 47; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 48, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 48; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 49, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 49; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 50, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 50; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 51, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 51; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 52, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 52; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 53, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 53; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 54, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 54; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 55, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 55; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 56, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 56; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 57, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 57; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 58, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 58; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 59, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 59; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 60, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 60; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 61, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 61; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 62, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 62; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 63, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 63; from u8, u16, u32, i8, i16, i32; try_from u64, u128, usize, i64, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 64, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 64; from u8, u16, u32, i8, i16, i32, i64; try_from u64, u128, usize, i128, isize; into i64, i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+    };
+    (int 65, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 65; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 66, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 66; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 67, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 67; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 68, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 68; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 69, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 69; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 70, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 70; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 71, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 71; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 72, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 72; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 73, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 73; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 74, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 74; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 75, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 75; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 76, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 76; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 77, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 77; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 78, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 78; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 79, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 79; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 80, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 80; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 81, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 81; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 82, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 82; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 83, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 83; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 84, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 84; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 85, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 85; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 86, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 86; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 87, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 87; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 88, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 88; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 89, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 89; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 90, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 90; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 91, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 91; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 92, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 92; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 93, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 93; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 94, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 94; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 95, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 95; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 96, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 96; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 97, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 97; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 98, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 98; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 99, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 99; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 100, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 100; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 101, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 101; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 102, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 102; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 103, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 103; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 104, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 104; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 105, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 105; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 106, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 106; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 107, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 107; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 108, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 108; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 109, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 109; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 110, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 110; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 111, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 111; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 112, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 112; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 113, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 113; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 114, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 114; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 115, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 115; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 116, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 116; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 117, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 117; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 118, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 118; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 119, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 119; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 120, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 120; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 121, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 121; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 122, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 122; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 123, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 123; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 124, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 124; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 125, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 125; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 126, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 126; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 127, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 127; from u8, u16, u32, u64, i8, i16, i32, i64; try_from u128, usize, i128, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+    (int 128, $callback:path, ($($args:tt)*)) => {
+        $callback!($($args)* 128; from u8, u16, u32, u64, i8, i16, i32, i64, i128; try_from u128, usize, isize; into i128; try_into u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+    };
+}
+
+macro_rules! uint_convert_impl {
+    (
+        $bits:literal;
+        from $($from:ty),*;
+        try_from $($try_from:ty),*;
+        into $($into:ty),*;
+        try_into $($try_into:ty),*
+    ) => {
+        $(
+            impl From<$from> for UInt<$bits> {
+                fn from(value: $from) -> UInt<$bits> {
+                    UInt::masked(value as u128)
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for UInt<$bits> {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $try_from) -> Result<UInt<$bits>, TryFromIntError> {
+                    u128::try_from(value)
+                        .ok()
+                        .and_then(UInt::checked_new)
+                        .ok_or_else(|| TryFromIntError::unsigned(value, $bits))
+                }
+            }
+        )*
+
+        $(
+            impl From<UInt<$bits>> for $into {
+                fn from(value: UInt<$bits>) -> $into {
+                    value.get() as $into
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<UInt<$bits>> for $try_into {
+                type Error = TryFromIntError;
+
+                fn try_from(value: UInt<$bits>) -> Result<$try_into, TryFromIntError> {
+                    <$try_into>::try_from(value.get()).map_err(|_| {
+                        TryFromIntError::for_primitive::<$try_into>(value.get())
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! int_convert_impl {
+    (
+        $bits:literal;
+        from $($from:ty),*;
+        try_from $($try_from:ty),*;
+        into $($into:ty),*;
+        try_into $($try_into:ty),*
+    ) => {
+        $(
+            impl From<$from> for Int<$bits> {
+                fn from(value: $from) -> Int<$bits> {
+                    Int::masked(value as i128)
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for Int<$bits> {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $try_from) -> Result<Int<$bits>, TryFromIntError> {
+                    i128::try_from(value)
+                        .ok()
+                        .and_then(Int::checked_new)
+                        .ok_or_else(|| TryFromIntError::signed(value, $bits))
+                }
+            }
+        )*
+
+        $(
+            impl From<Int<$bits>> for $into {
+                fn from(value: Int<$bits>) -> $into {
+                    value.get() as $into
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<Int<$bits>> for $try_into {
+                type Error = TryFromIntError;
+
+                fn try_from(value: Int<$bits>) -> Result<$try_into, TryFromIntError> {
+                    <$try_into>::try_from(value.get()).map_err(|_| {
+                        TryFromIntError::for_primitive::<$try_into>(value.get())
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! convert_impl {
+    ($($kind:ident, $callback:ident;)*) => {
+        $(
+            convert_impl!(@widths $kind, $callback;
+                1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59
+                60 61 62 63 64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87
+                88 89 90 91 92 93 94 95 96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+                112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128
+            );
+        )*
+    };
+    (@widths $kind:ident, $callback:ident; $($bits:tt)*) => {
+        $(
+            crate::__conversions!($kind $bits, $callback, ());
+        )*
+    };
+}
+
+convert_impl!(
+    uint, uint_convert_impl;
+    int, int_convert_impl;
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    macro_rules! check_unsigned {
+        ($($bits:literal),*) => {
+            $(
+                let max = UInt::<$bits>::MAX.get();
+
+                for value in [0, 1, max / 2, max] {
+                    let narrow = UInt::<$bits>::try_from(value).unwrap();
+
+                    asserting!("values round-trip through the primitives")
+                        .that(&(
+                            UInt::<$bits>::try_from(u64::try_from(narrow).unwrap()),
+                            UInt::<$bits>::try_from(i64::try_from(narrow).unwrap()),
+                            UInt::<$bits>::try_from(u128::from(narrow)),
+                        ))
+                        .is_equal_to((Ok(narrow), Ok(narrow), Ok(narrow)));
+                }
+
+                asserting!("values that don't fit are rejected")
+                    .that(&(
+                        UInt::<$bits>::try_from(max + 1).map_err(|error| error.to_string()),
+                        UInt::<$bits>::try_from(-1_i8).map_err(|error| error.to_string()),
+                    ))
+                    .is_equal_to((
+                        Err(format!(
+                            "{} doesn't fit in {} bits as an unsigned integer",
+                            max + 1,
+                            $bits
+                        )),
+                        Err(format!("-1 doesn't fit in {} bits as an unsigned integer", $bits)),
+                    ));
+            )*
+        };
+    }
+
+    macro_rules! check_signed {
+        ($($bits:literal),*) => {
+            $(
+                let (min, max) = (Int::<$bits>::MIN.get(), Int::<$bits>::MAX.get());
+
+                for value in [min, min / 2, 0, max / 2, max] {
+                    let narrow = Int::<$bits>::try_from(value).unwrap();
+
+                    asserting!("values round-trip through the primitives")
+                        .that(&(
+                            Int::<$bits>::try_from(i64::try_from(narrow).unwrap()),
+                            Int::<$bits>::try_from(i128::from(narrow)),
+                        ))
+                        .is_equal_to((Ok(narrow), Ok(narrow)));
+                }
+
+                asserting!("values that don't fit are rejected")
+                    .that(&(
+                        Int::<$bits>::try_from(max + 1).map_err(|error| error.to_string()),
+                        Int::<$bits>::try_from(min - 1).map_err(|error| error.to_string()),
+                        u64::try_from(Int::<$bits>::MIN).map_err(|error| error.to_string()),
+                    ))
+                    .is_equal_to((
+                        Err(format!(
+                            "{} doesn't fit in {} bits as a signed integer",
+                            max + 1,
+                            $bits
+                        )),
+                        Err(format!(
+                            "{} doesn't fit in {} bits as a signed integer",
+                            min - 1,
+                            $bits
+                        )),
+                        Err(format!("{} doesn't fit in 64 bits as an unsigned integer", min)),
+                    ));
+            )*
+        };
+    }
+
+    #[test]
+    fn round_trips() {
+        check_unsigned!(1, 7, 12, 24, 48, 63);
+        check_signed!(1, 7, 12, 24, 48, 63);
+    }
+
+    #[test]
+    fn lossless_conversions() {
+        asserting!("smaller primitives convert into the narrow types")
+            .that(&(
+                UInt::<12>::from(0xff_u8).get(),
+                UInt::<8>::from(0xff_u8).get(),
+                Int::<12>::from(-128_i8).get(),
+                Int::<9>::from(0xff_u8).get(),
+            ))
+            .is_equal_to((0xff, 0xff, -128, 0xff));
+
+        asserting!("narrow types convert into wider primitives")
+            .that(&(
+                u16::from(UInt::<12>::MAX),
+                i16::from(UInt::<12>::MAX),
+                i32::from(Int::<12>::MIN),
+                usize::from(UInt::<16>::MAX),
+            ))
+            .is_equal_to((0xfff, 0xfff, -0x800, 0xffff));
+
+        asserting!("narrowing conversions are checked")
+            .that(&(
+                u8::try_from(UInt::<12>::new(0xff)).ok(),
+                u8::try_from(UInt::<12>::new(0x100)).ok(),
+                i8::try_from(Int::<12>::new(-129)).ok(),
+                u8::try_from(Int::<12>::new(-1)).ok(),
+            ))
+            .is_equal_to((Some(0xff), None, None, None));
+
+        asserting!("errors describe the destination")
+            .that(&(
+                u8::try_from(UInt::<12>::new(0x100))
+                    .unwrap_err()
+                    .to_string(),
+                i8::try_from(Int::<12>::new(-129)).unwrap_err().bits(),
+            ))
+            .is_equal_to((
+                "256 doesn't fit in 8 bits as an unsigned integer".to_string(),
+                8,
+            ));
+    }
+}
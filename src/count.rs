@@ -0,0 +1,222 @@
+use crate::{Int, UInt};
+
+// The counts only look at the bits inside the width, so a `UInt<12>` has 12 bits to count no matter
+// how it's stored. Signed values are counted by their two's complement pattern, the same way the
+// primitives count them, which means a negative value has leading ones rather than leading zeros.
+
+macro_rules! count_impl {
+    ($($type:ident, $value:literal, $pattern:literal, $ones:literal, $leading_zeros:literal;)*) => {
+        $(
+            impl<const BITS: usize> $type<BITS> {
+                #[doc = concat!(
+                    "Returns the number of ones in the bits of the value.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "// ", $pattern, "\n",
+                    "let value = ", stringify!($type), "::<12>::new(", $value, ");\n",
+                    "assert_eq!(value.count_ones(), ", $ones, ");\n",
+                    "assert_eq!(value.count_zeros(), 12 - ", $ones, ");\n",
+                    "```",
+                )]
+                pub const fn count_ones(self) -> u32 {
+                    self.to_bits().count_ones()
+                }
+
+                /// Returns the number of zeros in the bits of the value, counting only the bits
+                /// inside the width.
+                pub const fn count_zeros(self) -> u32 {
+                    BITS as u32 - self.count_ones()
+                }
+
+                #[doc = concat!(
+                    "Returns the number of leading zeros in the bits of the value, counting down ",
+                    "from bit `BITS - 1`.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "// ", $pattern, "\n",
+                    "let value = ", stringify!($type), "::<12>::new(", $value, ");\n",
+                    "assert_eq!(value.leading_zeros(), ", $leading_zeros, ");\n",
+                    "assert_eq!(", stringify!($type), "::<12>::ZERO.leading_zeros(), 12);\n",
+                    "```",
+                )]
+                pub const fn leading_zeros(self) -> u32 {
+                    self.to_bits().leading_zeros() - (128 - BITS as u32)
+                }
+
+                /// Returns the number of trailing zeros in the bits of the value, which is the
+                /// width for zero.
+                pub const fn trailing_zeros(self) -> u32 {
+                    let zeros = self.to_bits().trailing_zeros();
+                    if zeros < BITS as u32 {
+                        zeros
+                    } else {
+                        BITS as u32
+                    }
+                }
+
+                /// Returns the number of leading ones in the bits of the value, counting down from
+                /// bit `BITS - 1`.
+                pub const fn leading_ones(self) -> u32 {
+                    (self.to_bits() << (128 - BITS)).leading_ones()
+                }
+
+                /// Returns the number of trailing ones in the bits of the value.
+                pub const fn trailing_ones(self) -> u32 {
+                    self.to_bits().trailing_ones()
+                }
+            }
+        )*
+    };
+}
+
+count_impl! {
+    UInt, "0x0f0", "0b0000_1111_0000", "4", "4";
+    Int, "-0x100", "0b1111_0000_0000", "4", "0";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::consts;
+    use crate::test_util::random_u128s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn matching_primitives() {
+        macro_rules! check {
+            ($($bits:literal => $unsigned:ty, $signed:ty;)*) => {
+                $(
+                    for value in [0, 1, 0x80, 0x0ff0, 0x8001, u128::MAX, 0x1234_5678_9abc_def0] {
+                        let (x, y) = (UInt::<$bits>::masked(value), Int::<$bits>::masked(value as i128));
+                        let (p, q) = (value as $unsigned, value as $signed);
+
+                        asserting!("unsigned counts match the primitive")
+                            .that(&(
+                                x.count_ones(),
+                                x.count_zeros(),
+                                x.leading_zeros(),
+                                x.trailing_zeros(),
+                                x.leading_ones(),
+                                x.trailing_ones(),
+                            ))
+                            .is_equal_to((
+                                p.count_ones(),
+                                p.count_zeros(),
+                                p.leading_zeros(),
+                                p.trailing_zeros(),
+                                p.leading_ones(),
+                                p.trailing_ones(),
+                            ));
+
+                        asserting!("signed counts match the primitive")
+                            .that(&(
+                                y.count_ones(),
+                                y.count_zeros(),
+                                y.leading_zeros(),
+                                y.trailing_zeros(),
+                                y.leading_ones(),
+                                y.trailing_ones(),
+                            ))
+                            .is_equal_to((
+                                q.count_ones(),
+                                q.count_zeros(),
+                                q.leading_zeros(),
+                                q.trailing_zeros(),
+                                q.leading_ones(),
+                                q.trailing_ones(),
+                            ));
+                    }
+                )*
+            };
+        }
+
+        check! {
+            8 => u8, i8;
+            16 => u16, i16;
+            32 => u32, i32;
+            64 => u64, i64;
+            128 => u128, i128;
+        }
+    }
+
+    #[test]
+    fn counting_within_the_width() {
+        fn check<const BITS: usize>() {
+            let mask = consts::mask_u128(BITS);
+            let unused = 128 - BITS as u32;
+
+            for seed in random_u128s().take(100) {
+                let bits = seed & mask;
+                let expected = (
+                    bits.count_ones(),
+                    BITS as u32 - bits.count_ones(),
+                    bits.leading_zeros() - unused,
+                    bits.trailing_zeros().min(BITS as u32),
+                    (bits << unused).leading_ones(),
+                    bits.trailing_ones(),
+                );
+
+                let x = UInt::<BITS>::masked(seed);
+                asserting!("unsigned counts only see the width")
+                    .that(&(
+                        x.count_ones(),
+                        x.count_zeros(),
+                        x.leading_zeros(),
+                        x.trailing_zeros(),
+                        x.leading_ones(),
+                        x.trailing_ones(),
+                    ))
+                    .is_equal_to(expected);
+
+                let y = Int::<BITS>::masked(seed as i128);
+                asserting!("signed counts see the pattern inside the width")
+                    .that(&(
+                        y.count_ones(),
+                        y.count_zeros(),
+                        y.leading_zeros(),
+                        y.trailing_zeros(),
+                        y.leading_ones(),
+                        y.trailing_ones(),
+                    ))
+                    .is_equal_to(expected);
+            }
+        }
+
+        check::<1>();
+        check::<5>();
+        check::<12>();
+        check::<33>();
+        check::<127>();
+    }
+
+    #[test]
+    fn edges() {
+        asserting!("zero and all ones are the whole width")
+            .that(&(
+                UInt::<12>::ZERO.leading_zeros(),
+                UInt::<12>::ZERO.trailing_zeros(),
+                UInt::<12>::MAX.leading_ones(),
+                UInt::<12>::MAX.trailing_ones(),
+                Int::<12>::new(-1).count_ones(),
+                Int::<12>::new(-1).leading_ones(),
+            ))
+            .is_equal_to((12, 12, 12, 12, 12, 12));
+
+        asserting!("the sign bit is the leading bit")
+            .that(&(
+                Int::<12>::MIN.leading_ones(),
+                Int::<12>::MIN.trailing_zeros(),
+                Int::<12>::MAX.leading_zeros(),
+                Int::<1>::new(-1).leading_ones(),
+            ))
+            .is_equal_to((1, 11, 1, 1));
+    }
+}
@@ -0,0 +1,894 @@
+/// Defines a named unsigned integer type with a fixed number of bits.
+///
+/// The type is a newtype around [`UInt`](struct.UInt.html) of the same width, with the same
+/// constants and constructors, and conversions to and from that `UInt`. The operators, `FromStr`,
+/// the radix formatting traits, `BitSize`, `BitIndex`, `BitMask`, and `Signs` all pass through to
+/// the `UInt`, as do its conversions to and from the primitives, its comparisons with them, its
+/// `checked_*`, `wrapping_*`, `saturating_*`, and `overflowing_*` arithmetic, its byte conversions,
+/// and its ranges. It formats as its number with `Display` and as `Name(number)` with `Debug`.
+/// Attributes written before the visibility are applied to the type, so it can derive more traits
+/// or carry documentation of its own.
+///
+/// The width has to be written as a number, since the conversions it gets depend on it.
+///
+/// Giving a width its own name keeps values of different kinds apart: a physical address can't be
+/// passed where a virtual one is expected, even though both are 52 bits.
+///
+/// # Examples
+///
+/// ```
+/// use quark::define_uint;
+///
+/// define_uint!(
+///     /// A physical address on a machine with 52-bit physical addressing.
+///     pub PhysAddr, 52
+/// );
+/// define_uint!(pub PageNumber, 40);
+///
+/// const KERNEL_BASE: PhysAddr = PhysAddr::new(0x0010_0000);
+///
+/// fn page_number(address: PhysAddr) -> PageNumber {
+///     PageNumber::masked(address.get() >> 12)
+/// }
+///
+/// let address = PhysAddr::new(0x000f_ffff_ffff_f123);
+/// assert_eq!(page_number(address), PageNumber::MAX);
+/// assert_eq!(format!("{:?}", PageNumber::new(5)), "PageNumber(5)");
+/// assert!(PhysAddr::try_new(1 << 52).is_err());
+/// assert_eq!(page_number(KERNEL_BASE), PageNumber::new(0x100));
+///
+/// let address = PhysAddr::from(5_u8);
+/// assert_eq!(address.checked_add(PhysAddr::MAX), None);
+/// assert!(address < 6_u32);
+/// ```
+#[macro_export]
+macro_rules! define_uint {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $bits:tt $(,)?) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::UInt<{ $bits }>);
+
+        impl $name {
+            /// The smallest value of the type, 0.
+            pub const MIN: $name = $name($crate::UInt::MIN);
+
+            /// The largest value of the type.
+            pub const MAX: $name = $name($crate::UInt::MAX);
+
+            /// Creates a value, panicking if it doesn't fit in the type.
+            ///
+            /// In a constant, a value that doesn't fit is a compile error.
+            #[allow(dead_code)]
+            #[track_caller]
+            pub const fn new(value: u128) -> $name {
+                $name($crate::UInt::new(value))
+            }
+
+            /// Creates a value, returning an error if it doesn't fit in the type.
+            #[allow(dead_code)]
+            pub const fn try_new(
+                value: u128,
+            ) -> ::std::result::Result<$name, $crate::TryFromIntError> {
+                match $crate::UInt::try_new(value) {
+                    Ok(value) => Ok($name(value)),
+                    Err(error) => Err(error),
+                }
+            }
+
+            /// Creates a value from the lowest bits of `value`, discarding the rest.
+            #[allow(dead_code)]
+            pub const fn masked(value: u128) -> $name {
+                $name($crate::UInt::masked(value))
+            }
+
+            /// Returns the value as a `u128`.
+            #[allow(dead_code)]
+            pub const fn get(self) -> u128 {
+                self.0.get()
+            }
+
+            /// Returns the value as a `u128`, the same as `get`.
+            #[allow(dead_code)]
+            pub const fn value(self) -> u128 {
+                self.0.get()
+            }
+
+            /// Consumes the wrapper and returns the value as a `u128`, the same as `get`.
+            #[allow(dead_code)]
+            pub const fn into_inner(self) -> u128 {
+                self.0.get()
+            }
+        }
+
+        $crate::__define_common!($name, $crate::UInt<{ $bits }>);
+        $crate::__conversions!(
+            uint $bits,
+            $crate::__define_common,
+            (@convert $name, $crate::UInt<{ $bits }>;)
+        );
+    };
+}
+
+/// Defines a named signed integer type with a fixed number of bits.
+///
+/// This is the signed counterpart of [`define_uint!`](macro.define_uint.html): the type is a
+/// newtype around [`Int`](struct.Int.html) of the same width, with the same constants,
+/// constructors, conversions, comparisons, methods, formatting, operators, and bit traits, as well
+/// as `Neg`.
+///
+/// # Examples
+///
+/// ```
+/// use quark::define_int;
+///
+/// define_int!(
+///     #[derive(Default)]
+///     pub SampleI20, 20
+/// );
+///
+/// let sample = SampleI20::masked(0xf_ffff);
+/// assert_eq!(sample.get(), -1);
+/// assert_eq!(SampleI20::MIN.get(), -0x8_0000);
+/// assert_eq!(SampleI20::default().get(), 0);
+/// assert_eq!(sample.to_string(), "-1");
+/// ```
+#[macro_export]
+macro_rules! define_int {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $bits:tt $(,)?) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::Int<{ $bits }>);
+
+        impl $name {
+            /// The smallest value of the type.
+            pub const MIN: $name = $name($crate::Int::MIN);
+
+            /// The largest value of the type.
+            pub const MAX: $name = $name($crate::Int::MAX);
+
+            /// Creates a value, panicking if it doesn't fit in the type.
+            ///
+            /// In a constant, a value that doesn't fit is a compile error.
+            #[allow(dead_code)]
+            #[track_caller]
+            pub const fn new(value: i128) -> $name {
+                $name($crate::Int::new(value))
+            }
+
+            /// Creates a value, returning an error if it doesn't fit in the type.
+            #[allow(dead_code)]
+            pub const fn try_new(
+                value: i128,
+            ) -> ::std::result::Result<$name, $crate::TryFromIntError> {
+                match $crate::Int::try_new(value) {
+                    Ok(value) => Ok($name(value)),
+                    Err(error) => Err(error),
+                }
+            }
+
+            /// Creates a value from the lowest bits of `value`, sign-extending from the top bit of
+            /// the width.
+            #[allow(dead_code)]
+            pub const fn masked(value: i128) -> $name {
+                $name($crate::Int::masked(value))
+            }
+
+            /// Returns the value as an `i128`.
+            #[allow(dead_code)]
+            pub const fn get(self) -> i128 {
+                self.0.get()
+            }
+
+            /// Returns the value as an `i128`, the same as `get`.
+            #[allow(dead_code)]
+            pub const fn value(self) -> i128 {
+                self.0.get()
+            }
+
+            /// Consumes the wrapper and returns the value as an `i128`, the same as `get`.
+            #[allow(dead_code)]
+            pub const fn into_inner(self) -> i128 {
+                self.0.get()
+            }
+        }
+
+        $crate::__define_common!($name, $crate::Int<{ $bits }>);
+        $crate::__conversions!(
+            int $bits,
+            $crate::__define_common,
+            (@convert $name, $crate::Int<{ $bits }>;)
+        );
+
+        impl ::std::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+    };
+}
+
+/// Implements the parts of `define_uint!` and `define_int!` that don't depend on the sign.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_common {
+    ($name:ident, $inner:ty) => {
+        impl $crate::BitSize for $name {
+            const BIT_SIZE: usize = <$inner as $crate::BitSize>::BIT_SIZE;
+        }
+
+        impl $crate::BitConsts for $name {
+            const ALL_SET: $name = $name(<$inner as $crate::BitConsts>::ALL_SET);
+            const NONE_SET: $name = $name(<$inner as $crate::BitConsts>::NONE_SET);
+        }
+
+        impl ::std::convert::From<$inner> for $name {
+            fn from(value: $inner) -> $name {
+                $name(value)
+            }
+        }
+
+        impl ::std::convert::From<$name> for $inner {
+            fn from(value: $name) -> $inner {
+                value.0
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(src: &str) -> ::std::result::Result<$name, $crate::ParseError> {
+                <$inner as ::std::str::FromStr>::from_str(src).map($name)
+            }
+        }
+
+        impl $crate::BitIndex for $name {
+            fn bit(&self, index: usize) -> bool {
+                $crate::BitIndex::bit(&self.0, index)
+            }
+
+            fn bits<Idx: ::std::ops::RangeBounds<usize>>(&self, index: Idx) -> $name {
+                $name($crate::BitIndex::bits(&self.0, index))
+            }
+
+            fn with_bits<Idx: ::std::ops::RangeBounds<usize>>(
+                &self,
+                index: Idx,
+                value: $name,
+            ) -> $name {
+                $name($crate::BitIndex::with_bits(&self.0, index, value.0))
+            }
+        }
+
+        impl $crate::BitMask for $name {
+            fn mask(size: usize) -> $name {
+                $name(<$inner as $crate::BitMask>::mask(size))
+            }
+
+            fn mask_to(&self, size: usize) -> $name {
+                $name($crate::BitMask::mask_to(&self.0, size))
+            }
+
+            fn mask_range<Idx: ::std::ops::RangeBounds<usize>>(range: Idx) -> $name {
+                $name(<$inner as $crate::BitMask>::mask_range(range))
+            }
+        }
+
+        impl $crate::Signs for $name {
+            fn sign_bit(&self) -> bool {
+                $crate::Signs::sign_bit(&self.0)
+            }
+
+            fn leading_sign_bits(&self) -> u32 {
+                $crate::Signs::leading_sign_bits(&self.0)
+            }
+
+            fn sign_extend(&self, bits: usize) -> $name {
+                $name($crate::Signs::sign_extend(&self.0, bits))
+            }
+
+            fn sign_extend_from(&self, width: usize) -> $name {
+                $name($crate::Signs::sign_extend_from(&self.0, width))
+            }
+
+            fn checked_sign_extend(&self, bits: usize) -> ::std::option::Option<$name> {
+                $crate::Signs::checked_sign_extend(&self.0, bits).map($name)
+            }
+
+            fn checked_sign_extend_from(&self, width: usize) -> ::std::option::Option<$name> {
+                $crate::Signs::checked_sign_extend_from(&self.0, width).map($name)
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                $crate::Signs::fits_in_unsigned(&self.0, bits)
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                $crate::Signs::fits_in_signed(&self.0, bits)
+            }
+
+            fn min_unsigned_bits(&self) -> usize {
+                $crate::Signs::min_unsigned_bits(&self.0)
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                $crate::Signs::min_signed_bits(&self.0)
+            }
+
+            fn saturate_to_bits_unsigned(&self, width: usize) -> $name {
+                $name($crate::Signs::saturate_to_bits_unsigned(&self.0, width))
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> $name {
+                $name($crate::Signs::saturate_to_bits_signed(&self.0, width))
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> $name {
+                $name($crate::Signs::wrapping_truncate_to_bits(&self.0, width))
+            }
+
+            fn to_sign_magnitude(&self, width: usize) -> $name {
+                $name($crate::Signs::to_sign_magnitude(&self.0, width))
+            }
+
+            fn try_to_sign_magnitude(&self, width: usize) -> ::std::option::Option<$name> {
+                $crate::Signs::try_to_sign_magnitude(&self.0, width).map($name)
+            }
+
+            fn from_sign_magnitude(&self, width: usize) -> $name {
+                $name($crate::Signs::from_sign_magnitude(&self.0, width))
+            }
+
+            fn zero_extend(&self, bits: usize) -> $name {
+                $name($crate::Signs::zero_extend(&self.0, bits))
+            }
+
+            fn arithmetic_shr(&self, n: usize) -> $name {
+                $name($crate::Signs::arithmetic_shr(&self.0, n))
+            }
+
+            fn logical_shr(&self, n: usize) -> $name {
+                $name($crate::Signs::logical_shr(&self.0, n))
+            }
+        }
+
+        impl ::std::ops::Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                $name(!self.0)
+            }
+        }
+
+        $crate::__define_common!(@binary $name;
+            Add, add, AddAssign, add_assign;
+            Sub, sub, SubAssign, sub_assign;
+            Mul, mul, MulAssign, mul_assign;
+            Div, div, DivAssign, div_assign;
+            Rem, rem, RemAssign, rem_assign;
+            BitAnd, bitand, BitAndAssign, bitand_assign;
+            BitOr, bitor, BitOrAssign, bitor_assign;
+            BitXor, bitxor, BitXorAssign, bitxor_assign;
+        );
+        $crate::__define_common!(@shift $name; u32, usize);
+        $crate::__define_common!(@radix $name; Binary, Octal, LowerHex, UpperHex);
+        $crate::__define_common!(
+            @compare $name;
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        );
+
+        #[allow(dead_code)]
+        impl $name {
+            $crate::__define_common!(
+                @method $name, ::std::option::Option<$name>;
+                checked_add, checked_sub, checked_mul, checked_div, checked_rem
+            );
+            $crate::__define_common!(
+                @method $name, $name;
+                wrapping_add, wrapping_sub, wrapping_mul, wrapping_div, wrapping_rem,
+                saturating_add, saturating_sub, saturating_mul
+            );
+            $crate::__define_common!(
+                @method $name, ($name, bool);
+                overflowing_add, overflowing_sub, overflowing_mul
+            );
+
+            /// Negates the value, returning `None` if the result doesn't fit, the same as
+            /// `checked_neg` on the wrapped type.
+            pub const fn checked_neg(self) -> ::std::option::Option<$name> {
+                match self.0.checked_neg() {
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some($name(value)),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                }
+            }
+
+            /// Negates the value, wrapping around at the width, the same as `wrapping_neg` on the
+            /// wrapped type.
+            pub const fn wrapping_neg(self) -> $name {
+                $name(self.0.wrapping_neg())
+            }
+
+            /// Negates the value, saturating at the limits of the width, the same as
+            /// `saturating_neg` on the wrapped type.
+            pub const fn saturating_neg(self) -> $name {
+                $name(self.0.saturating_neg())
+            }
+
+            /// Negates the value, wrapping around at the width and returning whether it did, the
+            /// same as `overflowing_neg` on the wrapped type.
+            pub const fn overflowing_neg(self) -> ($name, bool) {
+                let (value, overflowed) = self.0.overflowing_neg();
+                ($name(value), overflowed)
+            }
+
+            /// Returns the value as the fewest bytes that can hold the width, in little-endian
+            /// order, the same as `to_le_bytes` on the wrapped type.
+            pub const fn to_le_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+                self.0.to_le_bytes()
+            }
+
+            /// Returns the value as the fewest bytes that can hold the width, in big-endian order,
+            /// the same as `to_be_bytes` on the wrapped type.
+            pub const fn to_be_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+                self.0.to_be_bytes()
+            }
+
+            /// Creates a value from the fewest bytes that can hold the width, in little-endian
+            /// order, returning `None` if they hold a value that doesn't fit in the width, the
+            /// same as `from_le_bytes` on the wrapped type.
+            pub const fn from_le_bytes<const BYTES: usize>(
+                bytes: [u8; BYTES],
+            ) -> ::std::option::Option<$name> {
+                match <$inner>::from_le_bytes(bytes) {
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some($name(value)),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                }
+            }
+
+            /// Creates a value from the fewest bytes that can hold the width, in big-endian order,
+            /// returning `None` if they hold a value that doesn't fit in the width, the same as
+            /// `from_be_bytes` on the wrapped type.
+            pub const fn from_be_bytes<const BYTES: usize>(
+                bytes: [u8; BYTES],
+            ) -> ::std::option::Option<$name> {
+                match <$inner>::from_be_bytes(bytes) {
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some($name(value)),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                }
+            }
+
+            /// Returns an iterator over every value of the type, the same as `all_values` on the
+            /// wrapped type.
+            pub fn all_values() -> ::std::iter::Map<$crate::Values<$inner>, fn($inner) -> $name> {
+                <$inner>::all_values().map($name as fn($inner) -> $name)
+            }
+
+            /// Returns an iterator over the values from `start` up to, but not including, `end`,
+            /// the same as `range` on the wrapped type.
+            pub fn range(
+                start: $name,
+                end: $name,
+            ) -> ::std::iter::Map<$crate::Values<$inner>, fn($inner) -> $name> {
+                <$inner>::range(start.0, end.0).map($name as fn($inner) -> $name)
+            }
+
+            /// Returns an iterator over the values from `start` up to and including `end`, the
+            /// same as `range_inclusive` on the wrapped type.
+            pub fn range_inclusive(
+                start: $name,
+                end: $name,
+            ) -> ::std::iter::Map<$crate::Values<$inner>, fn($inner) -> $name> {
+                <$inner>::range_inclusive(start.0, end.0).map($name as fn($inner) -> $name)
+            }
+        }
+    };
+    (
+        @convert $name:ident, $inner:ty;
+        $bits:tt;
+        from $($from:ty),*;
+        try_from $($try_from:ty),*;
+        into $($into:ty),*;
+        try_into $($try_into:ty),*
+    ) => {
+        $(
+            impl ::std::convert::From<$from> for $name {
+                fn from(value: $from) -> $name {
+                    $name(<$inner as ::std::convert::From<$from>>::from(value))
+                }
+            }
+        )*
+
+        $(
+            impl ::std::convert::TryFrom<$try_from> for $name {
+                type Error = $crate::TryFromIntError;
+
+                fn try_from(
+                    value: $try_from,
+                ) -> ::std::result::Result<$name, $crate::TryFromIntError> {
+                    <$inner as ::std::convert::TryFrom<$try_from>>::try_from(value).map($name)
+                }
+            }
+        )*
+
+        $(
+            impl ::std::convert::From<$name> for $into {
+                fn from(value: $name) -> $into {
+                    <$into as ::std::convert::From<$inner>>::from(value.0)
+                }
+            }
+        )*
+
+        $(
+            impl ::std::convert::TryFrom<$name> for $try_into {
+                type Error = $crate::TryFromIntError;
+
+                fn try_from(
+                    value: $name,
+                ) -> ::std::result::Result<$try_into, $crate::TryFromIntError> {
+                    <$try_into as ::std::convert::TryFrom<$inner>>::try_from(value.0)
+                }
+            }
+        )*
+    };
+    (@compare $name:ident; $($primitive:ty),*) => {
+        $(
+            impl ::std::cmp::PartialEq<$primitive> for $name {
+                fn eq(&self, other: &$primitive) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl ::std::cmp::PartialEq<$name> for $primitive {
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.0
+                }
+            }
+
+            impl ::std::cmp::PartialOrd<$primitive> for $name {
+                fn partial_cmp(
+                    &self,
+                    other: &$primitive,
+                ) -> ::std::option::Option<::std::cmp::Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            impl ::std::cmp::PartialOrd<$name> for $primitive {
+                fn partial_cmp(
+                    &self,
+                    other: &$name,
+                ) -> ::std::option::Option<::std::cmp::Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+        )*
+    };
+    (@method $name:ident, ::std::option::Option<$name_again:ident>; $($method:ident),*) => {
+        $(
+            #[doc = concat!("The same as `", stringify!($method), "` on the wrapped type.")]
+            pub const fn $method(self, rhs: $name) -> ::std::option::Option<$name> {
+                match self.0.$method(rhs.0) {
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some($name(value)),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                }
+            }
+        )*
+    };
+    (@method $name:ident, ($name_again:ident, bool); $($method:ident),*) => {
+        $(
+            #[doc = concat!("The same as `", stringify!($method), "` on the wrapped type.")]
+            pub const fn $method(self, rhs: $name) -> ($name, bool) {
+                let (value, overflowed) = self.0.$method(rhs.0);
+                ($name(value), overflowed)
+            }
+        )*
+    };
+    (@method $name:ident, $name_again:ident; $($method:ident),*) => {
+        $(
+            #[doc = concat!("The same as `", stringify!($method), "` on the wrapped type.")]
+            pub const fn $method(self, rhs: $name) -> $name {
+                $name(self.0.$method(rhs.0))
+            }
+        )*
+    };
+    (@binary $name:ident; $($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)*) => {
+        $(
+            impl ::std::ops::$trait for $name {
+                type Output = $name;
+
+                fn $method(self, rhs: $name) -> $name {
+                    $name(::std::ops::$trait::$method(self.0, rhs.0))
+                }
+            }
+
+            impl ::std::ops::$assign_trait for $name {
+                fn $assign_method(&mut self, rhs: $name) {
+                    ::std::ops::$assign_trait::$assign_method(&mut self.0, rhs.0);
+                }
+            }
+        )*
+    };
+    (@shift $name:ident; $($type:ty),*) => {
+        $(
+            impl ::std::ops::Shl<$type> for $name {
+                type Output = $name;
+
+                fn shl(self, rhs: $type) -> $name {
+                    $name(self.0 << rhs)
+                }
+            }
+
+            impl ::std::ops::Shr<$type> for $name {
+                type Output = $name;
+
+                fn shr(self, rhs: $type) -> $name {
+                    $name(self.0 >> rhs)
+                }
+            }
+
+            impl ::std::ops::ShlAssign<$type> for $name {
+                fn shl_assign(&mut self, rhs: $type) {
+                    self.0 <<= rhs;
+                }
+            }
+
+            impl ::std::ops::ShrAssign<$type> for $name {
+                fn shr_assign(&mut self, rhs: $type) {
+                    self.0 >>= rhs;
+                }
+            }
+        )*
+    };
+    (@radix $name:ident; $($trait:ident),*) => {
+        $(
+            impl ::std::fmt::$trait for $name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    ::std::fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aliases::{i20, u12};
+    use crate::BitSize;
+    use spectral::prelude::*;
+    use std::convert::TryFrom;
+
+    define_uint!(Tag, 12);
+    define_int!(
+        #[derive(Default)]
+        Offset,
+        20
+    );
+
+    #[test]
+    fn matches_the_alias() {
+        for value in [0, 1, 0x7ff, 0xfff, 0x1000, 0xabcd, u128::MAX] {
+            asserting!("unsigned constructors match")
+                .that(&(Tag::try_new(value).map(Tag::get), Tag::masked(value).get()))
+                .is_equal_to((u12::try_new(value).map(u12::get), u12::masked(value).get()));
+        }
+
+        for value in [0, -1, 0x7_ffff, -0x8_0000, 0x8_0000, -0x8_0001, i128::MIN] {
+            asserting!("signed constructors match")
+                .that(&(
+                    Offset::try_new(value).map(Offset::get),
+                    Offset::masked(value).get(),
+                ))
+                .is_equal_to((i20::try_new(value).map(i20::get), i20::masked(value).get()));
+        }
+
+        asserting!("constants match")
+            .that(&(
+                (Tag::MIN.get(), Tag::MAX.get(), Tag::BIT_SIZE),
+                (Offset::MIN.get(), Offset::MAX.get(), Offset::BIT_SIZE),
+            ))
+            .is_equal_to((
+                (u12::MIN.get(), u12::MAX.get(), 12),
+                (i20::MIN.get(), i20::MAX.get(), 20),
+            ));
+
+        asserting!("primitive conversions match")
+            .that(&(
+                Tag::from(5_u8).get(),
+                Tag::try_from(0x1000_u16).map(Tag::get),
+                Offset::from(-5_i16).get(),
+                Offset::try_from(0x8_0000_i32).map(Offset::get),
+                u16::from(Tag::MAX),
+                u8::try_from(Tag::MAX),
+                i32::from(Offset::MIN),
+                u32::try_from(Offset::new(-1)),
+            ))
+            .is_equal_to((
+                u12::from(5_u8).get(),
+                u12::try_from(0x1000_u16).map(u12::get),
+                i20::from(-5_i16).get(),
+                i20::try_from(0x8_0000_i32).map(i20::get),
+                u16::from(u12::MAX),
+                u8::try_from(u12::MAX),
+                i32::from(i20::MIN),
+                u32::try_from(i20::new(-1)),
+            ));
+
+        asserting!("primitive comparisons match")
+            .that(&(
+                Tag::new(7) == 7_u8,
+                -1_i8 < Tag::new(0),
+                Offset::new(-3) > -4_i64,
+                0x10_0000_u32 > Offset::MAX,
+            ))
+            .is_equal_to((
+                u12::new(7) == 7_u8,
+                -1_i8 < u12::new(0),
+                i20::new(-3) > -4_i64,
+                0x10_0000_u32 > i20::MAX,
+            ));
+
+        asserting!("unsigned arithmetic matches")
+            .that(&(
+                Tag::MAX.checked_add(Tag::new(1)),
+                Tag::new(7).checked_rem(Tag::new(4)).map(Tag::get),
+                Tag::new(0).wrapping_sub(Tag::new(1)).get(),
+                Tag::new(0x800).saturating_mul(Tag::new(2)).get(),
+                Tag::new(0x800).overflowing_add(Tag::new(0x900)).1,
+                Tag::new(1).checked_neg(),
+            ))
+            .is_equal_to((
+                u12::MAX.checked_add(u12::new(1)).map(Tag::from),
+                u12::new(7).checked_rem(u12::new(4)).map(u12::get),
+                u12::new(0).wrapping_sub(u12::new(1)).get(),
+                u12::new(0x800).saturating_mul(u12::new(2)).get(),
+                u12::new(0x800).overflowing_add(u12::new(0x900)).1,
+                u12::new(1).checked_neg().map(Tag::from),
+            ));
+
+        asserting!("signed arithmetic matches")
+            .that(&(
+                Offset::MIN.checked_div(Offset::new(-1)),
+                Offset::MIN.wrapping_neg().get(),
+                Offset::MIN.saturating_neg().get(),
+                Offset::MAX.overflowing_mul(Offset::new(2)),
+            ))
+            .is_equal_to((
+                i20::MIN.checked_div(i20::new(-1)).map(Offset::from),
+                i20::MIN.wrapping_neg().get(),
+                i20::MIN.saturating_neg().get(),
+                (Offset::new(-2), true),
+            ));
+
+        asserting!("bytes match")
+            .that(&(
+                Tag::new(0xabc).to_le_bytes::<2>(),
+                Offset::new(-2).to_be_bytes::<3>(),
+                Tag::from_be_bytes([0x0a, 0xbc]),
+                Tag::from_le_bytes([0xbc, 0x1a]),
+            ))
+            .is_equal_to((
+                u12::new(0xabc).to_le_bytes::<2>(),
+                i20::new(-2).to_be_bytes::<3>(),
+                u12::from_be_bytes([0x0a, 0xbc]).map(Tag::from),
+                u12::from_le_bytes([0xbc, 0x1a]).map(Tag::from),
+            ));
+
+        asserting!("ranges match")
+            .that(&(
+                Tag::range(Tag::new(3), Tag::new(6))
+                    .map(Tag::get)
+                    .collect::<Vec<_>>(),
+                Offset::range_inclusive(Offset::new(-2), Offset::new(1))
+                    .map(Offset::get)
+                    .collect::<Vec<_>>(),
+                Tag::all_values().count(),
+            ))
+            .is_equal_to((
+                u12::range(u12::new(3), u12::new(6))
+                    .map(u12::get)
+                    .collect::<Vec<_>>(),
+                i20::range_inclusive(i20::new(-2), i20::new(1))
+                    .map(i20::get)
+                    .collect::<Vec<_>>(),
+                u12::all_values().count(),
+            ));
+    }
+
+    #[test]
+    fn converting_and_formatting() {
+        asserting!("values convert to and from the generic types")
+            .that(&(u12::from(Tag::new(7)), Offset::from(i20::new(-7))))
+            .is_equal_to((u12::new(7), Offset::new(-7)));
+
+        asserting!("values format as numbers")
+            .that(&(
+                Tag::MAX.to_string(),
+                format!("{:?}", Offset::new(-3)),
+                format!("{:>4}", Tag::new(9)),
+            ))
+            .is_equal_to((
+                "4095".to_string(),
+                "Offset(-3)".to_string(),
+                "   9".to_string(),
+            ));
+
+        asserting!("extra attributes are applied")
+            .that(&Offset::default())
+            .is_equal_to(Offset::new(0));
+
+        asserting!("values order by their numbers")
+            .that(&(
+                Offset::new(-1) < Offset::new(0),
+                Tag::new(3).max(Tag::new(2)),
+            ))
+            .is_equal_to((true, Tag::new(3)));
+    }
+
+    #[test]
+    fn delegating_to_the_inner_type() {
+        asserting!("the operators match the generic types")
+            .that(&(
+                (Tag::new(0x0f0) + Tag::new(1)).get(),
+                (Tag::new(0x0f0) & Tag::new(0x3c)).get(),
+                (!Tag::new(0x0f0)).get(),
+                (Tag::new(0x0f0) << 8_u32).get(),
+                (-Offset::new(5) * Offset::new(3)).get(),
+            ))
+            .is_equal_to((
+                (u12::new(0x0f0) + u12::new(1)).get(),
+                (u12::new(0x0f0) & u12::new(0x3c)).get(),
+                (!u12::new(0x0f0)).get(),
+                (u12::new(0x0f0) << 8_u32).get(),
+                (-i20::new(5) * i20::new(3)).get(),
+            ));
+
+        let mut tag = Tag::new(6);
+        tag -= Tag::new(2);
+        tag >>= 1_usize;
+
+        asserting!("the assigning operators match the generic types")
+            .that(&tag.get())
+            .is_equal_to(2);
+
+        asserting!("the bit traits match the generic types")
+            .that(&(
+                crate::BitIndex::bits(&Tag::new(0xabc), 4..8).get(),
+                crate::BitMask::mask_to(&Tag::MAX, 5).get(),
+                crate::Signs::sign_extend_from(&Offset::new(0xff), 8).get(),
+            ))
+            .is_equal_to((0xb, 0x1f, -1));
+
+        asserting!("values parse and format in every radix")
+            .that(&(
+                "0xabc".parse::<Tag>().map(Tag::get),
+                "0x1000".parse::<Tag>().is_err(),
+                format!(
+                    "{:x} {:#o} {:b} {:X}",
+                    Tag::new(0xabc),
+                    Tag::new(8),
+                    Tag::new(5),
+                    Offset::new(-1)
+                ),
+            ))
+            .is_equal_to((Ok(0xabc), true, "abc 0o10 101 FFFFF".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "4096 doesn't fit in 12 bits as an unsigned integer")]
+    fn new_panics_when_out_of_range() {
+        Tag::new(0x1000);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{i24, u24, u48, Field, Int, UInt};
+use defmt::{write, Format, Formatter};
+
+// Values are logged as the width and the value, like `12'0xab` or `12'-5`, with unsigned values in
+// hex since they're usually bit patterns. The value goes over the wire as the smallest primitive
+// that holds every value of the width, so logging a `UInt<12>` costs no more than a `u16`, and
+// the text is only put together by the host that decodes the log.
+
+impl<const BITS: usize> Format for UInt<BITS> {
+    fn format(&self, f: Formatter) {
+        let bits = BITS as u8;
+        match BITS {
+            0..=8 => write!(f, "{=u8}'{=u8:#x}", bits, self.get() as u8),
+            9..=16 => write!(f, "{=u8}'{=u16:#x}", bits, self.get() as u16),
+            17..=32 => write!(f, "{=u8}'{=u32:#x}", bits, self.get() as u32),
+            33..=64 => write!(f, "{=u8}'{=u64:#x}", bits, self.get() as u64),
+            _ => write!(f, "{=u8}'{=u128:#x}", bits, self.get()),
+        }
+    }
+}
+
+impl<const BITS: usize> Format for Int<BITS> {
+    fn format(&self, f: Formatter) {
+        let bits = BITS as u8;
+        match BITS {
+            0..=8 => write!(f, "{=u8}'{=i8}", bits, self.get() as i8),
+            9..=16 => write!(f, "{=u8}'{=i16}", bits, self.get() as i16),
+            17..=32 => write!(f, "{=u8}'{=i32}", bits, self.get() as i32),
+            33..=64 => write!(f, "{=u8}'{=i64}", bits, self.get() as i64),
+            _ => write!(f, "{=u8}'{=i128}", bits, self.get()),
+        }
+    }
+}
+
+impl Format for u24 {
+    fn format(&self, f: Formatter) {
+        write!(f, "24'{=u32:#x}", self.get())
+    }
+}
+
+impl Format for i24 {
+    fn format(&self, f: Formatter) {
+        write!(f, "24'{=i32}", self.get())
+    }
+}
+
+impl Format for u48 {
+    fn format(&self, f: Formatter) {
+        write!(f, "48'{=u64:#x}", self.get())
+    }
+}
+
+impl Format for Field {
+    fn format(&self, f: Formatter) {
+        write!(
+            f,
+            "Field {{ offset: {=usize}, width: {=usize} }}",
+            self.offset(),
+            self.width()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Logging needs a global logger and the linker script of an embedded target, so the tests
+    // can only check that the types can be logged.
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn types_can_be_logged() {
+        assert_format::<UInt<1>>();
+        assert_format::<UInt<12>>();
+        assert_format::<UInt<128>>();
+        assert_format::<Int<5>>();
+        assert_format::<Int<100>>();
+        assert_format::<u24>();
+        assert_format::<i24>();
+        assert_format::<u48>();
+        assert_format::<Field>();
+        assert_format::<(UInt<3>, Option<Field>)>();
+    }
+}
@@ -0,0 +1,356 @@
+use crate::Quark;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The error returned when a value doesn't fit in a narrower integer type.
+///
+/// The error keeps the value that was rejected and the type it didn't fit in, and includes both
+/// in its message.
+///
+/// # Examples
+///
+/// ```
+/// use quark::u24;
+/// use std::convert::TryFrom;
+///
+/// let error = u24::try_from(0x0100_0000_u32).unwrap_err();
+/// assert_eq!(error.bits(), 24);
+/// assert_eq!(error.to_string(), "16777216 doesn't fit in 24 bits as an unsigned integer");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromIntError {
+    value: Value,
+    bits: usize,
+    signed: bool,
+    non_zero: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Value {
+    Unsigned(u128),
+    Signed(i128),
+}
+
+macro_rules! value_from_impl {
+    ($variant:ident, $($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Value {
+                    Value::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+value_from_impl!(Unsigned, u8, u16, u32, u64, u128, usize);
+value_from_impl!(Signed, i8, i16, i32, i64, i128, isize);
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Value::Unsigned(value) => Display::fmt(value, f),
+            Value::Signed(value) => Display::fmt(value, f),
+        }
+    }
+}
+
+impl TryFromIntError {
+    pub(crate) fn unsigned(value: impl Into<Value>, bits: usize) -> TryFromIntError {
+        TryFromIntError {
+            value: value.into(),
+            bits,
+            signed: false,
+            non_zero: false,
+        }
+    }
+
+    pub(crate) fn signed(value: impl Into<Value>, bits: usize) -> TryFromIntError {
+        TryFromIntError {
+            value: value.into(),
+            bits,
+            signed: true,
+            non_zero: false,
+        }
+    }
+
+    pub(crate) const fn from_u128(value: u128, bits: usize) -> TryFromIntError {
+        TryFromIntError {
+            value: Value::Unsigned(value),
+            bits,
+            signed: false,
+            non_zero: false,
+        }
+    }
+
+    pub(crate) const fn from_i128(value: i128, bits: usize) -> TryFromIntError {
+        TryFromIntError {
+            value: Value::Signed(value),
+            bits,
+            signed: true,
+            non_zero: false,
+        }
+    }
+
+    pub(crate) fn for_primitive<T: Quark>(value: impl Into<Value>) -> TryFromIntError {
+        TryFromIntError {
+            value: value.into(),
+            bits: T::BIT_SIZE,
+            signed: T::ALL_SET < T::ZERO,
+            non_zero: false,
+        }
+    }
+
+    pub(crate) fn zero(bits: usize, signed: bool) -> TryFromIntError {
+        TryFromIntError {
+            value: Value::Unsigned(0),
+            bits,
+            signed,
+            non_zero: true,
+        }
+    }
+
+    /// Returns the number of bits in the type the value didn't fit in.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Panics with the error's message.
+    ///
+    /// This is for the `const fn new` constructors, which can't use `format!` to build the
+    /// message, so it's built one byte at a time instead. The message is the same as the one
+    /// `Display` writes.
+    #[track_caller]
+    pub(crate) const fn panic(self) -> ! {
+        let message = match self.value {
+            Value::Unsigned(value) => ConstMessage::new().push_u128(value),
+            Value::Signed(value) => ConstMessage::new().push_i128(value),
+        };
+        let message = message
+            .push_str(" doesn't fit in ")
+            .push_u128(self.bits as u128)
+            .push_str(" bits as ")
+            .push_str(integer_kind(self.signed, self.non_zero))
+            .push_str(" integer");
+
+        panic!("{}", message.as_str())
+    }
+}
+
+/// A message built up in a `const fn`, where `format!` isn't available.
+///
+/// The buffer holds the longest message `TryFromIntError::panic` builds, a 40-character `i128`
+/// and a 20-digit width with the text around them.
+struct ConstMessage {
+    bytes: [u8; 128],
+    len: usize,
+}
+
+impl ConstMessage {
+    const fn new() -> ConstMessage {
+        ConstMessage {
+            bytes: [0; 128],
+            len: 0,
+        }
+    }
+
+    const fn push_str(mut self, text: &str) -> ConstMessage {
+        let text = text.as_bytes();
+        let mut index = 0;
+        while index < text.len() {
+            self.bytes[self.len] = text[index];
+            self.len += 1;
+            index += 1;
+        }
+        self
+    }
+
+    const fn push_u128(mut self, value: u128) -> ConstMessage {
+        let mut digits = [0; 39];
+        let mut count = 0;
+        let mut rest = value;
+        loop {
+            digits[count] = b'0' + (rest % 10) as u8;
+            count += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+
+        while count > 0 {
+            count -= 1;
+            self.bytes[self.len] = digits[count];
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn push_i128(self, value: i128) -> ConstMessage {
+        if value < 0 {
+            self.push_str("-").push_u128(value.unsigned_abs())
+        } else {
+            self.push_u128(value as u128)
+        }
+    }
+
+    const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(message) => message,
+            Err(_) => "",
+        }
+    }
+}
+
+/// Describes the kind of integer a value was checked against, with its article, as in "an
+/// unsigned" or "a non-zero signed".
+pub(crate) const fn integer_kind(signed: bool, non_zero: bool) -> &'static str {
+    match (non_zero, signed) {
+        (false, false) => "an unsigned",
+        (false, true) => "a signed",
+        (true, false) => "a non-zero unsigned",
+        (true, true) => "a non-zero signed",
+    }
+}
+
+impl Display for TryFromIntError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} doesn't fit in {} bits as {} integer",
+            self.value,
+            self.bits,
+            integer_kind(self.signed, self.non_zero)
+        )
+    }
+}
+
+impl Error for TryFromIntError {}
+
+/// The error returned when a string can't be parsed as a narrow integer type.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{ParseErrorKind, UInt};
+///
+/// let error = "0x200".parse::<UInt<9>>().unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::OutOfRange);
+/// assert_eq!(error.to_string(), "number doesn't fit in 9 bits as an unsigned integer");
+///
+/// let error = "0x1g".parse::<UInt<9>>().unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    bits: usize,
+    signed: bool,
+}
+
+/// The reasons parsing a narrow integer type can fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The string had no digits.
+    Empty,
+    /// The string had a character that isn't a digit in the radix.
+    InvalidDigit,
+    /// The number doesn't fit in the type.
+    OutOfRange,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, bits: usize, signed: bool) -> ParseError {
+        ParseError { kind, bits, signed }
+    }
+
+    /// Returns the reason parsing failed.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the number of bits in the type that was being parsed.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseErrorKind::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseErrorKind::OutOfRange => write!(
+                f,
+                "number doesn't fit in {} bits as {} integer",
+                self.bits,
+                integer_kind(self.signed, false)
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The error returned when bytes can't be decoded as LEB128.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{Leb128Error, Uleb128};
+///
+/// assert_eq!(u32::decode_uleb128(&[0x80, 0x80]), Err(Leb128Error::Truncated));
+/// assert_eq!(u8::decode_uleb128(&[0x80, 0x02]), Err(Leb128Error::Overflow));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Leb128Error {
+    /// The bytes ended before the last byte of the encoding.
+    Truncated,
+    /// The value doesn't fit in the type, or the encoding has more bytes than the type's
+    /// largest value needs.
+    Overflow,
+}
+
+impl Display for Leb128Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Leb128Error::Truncated => write!(f, "LEB128 encoding ends before its last byte"),
+            Leb128Error::Overflow => write!(f, "LEB128 value out of range for the type"),
+        }
+    }
+}
+
+impl Error for Leb128Error {}
+
+/// The error returned when bytes can't be decoded as a variable-length quantity.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{Vlq, VlqError};
+///
+/// assert_eq!(u32::decode_vlq(&[0x81, 0x80]), Err(VlqError::Truncated));
+/// assert_eq!(u8::decode_vlq(&[0x82, 0x00]), Err(VlqError::Overflow));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VlqError {
+    /// The bytes ended before the last byte of the encoding.
+    Truncated,
+    /// The value doesn't fit in the type, or the encoding has more bytes than the type's
+    /// largest value needs.
+    Overflow,
+}
+
+impl Display for VlqError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            VlqError::Truncated => write!(f, "variable-length quantity ends before its last byte"),
+            VlqError::Overflow => write!(f, "variable-length quantity out of range for the type"),
+        }
+    }
+}
+
+impl Error for VlqError {}
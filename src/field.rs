@@ -0,0 +1,231 @@
+use crate::{BitIndex, BitMask, Signs};
+use std::ops::{BitAnd, BitOr, Not, Range, RangeInclusive};
+
+/// Describes a field of bits within a value.
+///
+/// A field is a contiguous run of `width` bits starting at bit `offset`. Because it doesn't name
+/// the type it's applied to, a single field can be declared once as a constant and used to both
+/// decode and encode values of any width.
+///
+/// Fields aren't checked against the type they're applied to. Bits of a field that lie past the
+/// end of the type are ignored: they read as zero (or as the sign fill for negative signed values,
+/// matching [`BitIndex::bits`](trait.BitIndex.html#tymethod.bits)) and are discarded on insertion.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Field;
+///
+/// const RD: Field = Field::new(16, 4);
+///
+/// let instruction: u32 = 0xe01a3497;
+/// assert_eq!(RD.extract(instruction), 0xa);
+/// assert_eq!(RD.insert(instruction, 0x5), 0xe0153497);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Field {
+    offset: usize,
+    width: usize,
+}
+
+impl Field {
+    /// Creates a field of `width` bits starting at bit `offset`.
+    pub const fn new(offset: usize, width: usize) -> Field {
+        Field { offset, width }
+    }
+
+    /// Returns the index of the lowest bit in the field.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of bits in the field.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the range of bit indices covered by the field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field ends past `usize::MAX`, since the range couldn't be represented.
+    pub fn range(&self) -> Range<usize> {
+        match self.offset.checked_add(self.width) {
+            Some(end) => self.offset..end,
+            None => panic!(
+                "field of {} bits at bit {} ends past usize::MAX",
+                self.width, self.offset
+            ),
+        }
+    }
+
+    /// Returns a mask with the bits of the field set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field ends past `usize::MAX`, like [`range`](#method.range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Field;
+    ///
+    /// assert_eq!(Field::new(4, 8).mask::<u16>(), 0x0ff0);
+    /// ```
+    pub fn mask<T: BitMask>(&self) -> T {
+        T::mask_range(self.range())
+    }
+
+    /// Returns the value of the field, shifted down to the low bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field ends past `usize::MAX`, like [`range`](#method.range).
+    pub fn extract<T: BitIndex>(&self, value: T) -> T {
+        value.bits(self.range())
+    }
+
+    /// Returns the value of the field, sign-extended from the top bit of the field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field ends past `usize::MAX`, like [`range`](#method.range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Field;
+    ///
+    /// let imm = Field::new(0, 12);
+    /// assert_eq!(imm.extract_signed(0x0000_0801_u32), 0xffff_f801);
+    /// assert_eq!(imm.extract_signed(0x0000_0801_i32), -2047);
+    /// ```
+    pub fn extract_signed<T: BitIndex + BitMask + Signs + Copy>(&self, value: T) -> T {
+        self.extract(value).sign_extend_from(self.width)
+    }
+
+    /// Returns `container` with the field replaced by the low bits of `value`.
+    ///
+    /// Bits of `value` that don't fit in the field are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field ends past `usize::MAX`, like [`range`](#method.range).
+    pub fn insert<T>(&self, container: T, value: T) -> T
+    where
+        T: BitIndex + Copy + Not<Output = T> + BitAnd<Output = T> + BitOr<Output = T>,
+    {
+        container.with_bits(self.range(), value)
+    }
+}
+
+impl From<Range<usize>> for Field {
+    fn from(range: Range<usize>) -> Field {
+        Field::new(range.start, range.end.saturating_sub(range.start))
+    }
+}
+
+impl From<RangeInclusive<usize>> for Field {
+    fn from(range: RangeInclusive<usize>) -> Field {
+        Field::new(
+            *range.start(),
+            (*range.end() + 1).saturating_sub(*range.start()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    const RD: Field = Field::new(16, 4);
+
+    #[test]
+    fn constructing_fields() {
+        asserting!("fields can be created from ranges")
+            .that(&[Field::from(16..20), Field::from(16..=19)])
+            .is_equal_to([RD, RD]);
+
+        asserting!("fields created from reversed ranges are empty")
+            .that(&Field::from(Range { start: 20, end: 16 }).width())
+            .is_equal_to(0);
+
+        asserting!("fields cover the range they were created with")
+            .that(&RD.range())
+            .is_equal_to(16..20);
+    }
+
+    #[test]
+    #[should_panic(expected = "ends past usize::MAX")]
+    fn range_rejects_overflow() {
+        Field::new(usize::MAX, 2).range();
+    }
+
+    #[test]
+    fn field_masks() {
+        asserting!("field masks cover the field")
+            .that(&(RD.mask::<u32>(), RD.mask::<u64>()))
+            .is_equal_to((0x000f_0000, 0x0000_0000_000f_0000));
+
+        asserting!("field masks ignore bits past the end of the type")
+            .that(&[RD.mask::<u16>(), Field::new(12, 8).mask::<u16>()])
+            .is_equal_to([0x0000, 0xf000]);
+    }
+
+    #[test]
+    fn extracting_and_inserting() {
+        let value: u32 = 0xe01a3497;
+
+        asserting!("extracting reads the field")
+            .that(&RD.extract(value))
+            .is_equal_to(0xa);
+
+        asserting!("inserting replaces the field")
+            .that(&RD.insert(value, 0x5))
+            .is_equal_to(0xe015_3497);
+
+        asserting!("inserting discards bits that don't fit in the field")
+            .that(&RD.insert(value, 0xf5))
+            .is_equal_to(0xe015_3497);
+
+        for field in &[RD, Field::new(0, 1), Field::new(4, 12), Field::new(0, 32)] {
+            asserting!("inserting an extracted field returns the original value")
+                .that(&field.insert(0, field.extract(value)))
+                .is_equal_to(value & field.mask::<u32>());
+
+            asserting!("extracting an inserted field returns the inserted value")
+                .that(&field.extract(field.insert(value, 0x5)))
+                .is_equal_to(0x5 & u32::mask(field.width()));
+        }
+    }
+
+    #[test]
+    fn extracting_signed_fields() {
+        asserting!("signed extraction sign-extends negative fields")
+            .that(&(
+                RD.extract_signed(0x000a_0000_u32),
+                RD.extract_signed(0x000a_0000_i32),
+            ))
+            .is_equal_to((0xffff_fffa, -6));
+
+        asserting!("signed extraction leaves positive fields alone")
+            .that(&(
+                RD.extract_signed(0x0005_0000_u32),
+                RD.extract_signed(0x0005_0000_i32),
+            ))
+            .is_equal_to((0x5, 0x5));
+
+        asserting!("signed extraction of empty fields returns zero")
+            .that(&Field::new(4, 0).extract_signed(-1_i32))
+            .is_equal_to(0);
+
+        asserting!("signed extraction of full-width fields returns the value")
+            .that(&Field::new(0, 8).extract_signed(-90_i8))
+            .is_equal_to(-90);
+
+        asserting!("signed fields round-trip through insertion")
+            .that(&RD.extract_signed(RD.insert(0_u32, -3_i32 as u32)))
+            .is_equal_to(-3_i32 as u32);
+    }
+}
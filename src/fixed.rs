@@ -0,0 +1,539 @@
+use crate::consts;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A signed fixed-point number with `INT` integer bits and `FRAC` fractional bits.
+///
+/// This is the Q notation used in signal processing, where `Fixed<1, 15>` is Q1.15: a 16-bit two's
+/// complement number whose integer bits include the sign bit, covering -1 up to just below 1 in
+/// steps of 2<sup>-15</sup>. `INT` must be at least 1 and the whole number can be up to 64 bits
+/// wide, so that products fit in the 128 bits they're computed in. Using a format outside those
+/// limits fails to compile.
+///
+/// Addition and subtraction work on matching formats and follow the integer types: the operators
+/// panic on overflow when debug assertions are enabled and wrap otherwise, and there are checked,
+/// wrapping, and saturating versions. Multiplication computes the full product and shifts it back
+/// down, rounding to the nearest value with halves rounded up.
+///
+/// Values order and compare the same way their numbers do. `Display` prints a decimal
+/// approximation of the number, and `Debug` adds the format, as in `Fixed<1, 15>(0.5)`.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Fixed;
+///
+/// type Q15 = Fixed<1, 15>;
+///
+/// let half = Q15::saturating_from_f64(0.5);
+/// assert_eq!(half.to_bits(), 0x4000);
+/// assert_eq!((half * half).to_f64(), 0.25);
+/// assert_eq!(half.saturating_add(half), Q15::MAX);
+/// assert_eq!(Q15::MIN.saturating_mul(Q15::MIN), Q15::MAX);
+/// assert_eq!(format!("{:.3}", half), "0.500");
+/// ```
+///
+/// ```compile_fail
+/// use quark::Fixed;
+///
+/// let wide = Fixed::<32, 40>::ZERO.to_f64();
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const INT: usize, const FRAC: usize> {
+    bits: i128,
+}
+
+impl<const INT: usize, const FRAC: usize> Fixed<INT, FRAC> {
+    const VALID_FORMAT: () = assert!(
+        INT >= 1 && INT + FRAC <= 64,
+        "Fixed must have at least 1 integer bit and at most 64 bits in total"
+    );
+
+    /// The smallest value of the format, -2<sup>INT - 1</sup>.
+    pub const MIN: Self = Fixed {
+        bits: !Self::MAX.bits,
+    };
+
+    /// The largest value of the format, 2<sup>INT - 1</sup> - 2<sup>-FRAC</sup>.
+    pub const MAX: Self = Fixed {
+        bits: consts::mask_i128(INT + FRAC - 1),
+    };
+
+    /// The value 0.
+    pub const ZERO: Self = Fixed { bits: 0 };
+
+    /// The smallest positive value of the format, 2<sup>-FRAC</sup>.
+    pub const DELTA: Self = Fixed { bits: 1 };
+
+    /// Creates a value from its two's complement bits, returning `None` if they don't fit in
+    /// `INT + FRAC` bits.
+    ///
+    /// The bits are the number multiplied by 2<sup>FRAC</sup>, so the bits of Q1.15's 0.5 are
+    /// `0x4000`.
+    pub const fn from_bits(bits: i128) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FORMAT;
+
+        if bits >= Self::MIN.bits && bits <= Self::MAX.bits {
+            Some(Fixed { bits })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the two's complement bits of the value, sign-extended to an `i128`.
+    pub const fn to_bits(self) -> i128 {
+        self.bits
+    }
+
+    /// Creates a value from an integer, returning `None` if it doesn't fit in the integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Fixed;
+    ///
+    /// assert_eq!(Fixed::<4, 4>::from_int(-8).map(Fixed::to_f64), Some(-8.0));
+    /// assert_eq!(Fixed::<4, 4>::from_int(8), None);
+    /// ```
+    pub const fn from_int(value: i128) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FORMAT;
+
+        if value >= Self::MIN.bits >> FRAC && value <= Self::MAX.bits >> FRAC {
+            Some(Fixed {
+                bits: value << FRAC,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a value from an integer, clamping it to the range of the format.
+    pub const fn saturating_from_int(value: i128) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FORMAT;
+
+        if value < Self::MIN.bits >> FRAC {
+            Self::MIN
+        } else if value > Self::MAX.bits >> FRAC {
+            Self::MAX
+        } else {
+            Fixed {
+                bits: value << FRAC,
+            }
+        }
+    }
+
+    /// Creates a value from an `f64`, rounding to the nearest value of the format with halves
+    /// rounded away from zero. Returns `None` if the rounded number is out of range or `value`
+    /// is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Fixed;
+    ///
+    /// type Q15 = Fixed<1, 15>;
+    ///
+    /// assert_eq!(Q15::from_f64(-0.25).map(Q15::to_bits), Some(-0x2000));
+    /// assert_eq!(Q15::from_f64(1.0), None);
+    /// assert_eq!(Q15::from_f64(0.999_98).map(Q15::to_bits), Some(0x7fff));
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if value.is_nan() {
+            None
+        } else {
+            Self::from_bits((value * Self::scale()).round() as i128)
+        }
+    }
+
+    /// Creates a value from an `f32`, rounding the same way as [`from_f64`](#method.from_f64).
+    pub fn from_f32(value: f32) -> Option<Self> {
+        Self::from_f64(value.into())
+    }
+
+    /// Creates a value from an `f64`, rounding the same way as [`from_f64`](#method.from_f64) and
+    /// clamping the result to the range of the format. NaN becomes zero.
+    pub fn saturating_from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            Self::ZERO
+        } else {
+            Self::saturating_from_bits((value * Self::scale()).round() as i128)
+        }
+    }
+
+    /// Creates a value from an `f32`, rounding and clamping the same way as
+    /// [`saturating_from_f64`](#method.saturating_from_f64).
+    pub fn saturating_from_f32(value: f32) -> Self {
+        Self::saturating_from_f64(value.into())
+    }
+
+    /// Returns the nearest `f64` to the value.
+    ///
+    /// The conversion is exact for formats up to 53 bits wide.
+    pub fn to_f64(self) -> f64 {
+        self.bits as f64 / Self::scale()
+    }
+
+    /// Returns the nearest `f32` to the value.
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Adds two values, returning `None` if the sum is out of range.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::from_bits(self.bits + rhs.bits)
+    }
+
+    /// Subtracts two values, returning `None` if the difference is out of range.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::from_bits(self.bits - rhs.bits)
+    }
+
+    /// Multiplies two values, returning `None` if the rounded product is out of range.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::from_bits(self.product(rhs))
+    }
+
+    /// Adds two values, wrapping around at the range of the format.
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self::masked(self.bits + rhs.bits)
+    }
+
+    /// Subtracts two values, wrapping around at the range of the format.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::masked(self.bits - rhs.bits)
+    }
+
+    /// Multiplies two values, wrapping the rounded product around at the range of the format.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::masked(self.product(rhs))
+    }
+
+    /// Adds two values, clamping the sum to the range of the format.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturating_from_bits(self.bits + rhs.bits)
+    }
+
+    /// Subtracts two values, clamping the difference to the range of the format.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturating_from_bits(self.bits - rhs.bits)
+    }
+
+    /// Multiplies two values, clamping the rounded product to the range of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Fixed;
+    ///
+    /// type Q15 = Fixed<1, 15>;
+    ///
+    /// let minus_one = Q15::MIN;
+    /// assert_eq!(minus_one.saturating_mul(minus_one), Q15::MAX);
+    /// assert_eq!(minus_one.saturating_mul(Q15::MAX).to_bits(), -0x7fff);
+    /// ```
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturating_from_bits(self.product(rhs))
+    }
+
+    /// Returns 2<sup>FRAC</sup>, the number of steps of the format in 1.
+    fn scale() -> f64 {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FORMAT;
+
+        (1_u64 << FRAC) as f64
+    }
+
+    /// Returns the product of two values in the format's bits, rounded to the nearest step with
+    /// halves rounded up.
+    ///
+    /// Both values have at most 64 bits, so the full product fits in an `i128`.
+    const fn product(self, rhs: Self) -> i128 {
+        let product = self.bits * rhs.bits;
+
+        if FRAC == 0 {
+            product
+        } else {
+            (product + (1 << (FRAC - 1))) >> FRAC
+        }
+    }
+
+    const fn masked(bits: i128) -> Self {
+        Fixed {
+            bits: consts::sign_extend_i128(bits, 128 - (INT + FRAC)),
+        }
+    }
+
+    const fn saturating_from_bits(bits: i128) -> Self {
+        if bits < Self::MIN.bits {
+            Self::MIN
+        } else if bits > Self::MAX.bits {
+            Self::MAX
+        } else {
+            Fixed { bits }
+        }
+    }
+}
+
+macro_rules! fixed_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $wrapping:ident, $message:literal;)*) => {
+        $(
+            /// Panics if the result is out of range when debug assertions are enabled, and wraps
+            /// around otherwise, like the operators on the integer types.
+            impl<const INT: usize, const FRAC: usize> $trait for Fixed<INT, FRAC> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    if cfg!(debug_assertions) {
+                        match self.$checked(rhs) {
+                            Some(value) => value,
+                            None => panic!($message),
+                        }
+                    } else {
+                        self.$wrapping(rhs)
+                    }
+                }
+            }
+
+            impl<const INT: usize, const FRAC: usize> $assign_trait for Fixed<INT, FRAC> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+fixed_op_impl! {
+    Add, add, AddAssign, add_assign, checked_add, wrapping_add, "attempt to add with overflow";
+    Sub, sub, SubAssign, sub_assign, checked_sub, wrapping_sub, "attempt to subtract with overflow";
+    Mul, mul, MulAssign, mul_assign, checked_mul, wrapping_mul, "attempt to multiply with overflow";
+}
+
+impl<const INT: usize, const FRAC: usize> Display for Fixed<INT, FRAC> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> Debug for Fixed<INT, FRAC> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple(&format!("Fixed<{}, {}>", INT, FRAC))
+            .field(&self.to_f64())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    type Q15 = Fixed<1, 15>;
+
+    fn q15(bits: i128) -> Q15 {
+        Q15::from_bits(bits).unwrap()
+    }
+
+    #[test]
+    fn limits() {
+        asserting!("the limits cover the format")
+            .that(&(
+                Q15::MIN.to_bits(),
+                Q15::MAX.to_bits(),
+                Fixed::<4, 4>::MIN.to_f64(),
+                Fixed::<4, 4>::MAX.to_f64(),
+                Fixed::<8, 0>::MAX.to_bits(),
+                Fixed::<64, 0>::MIN.to_bits(),
+                Fixed::<1, 63>::MAX.to_bits(),
+            ))
+            .is_equal_to((
+                -0x8000,
+                0x7fff,
+                -8.0,
+                7.9375,
+                127,
+                i64::MIN as i128,
+                i64::MAX as i128,
+            ));
+
+        asserting!("the step is the smallest positive value")
+            .that(&(Q15::DELTA.to_f64(), Fixed::<4, 4>::DELTA.to_f64()))
+            .is_equal_to((1.0 / 32768.0, 0.0625));
+    }
+
+    #[test]
+    fn constructing() {
+        asserting!("integers fit in the integer bits")
+            .that(&(
+                Fixed::<4, 4>::from_int(7).map(Fixed::to_bits),
+                Fixed::<4, 4>::from_int(-8).map(Fixed::to_bits),
+                Fixed::<4, 4>::from_int(8),
+                Fixed::<4, 4>::from_int(-9),
+                Q15::from_int(-1),
+                Q15::from_int(1),
+            ))
+            .is_equal_to((Some(0x70), Some(-0x80), None, None, Some(Q15::MIN), None));
+
+        asserting!("integers saturate at the limits")
+            .that(&(
+                Fixed::<4, 4>::saturating_from_int(100),
+                Fixed::<4, 4>::saturating_from_int(-100),
+                Fixed::<4, 4>::saturating_from_int(3).to_f64(),
+            ))
+            .is_equal_to((Fixed::MAX, Fixed::MIN, 3.0));
+
+        asserting!("bits outside the format are rejected")
+            .that(&(Q15::from_bits(0x8000), Q15::from_bits(-0x8001)))
+            .is_equal_to((None, None));
+    }
+
+    #[test]
+    fn floats() {
+        asserting!("floats round to the nearest step, halves away from zero")
+            .that(&(
+                Fixed::<4, 4>::from_f64(1.03).map(Fixed::to_bits),
+                Fixed::<4, 4>::from_f64(1.03125).map(Fixed::to_bits),
+                Fixed::<4, 4>::from_f64(-1.03125).map(Fixed::to_bits),
+                Fixed::<4, 4>::from_f32(-0.01).map(Fixed::to_bits),
+            ))
+            .is_equal_to((Some(0x10), Some(0x11), Some(-0x11), Some(0)));
+
+        asserting!("floats out of range are rejected")
+            .that(&(
+                Q15::from_f64(1.0),
+                Q15::from_f64(-1.000_02),
+                Q15::from_f64(f64::NAN),
+                Q15::from_f64(f64::INFINITY),
+                Q15::from_f64(-1.000_01).map(Q15::to_bits),
+            ))
+            .is_equal_to((None, None, None, None, Some(-0x8000)));
+
+        asserting!("floats saturate at the limits")
+            .that(&(
+                Q15::saturating_from_f64(2.0),
+                Q15::saturating_from_f32(-2.0),
+                Q15::saturating_from_f64(f64::NEG_INFINITY),
+                Q15::saturating_from_f64(f64::NAN),
+            ))
+            .is_equal_to((Q15::MAX, Q15::MIN, Q15::MIN, Q15::ZERO));
+
+        for bits in -0x80..0x80 {
+            let value = Fixed::<4, 4>::from_bits(bits).unwrap();
+
+            asserting!("values round trip through floats")
+                .that(&(
+                    Fixed::<4, 4>::from_f64(value.to_f64()),
+                    Fixed::<4, 4>::from_f32(value.to_f32()),
+                ))
+                .is_equal_to((Some(value), Some(value)));
+        }
+    }
+
+    #[test]
+    fn adding() {
+        asserting!("sums and differences match the numbers")
+            .that(&(
+                (q15(0x2000) + q15(0x1000)).to_f64(),
+                (q15(0x2000) - q15(0x3000)).to_f64(),
+                (Fixed::<4, 4>::from_int(3).unwrap() - Fixed::from_int(5).unwrap()).to_f64(),
+            ))
+            .is_equal_to((0.375, -0.125, -2.0));
+
+        asserting!("sums out of range overflow")
+            .that(&(
+                Q15::MAX.checked_add(Q15::DELTA),
+                Q15::MIN.checked_sub(Q15::DELTA),
+                Q15::MAX.wrapping_add(Q15::DELTA),
+                Q15::MIN.wrapping_sub(Q15::DELTA),
+                Q15::MAX.saturating_add(Q15::MAX),
+                Q15::MIN.saturating_sub(Q15::MAX),
+            ))
+            .is_equal_to((None, None, Q15::MIN, Q15::MAX, Q15::MAX, Q15::MIN));
+
+        let mut value = q15(0x1000);
+        value += q15(0x1000);
+        value -= q15(0x0800);
+        asserting!("assigning operators match the operators")
+            .that(&value)
+            .is_equal_to(q15(0x1800));
+    }
+
+    #[test]
+    fn multiplying() {
+        asserting!("products match the numbers")
+            .that(&(
+                (q15(0x4000) * q15(0x4000)).to_f64(),
+                (q15(-0x4000) * q15(0x2000)).to_f64(),
+                (Fixed::<4, 4>::from_f64(2.5).unwrap() * Fixed::from_f64(-1.5).unwrap()).to_f64(),
+                (Fixed::<8, 0>::from_int(-11).unwrap() * Fixed::from_int(11).unwrap()).to_f64(),
+            ))
+            .is_equal_to((0.25, -0.125, -3.75, -121.0));
+
+        asserting!("products round to the nearest step with halves rounded up")
+            .that(&(
+                (Q15::DELTA * q15(0x4000)).to_bits(),
+                (q15(-1) * q15(0x4000)).to_bits(),
+                (q15(3) * q15(0x4000)).to_bits(),
+                (q15(-3) * q15(0x4000)).to_bits(),
+                (q15(5) * q15(0x2000)).to_bits(),
+                (q15(-5) * q15(0x2000)).to_bits(),
+            ))
+            .is_equal_to((1, 0, 2, -1, 1, -1));
+
+        asserting!("-1 × -1 is out of range in Q1.15")
+            .that(&(
+                Q15::MIN.checked_mul(Q15::MIN),
+                Q15::MIN.saturating_mul(Q15::MIN),
+                Q15::MIN.wrapping_mul(Q15::MIN),
+                Q15::MIN.saturating_mul(Q15::MAX).to_bits(),
+                Q15::MAX.saturating_mul(Q15::MAX).to_bits(),
+            ))
+            .is_equal_to((None, Q15::MAX, Q15::MIN, -0x7fff, 0x7ffe));
+
+        asserting!("the widest formats multiply without overflowing the product")
+            .that(&(
+                Fixed::<64, 0>::MIN.checked_mul(Fixed::MIN),
+                Fixed::<64, 0>::MIN.saturating_mul(Fixed::MIN),
+                Fixed::<1, 63>::MIN.saturating_mul(Fixed::MIN),
+                Fixed::<1, 63>::MIN
+                    .checked_mul(Fixed::MAX)
+                    .map(Fixed::to_bits),
+            ))
+            .is_equal_to((None, Fixed::MAX, Fixed::MAX, Some(-(i64::MAX as i128))));
+
+        let mut value = q15(0x4000);
+        value *= q15(0x4000);
+        asserting!("assigning multiplication matches the operator")
+            .that(&value)
+            .is_equal_to(q15(0x2000));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to multiply with overflow")
+    )]
+    fn overflowing_products_panic() {
+        assert_eq!(Q15::MIN * Q15::MIN, Q15::MIN);
+    }
+
+    #[test]
+    fn formatting() {
+        asserting!("values format as decimals")
+            .that(&(
+                format!("{}", q15(0x4000)),
+                format!("{:.2}", Fixed::<4, 4>::from_f64(-2.4375).unwrap()),
+                format!("{}", Fixed::<8, 0>::from_int(-100).unwrap()),
+                format!("{:?}", q15(-0x2000)),
+            ))
+            .is_equal_to((
+                "0.5".to_string(),
+                "-2.44".to_string(),
+                "-100".to_string(),
+                "Fixed<1, 15>(-0.25)".to_string(),
+            ));
+    }
+}
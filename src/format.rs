@@ -0,0 +1,171 @@
+use crate::{Int, UInt};
+use std::fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+
+// `Display` prints the number, and `Debug` prints it with the type around it, as in
+// `UInt<12>(2748)`, so values of different widths can be told apart in assertion failures. The
+// radix traits print the bits of the value within its width, so negative signed values are
+// printed as their two's complement, the same as for the primitives. Asking for zero padding
+// without a width, as in `{:#0b}`, pads to the number of digits the width of the type needs.
+
+impl<const BITS: usize> Display for UInt<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl<const BITS: usize> Display for Int<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl<const BITS: usize> Debug for UInt<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple(&format!("UInt<{}>", BITS))
+            .field(&self.get())
+            .finish()
+    }
+}
+
+impl<const BITS: usize> Debug for Int<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple(&format!("Int<{}>", BITS))
+            .field(&self.get())
+            .finish()
+    }
+}
+
+macro_rules! radix_impl {
+    ($($trait:ident, $helper:ident, $digit_bits:literal, $spec:literal;)*) => {
+        $(
+            fn $helper(bits: u128, width: usize, f: &mut Formatter) -> fmt::Result {
+                if f.sign_aware_zero_pad() && f.width().is_none() {
+                    let digits = width.div_ceil($digit_bits);
+
+                    if f.alternate() {
+                        write!(f, concat!("{:#0width$", $spec, "}"), bits, width = digits + 2)
+                    } else {
+                        write!(f, concat!("{:0width$", $spec, "}"), bits, width = digits)
+                    }
+                } else {
+                    $trait::fmt(&bits, f)
+                }
+            }
+
+            impl<const BITS: usize> $trait for UInt<BITS> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    $helper(self.to_bits(), BITS, f)
+                }
+            }
+
+            impl<const BITS: usize> $trait for Int<BITS> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    $helper(self.to_bits(), BITS, f)
+                }
+            }
+        )*
+    };
+}
+
+radix_impl! {
+    Binary, fmt_binary, 1, "b";
+    Octal, fmt_octal, 3, "o";
+    LowerHex, fmt_lower_hex, 4, "x";
+    UpperHex, fmt_upper_hex, 4, "X";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn numbers() {
+        asserting!("values display as their numbers")
+            .that(&(
+                UInt::<12>::new(2748).to_string(),
+                Int::<12>::new(-5).to_string(),
+                format!("{:>6}", Int::<12>::MIN),
+                format!("{:+}", UInt::<4>::MAX),
+            ))
+            .is_equal_to((
+                "2748".to_string(),
+                "-5".to_string(),
+                " -2048".to_string(),
+                "+15".to_string(),
+            ));
+
+        asserting!("debug output includes the type")
+            .that(&(
+                format!("{:?}", UInt::<12>::new(2748)),
+                format!("{:?}", Int::<5>::new(-16)),
+                format!("{:x?}", UInt::<12>::new(2748)),
+            ))
+            .is_equal_to((
+                "UInt<12>(2748)".to_string(),
+                "Int<5>(-16)".to_string(),
+                "UInt<12>(abc)".to_string(),
+            ));
+    }
+
+    #[test]
+    fn radixes() {
+        let value = UInt::<12>::new(0xabc);
+
+        asserting!("flags behave as they do for the primitives")
+            .that(&[
+                format!("{:x}", value),
+                format!("{:X}", value),
+                format!("{:#x}", value),
+                format!("{:#06x}", value),
+                format!("{:o}", value),
+                format!("{:#o}", value),
+                format!("{:b}", value),
+                format!("{:8x}", value),
+            ])
+            .is_equal_to([
+                "abc".to_string(),
+                "ABC".to_string(),
+                "0xabc".to_string(),
+                "0x0abc".to_string(),
+                "5274".to_string(),
+                "0o5274".to_string(),
+                "101010111100".to_string(),
+                "     abc".to_string(),
+            ]);
+
+        let small = UInt::<12>::new(5);
+
+        asserting!("zero padding without a width pads to the width of the type")
+            .that(&[
+                format!("{:0b}", small),
+                format!("{:#0b}", small),
+                format!("{:#0x}", small),
+                format!("{:0o}", small),
+                format!("{:#0X}", UInt::<13>::new(0xa)),
+            ])
+            .is_equal_to([
+                "000000000101".to_string(),
+                "0b000000000101".to_string(),
+                "0x005".to_string(),
+                "0005".to_string(),
+                "0x000A".to_string(),
+            ]);
+
+        asserting!("signed values print their bits within the width")
+            .that(&[
+                format!("{:x}", Int::<12>::new(-1)),
+                format!("{:#b}", Int::<5>::MIN),
+                format!("{:#0b}", Int::<5>::new(1)),
+                format!("{:o}", Int::<7>::new(-1)),
+                format!("{:x}", Int::<128>::MIN),
+            ])
+            .is_equal_to([
+                "fff".to_string(),
+                "0b10000".to_string(),
+                "0b00001".to_string(),
+                "177".to_string(),
+                format!("{:x}", i128::MIN),
+            ]);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::Signs;
+
+/// Converts values to and from Gray code.
+///
+/// In Gray code, consecutive numbers differ in exactly one bit, so a reading taken while a value
+/// changes is off by at most one. It's used by rotary encoders and by counters that cross clock
+/// domains. Signed values are converted by their bit patterns.
+///
+/// # Examples
+///
+/// ```
+/// use quark::GrayCode;
+///
+/// assert_eq!(0b0111_u8.to_gray(), 0b0100);
+/// assert_eq!(0b1000_u8.to_gray(), 0b1100);
+/// assert_eq!(0b1100_u8.from_gray(), 0b1000);
+/// ```
+pub trait GrayCode {
+    /// Returns the Gray code of a value.
+    fn to_gray(&self) -> Self;
+
+    /// Returns the value a Gray code represents.
+    ///
+    /// Each bit of the value is the parity of the bits of the code at and above it, which takes a
+    /// step for each doubling of the width rather than one for each bit.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_gray(&self) -> Self;
+
+    /// Steps the Gray code in the lowest N bits of a value to the code of the next value, wrapping
+    /// at N bits.
+    ///
+    /// This is the next state of an N-bit Gray code counter. The bits above the width are
+    /// ignored and cleared, a width of 0 always gives 0, and widths at least as large as the type
+    /// wrap at the full width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::GrayCode;
+    ///
+    /// assert_eq!(0b0100_u8.gray_increment_in(4), 0b1100);
+    /// assert_eq!(0b1000_u8.gray_increment_in(4), 0b0000);
+    /// assert_eq!(0b1000_u8.gray_increment_in(8), 0b1_1000);
+    /// ```
+    fn gray_increment_in(&self, width: usize) -> Self;
+}
+
+macro_rules! gray_code_impl {
+    ($($type:ident),*) => {
+        $(
+            impl GrayCode for $type {
+                #[inline]
+                fn to_gray(&self) -> Self {
+                    *self ^ *self >> 1
+                }
+
+                fn from_gray(&self) -> Self {
+                    let mut value = *self;
+                    let mut shift = 1;
+                    while shift < Self::BITS {
+                        value ^= value >> shift;
+                        shift *= 2;
+                    }
+                    value
+                }
+
+                fn gray_increment_in(&self, width: usize) -> Self {
+                    let width = width.min(Self::BITS as usize);
+                    let truncate = |value: Self| value.zero_extend(Self::BITS as usize - width);
+
+                    truncate(truncate(*self).from_gray().wrapping_add(1)).to_gray()
+                }
+            }
+        )*
+    };
+}
+
+gray_code_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed_gray_code_impl {
+    ($($type:ident as $unsigned:ident),*) => {
+        $(
+            impl GrayCode for $type {
+                #[inline]
+                fn to_gray(&self) -> Self {
+                    (*self as $unsigned).to_gray() as $type
+                }
+
+                fn from_gray(&self) -> Self {
+                    (*self as $unsigned).from_gray() as $type
+                }
+
+                fn gray_increment_in(&self, width: usize) -> Self {
+                    (*self as $unsigned).gray_increment_in(width) as $type
+                }
+            }
+        )*
+    };
+}
+
+signed_gray_code_impl!(
+    i8 as u8,
+    i16 as u16,
+    i32 as u32,
+    i64 as u64,
+    i128 as u128,
+    isize as usize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn corners() {
+        asserting!("codes of the extremes")
+            .that(&(
+                0_u8.to_gray(),
+                u8::MAX.to_gray(),
+                u128::MAX.to_gray(),
+                (-1_i8).to_gray(),
+                i16::MIN.to_gray(),
+            ))
+            .is_equal_to((0, 0x80, 1 << 127, i8::MIN, -0x4000));
+
+        asserting!("values of the extremes")
+            .that(&(
+                0x80_u8.from_gray(),
+                (1_u128 << 127).from_gray(),
+                u64::MAX.from_gray(),
+                i8::MIN.from_gray(),
+            ))
+            .is_equal_to((u8::MAX, u128::MAX, 0xaaaa_aaaa_aaaa_aaaa, -1));
+
+        asserting!("counters wrap at their width")
+            .that(&(
+                0b1000_u8.gray_increment_in(4),
+                0xf8_u8.gray_increment_in(4),
+                0x80_u8.gray_increment_in(8),
+                0x80_u8.gray_increment_in(100),
+                (1_u128 << 127).gray_increment_in(128),
+                0xff_u8.gray_increment_in(0),
+                (-128_i8).gray_increment_in(8),
+            ))
+            .is_equal_to((0, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn consecutive_codes_differ_in_one_bit() {
+        for value in 0..=u16::MAX {
+            let next = value.wrapping_add(1);
+            asserting!("u16 codes are one bit apart")
+                .that(&(value.to_gray() ^ next.to_gray()).count_ones())
+                .is_equal_to(1);
+            asserting!("u16 codes step to the next code")
+                .that(&value.to_gray().gray_increment_in(16))
+                .is_equal_to(next.to_gray());
+
+            let (value, next) = (value as u8, next as u8);
+            asserting!("u8 codes are one bit apart")
+                .that(&(value.to_gray() ^ next.to_gray()).count_ones())
+                .is_equal_to(1);
+        }
+
+        for width in 1..=8 {
+            let mut code = 0_u8;
+            let mut seen = [false; 256];
+            for _ in 0..1 << width {
+                seen[code as usize] = true;
+                code = code.gray_increment_in(width);
+            }
+            asserting!("a counter visits every code of its width once")
+                .that(&(code, seen.iter().filter(|&&seen| seen).count()))
+                .is_equal_to((0, 1 << width));
+        }
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for seed in random_u64s().take(1000) {
+            let wide = (seed as u128) << 64 | seed.rotate_left(7) as u128;
+
+            asserting!("values come back from their codes")
+                .that(&(
+                    seed.to_gray().from_gray(),
+                    seed.from_gray().to_gray(),
+                    wide.to_gray().from_gray(),
+                    (seed as i32).to_gray().from_gray(),
+                    (seed as usize).to_gray().from_gray(),
+                ))
+                .is_equal_to((seed, seed, wide, seed as i32, seed as usize));
+
+            // The bit-by-bit definition of decoding
+            let expected = (0..64).fold(0, |value, i| {
+                value | ((seed >> i).count_ones() as u64 & 1) << i
+            });
+            asserting!("each bit of the value is the parity of the code above it")
+                .that(&seed.from_gray())
+                .is_equal_to(expected);
+
+            let mask = (1 << 40) - 1;
+            asserting!("wide counters step like the values they encode")
+                .that(&(
+                    seed.to_gray().gray_increment_in(64),
+                    ((seed & mask).to_gray() | seed & !mask).gray_increment_in(40),
+                ))
+                .is_equal_to((
+                    seed.wrapping_add(1).to_gray(),
+                    (((seed & mask) + 1) & mask).to_gray(),
+                ));
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::BitIndex;
+use std::ops::RangeBounds;
+
+/// Counts the bits that differ between two values.
+///
+/// The Hamming distance between two values is the number of bit positions where they differ,
+/// which is the number of bit flips it takes to turn one into the other. Signed values are
+/// compared by their bit patterns.
+///
+/// # Examples
+///
+/// ```
+/// use quark::HammingDistance;
+///
+/// assert_eq!(0b1011_u8.hamming_distance(&0b1001), 1);
+/// assert_eq!(0_u32.hamming_distance(&u32::MAX), 32);
+/// assert_eq!((-1_i8).hamming_distance(&0), 8);
+/// ```
+pub trait HammingDistance {
+    /// Returns the number of bits that differ between two values.
+    fn hamming_distance(&self, other: &Self) -> u32;
+
+    /// Returns the number of bits in a range that differ between two values.
+    ///
+    /// Bits of the range past the end of the type don't count, even for signed values, whose
+    /// bits past the end would otherwise be read as copies of the sign bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::HammingDistance;
+    ///
+    /// assert_eq!(0x12_34_u16.hamming_distance_in(&0x12_00, 8..), 0);
+    /// assert_eq!(0x12_34_u16.hamming_distance_in(&0x12_00, ..8), 3);
+    /// assert_eq!((-1_i8).hamming_distance_in(&0, 4..12), 4);
+    /// ```
+    fn hamming_distance_in<Idx: RangeBounds<usize>>(&self, other: &Self, range: Idx) -> u32;
+}
+
+macro_rules! hamming_distance_impl {
+    ($($type:ident),*) => {
+        $(
+            impl HammingDistance for $type {
+                #[inline]
+                fn hamming_distance(&self, other: &Self) -> u32 {
+                    (self ^ other).count_ones()
+                }
+
+                fn hamming_distance_in<Idx: RangeBounds<usize>>(
+                    &self,
+                    other: &Self,
+                    range: Idx,
+                ) -> u32 {
+                    (self ^ other).bits(range).count_ones()
+                }
+            }
+        )*
+    };
+}
+
+hamming_distance_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed_hamming_distance_impl {
+    ($($type:ident as $unsigned:ident),*) => {
+        $(
+            impl HammingDistance for $type {
+                #[inline]
+                fn hamming_distance(&self, other: &Self) -> u32 {
+                    (*self as $unsigned).hamming_distance(&(*other as $unsigned))
+                }
+
+                fn hamming_distance_in<Idx: RangeBounds<usize>>(
+                    &self,
+                    other: &Self,
+                    range: Idx,
+                ) -> u32 {
+                    // Compare the unsigned patterns, which are zero past the end of the type
+                    (*self as $unsigned).hamming_distance_in(&(*other as $unsigned), range)
+                }
+            }
+        )*
+    };
+}
+
+signed_hamming_distance_impl!(
+    i8 as u8,
+    i16 as u16,
+    i32 as u32,
+    i64 as u64,
+    i128 as u128,
+    isize as usize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn distances() {
+        asserting!("whole values differ in the bits of their XOR")
+            .that(&(
+                0_u8.hamming_distance(&0),
+                0_u8.hamming_distance(&u8::MAX),
+                u128::MAX.hamming_distance(&0),
+                (1_u128 << 127).hamming_distance(&1),
+                i64::MIN.hamming_distance(&i64::MAX),
+                (-1_i16).hamming_distance(&1),
+            ))
+            .is_equal_to((0, 8, 128, 2, 64, 15));
+
+        asserting!("fields only count the bits inside the type")
+            .that(&(
+                0xff_u8.hamming_distance_in(&0, 3..3),
+                0xff_u8.hamming_distance_in(&0, 4..),
+                0xff_u8.hamming_distance_in(&0, 4..100),
+                (-1_i8).hamming_distance_in(&0, 4..12),
+                i128::MIN.hamming_distance_in(&0, 120..),
+                (-1_isize).hamming_distance_in(&0, ..),
+            ))
+            .is_equal_to((0, 4, 4, 4, 1, usize::BITS));
+    }
+
+    #[test]
+    fn metric_properties() {
+        let mut random = random_u64s();
+        let mut next = || random.next().unwrap();
+
+        for _ in 0..1000 {
+            let (a, b, c) = (next(), next(), next());
+            let (start, end) = ((c >> 58) as usize, (c >> 52) as usize % 64);
+            let range = start.min(end)..start.max(end);
+
+            asserting!("distances are symmetric and zero to the same value")
+                .that(&(
+                    a.hamming_distance(&b),
+                    (a as i32).hamming_distance(&(b as i32)),
+                    a.hamming_distance(&a),
+                    (a as i8).hamming_distance_in(&(a as i8), ..),
+                ))
+                .is_equal_to((
+                    b.hamming_distance(&a),
+                    (b as i32).hamming_distance(&(a as i32)),
+                    0,
+                    0,
+                ));
+
+            asserting!("distances satisfy the triangle inequality")
+                .that(&(
+                    a.hamming_distance(&c) <= a.hamming_distance(&b) + b.hamming_distance(&c),
+                    (a as i16).hamming_distance_in(&(c as i16), 4..)
+                        <= (a as i16).hamming_distance_in(&(b as i16), 4..)
+                            + (b as i16).hamming_distance_in(&(c as i16), 4..),
+                ))
+                .is_equal_to((true, true));
+
+            asserting!("a field and the rest of the value add up to the whole distance")
+                .that(&(
+                    a.hamming_distance_in(&b, range.clone())
+                        + a.hamming_distance_in(&b, ..range.start)
+                        + a.hamming_distance_in(&b, range.end..),
+                    (a as i64).hamming_distance_in(&(b as i64), range.clone())
+                        + (a as i64).hamming_distance_in(&(b as i64), ..range.start)
+                        + (a as i64).hamming_distance_in(&(b as i64), range.end..),
+                ))
+                .is_equal_to((a.hamming_distance(&b), a.hamming_distance(&b)));
+        }
+    }
+}
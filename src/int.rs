@@ -0,0 +1,1672 @@
+use crate::uint::Widening;
+use crate::{consts, BitConsts, BitSize, TryFromIntError, UInt};
+use std::convert::TryFrom;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// A signed integer with an arbitrary number of bits.
+///
+/// `BITS` can be anything from 1 to 128, and values are two's complement numbers of that width.
+/// They're held in an `i128` sign-extended from the top bit of the width, so two values are equal
+/// exactly when their numbers are, and they order the same way their numbers do, with negative
+/// values below positive ones. `Default` is zero and `Hash` agrees with `Eq`, so values work as
+/// keys in any of the standard collections. Using a width outside the supported range fails to
+/// compile.
+///
+/// Overflowing arithmetic wraps from `MAX` to `MIN` and back, as two's complement does. The
+/// `wrapping_*` methods always do this, while the operators only do when debug assertions are
+/// disabled and panic otherwise, the same as for `i32`. Also as for `i32`, dividing `MIN` by -1
+/// with `/` or `%` panics either way, and the `wrapping_*` methods give `MIN` and 0 instead.
+///
+/// Conversions to and from the primitives follow the same rules as between the primitives
+/// themselves: they use `From` wherever every value fits, such as `Int<12>` into `i16`, and
+/// `TryFrom` everywhere else, including any conversion between signed and unsigned that could see
+/// a negative value. Comparisons with the primitives, in either order, are by number even across
+/// signedness, so `Int::<4>::new(-1) < 0_u8` holds.
+///
+/// `Display` prints the signed number and `Debug` prints it with the type, as in `Int<5>(-16)`.
+/// The hex, octal, and binary traits print the two's complement bits within the width instead, so
+/// `Int::<12>::new(-1)` prints as `fff` in hex.
+///
+/// `BitIndex`, `BitMask`, and `Signs` treat the type as `BITS` bits wide, the same way they treat
+/// the primitives as their bit size, so indices past the top bit of a negative value read as ones.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{BitIndex, Int, Signs};
+/// use std::convert::TryFrom;
+///
+/// let value = Int::<5>::new(-16);
+/// assert_eq!(value.get(), -16);
+/// assert!(Int::<5>::try_new(16).is_err());
+/// assert_eq!(Int::<5>::masked(0b11111).get(), -1);
+///
+/// assert_eq!(i16::from(Int::<12>::MIN), -2048);
+/// assert!(u16::try_from(Int::<12>::new(-1)).is_err());
+///
+/// assert!(value.bit(20));
+/// assert_eq!(value.bits(2..).get(), -4);
+/// assert_eq!(Int::<20>::new(0x10).sign_extend_from(5).get(), -16);
+/// ```
+///
+/// ```compile_fail
+/// use quark::Int;
+///
+/// let empty = Int::<0>::masked(0);
+/// ```
+///
+/// ```compile_fail
+/// use quark::Int;
+///
+/// let one = Int::<1>::ONE;
+/// ```
+///
+/// ```compile_fail
+/// use quark::Int;
+///
+/// let one = Int::<0>::ONE;
+/// ```
+///
+/// ```compile_fail
+/// use quark::Int;
+///
+/// let zero = Int::<0>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Int<const BITS: usize> {
+    value: i128,
+}
+
+impl<const BITS: usize> Int<BITS> {
+    const VALID_WIDTH: () = assert!(
+        BITS > 0 && BITS <= 128,
+        "Int must have between 1 and 128 bits"
+    );
+
+    /// The smallest value of the type, -2<sup>BITS - 1</sup>.
+    pub const MIN: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        Int {
+            value: !Self::MAX.value,
+        }
+    };
+
+    /// The largest value of the type, 2<sup>BITS - 1</sup> - 1.
+    pub const MAX: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        Int {
+            value: consts::mask_i128(BITS.saturating_sub(1)),
+        }
+    };
+
+    /// The value 0.
+    pub const ZERO: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        Int { value: 0 }
+    };
+
+    /// The value 1.
+    ///
+    /// `Int<1>` only holds -1 and 0, so using this constant with it fails to compile.
+    pub const ONE: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        assert!(BITS > 1, "Int<1> can't hold the value 1");
+        Int { value: 1 }
+    };
+
+    /// The number of bits in the type, as a `u32` like the primitives' `BITS`.
+    pub const BITS: u32 = BITS as u32;
+
+    /// Creates a value, panicking if it doesn't fit in `BITS` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is smaller than [`MIN`](#associatedconstant.MIN) or larger than
+    /// [`MAX`](#associatedconstant.MAX). The message names the value and the width, the same as
+    /// the error [`try_new`](#method.try_new) returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<4>::new(-8).get(), -8);
+    ///
+    /// const OFFSET: Int<7> = Int::new(-0x40);
+    /// assert_eq!(OFFSET.get(), -0x40);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use quark::Int;
+    ///
+    /// // Panics with "8 doesn't fit in 4 bits as a signed integer".
+    /// Int::<4>::new(8);
+    /// ```
+    ///
+    /// In a constant, a value that doesn't fit is a compile error:
+    ///
+    /// ```compile_fail
+    /// use quark::Int;
+    ///
+    /// const OFFSET: Int<7> = Int::new(0x40);
+    /// ```
+    #[track_caller]
+    pub const fn new(value: i128) -> Self {
+        match Self::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it doesn't fit in `BITS` bits.
+    ///
+    /// This checks the value in the same way as converting with `TryFrom`.
+    pub const fn try_new(value: i128) -> Result<Self, TryFromIntError> {
+        match Self::checked_new(value) {
+            Some(value) => Ok(value),
+            None => Err(TryFromIntError::from_i128(value, BITS)),
+        }
+    }
+
+    /// Creates a value, returning `None` if it doesn't fit in `BITS` bits.
+    pub(crate) const fn checked_new(value: i128) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        if value >= Self::MIN.value && value <= Self::MAX.value {
+            Some(Int { value })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a value from the lowest `BITS` bits of `value`, discarding the rest.
+    ///
+    /// The lowest bits are read as a two's complement number, so the result is sign-extended from
+    /// bit `BITS - 1`.
+    pub const fn masked(value: i128) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        Int {
+            value: consts::sign_extend_i128(value, 128 - BITS),
+        }
+    }
+
+    /// Returns the value as an `i128`.
+    pub const fn get(self) -> i128 {
+        self.value
+    }
+
+    /// Returns the value as an `i128`, the same as [`get`](#method.get).
+    pub const fn value(self) -> i128 {
+        self.value
+    }
+
+    /// Consumes the wrapper and returns the value as an `i128`, the same as
+    /// [`get`](#method.get).
+    pub const fn into_inner(self) -> i128 {
+        self.value
+    }
+
+    /// Converts the value to a type that's at least as wide, filling the new bits with copies of
+    /// the sign bit.
+    ///
+    /// Widening to a narrower type fails to compile; use [`try_resize`](#method.try_resize) or
+    /// [`masked_resize`](#method.masked_resize) to narrow a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// let offset = Int::<8>::new(-3);
+    /// assert_eq!(offset.widen::<20>(), Int::<20>::new(-3));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use quark::Int;
+    ///
+    /// let narrowed = Int::<20>::MAX.widen::<8>();
+    /// ```
+    pub const fn widen<const TO: usize>(self) -> Int<TO> {
+        #[allow(clippy::let_unit_value)]
+        let () = Widening::<BITS, TO>::VALID;
+
+        Int { value: self.value }
+    }
+
+    /// Converts the value to a type of any width, returning `None` if it doesn't fit.
+    pub const fn try_resize<const TO: usize>(self) -> Option<Int<TO>> {
+        Int::checked_new(self.value)
+    }
+
+    /// Converts the value to a type of any width, keeping only the lowest `TO` bits and
+    /// sign-extending from the top one.
+    pub const fn masked_resize<const TO: usize>(self) -> Int<TO> {
+        Int::masked(self.value)
+    }
+
+    /// Returns whether the value is held the way the type's invariant requires.
+    const fn is_canonical(self) -> bool {
+        Self::masked(self.value).value == self.value
+    }
+
+    /// Returns the bits of the value within its width, read as an unsigned number.
+    pub(crate) const fn to_bits(self) -> u128 {
+        self.value as u128 & consts::mask_u128(BITS)
+    }
+
+    /// Creates a value from the lowest `BITS` bits of `bits`, sign-extending from the top one.
+    pub(crate) const fn from_bits(bits: u128) -> Self {
+        Self::masked(bits as i128)
+    }
+
+    /// Adds two values, wrapping around at the limits of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<12>::MAX.wrapping_add(Int::new(1)), Int::MIN);
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_add(rhs.value))
+    }
+
+    /// Subtracts `rhs` from the value, wrapping around at the limits of the type.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_sub(rhs.value))
+    }
+
+    /// Multiplies two values, wrapping around at the limits of the type.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_mul(rhs.value))
+    }
+
+    /// Divides the value by `rhs`, wrapping around at the limits of the type.
+    ///
+    /// The only quotient that wraps is [`MIN`](#associatedconstant.MIN) divided by -1, which
+    /// gives `MIN`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_div(rhs.value))
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, giving 0 for
+    /// [`MIN`](#associatedconstant.MIN) divided by -1 rather than overflowing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_rem(rhs.value))
+    }
+
+    /// Divides the value by `rhs`, rounding so that the remainder is never negative.
+    ///
+    /// This is the quotient that goes with [`rem_euclid`](#method.rem_euclid), and it differs
+    /// from `/` when the value is negative: `-7` divided by `2` is `-4` rather than `-3`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient overflows, which only happens for
+    /// [`MIN`](#associatedconstant.MIN) divided by -1. Unlike `+`, this panics whether or not
+    /// debug assertions are enabled, the same as `div_euclid` on the primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<6>::new(-7).div_euclid(Int::new(2)), Int::new(-4));
+    /// assert_eq!(Int::<6>::new(-7).div_euclid(Int::new(-2)), Int::new(4));
+    /// ```
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        match self.checked_div_euclid(rhs) {
+            Some(value) => value,
+            None if rhs.value == 0 => panic!("attempt to divide by zero"),
+            None => panic!("attempt to divide with overflow"),
+        }
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, which is never negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the value is [`MIN`](#associatedconstant.MIN) and `rhs` is
+    /// -1, the same as `rem_euclid` on the primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<6>::new(-7).rem_euclid(Int::new(2)), Int::new(1));
+    /// assert_eq!(Int::<6>::new(-7) % Int::new(2), Int::new(-1));
+    /// ```
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        match self.checked_rem_euclid(rhs) {
+            Some(value) => value,
+            None if rhs.value == 0 => {
+                panic!("attempt to calculate the remainder with a divisor of zero")
+            }
+            None => panic!("attempt to calculate the remainder with overflow"),
+        }
+    }
+
+    /// Adds two values, returning `None` if the sum doesn't fit in `BITS` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<7>::new(62).checked_add(Int::new(1)), Some(Int::MAX));
+    /// assert_eq!(Int::<7>::MAX.checked_add(Int::new(1)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_add(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from the value, returning `None` if the result doesn't fit in `BITS` bits.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_sub(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Multiplies two values, returning `None` if the product doesn't fit in `BITS` bits.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_mul(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Divides the value by `rhs`, returning `None` if `rhs` is zero or the quotient doesn't fit.
+    ///
+    /// The only quotient that doesn't fit is [`MIN`](#associatedconstant.MIN) divided by -1.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_div(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, or `None` if `rhs` is zero or the
+    /// division overflows.
+    ///
+    /// As with the primitives, [`MIN`](#associatedconstant.MIN) divided by -1 counts as
+    /// overflowing, even though its remainder would be 0.
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if self.overflows_division(rhs) {
+            return None;
+        }
+
+        match self.value.checked_rem(rhs.value) {
+            Some(value) => Some(Int { value }),
+            None => None,
+        }
+    }
+
+    /// Divides the value by `rhs` as in [`div_euclid`](#method.div_euclid), returning `None` if
+    /// `rhs` is zero or the quotient doesn't fit.
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_div_euclid(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Returns the remainder of dividing the value by `rhs` as in
+    /// [`rem_euclid`](#method.rem_euclid), or `None` if `rhs` is zero or the division overflows.
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if self.overflows_division(rhs) {
+            return None;
+        }
+
+        match self.value.checked_rem_euclid(rhs.value) {
+            Some(value) => Some(Int { value }),
+            None => None,
+        }
+    }
+
+    /// Returns whether dividing the value by `rhs` overflows the type, which only happens when
+    /// dividing [`MIN`](#associatedconstant.MIN) by -1.
+    const fn overflows_division(self, rhs: Self) -> bool {
+        self.value == Self::MIN.value && rhs.value == -1
+    }
+
+    /// Negates the value, returning `None` if it's [`MIN`](#associatedconstant.MIN).
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.value.checked_neg() {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Returns the absolute value, or `None` if the value is [`MIN`](#associatedconstant.MIN),
+    /// which has no positive counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<4>::new(-7).checked_abs(), Some(Int::new(7)));
+    /// assert_eq!(Int::<4>::MIN.checked_abs(), None);
+    /// ```
+    pub const fn checked_abs(self) -> Option<Self> {
+        if self.value < 0 {
+            self.checked_neg()
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Shifts the value left by `rhs` bits, returning `None` if `rhs` is at least `BITS`.
+    ///
+    /// As with the primitives, only the shift amount is checked: bits shifted past the top of the
+    /// value are discarded, and the result can change sign.
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if (rhs as usize) < BITS {
+            Some(Self::masked(self.value << rhs))
+        } else {
+            None
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, filling in copies of the sign bit, or returns `None`
+    /// if `rhs` is at least `BITS`.
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if (rhs as usize) < BITS {
+            Some(Int {
+                value: self.value >> rhs,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Adds two values, clamping the sum to [`MIN`](#associatedconstant.MIN) or
+    /// [`MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<16>::new(30_000).saturating_add(Int::new(30_000)), Int::MAX);
+    /// assert_eq!(Int::<16>::new(-30_000).saturating_add(Int::new(-30_000)), Int::MIN);
+    /// assert_eq!(Int::<16>::MIN.saturating_neg(), Int::MAX);
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None if rhs.value < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Subtracts `rhs` from the value, clamping the result to [`MIN`](#associatedconstant.MIN)
+    /// or [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None if rhs.value < 0 => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Multiplies two values, clamping the product to [`MIN`](#associatedconstant.MIN) or
+    /// [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(value) => value,
+            None if (self.value < 0) != (rhs.value < 0) => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Negates the value, clamping the negation of [`MIN`](#associatedconstant.MIN) to
+    /// [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns the absolute value, clamping the absolute value of
+    /// [`MIN`](#associatedconstant.MIN) to [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Adds two values, returning the sum wrapped at the limits of the type and whether it
+    /// overflowed them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// // An 18-bit add that leaves the signed range, as on the PDP-7.
+    /// let (sum, overflow) = Int::<18>::MAX.overflowing_add(Int::new(1));
+    /// assert_eq!((sum, overflow), (Int::MIN, true));
+    /// ```
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_add(rhs), self.checked_add(rhs).is_none())
+    }
+
+    /// Subtracts `rhs` from the value, returning the result wrapped at the limits of the type and
+    /// whether it overflowed them.
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_sub(rhs), self.checked_sub(rhs).is_none())
+    }
+
+    /// Multiplies two values, returning the product wrapped at the limits of the type and whether
+    /// it overflowed them.
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_mul(rhs), self.checked_mul(rhs).is_none())
+    }
+
+    /// Negates the value, returning the result wrapped at the limits of the type and whether it
+    /// overflowed them, which only happens for [`MIN`](#associatedconstant.MIN).
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), self.checked_neg().is_none())
+    }
+
+    /// Returns the absolute value, wrapped at the limits of the type, and whether it overflowed
+    /// them, which only happens for [`MIN`](#associatedconstant.MIN).
+    pub const fn overflowing_abs(self) -> (Self, bool) {
+        (self.wrapping_abs(), self.checked_abs().is_none())
+    }
+
+    /// Adds two values and a carry bit, returning the sum wrapped at the limits of the type and
+    /// whether it overflowed them.
+    ///
+    /// As with the signed primitives, the flag is signed overflow, not the carry out of the top
+    /// bit. When chaining several values into a wider number, use
+    /// [`UInt::carrying_add`](struct.UInt.html#method.carrying_add) for every part but the most
+    /// significant one.
+    pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let (sum, overflowed) = self.value.overflowing_add(rhs.value);
+        let (sum, overflowed_again) = sum.overflowing_add(carry as i128);
+        (
+            Self::masked(sum),
+            overflowed != overflowed_again || sum < Self::MIN.value || sum > Self::MAX.value,
+        )
+    }
+
+    /// Subtracts `rhs` and a borrow bit from the value, returning the result wrapped at the
+    /// limits of the type and whether it overflowed them.
+    pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let (difference, overflowed) = self.value.overflowing_sub(rhs.value);
+        let (difference, overflowed_again) = difference.overflowing_sub(borrow as i128);
+        (
+            Self::masked(difference),
+            overflowed != overflowed_again
+                || difference < Self::MIN.value
+                || difference > Self::MAX.value,
+        )
+    }
+
+    /// Shifts the value left by `rhs` bits, giving zero if `rhs` is at least `BITS`.
+    ///
+    /// Bits shifted past the top of the width are discarded. This is also what the `<<` operator
+    /// does, so unlike the primitives, shifting by the width or more never panics.
+    pub const fn unbounded_shl(self, rhs: u32) -> Self {
+        if (rhs as usize) < BITS {
+            Self::masked(self.value << rhs)
+        } else {
+            Self::NONE_SET
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, filling in copies of the sign bit.
+    ///
+    /// Shifting by `BITS` or more leaves only copies of the sign bit, which is -1 for negative
+    /// values and zero for the rest.
+    pub const fn unbounded_shr(self, rhs: u32) -> Self {
+        let rhs = if (rhs as usize) < BITS { rhs } else { 127 };
+        Int {
+            value: self.value >> rhs,
+        }
+    }
+
+    /// Shifts the value left by `rhs` modulo `BITS` bits, the way `wrapping_shl` does on the
+    /// primitives.
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        self.unbounded_shl(rhs % BITS as u32)
+    }
+
+    /// Shifts the value right by `rhs` modulo `BITS` bits, the way `wrapping_shr` does on the
+    /// primitives.
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.unbounded_shr(rhs % BITS as u32)
+    }
+
+    /// Rotates the bits of the value left by `n` places within the width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<5>::new(-16).rotate_left(1).get(), 1);
+    /// ```
+    pub const fn rotate_left(self, n: u32) -> Self {
+        let n = n as usize % BITS;
+        let bits = self.value as u128 & consts::mask_u128(BITS);
+
+        if n == 0 {
+            self
+        } else {
+            Self::masked((bits << n | bits >> (BITS - n)) as i128)
+        }
+    }
+
+    /// Rotates the bits of the value right by `n` places within the width of the type.
+    pub const fn rotate_right(self, n: u32) -> Self {
+        let n = n as usize % BITS;
+        let bits = self.value as u128 & consts::mask_u128(BITS);
+
+        if n == 0 {
+            self
+        } else {
+            Self::masked((bits >> n | bits << (BITS - n)) as i128)
+        }
+    }
+
+    /// Negates the value, wrapping around at the limits of the type.
+    ///
+    /// The only value that wraps is [`MIN`](#associatedconstant.MIN), which is its own negation.
+    pub const fn wrapping_neg(self) -> Self {
+        Self::masked(self.value.wrapping_neg())
+    }
+
+    /// Returns the absolute value, wrapping around at the limits of the type.
+    ///
+    /// As with [`wrapping_neg`](#method.wrapping_neg), the only value that wraps is
+    /// [`MIN`](#associatedconstant.MIN), which is its own absolute value.
+    pub const fn wrapping_abs(self) -> Self {
+        if self.value < 0 {
+            self.wrapping_neg()
+        } else {
+            self
+        }
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// The absolute value of [`MIN`](#associatedconstant.MIN) doesn't fit in the type. Like `abs`
+    /// on the primitives, this panics for `MIN` when debug assertions are enabled and returns
+    /// `MIN` otherwise; [`unsigned_abs`](#method.unsigned_abs) can represent it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<8>::new(-100).abs(), Int::new(100));
+    /// assert_eq!(Int::<8>::MAX.abs(), Int::MAX);
+    /// ```
+    pub const fn abs(self) -> Self {
+        if cfg!(debug_assertions) {
+            match self.checked_abs() {
+                Some(value) => value,
+                None => panic!("attempt to negate with overflow"),
+            }
+        } else {
+            self.wrapping_abs()
+        }
+    }
+
+    /// Returns the absolute value as an unsigned number of the same width, which can hold the
+    /// absolute value of every value of the type, including [`MIN`](#associatedconstant.MIN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::{Int, UInt};
+    ///
+    /// assert_eq!(Int::<8>::new(-5).unsigned_abs(), UInt::new(5));
+    /// assert_eq!(Int::<8>::MIN.unsigned_abs(), UInt::new(128));
+    /// ```
+    pub const fn unsigned_abs(self) -> UInt<BITS> {
+        UInt::masked(self.value.unsigned_abs())
+    }
+
+    /// Returns -1 if the value is negative, 0 if it's zero, and 1 if it's positive.
+    ///
+    /// `Int<1>` only holds -1 and 0, so it never needs to return 1.
+    pub const fn signum(self) -> Self {
+        Self::masked(self.value.signum())
+    }
+
+    /// Returns whether the value is below zero.
+    ///
+    /// This is the same as the [`sign_bit`](trait.Signs.html#tymethod.sign_bit) of the value.
+    pub const fn is_negative(self) -> bool {
+        self.value < 0
+    }
+
+    /// Returns whether the value is above zero.
+    pub const fn is_positive(self) -> bool {
+        self.value > 0
+    }
+}
+
+impl<const BITS: usize> BitSize for Int<BITS> {
+    const BIT_SIZE: usize = BITS;
+}
+
+impl<const BITS: usize> BitConsts for Int<BITS> {
+    const ALL_SET: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        Int { value: -1 }
+    };
+    const NONE_SET: Self = Self::ZERO;
+}
+
+impl<const BITS: usize> Default for Int<BITS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+macro_rules! int_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $wrapping:ident, $message:literal;)*) => {
+        $(
+            /// Panics if the result doesn't fit in `BITS` bits when debug assertions are enabled,
+            /// and wraps around otherwise, like the operators on the primitives.
+            impl<const BITS: usize> $trait for Int<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    if cfg!(debug_assertions) {
+                        match self.$checked(rhs) {
+                            Some(value) => value,
+                            None => panic!($message),
+                        }
+                    } else {
+                        self.$wrapping(rhs)
+                    }
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for Int<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+int_op_impl! {
+    Add, add, AddAssign, add_assign, checked_add, wrapping_add, "attempt to add with overflow";
+    Sub, sub, SubAssign, sub_assign, checked_sub, wrapping_sub, "attempt to subtract with overflow";
+    Mul, mul, MulAssign, mul_assign, checked_mul, wrapping_mul, "attempt to multiply with overflow";
+}
+
+macro_rules! int_div_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $zero_message:literal, $overflow_message:literal;)*) => {
+        $(
+            /// Panics if `rhs` is zero, or if the value is
+            /// [`MIN`](struct.Int.html#associatedconstant.MIN) and `rhs` is -1. Like the operators
+            /// on the primitives, this panics whether or not debug assertions are enabled.
+            impl<const BITS: usize> $trait for Int<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    match self.$checked(rhs) {
+                        Some(value) => value,
+                        None if rhs.value == 0 => panic!($zero_message),
+                        None => panic!($overflow_message),
+                    }
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for Int<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+int_div_impl! {
+    Div, div, DivAssign, div_assign, checked_div,
+    "attempt to divide by zero", "attempt to divide with overflow";
+    Rem, rem, RemAssign, rem_assign, checked_rem,
+    "attempt to calculate the remainder with a divisor of zero",
+    "attempt to calculate the remainder with overflow";
+}
+
+macro_rules! int_bit_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt;)*) => {
+        $(
+            impl<const BITS: usize> $trait for Int<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    debug_assert!(self.is_canonical() && rhs.is_canonical());
+                    Self::masked(self.value $op rhs.value)
+                }
+            }
+
+            /// Masks `rhs` to `BITS` bits first, so the bits above the width are ignored.
+            impl<const BITS: usize> $trait<i128> for Int<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: i128) -> Self {
+                    self $op Self::masked(rhs)
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for Int<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait<i128> for Int<BITS> {
+                fn $assign_method(&mut self, rhs: i128) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+int_bit_op_impl! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &;
+    BitOr, bitor, BitOrAssign, bitor_assign, |;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^;
+}
+
+impl<const BITS: usize> Not for Int<BITS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::masked(!self.value)
+    }
+}
+
+/// Panics when negating [`MIN`](struct.Int.html#associatedconstant.MIN) if debug assertions are
+/// enabled, and wraps around to `MIN` otherwise, like negating the primitives.
+impl<const BITS: usize> Neg for Int<BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if cfg!(debug_assertions) {
+            match self.checked_neg() {
+                Some(value) => value,
+                None => panic!("attempt to negate with overflow"),
+            }
+        } else {
+            self.wrapping_neg()
+        }
+    }
+}
+
+macro_rules! int_shift_impl {
+    ($($type:ty),*) => {
+        $(
+            /// Shifts left by the amount, discarding the bits past the top of the width. Shifting
+            /// by `BITS` or more gives zero.
+            impl<const BITS: usize> Shl<$type> for Int<BITS> {
+                type Output = Self;
+
+                fn shl(self, rhs: $type) -> Self {
+                    self.unbounded_shl(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            /// Shifts right by the amount, filling in copies of the sign bit. Shifting by `BITS` or more
+            /// leaves only copies of the sign bit.
+            impl<const BITS: usize> Shr<$type> for Int<BITS> {
+                type Output = Self;
+
+                fn shr(self, rhs: $type) -> Self {
+                    self.unbounded_shr(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            impl<const BITS: usize> ShlAssign<$type> for Int<BITS> {
+                fn shl_assign(&mut self, rhs: $type) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl<const BITS: usize> ShrAssign<$type> for Int<BITS> {
+                fn shr_assign(&mut self, rhs: $type) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+int_shift_impl!(u32, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u128s;
+    use crate::{Signs, UInt};
+    use spectral::prelude::*;
+
+    #[test]
+    fn constructing() {
+        asserting!("values that fit can be created")
+            .that(&(Int::<5>::new(15).get(), Int::<5>::new(-16).get()))
+            .is_equal_to((15, -16));
+
+        asserting!("values that don't fit are rejected")
+            .that(&(
+                Int::<5>::try_new(16),
+                Int::<5>::try_new(-17),
+                Int::<1>::try_new(1),
+            ))
+            .is_equal_to((
+                Err(TryFromIntError::from_i128(16, 5)),
+                Err(TryFromIntError::from_i128(-17, 5)),
+                Err(TryFromIntError::from_i128(1, 1)),
+            ));
+
+        asserting!("values that fit are accepted")
+            .that(&(
+                Int::<1>::try_new(-1).map(Int::get),
+                Int::<128>::try_new(i128::MIN).map(Int::get),
+            ))
+            .is_equal_to((Ok(-1), Ok(i128::MIN)));
+    }
+
+    #[test]
+    fn masking() {
+        asserting!("masking sign-extends from the top bit of the width")
+            .that(&(
+                Int::<5>::masked(0b11111).get(),
+                Int::<5>::masked(0b01111).get(),
+                Int::<5>::masked(0b10000).get(),
+                Int::<5>::masked(0x7e0).get(),
+            ))
+            .is_equal_to((-1, 15, -16, 0));
+
+        for value in -300..300 {
+            asserting!("masking matches sign extension")
+                .that(&Int::<7>::masked(value).get())
+                .is_equal_to(value.sign_extend_from(7));
+        }
+
+        asserting!("full-width values are unchanged")
+            .that(&Int::<128>::masked(-12345).get())
+            .is_equal_to(-12345);
+    }
+
+    #[test]
+    #[should_panic(expected = "-17 doesn't fit in 5 bits as a signed integer")]
+    fn new_panics_when_out_of_range() {
+        Int::<5>::new(-17);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "-170141183460469231731687303715884105728 doesn't fit in 127 bits as a signed integer"
+    )]
+    fn new_panics_with_the_whole_value() {
+        Int::<127>::new(i128::MIN);
+    }
+
+    #[test]
+    fn limits() {
+        asserting!("limits are powers of two around zero")
+            .that(&(
+                (Int::<1>::MIN.get(), Int::<1>::MAX.get()),
+                (Int::<5>::MIN.get(), Int::<5>::MAX.get()),
+                (Int::<64>::MIN.get(), Int::<64>::MAX.get()),
+                (Int::<128>::MIN.get(), Int::<128>::MAX.get()),
+            ))
+            .is_equal_to((
+                (-1, 0),
+                (-16, 15),
+                (i64::MIN as i128, i64::MAX as i128),
+                (i128::MIN, i128::MAX),
+            ));
+
+        asserting!("the bit size is the width")
+            .that(&(Int::<20>::BIT_SIZE, Int::<20>::ALL_SET.get()))
+            .is_equal_to((20, -1));
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        type I5 = Int<5>;
+
+        fn reduce(value: i32) -> i128 {
+            ((value + 16).rem_euclid(32) - 16) as i128
+        }
+
+        for a in -16..16 {
+            for b in -16..16 {
+                let (x, y) = (I5::new(a as i128), I5::new(b as i128));
+
+                asserting!("results are reduced to two's complement in 5 bits")
+                    .that(&(
+                        x.wrapping_add(y).get(),
+                        x.wrapping_sub(y).get(),
+                        x.wrapping_mul(y).get(),
+                    ))
+                    .is_equal_to((reduce(a + b), reduce(a - b), reduce(a * b)));
+            }
+
+            asserting!("negation is reduced to two's complement in 5 bits")
+                .that(&I5::new(a as i128).wrapping_neg().get())
+                .is_equal_to(reduce(-a));
+        }
+
+        asserting!("full-width values wrap at the i128 limits")
+            .that(&(
+                Int::<128>::MAX.wrapping_add(Int::new(1)),
+                Int::<128>::MIN.wrapping_neg(),
+            ))
+            .is_equal_to((Int::MIN, Int::MIN));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        type I5 = Int<5>;
+
+        fn fit(value: i32) -> Option<i128> {
+            if (-16..16).contains(&value) {
+                Some(value as i128)
+            } else {
+                None
+            }
+        }
+
+        for a in -16..16 {
+            let x = I5::new(a as i128);
+
+            for b in -16..16 {
+                let y = I5::new(b as i128);
+
+                asserting!("results are None exactly when they don't fit in 5 bits")
+                    .that(&(
+                        x.checked_add(y).map(I5::get),
+                        x.checked_sub(y).map(I5::get),
+                        x.checked_mul(y).map(I5::get),
+                        x.checked_div(y).map(I5::get),
+                    ))
+                    .is_equal_to((
+                        fit(a + b),
+                        fit(a - b),
+                        fit(a * b),
+                        a.checked_div(b).and_then(fit),
+                    ));
+            }
+
+            for shift in 0..8 {
+                asserting!("shifts are None exactly when the amount is at least 5")
+                    .that(&(
+                        x.checked_shl(shift).map(I5::get),
+                        x.checked_shr(shift).map(I5::get),
+                    ))
+                    .is_equal_to(if shift < 5 {
+                        (
+                            Some(((a << shift) as i128).sign_extend_from(5)),
+                            Some((a >> shift) as i128),
+                        )
+                    } else {
+                        (None, None)
+                    });
+            }
+
+            asserting!("negation and absolute values fail only for MIN")
+                .that(&(x.checked_neg().map(I5::get), x.checked_abs().map(I5::get)))
+                .is_equal_to((fit(-a), fit(a.abs())));
+        }
+
+        asserting!("limits are consistent with the constants")
+            .that(&(
+                Int::<7>::MAX.checked_add(Int::new(1)),
+                Int::<7>::MIN.checked_sub(Int::new(1)),
+                Int::<7>::MIN.checked_div(Int::new(-1)),
+                Int::<128>::MIN.checked_div(Int::new(-1)),
+                Int::<128>::MIN.checked_abs(),
+            ))
+            .is_equal_to((None, None, None, None, None));
+    }
+
+    #[test]
+    fn division() {
+        type I6 = Int<6>;
+
+        fn fit(value: i32) -> Option<i128> {
+            if (-32..32).contains(&value) {
+                Some(value as i128)
+            } else {
+                None
+            }
+        }
+
+        for a in -32..32_i32 {
+            for b in -32..32_i32 {
+                let (x, y) = (I6::new(a as i128), I6::new(b as i128));
+
+                asserting!("checked division matches a wider model")
+                    .that(&(
+                        x.checked_div(y).map(I6::get),
+                        x.checked_rem(y).map(I6::get),
+                        x.checked_div_euclid(y).map(I6::get),
+                        x.checked_rem_euclid(y).map(I6::get),
+                    ))
+                    .is_equal_to((
+                        a.checked_div(b).and_then(fit),
+                        a.checked_div(b)
+                            .and_then(fit)
+                            .and(a.checked_rem(b).map(i128::from)),
+                        a.checked_div_euclid(b).and_then(fit),
+                        a.checked_div(b)
+                            .and_then(fit)
+                            .and(a.checked_rem_euclid(b).map(i128::from)),
+                    ));
+
+                if b != 0 {
+                    asserting!("wrapping division matches the model reduced to the width")
+                        .that(&(x.wrapping_div(y).get(), x.wrapping_rem(y).get()))
+                        .is_equal_to((
+                            ((a / b) as i128).sign_extend_from(6),
+                            ((a % b) as i128).sign_extend_from(6),
+                        ));
+                }
+
+                if b != 0 && !(a == -32 && b == -1) {
+                    asserting!("operators match the model")
+                        .that(&(
+                            (x / y).get(),
+                            (x % y).get(),
+                            x.div_euclid(y).get(),
+                            x.rem_euclid(y).get(),
+                        ))
+                        .is_equal_to((
+                            (a / b) as i128,
+                            (a % b) as i128,
+                            a.div_euclid(b) as i128,
+                            a.rem_euclid(b) as i128,
+                        ));
+                }
+            }
+        }
+
+        asserting!("full-width division overflows at the i128 limits")
+            .that(&(
+                Int::<128>::MIN.checked_rem(Int::new(-1)),
+                Int::<128>::MIN.wrapping_div(Int::new(-1)),
+                Int::<128>::MIN.wrapping_rem(Int::new(-1)),
+            ))
+            .is_equal_to((None, Int::MIN, Int::ZERO));
+
+        let mut value = Int::<6>::new(-30);
+        value /= Int::new(4);
+        value %= Int::new(4);
+
+        asserting!("assignment operators divide in place")
+            .that(&value.get())
+            .is_equal_to(-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn dividing_min_by_minus_one_panics() {
+        let _ = Int::<6>::MIN / Int::new(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with overflow")]
+    fn remainder_of_min_by_minus_one_panics() {
+        let _ = Int::<6>::MIN.rem_euclid(Int::new(-1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn dividing_by_zero_panics() {
+        let _ = Int::<6>::MAX / Int::ZERO;
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        type I5 = Int<5>;
+
+        fn clamp(value: i32) -> i128 {
+            value.clamp(-16, 15) as i128
+        }
+
+        for a in -16..16 {
+            let x = I5::new(a as i128);
+
+            for b in -16..16 {
+                let y = I5::new(b as i128);
+
+                asserting!("results are clamped to the limits of 5 bits")
+                    .that(&(
+                        x.saturating_add(y).get(),
+                        x.saturating_sub(y).get(),
+                        x.saturating_mul(y).get(),
+                    ))
+                    .is_equal_to((clamp(a + b), clamp(a - b), clamp(a * b)));
+            }
+
+            asserting!("negation and absolute values are clamped")
+                .that(&(x.saturating_neg().get(), x.saturating_abs().get()))
+                .is_equal_to((clamp(-a), clamp(a.abs())));
+        }
+
+        asserting!("negating MIN saturates to MAX")
+            .that(&(
+                Int::<16>::MIN.saturating_neg(),
+                Int::<16>::MIN.saturating_abs(),
+                Int::<128>::MIN.saturating_neg(),
+            ))
+            .is_equal_to((Int::MAX, Int::MAX, Int::MAX));
+
+        asserting!("full-width results clamp to the i128 limits")
+            .that(&(
+                Int::<128>::MIN.saturating_sub(Int::new(1)),
+                Int::<128>::MIN.saturating_mul(Int::new(-1)),
+                Int::<128>::MAX.saturating_mul(Int::new(-2)),
+            ))
+            .is_equal_to((Int::MIN, Int::MAX, Int::MIN));
+    }
+
+    #[test]
+    fn overflowing_arithmetic() {
+        type I5 = Int<5>;
+
+        fn wrap(value: i32) -> (I5, bool) {
+            (I5::masked(value as i128), !(-16..16).contains(&value))
+        }
+
+        for a in -16..16 {
+            let x = I5::new(a as i128);
+
+            for b in -16..16 {
+                let y = I5::new(b as i128);
+
+                asserting!("overflow is measured at 5 bits")
+                    .that(&(
+                        x.overflowing_add(y),
+                        x.overflowing_sub(y),
+                        x.overflowing_mul(y),
+                    ))
+                    .is_equal_to((wrap(a + b), wrap(a - b), wrap(a * b)));
+
+                for carry in [false, true] {
+                    asserting!("overflow with a carry is measured at 5 bits")
+                        .that(&(x.carrying_add(y, carry), x.borrowing_sub(y, carry)))
+                        .is_equal_to((wrap(a + b + carry as i32), wrap(a - b - carry as i32)));
+                }
+            }
+
+            asserting!("negation overflows only for MIN")
+                .that(&x.overflowing_neg())
+                .is_equal_to(wrap(-a));
+        }
+
+        asserting!("a carry into a single bit overflows")
+            .that(&Int::<1>::new(-1).carrying_add(Int::new(0), true))
+            .is_equal_to((Int::new(0), false));
+
+        asserting!("full-width overflow is measured at 128 bits")
+            .that(&(
+                Int::<128>::MAX.carrying_add(Int::new(0), true),
+                Int::<128>::MAX.carrying_add(Int::new(-1), true),
+                Int::<128>::MIN.borrowing_sub(Int::new(0), true),
+                Int::<128>::MIN.borrowing_sub(Int::new(-1), true),
+            ))
+            .is_equal_to((
+                (Int::MIN, true),
+                (Int::MAX, false),
+                (Int::MAX, true),
+                (Int::MIN, false),
+            ));
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        fn check<const BITS: usize>() {
+            for seed in random_u128s().take(100) {
+                let (x, y) = (
+                    Int::<BITS>::masked(seed as i128),
+                    Int::<BITS>::masked((seed >> 64) as i128),
+                );
+
+                asserting!("negation flips every bit inside the width")
+                    .that(&(!x).get())
+                    .is_equal_to(Int::<BITS>::masked(x.get() ^ Int::<BITS>::ALL_SET.get()).get());
+
+                asserting!("binary operators match the operators on the values")
+                    .that(&((x & y).get(), (x | y).get(), (x ^ y).get()))
+                    .is_equal_to((x.get() & y.get(), x.get() | y.get(), x.get() ^ y.get()));
+
+                asserting!("primitive operands are masked first")
+                    .that(&(x | seed as i128, x ^ seed as i128, x & seed as i128))
+                    .is_equal_to((
+                        x | Int::masked(seed as i128),
+                        x ^ Int::masked(seed as i128),
+                        x & Int::masked(seed as i128),
+                    ));
+            }
+        }
+
+        check::<1>();
+        check::<5>();
+        check::<12>();
+        check::<64>();
+        check::<128>();
+
+        asserting!("negation matches two's complement")
+            .that(&((!Int::<5>::new(5)).get(), (!Int::<5>::MIN).get()))
+            .is_equal_to((-6, 15));
+
+        let mut value = Int::<12>::new(-1);
+        value &= Int::new(0x7f0);
+        value |= 0x0800_i128;
+        value ^= Int::new(0x00f);
+
+        asserting!("assignment operators stay within the width")
+            .that(&value.get())
+            .is_equal_to(-0x001);
+    }
+
+    #[test]
+    fn shifts_and_rotates() {
+        type I5 = Int<5>;
+
+        for a in -16..16_i32 {
+            let x = I5::new(a as i128);
+            let bits = (a & 31) as u32;
+
+            for n in 0..12_u32 {
+                let wide = bits << 5 | bits;
+
+                asserting!("shifts and rotates stay within 5 bits")
+                    .that(&(
+                        (x << n).get(),
+                        (x >> n as usize).get(),
+                        x.wrapping_shr(n).get(),
+                        x.rotate_left(n).get(),
+                        x.rotate_right(n).get(),
+                    ))
+                    .is_equal_to((
+                        if n < 5 {
+                            ((a << n) as i128).sign_extend_from(5)
+                        } else {
+                            0
+                        },
+                        (a >> n.min(31)) as i128,
+                        (a >> (n % 5)) as i128,
+                        ((wide >> (5 - n % 5)) as i128).sign_extend_from(5),
+                        ((wide >> (n % 5)) as i128).sign_extend_from(5),
+                    ));
+            }
+        }
+
+        asserting!("right shifts past the width leave the sign")
+            .that(&(
+                (Int::<12>::MIN >> 100_u32).get(),
+                (Int::<12>::MAX >> 12_u32).get(),
+                (Int::<128>::MIN >> 127_u32).get(),
+            ))
+            .is_equal_to((-1, 0, -1));
+    }
+
+    #[test]
+    fn resizing() {
+        asserting!("widening fills the new bits with copies of the sign bit")
+            .that(&(
+                Int::<8>::MIN.widen::<20>(),
+                Int::<1>::new(-1).widen::<128>().get(),
+                Int::<8>::MAX.widen::<20>().get(),
+            ))
+            .is_equal_to((Int::new(-128), -1, 127));
+
+        asserting!("widening the bits of unsigned values doesn't sign-extend them")
+            .that(&(
+                UInt::<8>::new(0x80).widen::<20>().get(),
+                Int::<8>::masked(0x80).widen::<20>().get(),
+            ))
+            .is_equal_to((0x80, -0x80));
+
+        asserting!("narrowing checks or masks the value")
+            .that(&(
+                Int::<20>::new(-8).try_resize::<4>(),
+                Int::<20>::new(8).try_resize::<4>(),
+                Int::<20>::new(0x1f).masked_resize::<4>(),
+            ))
+            .is_equal_to((Some(Int::MIN), None, Int::new(-1)));
+    }
+
+    #[test]
+    fn operators() {
+        let mut value = Int::<12>::new(-0x800);
+        value += Int::new(0x7ff);
+        value -= Int::new(0xf);
+        value *= Int::new(-2);
+
+        asserting!("operators compute the result when it fits")
+            .that(&(value.get(), (Int::<12>::new(3) * Int::new(-5)).get()))
+            .is_equal_to((32, -15));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to subtract with overflow")
+    )]
+    fn operators_overflow() {
+        assert_eq!(Int::<12>::MIN - Int::new(1), Int::MAX);
+    }
+
+    #[test]
+    fn constants() {
+        macro_rules! check {
+            ($($bits:literal: $min:expr, $max:expr),*) => {
+                $(
+                    asserting!("constants match the width")
+                        .that(&(
+                            Int::<$bits>::MIN.get(),
+                            Int::<$bits>::MAX.get(),
+                            Int::<$bits>::ZERO.get(),
+                            Int::<$bits>::ONE.get(),
+                            Int::<$bits>::BITS,
+                            Int::<$bits>::BIT_SIZE,
+                        ))
+                        .is_equal_to(($min, $max, 0, 1, $bits, $bits));
+                )*
+            };
+        }
+
+        check!(
+            2: -2, 1,
+            7: -64, 63,
+            8: i8::MIN as i128, i8::MAX as i128,
+            12: -0x800, 0x7ff,
+            16: i16::MIN as i128, i16::MAX as i128,
+            32: i32::MIN as i128, i32::MAX as i128,
+            64: i64::MIN as i128, i64::MAX as i128,
+            128: i128::MIN, i128::MAX
+        );
+
+        asserting!("a single bit holds -1 and 0")
+            .that(&(
+                Int::<1>::MIN.get(),
+                Int::<1>::MAX.get(),
+                Int::<1>::ZERO.get(),
+            ))
+            .is_equal_to((-1, 0, 0));
+    }
+
+    #[test]
+    fn absolute_values() {
+        macro_rules! check {
+            ($($bits:literal),*) => {
+                $(
+                    for a in [Int::<$bits>::MIN.get(), -1, 0, 1, Int::<$bits>::MAX.get()] {
+                        let x = Int::<$bits>::new(a);
+
+                        asserting!("signs match the number")
+                            .that(&(
+                                x.signum().get(),
+                                x.is_negative(),
+                                x.is_positive(),
+                                x.sign_bit(),
+                            ))
+                            .is_equal_to((a.signum(), a < 0, a > 0, a < 0));
+
+                        asserting!("unsigned absolute values are exact")
+                            .that(&x.unsigned_abs().get())
+                            .is_equal_to(a.unsigned_abs());
+
+                        if a != Int::<$bits>::MIN.get() {
+                            asserting!("negation and absolute values match the number")
+                                .that(&((-x).get(), x.abs().get(), x.overflowing_abs()))
+                                .is_equal_to((-a, a.abs(), (x.abs(), false)));
+                        }
+                    }
+
+                    asserting!("the minimum is its own negation")
+                        .that(&(
+                            Int::<$bits>::MIN.wrapping_abs(),
+                            Int::<$bits>::MIN.overflowing_abs(),
+                            Int::<$bits>::MIN.unsigned_abs().get(),
+                        ))
+                        .is_equal_to((Int::MIN, (Int::MIN, true), 1 << ($bits - 1)));
+                )*
+            };
+        }
+
+        check!(5, 8, 128);
+
+        asserting!("single-bit values have no positive values")
+            .that(&(
+                Int::<1>::new(-1).signum(),
+                Int::<1>::new(0).signum(),
+                Int::<1>::new(-1).unsigned_abs(),
+            ))
+            .is_equal_to((Int::new(-1), Int::new(0), UInt::new(1)));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to negate with overflow")
+    )]
+    fn negating_min_panics() {
+        assert_eq!(-Int::<8>::MIN, Int::MIN);
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to negate with overflow")
+    )]
+    fn abs_of_min_panics() {
+        assert_eq!(Int::<8>::MIN.abs(), Int::MIN);
+    }
+
+    #[test]
+    fn constructing_in_constants() {
+        const OFFSET: Int<7> = Int::new(-0x26);
+
+        asserting!("every constructor agrees on values that fit")
+            .that(&(
+                Int::<7>::new(-0x26),
+                Int::<7>::try_new(-0x26).ok(),
+                Int::<7>::masked(0x5a),
+                Int::<7>::try_from(-0x26_i128).ok(),
+            ))
+            .is_equal_to((OFFSET, Some(OFFSET), OFFSET, Some(OFFSET)));
+
+        asserting!("the accessors agree with get")
+            .that(&(OFFSET.value(), OFFSET.into_inner()))
+            .is_equal_to((OFFSET.get(), OFFSET.get()));
+
+        asserting!("values that don't fit are rejected or masked")
+            .that(&(
+                Int::<7>::try_new(0x5a).map_err(|error| error.to_string()),
+                Int::<7>::masked(-0xa6),
+                Int::<7>::try_from(0x5a_i128).map_err(|error| error.to_string()),
+            ))
+            .is_equal_to((
+                Err("90 doesn't fit in 7 bits as a signed integer".to_string()),
+                OFFSET,
+                Err("90 doesn't fit in 7 bits as a signed integer".to_string()),
+            ));
+    }
+
+    #[test]
+    fn comparing() {
+        let mut values = [3, -1, -16, 15, 0, -2].map(Int::<5>::new);
+        values.sort();
+
+        asserting!("values order by their numbers")
+            .that(&values.map(Int::get))
+            .is_equal_to([-16, -2, -1, 0, 3, 15]);
+
+        asserting!("the limits order below and above everything else")
+            .that(&(
+                Int::<128>::MIN < Int::new(-1),
+                Int::<128>::MAX > Int::new(0),
+                Int::<1>::MIN < Int::MAX,
+                Int::<12>::default(),
+            ))
+            .is_equal_to((true, true, true, Int::new(0)));
+    }
+
+    #[test]
+    fn collections() {
+        use std::collections::{BTreeMap, HashMap, HashSet};
+
+        let keys = [-2048, -1, 0, 1, 2047, -700, 700].map(Int::<12>::new);
+
+        let map: HashMap<_, _> = keys.iter().map(|&key| (key, key.get())).collect();
+        asserting!("every key finds its own value")
+            .that(&keys.iter().all(|key| map[key] == key.get()))
+            .is_true();
+
+        let set: HashSet<_> = [Int::<12>::masked(0xfff), Int::new(-1), Int::masked(-1)].into();
+        asserting!("equal values hash the same")
+            .that(&set.len())
+            .is_equal_to(1);
+
+        let tree: BTreeMap<_, _> = keys.iter().map(|&key| (key, ())).collect();
+        asserting!("tree maps iterate in numeric order")
+            .that(&tree.keys().map(|key| key.get()).collect::<Vec<_>>())
+            .is_equal_to(vec![-2048, -700, -1, 0, 1, 700, 2047]);
+    }
+}
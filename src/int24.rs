@@ -0,0 +1,348 @@
+use crate::{BitConsts, BitSize, Int, TryFromIntError};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A 24-bit signed integer that takes exactly three bytes.
+///
+/// This is the sample format of 24-bit PCM audio. Decoding bytes sign-extends them from bit 23, so
+/// a sample can be read straight out of a buffer without shifting it around by hand. Values
+/// convert to `i32` and `f32` without losing anything, and come back from `i32` by either wrapping
+/// or saturating.
+///
+/// In memory, a value is its three bytes in little-endian order on every target, with no padding
+/// and an alignment of 1. With the `bytemuck` feature, a byte buffer of three-byte values can be
+/// cast to a slice of `i24` without copying, and with the `zerocopy` feature a `i24` can be a field
+/// of a header struct. Wire formats in big-endian order can use `BigEndian<i24>` instead.
+///
+/// # Examples
+///
+/// ```
+/// use quark::i24;
+///
+/// let sample = i24::from_le_bytes([0x00, 0x00, 0x80]);
+/// assert_eq!(sample, i24::MIN);
+/// assert_eq!(i32::from(sample), -8_388_608);
+/// assert_eq!(i24::saturating(10_000_000), i24::MAX);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[repr(transparent)]
+pub struct i24([u8; 3]);
+
+impl i24 {
+    /// The smallest value of the type, -2<sup>23</sup>.
+    pub const MIN: i24 = i24([0x00, 0x00, 0x80]);
+
+    /// The largest value of the type, 2<sup>23</sup> - 1.
+    pub const MAX: i24 = i24([0xff, 0xff, 0x7f]);
+
+    /// Creates a value, panicking if it doesn't fit in 24 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is smaller than [`MIN`](#associatedconstant.MIN) or larger than
+    /// [`MAX`](#associatedconstant.MAX).
+    #[track_caller]
+    pub const fn new(value: i32) -> i24 {
+        match i24::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it doesn't fit in 24 bits.
+    pub const fn try_new(value: i32) -> Result<i24, TryFromIntError> {
+        if value >= -0x0080_0000 && value <= 0x007f_ffff {
+            Ok(i24::masked(value))
+        } else {
+            Err(TryFromIntError::from_i128(value as i128, 24))
+        }
+    }
+
+    /// Creates a value from the lowest 24 bits of `value`, wrapping around if it doesn't fit.
+    pub const fn masked(value: i32) -> i24 {
+        let [b0, b1, b2, _] = value.to_le_bytes();
+        i24([b0, b1, b2])
+    }
+
+    /// Creates a value, clamping it to [`MIN`](#associatedconstant.MIN) or
+    /// [`MAX`](#associatedconstant.MAX) if it doesn't fit.
+    pub const fn saturating(value: i32) -> i24 {
+        if value < -0x0080_0000 {
+            i24::MIN
+        } else if value > 0x007f_ffff {
+            i24::MAX
+        } else {
+            i24::masked(value)
+        }
+    }
+
+    /// Returns the value as an `i32`.
+    pub const fn get(self) -> i32 {
+        let [b0, b1, b2] = self.0;
+        i32::from_le_bytes([0, b0, b1, b2]) >> 8
+    }
+
+    /// Returns the value as an `i32`, the same as [`get`](#method.get).
+    pub const fn value(self) -> i32 {
+        self.get()
+    }
+
+    /// Consumes the wrapper and returns the value as an `i32`, the same as [`get`](#method.get).
+    pub const fn into_inner(self) -> i32 {
+        self.get()
+    }
+
+    /// Returns the value scaled into the range -1.0 to 1.0, as audio code usually expects.
+    ///
+    /// The value is divided by 2<sup>23</sup>, so [`MIN`](#associatedconstant.MIN) becomes
+    /// exactly -1.0 and [`MAX`](#associatedconstant.MAX) falls just short of 1.0. Converting with
+    /// `f32::from` keeps the raw value instead.
+    pub fn to_normalized_f32(self) -> f32 {
+        self.get() as f32 / 8_388_608.0
+    }
+
+    /// Creates a value from its bytes in little-endian order, sign-extending from bit 23.
+    pub const fn from_le_bytes(bytes: [u8; 3]) -> i24 {
+        i24(bytes)
+    }
+
+    /// Creates a value from its bytes in big-endian order, sign-extending from bit 23.
+    pub const fn from_be_bytes(bytes: [u8; 3]) -> i24 {
+        let [b0, b1, b2] = bytes;
+        i24([b2, b1, b0])
+    }
+
+    /// Returns the bytes of the value in little-endian order.
+    pub const fn to_le_bytes(self) -> [u8; 3] {
+        self.0
+    }
+
+    /// Returns the bytes of the value in big-endian order.
+    pub const fn to_be_bytes(self) -> [u8; 3] {
+        let [b0, b1, b2] = self.0;
+        [b2, b1, b0]
+    }
+}
+
+impl BitSize for i24 {
+    const BIT_SIZE: usize = 24;
+}
+
+impl BitConsts for i24 {
+    const ALL_SET: i24 = i24([0xff; 3]);
+    const NONE_SET: i24 = i24([0x00; 3]);
+}
+
+impl PartialOrd for i24 {
+    fn partial_cmp(&self, other: &i24) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i24 {
+    fn cmp(&self, other: &i24) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl Debug for i24 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for i24 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl From<i8> for i24 {
+    fn from(value: i8) -> i24 {
+        i24::masked(value.into())
+    }
+}
+
+impl From<i16> for i24 {
+    fn from(value: i16) -> i24 {
+        i24::masked(value.into())
+    }
+}
+
+impl TryFrom<i32> for i24 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i32) -> Result<i24, TryFromIntError> {
+        i24::try_new(value)
+    }
+}
+
+impl From<Int<24>> for i24 {
+    fn from(value: Int<24>) -> i24 {
+        i24::masked(value.get() as i32)
+    }
+}
+
+impl From<i24> for Int<24> {
+    fn from(value: i24) -> Int<24> {
+        Int::masked(value.get().into())
+    }
+}
+
+/// Converts the raw value, so the result ranges from -8388608.0 to 8388607.0.
+///
+/// Use [`i24::to_normalized_f32`](struct.i24.html#method.to_normalized_f32) for a value between
+/// -1.0 and 1.0.
+impl From<i24> for f32 {
+    fn from(value: i24) -> f32 {
+        value.get() as f32
+    }
+}
+
+macro_rules! from_i24_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<i24> for $type {
+                fn from(value: i24) -> $type {
+                    value.get() as $type
+                }
+            }
+        )*
+    };
+}
+
+from_i24_impl!(i32, i64, i128, f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn decoding_known_patterns() {
+        let cases = [
+            ([0x00, 0x00, 0x00], 0),
+            ([0x80, 0x00, 0x00], -8_388_608),
+            ([0x7f, 0xff, 0xff], 8_388_607),
+            ([0xff, 0xff, 0xff], -1),
+            ([0x00, 0x00, 0x01], 1),
+            ([0xff, 0xff, 0xfe], -2),
+            ([0x12, 0x34, 0x56], 0x12_3456),
+            ([0xed, 0xcb, 0xaa], -0x12_3456),
+        ];
+
+        for &(be_bytes, value) in &cases {
+            let mut le_bytes = be_bytes;
+            le_bytes.reverse();
+
+            asserting!("bytes sign-extend in either order")
+                .that(&(
+                    i24::from_be_bytes(be_bytes).get(),
+                    i24::from_le_bytes(le_bytes).get(),
+                ))
+                .is_equal_to((value, value));
+
+            asserting!("bytes round-trip in either order")
+                .that(&(i24::new(value).to_be_bytes(), i24::new(value).to_le_bytes()))
+                .is_equal_to((be_bytes, le_bytes));
+        }
+
+        asserting!("the extreme patterns are the limits")
+            .that(&(
+                i24::from_be_bytes([0x80, 0x00, 0x00]),
+                i24::from_be_bytes([0x7f, 0xff, 0xff]),
+            ))
+            .is_equal_to((i24::MIN, i24::MAX));
+    }
+
+    #[test]
+    fn converting_from_i32() {
+        asserting!("values that fit are accepted")
+            .that(&(i24::try_new(-8_388_608), i24::try_from(8_388_607_i32).ok()))
+            .is_equal_to((Ok(i24::MIN), Some(i24::MAX)));
+
+        asserting!("values that don't fit are rejected")
+            .that(&(i24::try_new(8_388_608).ok(), i24::try_new(-8_388_609).ok()))
+            .is_equal_to((None, None));
+
+        asserting!("errors report the value and width")
+            .that(&i24::try_from(-8_388_609_i32).unwrap_err().to_string())
+            .is_equal_to("-8388609 doesn't fit in 24 bits as a signed integer".to_string());
+
+        asserting!("wrapping keeps the lowest 24 bits")
+            .that(&(
+                i24::masked(8_388_608).get(),
+                i24::masked(-8_388_609).get(),
+                i24::masked(0x0100_0005).get(),
+            ))
+            .is_equal_to((-8_388_608, 8_388_607, 5));
+
+        asserting!("saturating clamps to the limits")
+            .that(&(
+                i24::saturating(i32::MAX),
+                i24::saturating(i32::MIN),
+                i24::saturating(-42),
+            ))
+            .is_equal_to((i24::MAX, i24::MIN, i24::new(-42)));
+    }
+
+    #[test]
+    #[should_panic(expected = "8388608 doesn't fit in 24 bits as a signed integer")]
+    fn new_panics_when_out_of_range() {
+        i24::new(8_388_608);
+    }
+
+    #[test]
+    fn converting_to_wider_types() {
+        asserting!("values convert to integers and Int<24>")
+            .that(&(
+                i32::from(i24::MIN),
+                i64::from(i24::MAX),
+                Int::<24>::from(i24::MIN),
+                i24::from(Int::<24>::MAX),
+            ))
+            .is_equal_to((-8_388_608, 8_388_607, Int::MIN, i24::MAX));
+
+        asserting!("f32 conversion keeps the raw value")
+            .that(&(
+                f32::from(i24::MIN),
+                f32::from(i24::MAX),
+                f64::from(i24::new(-1)),
+            ))
+            .is_equal_to((-8_388_608.0, 8_388_607.0, -1.0));
+
+        asserting!("normalized values range from -1 to 1")
+            .that(&(
+                i24::MIN.to_normalized_f32(),
+                i24::new(0x40_0000).to_normalized_f32(),
+                i24::default().to_normalized_f32(),
+            ))
+            .is_equal_to((-1.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn ordering_and_formatting() {
+        let mut values = [0, -1, 1, -8_388_608, 8_388_607, 0x10_0000].map(i24::new);
+        values.sort();
+
+        asserting!("values order by their numbers, not their bytes")
+            .that(&values.map(i24::get))
+            .is_equal_to([-8_388_608, -1, 0, 1, 0x10_0000, 8_388_607]);
+
+        asserting!("values format as numbers")
+            .that(&(i24::MIN.to_string(), format!("{:?}", i24::new(-7))))
+            .is_equal_to(("-8388608".to_string(), "-7".to_string()));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::wide::{self, Limbs};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A 256-bit signed integer.
+///
+/// This is the two's complement twin of [`u256`](struct.u256.html), held in four `u64` limbs.
+/// Like `u256`, it supports the bit operation traits across the whole width, converts to and from
+/// bytes in either order, and provides the bitwise operators and wrapping addition and
+/// subtraction rather than the full set of arithmetic. Right shifts fill with copies of the sign
+/// bit, and shifting by 256 or more gives zero or -1 rather than panicking.
+///
+/// Formatting with `{}` prints the signed number in decimal, and the hex and binary traits print
+/// the two's complement bits, as they do for the primitives.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{i256, Signs};
+///
+/// let word = i256::from_limbs([0, 0, 0, 0x8000_0000_0000_0000]);
+/// assert!(word.is_negative());
+/// assert_eq!(word >> 255_u32, i256::from(-1));
+///
+/// let byte = i256::from(0x80);
+/// assert_eq!(byte.sign_extend_from(8), i256::from(-0x80));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct i256(Limbs);
+
+impl i256 {
+    /// The smallest value of the type, -2<sup>255</sup>.
+    pub const MIN: i256 = i256([0, 0, 0, 1 << 63]);
+
+    /// The largest value of the type, 2<sup>255</sup> - 1.
+    pub const MAX: i256 = i256([u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1]);
+
+    /// Creates a value from the `u64` limbs of its two's complement bits, least significant first.
+    pub const fn from_limbs(limbs: [u64; 4]) -> i256 {
+        i256(limbs)
+    }
+
+    /// Returns the `u64` limbs of the two's complement bits of the value, least significant first.
+    pub const fn to_limbs(self) -> [u64; 4] {
+        self.0
+    }
+
+    /// Returns `true` if the value is negative.
+    pub const fn is_negative(self) -> bool {
+        (self.0[3] as i64) < 0
+    }
+
+    /// Adds two values, returning `None` if the sum overflows.
+    pub const fn checked_add(self, rhs: i256) -> Option<i256> {
+        let sum = self.wrapping_add(rhs);
+        if self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+
+    /// Subtracts `rhs` from the value, returning `None` if the difference overflows.
+    pub const fn checked_sub(self, rhs: i256) -> Option<i256> {
+        let difference = self.wrapping_sub(rhs);
+        if self.is_negative() != rhs.is_negative() && difference.is_negative() != self.is_negative()
+        {
+            None
+        } else {
+            Some(difference)
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, filling the upper bits with copies of the sign bit.
+    /// Shifting by 256 or more gives zero for positive values and -1 for negative ones.
+    pub const fn unbounded_shr(self, rhs: u32) -> i256 {
+        let fill = if self.is_negative() { u64::MAX } else { 0 };
+        i256(wide::shr(self.0, rhs, fill))
+    }
+
+    /// Returns the limbs of the value with the sign bit flipped, which order as unsigned numbers
+    /// the same way the values do as signed ones.
+    const fn biased(self) -> Limbs {
+        let [a, b, c, d] = self.0;
+        [a, b, c, d ^ 1 << 63]
+    }
+}
+
+impl PartialOrd for i256 {
+    fn partial_cmp(&self, other: &i256) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i256 {
+    fn cmp(&self, other: &i256) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else if wide::less_than(self.biased(), other.biased()) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl Debug for i256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for i256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let magnitude = if self.is_negative() {
+            self.wrapping_neg()
+        } else {
+            *self
+        };
+
+        f.pad_integral(!self.is_negative(), "", &wide::to_decimal(magnitude.0))
+    }
+}
+
+macro_rules! i256_from_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for i256 {
+                fn from(value: $type) -> i256 {
+                    let value = value as i128;
+                    let fill = if value < 0 { u64::MAX } else { 0 };
+                    i256([value as u64, (value >> 64) as u64, fill, fill])
+                }
+            }
+        )*
+    };
+}
+
+i256_from_impl!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<u128> for i256 {
+    fn from(value: u128) -> i256 {
+        i256([value as u64, (value >> 64) as u64, 0, 0])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn conversions() {
+        asserting!("negative primitives fill the upper limbs with ones")
+            .that(&(
+                i256::from(-1).to_limbs(),
+                i256::from(i128::MIN).to_limbs(),
+                i256::from(u128::MAX).to_limbs(),
+                i256::from(i8::MAX).to_limbs(),
+            ))
+            .is_equal_to((
+                [u64::MAX; 4],
+                [0, 1 << 63, u64::MAX, u64::MAX],
+                [u64::MAX, u64::MAX, 0, 0],
+                [0x7f, 0, 0, 0],
+            ));
+
+        asserting!("bytes hold the two's complement bits")
+            .that(&(
+                i256::from(-2).to_be_bytes()[31],
+                i256::from(-2).to_le_bytes()[31],
+                i256::from_be_bytes([0xff; 32]),
+                i256::from_le_bytes(i256::MIN.to_le_bytes()),
+            ))
+            .is_equal_to((0xfe, 0xff, i256::from(-1), i256::MIN));
+    }
+
+    #[test]
+    fn ordering() {
+        let values = [
+            i256::MIN,
+            i256::from(i128::MIN),
+            i256::from(-1),
+            i256::ZERO,
+            i256::ONE,
+            i256::from(u128::MAX),
+            i256::MAX,
+        ];
+
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                asserting!("values order by their numbers")
+                    .that(&a.cmp(b))
+                    .is_equal_to(i.cmp(&j));
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        asserting!("addition wraps from MAX to MIN")
+            .that(&(
+                i256::MAX.wrapping_add(i256::ONE),
+                i256::MAX.checked_add(i256::ONE),
+                i256::MIN.checked_sub(i256::ONE),
+                i256::MIN.checked_add(i256::from(-1)),
+                i256::from(-5).checked_add(i256::from(3)),
+                i256::from(-5).checked_sub(i256::from(-7)),
+                i256::MIN.wrapping_neg(),
+                i256::from(i128::MIN).wrapping_neg(),
+            ))
+            .is_equal_to((
+                i256::MIN,
+                None,
+                None,
+                None,
+                Some(i256::from(-2)),
+                Some(i256::from(2)),
+                i256::MIN,
+                i256::from(1_u128 << 127),
+            ));
+    }
+
+    #[test]
+    fn shifting() {
+        let value = i256::from(-0x100);
+
+        asserting!("right shifts fill with the sign bit")
+            .that(&(
+                value >> 4_u32,
+                value >> 255_u32,
+                value >> 256_u32,
+                i256::MAX >> 300_usize,
+                i256::MIN >> 192_u32,
+            ))
+            .is_equal_to((
+                i256::from(-0x10),
+                i256::from(-1),
+                i256::from(-1),
+                i256::ZERO,
+                i256::from(i64::MIN),
+            ));
+
+        asserting!("left shifts move bits into the sign")
+            .that(&(i256::ONE << 255_u32, value << 256_u32))
+            .is_equal_to((i256::MIN, i256::ZERO));
+    }
+
+    #[test]
+    fn formatting() {
+        asserting!("values format as signed numbers")
+            .that(&(
+                format!("{}", i256::from(i128::MIN)),
+                format!("{:?}", i256::from(-42)),
+                format!("{:+}", i256::from(42)),
+                format!("{:>5}", i256::from(-42)),
+                format!("{:x}", i256::from(-1)),
+                format!("{:#x}", i256::MIN),
+            ))
+            .is_equal_to((
+                i128::MIN.to_string(),
+                "-42".to_string(),
+                "+42".to_string(),
+                "  -42".to_string(),
+                "f".repeat(64),
+                format!("0x8{}", "0".repeat(63)),
+            ));
+
+        asserting!("the extremes print every digit")
+            .that(&(format!("{}", i256::MIN), format!("{}", i256::MAX)))
+            .is_equal_to((
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+                    .to_string(),
+                "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+                    .to_string(),
+            ));
+    }
+}
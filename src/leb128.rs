@@ -0,0 +1,418 @@
+use crate::Leb128Error;
+
+// LEB128 stores a value seven bits at a time, least significant group first, with the top bit of
+// each byte set when another byte follows. Decoding accepts padding, like `0x80 0x00` for zero,
+// as long as the encoding is no longer than the longest encoding of the type, so every value of
+// the type can be read back from any encoder that pads to a fixed width. The bits of the last
+// group that lie past the top of the type must be padding too: zeros for unsigned values, and
+// copies of the sign bit for signed ones.
+
+/// Encodes and decodes unsigned values as unsigned LEB128.
+///
+/// Unsigned LEB128 is the variable-length integer format of DWARF, WebAssembly, and many
+/// serialization formats. Small values take fewer bytes: each byte holds seven bits of the value,
+/// so values below 128 take one byte and a `u64` takes at most ten.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Uleb128;
+///
+/// let mut buf = [0; u32::MAX_LEB128_LEN];
+/// let len = 624_485_u32.encode_uleb128(&mut buf);
+/// assert_eq!(&buf[..len], &[0xe5, 0x8e, 0x26]);
+/// assert_eq!(u32::decode_uleb128(&buf), Ok((624_485, 3)));
+/// ```
+pub trait Uleb128: Sized {
+    /// The length of the longest encoding of a value of the type.
+    const MAX_LEB128_LEN: usize;
+
+    /// Writes the encoding of a value to the start of a buffer, and returns its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoding is longer than the buffer. A buffer of
+    /// [`MAX_LEB128_LEN`](Uleb128::MAX_LEB128_LEN) bytes is always long enough.
+    fn encode_uleb128(&self, buf: &mut [u8]) -> usize;
+
+    /// Reads a value from the encoding at the start of some bytes, and returns it with the length
+    /// of the encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Leb128Error::Truncated`] if the bytes end before the encoding does, and
+    /// [`Leb128Error::Overflow`] if the value doesn't fit in the type or the encoding is longer
+    /// than [`MAX_LEB128_LEN`](Uleb128::MAX_LEB128_LEN).
+    fn decode_uleb128(bytes: &[u8]) -> Result<(Self, usize), Leb128Error>;
+}
+
+/// Encodes and decodes signed values as signed LEB128.
+///
+/// Signed LEB128 is the signed counterpart of [`Uleb128`]: the value is stored in two's
+/// complement, seven bits at a time, and the last group is sign-extended. Values from -64 to 63
+/// take one byte.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Sleb128;
+///
+/// let mut buf = [0; i32::MAX_LEB128_LEN];
+/// let len = (-123_456_i32).encode_sleb128(&mut buf);
+/// assert_eq!(&buf[..len], &[0xc0, 0xbb, 0x78]);
+/// assert_eq!(i32::decode_sleb128(&buf), Ok((-123_456, 3)));
+/// ```
+pub trait Sleb128: Sized {
+    /// The length of the longest encoding of a value of the type.
+    const MAX_LEB128_LEN: usize;
+
+    /// Writes the encoding of a value to the start of a buffer, and returns its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoding is longer than the buffer. A buffer of
+    /// [`MAX_LEB128_LEN`](Sleb128::MAX_LEB128_LEN) bytes is always long enough.
+    fn encode_sleb128(&self, buf: &mut [u8]) -> usize;
+
+    /// Reads a value from the encoding at the start of some bytes, and returns it with the length
+    /// of the encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Leb128Error::Truncated`] if the bytes end before the encoding does, and
+    /// [`Leb128Error::Overflow`] if the value doesn't fit in the type or the encoding is longer
+    /// than [`MAX_LEB128_LEN`](Sleb128::MAX_LEB128_LEN).
+    fn decode_sleb128(bytes: &[u8]) -> Result<(Self, usize), Leb128Error>;
+}
+
+macro_rules! uleb128_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Uleb128 for $type {
+                const MAX_LEB128_LEN: usize = ($type::BITS as usize).div_ceil(7);
+
+                fn encode_uleb128(&self, buf: &mut [u8]) -> usize {
+                    let mut value = *self;
+                    let mut len = 0;
+                    loop {
+                        let group = value as u8 & 0x7f;
+                        value >>= 7;
+                        if value == 0 {
+                            buf[len] = group;
+                            return len + 1;
+                        }
+                        buf[len] = group | 0x80;
+                        len += 1;
+                    }
+                }
+
+                fn decode_uleb128(bytes: &[u8]) -> Result<(Self, usize), Leb128Error> {
+                    let mut value: Self = 0;
+                    for (index, &byte) in bytes.iter().take(Self::MAX_LEB128_LEN).enumerate() {
+                        let group = (byte & 0x7f) as Self;
+                        let shift = index * 7;
+                        if group << shift >> shift != group {
+                            return Err(Leb128Error::Overflow);
+                        }
+
+                        value |= group << shift;
+                        if byte & 0x80 == 0 {
+                            return Ok((value, index + 1));
+                        }
+                    }
+
+                    if bytes.len() >= Self::MAX_LEB128_LEN {
+                        Err(Leb128Error::Overflow)
+                    } else {
+                        Err(Leb128Error::Truncated)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+uleb128_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! sleb128_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Sleb128 for $type {
+                const MAX_LEB128_LEN: usize = ($type::BITS as usize).div_ceil(7);
+
+                fn encode_sleb128(&self, buf: &mut [u8]) -> usize {
+                    let mut value = *self;
+                    let mut len = 0;
+                    loop {
+                        let group = value as u8 & 0x7f;
+                        value >>= 7;
+                        // The rest of the value is the sign extension of this group
+                        if value == -((group >> 6) as $type) {
+                            buf[len] = group;
+                            return len + 1;
+                        }
+                        buf[len] = group | 0x80;
+                        len += 1;
+                    }
+                }
+
+                fn decode_sleb128(bytes: &[u8]) -> Result<(Self, usize), Leb128Error> {
+                    let mut value: Self = 0;
+                    for (index, &byte) in bytes.iter().take(Self::MAX_LEB128_LEN).enumerate() {
+                        // The group sign-extended from seven bits
+                        let group = ((byte << 1) as i8 >> 1) as Self;
+                        let shift = index * 7;
+
+                        if byte & 0x80 == 0 {
+                            if group << shift >> shift != group {
+                                return Err(Leb128Error::Overflow);
+                            }
+                            return Ok((value | group << shift, index + 1));
+                        }
+                        value |= (group & 0x7f) << shift;
+                    }
+
+                    if bytes.len() >= Self::MAX_LEB128_LEN {
+                        Err(Leb128Error::Overflow)
+                    } else {
+                        Err(Leb128Error::Truncated)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+sleb128_impl!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    fn uleb128<T: Uleb128>(value: T) -> Vec<u8> {
+        let mut buf = [0; 19];
+        let len = value.encode_uleb128(&mut buf);
+        buf[..len].to_vec()
+    }
+
+    fn sleb128<T: Sleb128>(value: T) -> Vec<u8> {
+        let mut buf = [0; 19];
+        let len = value.encode_sleb128(&mut buf);
+        buf[..len].to_vec()
+    }
+
+    #[test]
+    fn unsigned_boundaries() {
+        asserting!("values take a byte for every seven bits")
+            .that(&(
+                uleb128(0_u8),
+                uleb128(127_u8),
+                uleb128(128_u8),
+                uleb128(u8::MAX),
+                uleb128(16_383_u16),
+                uleb128(16_384_u16),
+                uleb128(u32::MAX),
+            ))
+            .is_equal_to((
+                vec![0x00],
+                vec![0x7f],
+                vec![0x80, 0x01],
+                vec![0xff, 0x01],
+                vec![0xff, 0x7f],
+                vec![0x80, 0x80, 0x01],
+                vec![0xff, 0xff, 0xff, 0xff, 0x0f],
+            ));
+
+        let mut max = vec![0xff; 18];
+        max.push(0x03);
+        asserting!("the largest values take the longest encodings")
+            .that(&(
+                uleb128(u64::MAX).len(),
+                uleb128(u128::MAX),
+                (
+                    u8::MAX_LEB128_LEN,
+                    u64::MAX_LEB128_LEN,
+                    u128::MAX_LEB128_LEN,
+                ),
+            ))
+            .is_equal_to((10, max, (2, 10, 19)));
+
+        asserting!("decoding stops at the last byte")
+            .that(&(
+                u8::decode_uleb128(&[0x7f, 0xff]),
+                u8::decode_uleb128(&[0xff, 0x01]),
+                u16::decode_uleb128(&[0x80, 0x80, 0x01, 0x00]),
+                u64::decode_uleb128(&[0x80, 0x00]),
+                u32::decode_uleb128(&[0xff, 0xff, 0xff, 0xff, 0x0f]),
+            ))
+            .is_equal_to((
+                Ok((127, 1)),
+                Ok((u8::MAX, 2)),
+                Ok((16_384, 3)),
+                Ok((0, 2)),
+                Ok((u32::MAX, 5)),
+            ));
+    }
+
+    #[test]
+    fn signed_boundaries() {
+        asserting!("values take a byte for every seven bits, counting the sign")
+            .that(&(
+                sleb128(0_i8),
+                sleb128(-1_i8),
+                sleb128(63_i8),
+                sleb128(64_i8),
+                sleb128(-64_i8),
+                sleb128(-65_i8),
+                sleb128(i8::MIN),
+                sleb128(i8::MAX),
+                sleb128(i32::MIN),
+            ))
+            .is_equal_to((
+                vec![0x00],
+                vec![0x7f],
+                vec![0x3f],
+                vec![0xc0, 0x00],
+                vec![0x40],
+                vec![0xbf, 0x7f],
+                vec![0x80, 0x7f],
+                vec![0xff, 0x00],
+                vec![0x80, 0x80, 0x80, 0x80, 0x78],
+            ));
+
+        asserting!("the largest values take the longest encodings")
+            .that(&(
+                sleb128(i64::MIN).len(),
+                sleb128(i128::MAX).len(),
+                sleb128(i128::MIN).len(),
+                (
+                    i8::MAX_LEB128_LEN,
+                    i64::MAX_LEB128_LEN,
+                    i128::MAX_LEB128_LEN,
+                ),
+            ))
+            .is_equal_to((10, 19, 19, (2, 10, 19)));
+
+        asserting!("decoding sign-extends the last group")
+            .that(&(
+                i8::decode_sleb128(&[0x7f]),
+                i8::decode_sleb128(&[0x80, 0x7f]),
+                i8::decode_sleb128(&[0xff, 0x00]),
+                i16::decode_sleb128(&[0xff, 0x7f]),
+                i64::decode_sleb128(&[0xc0, 0xbb, 0x78, 0x01]),
+                i32::decode_sleb128(&[0x80, 0x80, 0x80, 0x80, 0x78]),
+            ))
+            .is_equal_to((
+                Ok((-1, 1)),
+                Ok((i8::MIN, 2)),
+                Ok((i8::MAX, 2)),
+                Ok((-1, 2)),
+                Ok((-123_456, 3)),
+                Ok((i32::MIN, 5)),
+            ));
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        asserting!("encodings that end early are truncated")
+            .that(&(
+                u32::decode_uleb128(&[]),
+                u32::decode_uleb128(&[0x80]),
+                u16::decode_uleb128(&[0xff, 0xff]),
+                i64::decode_sleb128(&[0xff; 9]),
+                i8::decode_sleb128(&[]),
+            ))
+            .is_equal_to((
+                Err(Leb128Error::Truncated),
+                Err(Leb128Error::Truncated),
+                Err(Leb128Error::Truncated),
+                Err(Leb128Error::Truncated),
+                Err(Leb128Error::Truncated),
+            ));
+
+        asserting!("values past the top of the type overflow")
+            .that(&(
+                u8::decode_uleb128(&[0xff, 0x02]),
+                u32::decode_uleb128(&[0xff, 0xff, 0xff, 0xff, 0x1f]),
+                i8::decode_sleb128(&[0x80, 0x7e]),
+                i8::decode_sleb128(&[0xff, 0x01]),
+                i32::decode_sleb128(&[0x80, 0x80, 0x80, 0x80, 0x70]),
+            ))
+            .is_equal_to((
+                Err(Leb128Error::Overflow),
+                Err(Leb128Error::Overflow),
+                Err(Leb128Error::Overflow),
+                Err(Leb128Error::Overflow),
+                Err(Leb128Error::Overflow),
+            ));
+
+        asserting!("padding is accepted up to the longest encoding")
+            .that(&(
+                u8::decode_uleb128(&[0x80, 0x00]),
+                u8::decode_uleb128(&[0x80, 0x80, 0x00]),
+                u32::decode_uleb128(&[0x81, 0x80, 0x80, 0x80, 0x00]),
+                u32::decode_uleb128(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x00]),
+                i16::decode_sleb128(&[0xff, 0xff, 0x7f]),
+                i16::decode_sleb128(&[0xff, 0xff, 0xff, 0x7f]),
+            ))
+            .is_equal_to((
+                Ok((0, 2)),
+                Err(Leb128Error::Overflow),
+                Ok((1, 5)),
+                Err(Leb128Error::Overflow),
+                Ok((-1, 3)),
+                Err(Leb128Error::Overflow),
+            ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn encoding_into_a_short_buffer() {
+        u32::MAX.encode_uleb128(&mut [0; 4]);
+    }
+
+    #[test]
+    fn round_trips() {
+        for seed in random_u64s().take(1000) {
+            // Shift the values down so that every length of encoding comes up
+            let value = seed >> (seed % 64);
+            let wide = (value as u128) << (seed % 65);
+
+            let encoded = (
+                uleb128(value),
+                uleb128(wide),
+                sleb128(value as i64),
+                sleb128(wide as i128),
+            );
+            asserting!("values come back from their encodings")
+                .that(&(
+                    u64::decode_uleb128(&encoded.0),
+                    u128::decode_uleb128(&encoded.1),
+                    i64::decode_sleb128(&encoded.2),
+                    i128::decode_sleb128(&encoded.3),
+                    u16::decode_uleb128(&uleb128(value as u16)),
+                    i16::decode_sleb128(&sleb128(value as i16)),
+                    usize::decode_uleb128(&uleb128(value as usize)),
+                    isize::decode_sleb128(&sleb128(value as isize)),
+                ))
+                .is_equal_to((
+                    Ok((value, encoded.0.len())),
+                    Ok((wide, encoded.1.len())),
+                    Ok((value as i64, encoded.2.len())),
+                    Ok((wide as i128, encoded.3.len())),
+                    Ok((value as u16, uleb128(value as u16).len())),
+                    Ok((value as i16, sleb128(value as i16).len())),
+                    Ok((value as usize, uleb128(value as usize).len())),
+                    Ok((value as isize, sleb128(value as isize).len())),
+                ));
+
+            let bits = 64 - value.leading_zeros() as usize;
+            let signed_bits = 65 - (value as i64 ^ (value as i64) >> 63).leading_zeros() as usize;
+            asserting!("encodings are as short as they can be")
+                .that(&(encoded.0.len(), encoded.2.len()))
+                .is_equal_to((bits.max(1).div_ceil(7), signed_bits.div_ceil(7)));
+        }
+    }
+}
@@ -31,26 +31,212 @@
 //! # Sign Extension
 //!
 //! The [`Signs`](trait.Signs.html) trait adds methods for checking the sign bit on unsigned
-//! primitives (and signed ones) and for sign-extending values an arbitrary number of bits:
+//! primitives (and signed ones) and for sign-extending values. A value can be sign-extended either
+//! from the width of the field it holds or by the number of upper bits to fill:
 //!
 //! ```
 //! # use quark::Signs;
 //! # let unsigned = 0x00ff_ffffu32;
+//! let signed = unsigned.sign_extend_from(24);
 //! let signed = unsigned.sign_extend(8);
 //! # assert_eq!(signed, 0xffff_ffff);
+//! # assert_eq!(unsigned.sign_extend_from(24), 0xffff_ffff);
 //! ```
 //!
+//! Extending from the field width reads the same regardless of the size of the type, which makes
+//! it the better fit for code that's generic over the primitive.
+//!
+//! # Joining and Splitting
+//!
+//! The [`BitConcat`](trait.BitConcat.html) and [`HalfBits`](trait.HalfBits.html) traits join two
+//! values into one twice as wide and split them back into typed halves, like the register pairs of
+//! an 8-bit CPU, and [`ConcatParts`](trait.ConcatParts.html) joins any number of fields, most
+//! significant first:
+//!
+//! ```
+//! # use quark::{BitConcat, ConcatParts, HalfBits};
+//! let bc = u8::concat(0x12, 0x34);
+//! assert_eq!((bc.high(), bc.low()), (0x12, 0x34));
+//! assert_eq!(u32::concat_fields([(0x1f, 5), (0x00, 6), (0x10, 5)]), 0xf810);
+//! ```
+//!
+//! # Narrow Arithmetic
+//!
+//! The [`WidthArith`](trait.WidthArith.html) trait performs arithmetic that wraps at an arbitrary
+//! bit width, for emulating registers that don't match the size of a primitive:
+//!
+//! ```
+//! # use quark::WidthArith;
+//! assert_eq!(0x80_u32.negate_in(8), 0x80);
+//! ```
+//!
+//! # Narrow Integers
+//!
+//! The [`UInt`](struct.UInt.html) and [`Int`](struct.Int.html) types hold unsigned and signed
+//! numbers with any width from 1 to 128 bits, for values that don't fit the primitives exactly:
+//!
+//! ```
+//! # use quark::{Int, UInt};
+//! let register = UInt::<12>::new(0xfff);
+//! assert!(UInt::<12>::try_new(0x1000).is_err());
+//! assert_eq!(Int::<5>::masked(0b11111).get(), -1);
+//! ```
+//!
+//! A [`PackedVec`](struct.PackedVec.html) stores a list of them back to back with no padding, so a
+//! million 12-bit values take 1.5 MB, and a [`PackedArray`](struct.PackedArray.html) does the same
+//! for a fixed number of them that can be built in a `const` or `static`.
+//!
+//! # Wide Integers
+//!
+//! The [`u256`](struct.u256.html) and [`i256`](struct.i256.html) types hold 256-bit numbers, like
+//! hashes and virtual machine words, and support the same bit operations as the primitives:
+//!
+//! ```
+//! # use quark::{i256, u256, BitIndex, SignCast, Signs};
+//! let word = u256::from(0x80_u8) << 120_u32;
+//! assert_eq!(word.bits(124..132), u256::from(0x08_u8));
+//! assert_eq!(word.cast_signed().sign_extend_from(128), i256::from(i128::MIN));
+//! ```
+//!
+//! # Fixed-Point Numbers
+//!
+//! The [`Fixed`](struct.Fixed.html) type holds signed fixed-point numbers in Q notation, with the
+//! number of integer and fractional bits as parameters:
+//!
+//! ```
+//! # use quark::Fixed;
+//! let gain = Fixed::<1, 15>::saturating_from_f64(0.75);
+//! let sample = Fixed::<1, 15>::saturating_from_f64(-0.5);
+//! assert_eq!((gain * sample).to_f64(), -0.375);
+//! ```
+//!
+//! # Optional Features
+//!
+//! - `arbitrary`: generates the integer types and [`Field`](struct.Field.html) from fuzzer input,
+//!   always in range and from as few bytes as possible.
+//! - `bytemuck`: implements `Pod` for [`u24`](struct.u24.html), [`i24`](struct.i24.html), and
+//!   [`u48`](struct.u48.html), so byte buffers can be cast to slices of them without copying.
+//! - `defmt`: logs the narrow integer types and [`Field`](struct.Field.html) with `defmt`, as the
+//!   width and the value, like `12'0xab`.
+//! - `proptest`: adds the [`proptest`](proptest/index.html) module of strategies for the narrow
+//!   integer types, bit ranges, and fields.
+//! - `quickcheck`: generates the narrow integer types and [`Field`](struct.Field.html) for
+//!   `quickcheck`, shrinking towards zero without leaving the width.
+//! - `rand`: samples the narrow integer types with `rng.gen()` and `rng.gen_range()`, uniformly
+//!   over the whole type or the range.
+//! - `serde`: serializes the narrow integer types as plain numbers, and rejects numbers that don't
+//!   fit in the width when deserializing.
+//! - `zerocopy`: derives the zerocopy traits for [`u24`](struct.u24.html),
+//!   [`i24`](struct.i24.html), and [`u48`](struct.u48.html), and adds the `BigEndian` wrapper for
+//!   the big-endian fields of wire formats.
+//!
 //! # Why `quark`?
 //!
 //! Because types like `i32`, `u8`, and `usize` are like atoms of data. The `quark` crate goes one
 //! level down, and quarks are one level down from atoms.
 
+pub mod aliases;
+pub mod consts;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod bcd;
 mod bit_index;
 mod bit_mask;
 mod bit_size;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod bytes;
+mod carrying;
+mod compare;
+mod concat;
+mod convert;
+mod count;
+mod define;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod error;
+mod field;
+mod fixed;
+mod format;
+mod gray;
+mod hamming;
+mod int;
+mod int24;
+mod int256;
+mod leb128;
+mod matrix;
+mod midpoint;
+mod non_zero;
+mod non_zero_narrow;
+mod packed;
+mod parity;
+mod parse;
+mod permute;
+mod pow;
+mod quark;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod signs;
+mod sum;
+#[cfg(test)]
+mod test_util;
+mod uint;
+mod uint1;
+mod uint24;
+mod uint256;
+mod uint48;
+mod values;
+mod vlq;
+mod wide;
+mod widening;
+mod width_arith;
+mod wrappers;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_impl;
+mod zigzag;
 
+pub use self::aliases::*;
+pub use self::bcd::*;
 pub use self::bit_index::*;
 pub use self::bit_mask::*;
 pub use self::bit_size::*;
+pub use self::carrying::*;
+pub use self::concat::*;
+pub use self::error::*;
+pub use self::field::*;
+pub use self::fixed::*;
+pub use self::gray::*;
+pub use self::hamming::*;
+pub use self::int::*;
+pub use self::int24::*;
+pub use self::int256::*;
+pub use self::leb128::*;
+pub use self::matrix::*;
+pub use self::non_zero::*;
+pub use self::non_zero_narrow::*;
+pub use self::packed::*;
+pub use self::parity::*;
+pub use self::permute::*;
+pub use self::quark::*;
+#[cfg(feature = "rand")]
+pub use self::rand_impl::*;
 pub use self::signs::*;
+pub use self::uint::*;
+pub use self::uint1::*;
+pub use self::uint24::*;
+pub use self::uint256::*;
+pub use self::uint48::*;
+pub use self::values::*;
+pub use self::vlq::*;
+pub use self::widening::*;
+pub use self::width_arith::*;
+#[cfg(feature = "zerocopy")]
+pub use self::zerocopy_impl::*;
+pub use self::zigzag::*;
@@ -0,0 +1,111 @@
+/// Treats a value as a square matrix of bits.
+///
+/// A `u64` holds an 8×8 matrix in row-major order: byte `r` is row `r`, and bit `c` of the byte
+/// is column `c`, so the element at row `r` and column `c` is bit `8 * r + c`. This is the layout
+/// of chess bitboards with square 0 in a corner, and of an 8×8 glyph with one byte per line.
+///
+/// # Examples
+///
+/// ```
+/// use quark::BitMatrix;
+///
+/// // The top row set becomes the first column set
+/// assert_eq!(0xff_u64.transpose_8x8(), 0x0101_0101_0101_0101);
+/// ```
+pub trait BitMatrix {
+    /// Transposes an 8×8 bit matrix, swapping its rows and columns.
+    ///
+    /// The element at row `r` and column `c` moves to row `c` and column `r`, which mirrors the
+    /// matrix across the diagonal from bit 0 to bit 63. It's the step that turns bytes into bit
+    /// slices for bit-sliced ciphers, and rotates a bitboard or glyph when combined with
+    /// `swap_bytes` or [`reverse_bit_order_in_bytes`](crate::BitPermute::reverse_bit_order_in_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitMatrix;
+    ///
+    /// // Row 1, column 3 moves to row 3, column 1
+    /// assert_eq!((1_u64 << (8 + 3)).transpose_8x8(), 1 << (3 * 8 + 1));
+    /// assert_eq!(0x8040_2010_0804_0201_u64.transpose_8x8(), 0x8040_2010_0804_0201);
+    /// ```
+    fn transpose_8x8(&self) -> Self;
+}
+
+impl BitMatrix for u64 {
+    fn transpose_8x8(&self) -> u64 {
+        // Each step swaps the off-diagonal blocks of the 2×2, 4×4, and then 8×8 blocks along the
+        // diagonal. An element moving from (r, c) to (r + d, c - d) moves up by 7 × d bits, and
+        // the masks pick out the elements above the diagonal of each block that move down-left.
+        let mut value = *self;
+        for &(shift, mask) in &[
+            (7, 0x00aa_00aa_00aa_00aa_u64),
+            (14, 0x0000_cccc_0000_cccc),
+            (28, 0x0000_0000_f0f0_f0f0),
+        ] {
+            let swap = (value ^ value >> shift) & mask;
+            value ^= swap ^ swap << shift;
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    /// Transposes an 8×8 bit matrix one element at a time.
+    fn transpose(value: u64) -> u64 {
+        let mut transposed = 0;
+        for row in 0..8 {
+            for column in 0..8 {
+                transposed |= (value >> (8 * row + column) & 1) << (8 * column + row);
+            }
+        }
+        transposed
+    }
+
+    #[test]
+    fn single_elements() {
+        for row in 0..8 {
+            for column in 0..8 {
+                asserting!("an element moves to the mirrored coordinate")
+                    .that(&(1_u64 << (8 * row + column)).transpose_8x8())
+                    .is_equal_to(1 << (8 * column + row));
+            }
+        }
+
+        asserting!("whole rows become columns, and the diagonals stay put")
+            .that(&(
+                0xff00_u64.transpose_8x8(),
+                0x8080_8080_8080_8080_u64.transpose_8x8(),
+                0x8040_2010_0804_0201_u64.transpose_8x8(),
+                0x0102_0408_1020_4080_u64.transpose_8x8(),
+                u64::MAX.transpose_8x8(),
+                0_u64.transpose_8x8(),
+            ))
+            .is_equal_to((
+                0x0202_0202_0202_0202,
+                0xff00_0000_0000_0000,
+                0x8040_2010_0804_0201,
+                0x0102_0408_1020_4080,
+                u64::MAX,
+                0,
+            ));
+    }
+
+    #[test]
+    fn random_matrices() {
+        for seed in random_u64s().take(1000) {
+            asserting!("matrices transpose like they do one element at a time")
+                .that(&seed.transpose_8x8())
+                .is_equal_to(transpose(seed));
+
+            asserting!("transposing twice gives the matrix back")
+                .that(&seed.transpose_8x8().transpose_8x8())
+                .is_equal_to(seed);
+        }
+    }
+}
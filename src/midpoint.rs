@@ -0,0 +1,151 @@
+use crate::{Int, UInt};
+
+// Adding the values first can overflow the width, or even the 128 bits they're held in, so the
+// midpoint is the bits the values share plus half the bits they don't, which always fits. The
+// difference between two values of a width always fits in an unsigned value of the same width, so
+// `abs_diff` can't overflow either.
+
+impl<const BITS: usize> UInt<BITS> {
+    /// Returns the average of two values, rounded down, without overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<4>::new(3).midpoint(UInt::new(8)), UInt::new(5));
+    /// assert_eq!(UInt::<4>::MAX.midpoint(UInt::new(14)), UInt::new(14));
+    /// ```
+    pub const fn midpoint(self, other: Self) -> Self {
+        let (a, b) = (self.get(), other.get());
+        Self::masked((a & b) + ((a ^ b) >> 1))
+    }
+
+    /// Returns the distance between two values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<4>::new(3).abs_diff(UInt::new(8)), UInt::new(5));
+    /// assert_eq!(UInt::<4>::MIN.abs_diff(UInt::MAX), UInt::MAX);
+    /// ```
+    pub const fn abs_diff(self, other: Self) -> Self {
+        Self::masked(self.get().abs_diff(other.get()))
+    }
+}
+
+impl<const BITS: usize> Int<BITS> {
+    /// Returns the average of two values, rounded towards zero, without overflowing.
+    ///
+    /// This rounds the same way as `midpoint` on the signed primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<4>::new(-3).midpoint(Int::new(6)), Int::new(1));
+    /// assert_eq!(Int::<4>::new(-3).midpoint(Int::new(0)), Int::new(-1));
+    /// assert_eq!(Int::<4>::MIN.midpoint(Int::MAX), Int::new(0));
+    /// ```
+    pub const fn midpoint(self, other: Self) -> Self {
+        let (a, b) = (self.get(), other.get());
+        let floor = (a & b) + ((a ^ b) >> 1);
+
+        if floor < 0 && (a ^ b) & 1 == 1 {
+            Self::masked(floor + 1)
+        } else {
+            Self::masked(floor)
+        }
+    }
+
+    /// Returns the distance between two values as an unsigned value of the same width, which can
+    /// hold the distance between any two values of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::{Int, UInt};
+    ///
+    /// assert_eq!(Int::<4>::new(-3).abs_diff(Int::new(6)), UInt::new(9));
+    /// assert_eq!(Int::<4>::MIN.abs_diff(Int::MAX), UInt::MAX);
+    /// ```
+    pub const fn abs_diff(self, other: Self) -> UInt<BITS> {
+        UInt::masked(self.get().abs_diff(other.get()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn extremes() {
+        asserting!("midpoints of the extremes don't overflow")
+            .that(&(
+                UInt::<12>::MAX.midpoint(UInt::MAX),
+                UInt::<12>::MAX.midpoint(UInt::new(0xffe)),
+                UInt::<128>::MAX.midpoint(UInt::new(u128::MAX - 2)),
+                Int::<12>::MAX.midpoint(Int::MAX),
+                Int::<12>::MIN.midpoint(Int::MIN),
+                Int::<128>::MIN.midpoint(Int::MAX),
+                Int::<128>::MIN.midpoint(Int::new(i128::MIN + 2)),
+            ))
+            .is_equal_to((
+                UInt::MAX,
+                UInt::new(0xffe),
+                UInt::new(u128::MAX - 1),
+                Int::MAX,
+                Int::MIN,
+                Int::new(0),
+                Int::new(i128::MIN + 1),
+            ));
+
+        asserting!("distances across the whole range don't overflow")
+            .that(&(
+                UInt::<12>::MIN.abs_diff(UInt::MAX),
+                UInt::<128>::MAX.abs_diff(UInt::MIN),
+                Int::<12>::MIN.abs_diff(Int::MAX),
+                Int::<12>::MAX.abs_diff(Int::MIN),
+                Int::<128>::MIN.abs_diff(Int::MAX),
+                Int::<1>::MIN.abs_diff(Int::MAX),
+            ))
+            .is_equal_to((
+                UInt::MAX,
+                UInt::MAX,
+                UInt::MAX,
+                UInt::MAX,
+                UInt::MAX,
+                UInt::MAX,
+            ));
+    }
+
+    #[test]
+    fn matching_the_primitives() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (Int::<8>::from(a), Int::<8>::from(b));
+                let (p, q) = (UInt::<8>::from(a as u8), UInt::<8>::from(b as u8));
+
+                asserting!("signed results match the primitives")
+                    .that(&(x.midpoint(y).get(), x.abs_diff(y).get()))
+                    .is_equal_to((midpoint_i8(a, b) as i128, a.abs_diff(b) as u128));
+
+                asserting!("unsigned results match the primitives")
+                    .that(&(p.midpoint(q).get(), p.abs_diff(q).get()))
+                    .is_equal_to((
+                        ((a as u8 as u16 + b as u8 as u16) / 2) as u128,
+                        (a as u8).abs_diff(b as u8) as u128,
+                    ));
+            }
+        }
+    }
+
+    /// The midpoint of two `i8`s, rounded towards zero, from a wider type.
+    fn midpoint_i8(a: i8, b: i8) -> i8 {
+        ((a as i16 + b as i16) / 2) as i8
+    }
+}
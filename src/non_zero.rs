@@ -0,0 +1,212 @@
+use crate::signs::sealed::Sealed;
+use crate::{BitIndex, BitMask, BitSize, Quark, Signs};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::ops::RangeBounds;
+
+/// Provides bit operations on the non-zero integer types.
+///
+/// The non-zero types can't implement [`BitSize`](trait.BitSize.html) and the traits built on it,
+/// because those need a value with no bits set and return values of the same type from methods
+/// that can give zero. Instead, this trait offers the read-only operations, returning the
+/// primitive type wherever the result could be zero. It's sealed and implemented for the
+/// `NonZero` type of every primitive.
+///
+/// # Examples
+///
+/// ```
+/// use quark::NonZeroBits;
+/// use std::num::NonZeroU32;
+///
+/// let value = NonZeroU32::new(0x0000_8a00).unwrap();
+/// assert!(value.bit(9));
+/// assert_eq!(value.bits(8..12), 0xa);
+/// assert_eq!(value.bits(0..8), 0);
+/// assert_eq!(NonZeroU32::BIT_SIZE, 32);
+/// ```
+pub trait NonZeroBits: Sealed + Copy {
+    /// The primitive type holding the value.
+    type Primitive: Quark;
+
+    /// The size of the type in bits.
+    const BIT_SIZE: usize = <Self::Primitive as BitSize>::BIT_SIZE;
+
+    /// Returns the value as the primitive type.
+    fn to_primitive(self) -> Self::Primitive;
+
+    /// Returns the bit at the index, like
+    /// [`BitIndex::bit`](trait.BitIndex.html#tymethod.bit).
+    fn bit(&self, index: usize) -> bool {
+        self.to_primitive().bit(index)
+    }
+
+    /// Returns the bits in the range, like
+    /// [`BitIndex::bits`](trait.BitIndex.html#tymethod.bits).
+    fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self::Primitive {
+        self.to_primitive().bits(index)
+    }
+
+    /// Keeps the requested number of low bits, like
+    /// [`BitMask::mask_to`](trait.BitMask.html#tymethod.mask_to).
+    fn mask_to(&self, size: usize) -> Self::Primitive {
+        self.to_primitive().mask_to(size)
+    }
+
+    /// Returns whether the sign bit is set, like
+    /// [`Signs::sign_bit`](trait.Signs.html#tymethod.sign_bit).
+    fn sign_bit(&self) -> bool {
+        self.to_primitive().sign_bit()
+    }
+
+    /// Returns the number of redundant sign bits, like
+    /// [`Signs::leading_sign_bits`](trait.Signs.html#method.leading_sign_bits).
+    fn leading_sign_bits(&self) -> u32 {
+        self.to_primitive().leading_sign_bits()
+    }
+
+    /// Sign-extends the value from its lowest N bits, like
+    /// [`Signs::sign_extend_from`](trait.Signs.html#method.sign_extend_from).
+    fn sign_extend_from(&self, width: usize) -> Self::Primitive {
+        self.to_primitive().sign_extend_from(width)
+    }
+
+    /// Clears the upper N bits of the value, like
+    /// [`Signs::zero_extend`](trait.Signs.html#method.zero_extend).
+    fn zero_extend(&self, bits: usize) -> Self::Primitive {
+        self.to_primitive().zero_extend(bits)
+    }
+}
+
+macro_rules! non_zero_bits_impl {
+    ($($type:ty => $primitive:ty),*) => {
+        $(
+            impl Sealed for $type {}
+
+            impl NonZeroBits for $type {
+                type Primitive = $primitive;
+
+                fn to_primitive(self) -> $primitive {
+                    self.get()
+                }
+            }
+        )*
+    };
+}
+
+non_zero_bits_impl!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BitConsts;
+    use spectral::prelude::*;
+
+    #[test]
+    fn matches_primitives() {
+        macro_rules! check {
+            ($($type:ident => $primitive:ty),*) => {
+                $(
+                    asserting!("non-zero types have the size of their primitive")
+                        .that(&<$type as NonZeroBits>::BIT_SIZE)
+                        .is_equal_to(<$primitive as BitSize>::BIT_SIZE);
+
+                    let values = [
+                        1,
+                        0x35,
+                        0x7f,
+                        <$primitive>::MAX,
+                        <$primitive>::MIN + 1,
+                        <$primitive>::ALL_SET << 3,
+                    ];
+
+                    for &value in &values {
+                        let non_zero = $type::new(value).unwrap();
+
+                        asserting!("non-zero values convert to their primitive")
+                            .that(&non_zero.to_primitive())
+                            .is_equal_to(value);
+
+                        for index in 0..<$primitive as BitSize>::BIT_SIZE {
+                            asserting!("bit matches the primitive")
+                                .that(&NonZeroBits::bit(&non_zero, index))
+                                .is_equal_to(BitIndex::bit(&value, index));
+                        }
+
+                        for &(start, end) in &[(0, 1), (0, 4), (4, 8), (8, 16), (1, 200)] {
+                            asserting!("bits matches the primitive")
+                                .that(&NonZeroBits::bits(&non_zero, start..end))
+                                .is_equal_to(BitIndex::bits(&value, start..end));
+                        }
+
+                        for width in 0..=<$primitive as BitSize>::BIT_SIZE {
+                            asserting!("mask_to matches the primitive")
+                                .that(&NonZeroBits::mask_to(&non_zero, width))
+                                .is_equal_to(BitMask::mask_to(&value, width));
+                            asserting!("sign_extend_from matches the primitive")
+                                .that(&NonZeroBits::sign_extend_from(&non_zero, width))
+                                .is_equal_to(Signs::sign_extend_from(&value, width));
+                            asserting!("zero_extend matches the primitive")
+                                .that(&NonZeroBits::zero_extend(&non_zero, width))
+                                .is_equal_to(Signs::zero_extend(&value, width));
+                        }
+
+                        asserting!("sign bits match the primitive")
+                            .that(&(
+                                NonZeroBits::sign_bit(&non_zero),
+                                NonZeroBits::leading_sign_bits(&non_zero),
+                            ))
+                            .is_equal_to((
+                                Signs::sign_bit(&value),
+                                Signs::leading_sign_bits(&value),
+                            ));
+                    }
+                )*
+            };
+        }
+
+        check!(
+            NonZeroU8 => u8,
+            NonZeroU16 => u16,
+            NonZeroU32 => u32,
+            NonZeroU64 => u64,
+            NonZeroU128 => u128,
+            NonZeroUsize => usize
+        );
+        check!(
+            NonZeroI8 => i8,
+            NonZeroI16 => i16,
+            NonZeroI32 => i32,
+            NonZeroI64 => i64,
+            NonZeroI128 => i128,
+            NonZeroIsize => isize
+        );
+    }
+
+    #[test]
+    fn zero_results_use_the_primitive() {
+        let value = NonZeroU16::new(0xff00).unwrap();
+
+        asserting!("fields that are clear are zero")
+            .that(&(value.bits(0..8), value.mask_to(8), value.zero_extend(16)))
+            .is_equal_to((0_u16, 0_u16, 0_u16));
+
+        asserting!("signed values extend into the primitive")
+            .that(&NonZeroI32::new(0xfff).unwrap().sign_extend_from(12))
+            .is_equal_to(-1_i32);
+    }
+}
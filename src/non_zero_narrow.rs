@@ -0,0 +1,570 @@
+use crate::{Int, TryFromIntError, UInt};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// A narrow unsigned integer that's known not to be zero.
+///
+/// This is the non-zero twin of [`UInt`](struct.UInt.html), like `NonZeroU32` is of `u32`. The
+/// value is held in a `NonZeroU128`, so the compiler can use zero to represent `None`, and
+/// `Option<NonZeroUInt<BITS>>` is the same size as `UInt<BITS>`. That relies on the niche of the
+/// storage type rather than on the width: the unused upper bits of a narrow value aren't a niche
+/// the compiler can see.
+///
+/// It carries the arithmetic that can't produce zero from non-zero operands, in checked and
+/// saturating forms, along with the bit counts.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{NonZeroUInt, UInt};
+/// use std::mem::size_of;
+///
+/// let divisor = NonZeroUInt::new(UInt::<12>::new(0x10)).unwrap();
+/// assert_eq!(divisor.get(), UInt::new(0x10));
+/// assert_eq!(divisor.trailing_zeros(), 4);
+/// assert_eq!(NonZeroUInt::new(UInt::<12>::ZERO), None);
+///
+/// assert_eq!(size_of::<Option<NonZeroUInt<12>>>(), size_of::<UInt<12>>());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroUInt<const BITS: usize> {
+    value: NonZeroU128,
+}
+
+/// A narrow signed integer that's known not to be zero.
+///
+/// This is the non-zero twin of [`Int`](struct.Int.html), like `NonZeroI32` is of `i32`. The value
+/// is held in a `NonZeroI128`, so `Option<NonZeroInt<BITS>>` is the same size as `Int<BITS>`.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{Int, NonZeroInt, NonZeroUInt, UInt};
+///
+/// let offset = NonZeroInt::new(Int::<12>::new(-0x10)).unwrap();
+/// assert!(offset.is_negative());
+/// assert_eq!(offset.unsigned_abs().get(), UInt::new(0x10));
+/// assert_eq!(NonZeroInt::<12>::MIN.checked_neg(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroInt<const BITS: usize> {
+    value: NonZeroI128,
+}
+
+macro_rules! non_zero_narrow_impl {
+    ($($type:ident, $narrow:ident, $storage:ident, $signedness:literal;)*) => {
+        $(
+            impl<const BITS: usize> $type<BITS> {
+                /// The number of bits in the type, as a `u32` like the primitives' `BITS`.
+                pub const BITS: u32 = BITS as u32;
+
+                /// Creates a non-zero value, returning `None` if the value is zero.
+                pub const fn new(value: $narrow<BITS>) -> Option<Self> {
+                    match $storage::new(value.get()) {
+                        Some(value) => Some($type { value }),
+                        None => None,
+                    }
+                }
+
+                /// Returns the value as the plain narrow type.
+                pub const fn get(self) -> $narrow<BITS> {
+                    $narrow::masked(self.value.get())
+                }
+
+                /// Returns the number of leading zeros within the width of the type.
+                pub const fn leading_zeros(self) -> u32 {
+                    self.get().leading_zeros()
+                }
+
+                /// Returns the number of trailing zeros, which is always less than the width of the
+                /// type.
+                pub const fn trailing_zeros(self) -> u32 {
+                    self.get().trailing_zeros()
+                }
+
+                /// Multiplies two values, returning `None` if the product doesn't fit in the width
+                /// of the type.
+                pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    match self.get().checked_mul(rhs.get()) {
+                        Some(product) => Self::new(product),
+                        None => None,
+                    }
+                }
+
+                /// Raises the value to the power of `exp`, returning `None` if the result doesn't
+                /// fit in the width of the type.
+                pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+                    match self.get().checked_pow(exp) {
+                        Some(power) => Self::new(power),
+                        None => None,
+                    }
+                }
+            }
+
+            impl<const BITS: usize> From<$type<BITS>> for $narrow<BITS> {
+                fn from(value: $type<BITS>) -> $narrow<BITS> {
+                    value.get()
+                }
+            }
+
+            impl<const BITS: usize> TryFrom<$narrow<BITS>> for $type<BITS> {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $narrow<BITS>) -> Result<$type<BITS>, TryFromIntError> {
+                    $type::new(value).ok_or_else(|| TryFromIntError::zero(BITS, $signedness))
+                }
+            }
+
+            impl<const BITS: usize> Display for $type<BITS> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    Display::fmt(&self.get(), f)
+                }
+            }
+
+            impl<const BITS: usize> Debug for $type<BITS> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    f.debug_tuple(&format!(concat!(stringify!($type), "<{}>"), BITS))
+                        .field(&self.get().get())
+                        .finish()
+                }
+            }
+        )*
+    };
+}
+
+non_zero_narrow_impl! {
+    NonZeroUInt, UInt, NonZeroU128, false;
+    NonZeroInt, Int, NonZeroI128, true;
+}
+
+impl<const BITS: usize> NonZeroUInt<BITS> {
+    /// The smallest value of the type, 1.
+    pub const MIN: Self = match Self::new(UInt::ONE) {
+        Some(value) => value,
+        None => unreachable!(),
+    };
+
+    /// The largest value of the type, 2<sup>BITS</sup> - 1.
+    pub const MAX: Self = match Self::new(UInt::MAX) {
+        Some(value) => value,
+        None => unreachable!(),
+    };
+
+    /// Returns `true` if the value is a power of two.
+    pub const fn is_power_of_two(self) -> bool {
+        self.value.get().is_power_of_two()
+    }
+
+    /// Returns the base 2 logarithm of the value, rounded down. This can't fail, since the value
+    /// isn't zero.
+    pub const fn ilog2(self) -> u32 {
+        127 - self.value.get().leading_zeros()
+    }
+
+    /// Adds a value that may be zero, returning `None` if the sum doesn't fit in the width of the
+    /// type.
+    pub const fn checked_add(self, rhs: UInt<BITS>) -> Option<Self> {
+        match self.get().checked_add(rhs) {
+            Some(sum) => Self::new(sum),
+            None => None,
+        }
+    }
+
+    /// Adds a value that may be zero, saturating at [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_add(self, rhs: UInt<BITS>) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => Self::MAX,
+        }
+    }
+
+    /// Multiplies two values, saturating at [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => Self::MAX,
+        }
+    }
+
+    /// Raises the value to the power of `exp`, saturating at [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(power) => power,
+            None => Self::MAX,
+        }
+    }
+}
+
+impl<const BITS: usize> NonZeroInt<BITS> {
+    /// The smallest value of the type, -2<sup>BITS - 1</sup>.
+    pub const MIN: Self = match Self::new(Int::MIN) {
+        Some(value) => value,
+        None => unreachable!(),
+    };
+
+    /// The largest value of the type, 2<sup>BITS - 1</sup> - 1. For a 1-bit type, which can only
+    /// hold 0 and -1, this is -1.
+    pub const MAX: Self = match Self::new(Int::MAX) {
+        Some(value) => value,
+        None => Self::MIN,
+    };
+
+    /// Returns `true` if the value is negative.
+    pub const fn is_negative(self) -> bool {
+        self.value.get() < 0
+    }
+
+    /// Returns `true` if the value is positive.
+    pub const fn is_positive(self) -> bool {
+        self.value.get() > 0
+    }
+
+    /// Returns the absolute value, or `None` if it doesn't fit in the width of the type.
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.get().checked_abs() {
+            Some(abs) => Self::new(abs),
+            None => None,
+        }
+    }
+
+    /// Returns the absolute value, saturating at [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns the absolute value, wrapping [`MIN`](#associatedconstant.MIN) around to itself.
+    pub const fn wrapping_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => self,
+        }
+    }
+
+    /// Returns the absolute value as an unsigned value of the same width, which can hold the
+    /// absolute value of every value of the type.
+    pub const fn unsigned_abs(self) -> NonZeroUInt<BITS> {
+        match NonZeroUInt::new(self.get().unsigned_abs()) {
+            Some(abs) => abs,
+            None => unreachable!(),
+        }
+    }
+
+    /// Negates the value, returning `None` if the result doesn't fit in the width of the type.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.get().checked_neg() {
+            Some(negated) => Self::new(negated),
+            None => None,
+        }
+    }
+
+    /// Negates the value, saturating at [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(negated) => negated,
+            None => Self::MAX,
+        }
+    }
+
+    /// Negates the value, wrapping [`MIN`](#associatedconstant.MIN) around to itself.
+    pub const fn wrapping_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(negated) => negated,
+            None => self,
+        }
+    }
+
+    /// Multiplies two values, saturating at [`MIN`](#associatedconstant.MIN) or
+    /// [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Raises the value to the power of `exp`, saturating at [`MIN`](#associatedconstant.MIN) or
+    /// [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(power) => power,
+            None if self.is_negative() && exp % 2 == 1 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+}
+
+// The conversions with the standard non-zero types are `From` wherever the plain narrow type
+// converts with the primitive using `From`, and go through the primitive otherwise.
+
+macro_rules! non_zero_std_impl {
+    ($($std:ident => $primitive:ty),*) => {
+        $(
+            impl<const BITS: usize> From<$std> for NonZeroUInt<BITS>
+            where
+                UInt<BITS>: From<$primitive>,
+            {
+                fn from(value: $std) -> NonZeroUInt<BITS> {
+                    NonZeroUInt::new(UInt::from(value.get())).expect("non-zero values stay non-zero")
+                }
+            }
+
+            impl<const BITS: usize> From<NonZeroUInt<BITS>> for $std
+            where
+                $primitive: From<UInt<BITS>>,
+            {
+                fn from(value: NonZeroUInt<BITS>) -> $std {
+                    $std::new(<$primitive>::from(value.get())).expect("non-zero values stay non-zero")
+                }
+            }
+
+            impl<const BITS: usize> From<$std> for NonZeroInt<BITS>
+            where
+                Int<BITS>: From<$primitive>,
+            {
+                fn from(value: $std) -> NonZeroInt<BITS> {
+                    NonZeroInt::new(Int::from(value.get())).expect("non-zero values stay non-zero")
+                }
+            }
+
+            impl<const BITS: usize> From<NonZeroInt<BITS>> for $std
+            where
+                $primitive: From<Int<BITS>>,
+            {
+                fn from(value: NonZeroInt<BITS>) -> $std {
+                    $std::new(<$primitive>::from(value.get())).expect("non-zero values stay non-zero")
+                }
+            }
+        )*
+    };
+}
+
+non_zero_std_impl!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+    use std::mem::size_of;
+
+    const _: () = assert!(size_of::<Option<NonZeroUInt<1>>>() == size_of::<UInt<1>>());
+    const _: () = assert!(size_of::<Option<NonZeroUInt<12>>>() == size_of::<UInt<12>>());
+    const _: () = assert!(size_of::<Option<NonZeroUInt<128>>>() == size_of::<UInt<128>>());
+    const _: () = assert!(size_of::<Option<NonZeroInt<1>>>() == size_of::<Int<1>>());
+    const _: () = assert!(size_of::<Option<NonZeroInt<12>>>() == size_of::<Int<12>>());
+    const _: () = assert!(size_of::<Option<NonZeroInt<128>>>() == size_of::<Int<128>>());
+
+    #[test]
+    fn rejecting_zero() {
+        asserting!("zero has no non-zero value")
+            .that(&(
+                NonZeroUInt::new(UInt::<12>::ZERO),
+                NonZeroInt::new(Int::<12>::ZERO),
+                NonZeroUInt::try_from(UInt::<12>::ZERO).map_err(|error| error.to_string()),
+                NonZeroInt::try_from(Int::<5>::ZERO).map_err(|error| error.to_string()),
+            ))
+            .is_equal_to((
+                None,
+                None,
+                Err("0 doesn't fit in 12 bits as a non-zero unsigned integer".to_string()),
+                Err("0 doesn't fit in 5 bits as a non-zero signed integer".to_string()),
+            ));
+
+        asserting!("every other value is kept")
+            .that(&(
+                NonZeroUInt::new(UInt::<12>::MAX).map(NonZeroUInt::get),
+                NonZeroInt::new(Int::<12>::new(-1)).map(NonZeroInt::get),
+                NonZeroUInt::try_from(UInt::<12>::ONE).map(UInt::from),
+                NonZeroInt::try_from(Int::<12>::MIN).map(Int::from),
+            ))
+            .is_equal_to((
+                Some(UInt::MAX),
+                Some(Int::new(-1)),
+                Ok(UInt::ONE),
+                Ok(Int::MIN),
+            ));
+
+        asserting!("options of the non-zero types use zero for None")
+            .that(&(
+                size_of::<Option<NonZeroUInt<12>>>(),
+                size_of::<Option<NonZeroInt<12>>>(),
+            ))
+            .is_equal_to((size_of::<UInt<12>>(), size_of::<Int<12>>()));
+    }
+
+    #[test]
+    fn constants() {
+        asserting!("the extremes match the plain types, skipping zero")
+            .that(&(
+                NonZeroUInt::<12>::MIN.get(),
+                NonZeroUInt::<12>::MAX.get(),
+                NonZeroInt::<12>::MIN.get(),
+                NonZeroInt::<12>::MAX.get(),
+                NonZeroInt::<1>::MIN.get(),
+                NonZeroInt::<1>::MAX.get(),
+                NonZeroUInt::<1>::MAX.get(),
+            ))
+            .is_equal_to((
+                UInt::ONE,
+                UInt::MAX,
+                Int::MIN,
+                Int::MAX,
+                Int::new(-1),
+                Int::new(-1),
+                UInt::ONE,
+            ));
+    }
+
+    #[test]
+    fn unsigned_arithmetic() {
+        let value = NonZeroUInt::new(UInt::<12>::new(0x40)).unwrap();
+
+        asserting!("arithmetic stays within the width")
+            .that(&(
+                value.checked_add(UInt::new(0x10)).map(NonZeroUInt::get),
+                NonZeroUInt::<12>::MAX.checked_add(UInt::ONE),
+                NonZeroUInt::<12>::MAX.saturating_add(UInt::ONE),
+                value
+                    .checked_mul(NonZeroUInt::new(UInt::new(0x3f)).unwrap())
+                    .map(NonZeroUInt::get),
+                value.checked_mul(value),
+                value.saturating_mul(value),
+                value.checked_pow(2).map(NonZeroUInt::get),
+                value.saturating_pow(3),
+            ))
+            .is_equal_to((
+                Some(UInt::new(0x50)),
+                None,
+                NonZeroUInt::MAX,
+                Some(UInt::new(0xfc0)),
+                None,
+                NonZeroUInt::MAX,
+                None,
+                NonZeroUInt::MAX,
+            ));
+
+        asserting!("bit counts are within the width")
+            .that(&(
+                value.leading_zeros(),
+                value.trailing_zeros(),
+                value.is_power_of_two(),
+                value.ilog2(),
+                NonZeroUInt::<12>::MAX.ilog2(),
+                NonZeroUInt::<12>::MIN.leading_zeros(),
+            ))
+            .is_equal_to((5, 6, true, 6, 11, 11));
+    }
+
+    #[test]
+    fn signed_arithmetic() {
+        let value = NonZeroInt::new(Int::<12>::new(-0x40)).unwrap();
+        let min = NonZeroInt::<12>::MIN;
+
+        asserting!("signs and absolute values")
+            .that(&(
+                value.is_negative(),
+                value.is_positive(),
+                value.checked_abs().map(NonZeroInt::get),
+                min.checked_abs(),
+                min.saturating_abs(),
+                min.wrapping_abs(),
+                min.unsigned_abs().get(),
+                value.unsigned_abs().get(),
+            ))
+            .is_equal_to((
+                true,
+                false,
+                Some(Int::new(0x40)),
+                None,
+                NonZeroInt::MAX,
+                min,
+                UInt::new(0x800),
+                UInt::new(0x40),
+            ));
+
+        asserting!("negation and multiplication saturate towards the sign of the result")
+            .that(&(
+                value.checked_neg().map(NonZeroInt::get),
+                min.checked_neg(),
+                min.saturating_neg(),
+                min.wrapping_neg(),
+                value.checked_mul(value),
+                value.saturating_mul(value),
+                value.saturating_mul(value.checked_neg().unwrap()),
+                value.saturating_pow(3),
+                value.saturating_pow(2),
+                value.checked_pow(1),
+            ))
+            .is_equal_to((
+                Some(Int::new(0x40)),
+                None,
+                NonZeroInt::MAX,
+                min,
+                None,
+                NonZeroInt::MAX,
+                min,
+                min,
+                NonZeroInt::MAX,
+                Some(value),
+            ));
+
+        asserting!("values order by their numbers")
+            .that(&(value < NonZeroInt::new(Int::ONE).unwrap(), min < value))
+            .is_equal_to((true, true));
+    }
+
+    #[test]
+    fn converting_with_the_standard_types() {
+        let byte = NonZeroU8::new(0xab).unwrap();
+
+        asserting!("widening conversions use From")
+            .that(&(
+                NonZeroUInt::<12>::from(byte).get(),
+                NonZeroInt::<12>::from(byte).get(),
+                NonZeroInt::<12>::from(NonZeroI8::new(-5).unwrap()).get(),
+                NonZeroU16::from(NonZeroUInt::new(UInt::<12>::MAX).unwrap()).get(),
+                NonZeroI16::from(NonZeroInt::new(Int::<12>::MIN).unwrap()).get(),
+                NonZeroI32::from(NonZeroUInt::new(UInt::<12>::MAX).unwrap()).get(),
+            ))
+            .is_equal_to((
+                UInt::new(0xab),
+                Int::new(0xab),
+                Int::new(-5),
+                0xfff,
+                -0x800,
+                0xfff,
+            ));
+
+        asserting!("values format like the plain types")
+            .that(&(
+                format!("{}", NonZeroInt::new(Int::<12>::new(-3)).unwrap()),
+                format!("{:?}", NonZeroUInt::new(UInt::<12>::new(3)).unwrap()),
+                format!("{:?}", NonZeroInt::new(Int::<12>::new(-3)).unwrap()),
+            ))
+            .is_equal_to((
+                "-3".to_string(),
+                "NonZeroUInt<12>(3)".to_string(),
+                "NonZeroInt<12>(-3)".to_string(),
+            ));
+    }
+}
@@ -0,0 +1,749 @@
+use crate::{consts, UInt};
+use std::fmt::{self, Debug, Formatter};
+use std::iter::{FromIterator, FusedIterator};
+
+// Element `i` of a packed container occupies bits `i * BITS` to `(i + 1) * BITS` of its words,
+// counting from the lowest bit of the first word, so an element can start in one word and end in
+// the next, or span three words at widths over 64 bits. Every bit past the last element is kept
+// zero, which lets containers with the same elements compare equal word for word.
+
+/// Reads `width` bits starting at bit `offset` of `words`.
+pub(crate) const fn read_bits(words: &[u64], offset: usize, width: usize) -> u128 {
+    let mut value = 0;
+    let mut done = 0;
+
+    while done < width {
+        let bit = offset + done;
+        let shift = bit % 64;
+        let take = min(64 - shift, width - done);
+
+        value |= ((words[bit / 64] >> shift) as u128 & consts::mask_u128(take)) << done;
+        done += take;
+    }
+
+    value
+}
+
+/// Writes the lowest `width` bits of `value` to `words`, starting at bit `offset`.
+pub(crate) fn write_bits(words: &mut [u64], offset: usize, width: usize, value: u128) {
+    let mut done = 0;
+
+    while done < width {
+        let bit = offset + done;
+        let shift = bit % 64;
+        let take = min(64 - shift, width - done);
+
+        words[bit / 64] = splice(words[bit / 64], shift, take, (value >> done) as u64);
+        done += take;
+    }
+}
+
+/// Replaces the `take` bits of `word` starting at bit `shift` with the lowest bits of `bits`.
+const fn splice(word: u64, shift: usize, take: usize, bits: u64) -> u64 {
+    let mask = consts::mask_u64(take) << shift;
+    word & !mask | bits << shift & mask
+}
+
+/// Returns the number of `u64` words needed to pack `len` elements of `bits` bits each.
+///
+/// This is the `WORDS` parameter of a [`PackedArray`](struct.PackedArray.html).
+///
+/// # Examples
+///
+/// ```
+/// use quark::packed_words;
+///
+/// assert_eq!(packed_words(12, 16), 3);
+/// assert_eq!(packed_words(12, 17), 4);
+/// assert_eq!(packed_words(64, 0), 0);
+/// ```
+pub const fn packed_words(bits: usize, len: usize) -> usize {
+    (bits * len).div_ceil(64)
+}
+
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// A growable list of narrow unsigned integers, packed back to back in `u64` words.
+///
+/// A `Vec<UInt<12>>` spends 16 bytes on every element, since each one holds a `u128`, and even a
+/// `Vec<u16>` wastes a quarter of its memory on 12-bit values. A `PackedVec<12>` stores each element
+/// in exactly 12 bits, with no padding between them: elements that don't fit in the rest of a word
+/// carry on into the next one.
+///
+/// Elements are read and written by value, since they don't sit at addressable locations, so
+/// [`get`](#method.get) returns a copy and [`set`](#method.set) replaces an element rather than
+/// handing out references.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{PackedVec, UInt};
+///
+/// let mut samples = PackedVec::<12>::new();
+/// samples.push(UInt::new(0xabc));
+/// samples.push(UInt::new(0x123));
+/// samples.set(0, UInt::new(0xfff));
+///
+/// assert_eq!(samples.len(), 2);
+/// assert_eq!(samples.get(0), Some(UInt::new(0xfff)));
+/// assert_eq!(samples.pop(), Some(UInt::new(0x123)));
+/// assert_eq!(samples.as_words(), &[0xfff]);
+/// ```
+///
+/// ```compile_fail
+/// use quark::PackedVec;
+///
+/// let empty = PackedVec::<0>::new();
+/// ```
+///
+/// ```compile_fail
+/// use quark::PackedVec;
+///
+/// let empty = PackedVec::<0>::default();
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PackedVec<const BITS: usize> {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl<const BITS: usize> PackedVec<BITS> {
+    /// Creates an empty list without allocating.
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = UInt::<BITS>::VALID_WIDTH;
+        PackedVec {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty list with room for at least `capacity` elements before it reallocates.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut packed = Self::new();
+        packed.words.reserve_exact(packed_words(BITS, capacity));
+        packed
+    }
+
+    /// Returns the number of elements the list can hold before it reallocates.
+    pub fn capacity(&self) -> usize {
+        self.words.capacity() * 64 / BITS
+    }
+
+    /// Reserves room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = packed_words(BITS, self.len + additional);
+        self.words.reserve(needed - self.words.len());
+    }
+
+    /// Returns the number of elements in the list.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the words holding the elements, with the first element in the lowest bits of the
+    /// first word. Any bits in the last word past the last element are zero.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the element at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<UInt<BITS>> {
+        if index < self.len {
+            Some(UInt::from_bits(read_bits(&self.words, index * BITS, BITS)))
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: UInt<BITS>) {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len,
+            index
+        );
+
+        write_bits(&mut self.words, index * BITS, BITS, value.to_bits());
+    }
+
+    /// Adds an element to the end of the list.
+    pub fn push(&mut self, value: UInt<BITS>) {
+        self.words.resize(packed_words(BITS, self.len + 1), 0);
+        write_bits(&mut self.words, self.len * BITS, BITS, value.to_bits());
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or returns `None` if the list is empty.
+    pub fn pop(&mut self) -> Option<UInt<BITS>> {
+        let value = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(value)
+    }
+
+    /// Shortens the list to `len` elements, doing nothing if it's already that short.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let used = len * BITS;
+            self.words.truncate(packed_words(BITS, len));
+            let unused = self.words.len() * 64 - used;
+            write_bits(&mut self.words, used, unused, 0);
+            self.len = len;
+        }
+    }
+
+    /// Removes every element, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the elements of the list.
+    pub fn iter(&self) -> PackedIter<'_, BITS> {
+        PackedIter {
+            words: &self.words,
+            front: 0,
+            back: self.len,
+        }
+    }
+}
+
+impl<const BITS: usize> Default for PackedVec<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize> Debug for PackedVec<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const BITS: usize> Extend<UInt<BITS>> for PackedVec<BITS> {
+    fn extend<I: IntoIterator<Item = UInt<BITS>>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<const BITS: usize> FromIterator<UInt<BITS>> for PackedVec<BITS> {
+    fn from_iter<I: IntoIterator<Item = UInt<BITS>>>(iter: I) -> Self {
+        let mut packed = Self::new();
+        packed.extend(iter);
+        packed
+    }
+}
+
+impl<'a, const BITS: usize> IntoIterator for &'a PackedVec<BITS> {
+    type Item = UInt<BITS>;
+    type IntoIter = PackedIter<'a, BITS>;
+
+    fn into_iter(self) -> PackedIter<'a, BITS> {
+        self.iter()
+    }
+}
+
+/// A fixed-length array of narrow unsigned integers, packed back to back in `u64` words.
+///
+/// This is the fixed-size sibling of [`PackedVec`](struct.PackedVec.html), for lookup tables that
+/// have to live in a `const` or `static` item rather than on the heap. It holds `LEN` elements of
+/// `BITS` bits each in an array of `WORDS` words.
+///
+/// Stable Rust can't size an array from an expression on other const parameters, so `WORDS` has to
+/// be spelled out, and [`packed_words`](fn.packed_words.html) computes it. Using any other number of
+/// words is a compile-time error.
+///
+/// Every method that builds an array is a `const fn`, so tables can be filled in at compile time
+/// with [`from_array`](#method.from_array) or [`with`](#method.with), and [`get`](#method.get) can
+/// read them in constant expressions too.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{packed_words, PackedArray, UInt};
+///
+/// type Table = PackedArray<5, 16, { packed_words(5, 16) }>;
+///
+/// static SQUARES: Table = {
+///     let mut squares = [UInt::ZERO; 16];
+///     let mut i = 0;
+///     while i < 16 {
+///         squares[i] = UInt::new((i * i) as u128 >> 3);
+///         i += 1;
+///     }
+///     Table::from_array(squares)
+/// };
+///
+/// assert_eq!(SQUARES.len(), 16);
+/// assert_eq!(SQUARES.get(15), Some(UInt::new(28)));
+/// assert_eq!(SQUARES.as_words().len(), 2);
+/// ```
+///
+/// ```compile_fail
+/// use quark::PackedArray;
+///
+/// let wrong = PackedArray::<5, 16, 1>::new();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedArray<const BITS: usize, const LEN: usize, const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const BITS: usize, const LEN: usize, const WORDS: usize> PackedArray<BITS, LEN, WORDS> {
+    const VALID_WORDS: () = assert!(
+        WORDS == packed_words(BITS, LEN),
+        "PackedArray must have the number of words given by packed_words"
+    );
+
+    /// Creates an array with every element zero.
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = UInt::<BITS>::VALID_WIDTH;
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WORDS;
+        PackedArray { words: [0; WORDS] }
+    }
+
+    /// Creates an array holding the given elements.
+    pub const fn from_array(values: [UInt<BITS>; LEN]) -> Self {
+        let mut packed = Self::new();
+        let mut i = 0;
+
+        while i < LEN {
+            packed = packed.with(i, values[i]);
+            i += 1;
+        }
+
+        packed
+    }
+
+    /// Returns the array with the element at `index` replaced.
+    ///
+    /// This is [`set`](#method.set) for constant expressions, which can't modify a value in
+    /// place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub const fn with(mut self, index: usize, value: UInt<BITS>) -> Self {
+        assert!(index < LEN, "index out of bounds");
+
+        let bits = value.to_bits();
+        let mut done = 0;
+
+        while done < BITS {
+            let bit = index * BITS + done;
+            let shift = bit % 64;
+            let take = min(64 - shift, BITS - done);
+
+            self.words[bit / 64] = splice(self.words[bit / 64], shift, take, (bits >> done) as u64);
+            done += take;
+        }
+
+        self
+    }
+
+    /// Returns the number of elements in the array, `LEN`.
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Returns `true` if the array has no elements.
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
+    /// Returns the words holding the elements, with the first element in the lowest bits of the
+    /// first word. Any bits in the last word past the last element are zero.
+    pub const fn as_words(&self) -> &[u64; WORDS] {
+        &self.words
+    }
+
+    /// Returns the element at `index`, or `None` if it's out of bounds.
+    pub const fn get(&self, index: usize) -> Option<UInt<BITS>> {
+        if index < LEN {
+            Some(UInt::from_bits(read_bits(&self.words, index * BITS, BITS)))
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: UInt<BITS>) {
+        assert!(
+            index < LEN,
+            "index out of bounds: the len is {} but the index is {}",
+            LEN,
+            index
+        );
+
+        write_bits(&mut self.words, index * BITS, BITS, value.to_bits());
+    }
+
+    /// Returns an iterator over the elements of the array.
+    pub fn iter(&self) -> PackedIter<'_, BITS> {
+        PackedIter {
+            words: &self.words,
+            front: 0,
+            back: LEN,
+        }
+    }
+}
+
+impl<const BITS: usize, const LEN: usize, const WORDS: usize> Default
+    for PackedArray<BITS, LEN, WORDS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, const LEN: usize, const WORDS: usize> Debug
+    for PackedArray<BITS, LEN, WORDS>
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, const BITS: usize, const LEN: usize, const WORDS: usize> IntoIterator
+    for &'a PackedArray<BITS, LEN, WORDS>
+{
+    type Item = UInt<BITS>;
+    type IntoIter = PackedIter<'a, BITS>;
+
+    fn into_iter(self) -> PackedIter<'a, BITS> {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`PackedVec`](struct.PackedVec.html) or a
+/// [`PackedArray`](struct.PackedArray.html).
+///
+/// This is returned by their `iter` methods. It can be iterated from either end, and knows exactly
+/// how many elements it has left.
+#[derive(Clone, Debug)]
+pub struct PackedIter<'a, const BITS: usize> {
+    words: &'a [u64],
+    front: usize,
+    back: usize,
+}
+
+impl<const BITS: usize> Iterator for PackedIter<'_, BITS> {
+    type Item = UInt<BITS>;
+
+    fn next(&mut self) -> Option<UInt<BITS>> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(UInt::from_bits(read_bits(
+                self.words,
+                (self.front - 1) * BITS,
+                BITS,
+            )))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<const BITS: usize> DoubleEndedIterator for PackedIter<'_, BITS> {
+    fn next_back(&mut self) -> Option<UInt<BITS>> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(UInt::from_bits(read_bits(
+                self.words,
+                self.back * BITS,
+                BITS,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const BITS: usize> ExactSizeIterator for PackedIter<'_, BITS> {}
+
+impl<const BITS: usize> FusedIterator for PackedIter<'_, BITS> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u128s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn packing_without_padding() {
+        let packed: PackedVec<13> = (1..=10).map(|i| UInt::new(0x1000 | i)).collect();
+
+        let mut expected = [0_u64; 3];
+        for i in 0..10 {
+            let bits = (0x1000 | (i as u128 + 1)) << (i * 13 % 64);
+            expected[i * 13 / 64] |= bits as u64;
+            if i * 13 % 64 > 64 - 13 {
+                expected[i * 13 / 64 + 1] |= (bits >> 64) as u64;
+            }
+        }
+
+        asserting!("elements straddle word boundaries")
+            .that(&packed.as_words())
+            .is_equal_to(&expected[..]);
+
+        let wide: PackedVec<100> = vec![UInt::MAX, UInt::ZERO, UInt::MAX].into_iter().collect();
+        asserting!("wide elements span three words")
+            .that(&wide.as_words())
+            .is_equal_to(&[!0, 0xf_ffff_ffff, 0, 0xffff_ffff_ffff_ff00, 0xfff_ffff_ffff][..]);
+    }
+
+    #[test]
+    fn round_trips_at_awkward_widths() {
+        fn check<const BITS: usize>() {
+            let mut packed = PackedVec::<BITS>::new();
+            let mut model = Vec::new();
+
+            for seed in random_u128s().take(1000) {
+                let value = UInt::<BITS>::masked(seed >> 8);
+                match seed % 8 {
+                    0 => asserting!("popping matches")
+                        .that(&packed.pop())
+                        .is_equal_to(model.pop()),
+                    1 if !model.is_empty() => {
+                        let index = (seed >> 64) as usize % model.len();
+                        packed.set(index, value);
+                        model[index] = value;
+                    }
+                    _ => {
+                        packed.push(value);
+                        model.push(value);
+                    }
+                }
+
+                asserting!("lengths match")
+                    .that(&packed.len())
+                    .is_equal_to(model.len());
+            }
+
+            asserting!("every element reads back")
+                .that(&(0..model.len()).map(|i| packed.get(i)).collect::<Vec<_>>())
+                .is_equal_to(model.iter().copied().map(Some).collect::<Vec<_>>());
+
+            asserting!("iterating reads every element from either end")
+                .that(&(
+                    packed.iter().collect::<Vec<_>>(),
+                    packed.iter().rev().collect::<Vec<_>>(),
+                ))
+                .is_equal_to((model.clone(), model.iter().rev().copied().collect()));
+
+            asserting!("the words hold exactly the elements")
+                .that(&packed.as_words().len())
+                .is_equal_to(packed_words(BITS, model.len()));
+
+            asserting!("lists with the same elements are equal")
+                .that(&model.iter().copied().collect::<PackedVec<BITS>>())
+                .is_equal_to(packed);
+        }
+
+        check::<1>();
+        check::<3>();
+        check::<13>();
+        check::<63>();
+        check::<64>();
+        check::<65>();
+        check::<100>();
+        check::<128>();
+    }
+
+    #[test]
+    fn accounting_for_capacity_and_len() {
+        let mut packed = PackedVec::<13>::with_capacity(100);
+        let capacity = packed.capacity();
+
+        asserting!("an empty list has the capacity it asked for")
+            .that(&(
+                packed.len(),
+                packed.is_empty(),
+                capacity >= 100,
+                capacity < 105,
+            ))
+            .is_equal_to((0, true, true, true));
+
+        packed.extend((0..100).map(UInt::new));
+
+        asserting!("filling the capacity doesn't reallocate")
+            .that(&(packed.len(), packed.capacity(), packed.as_words().len()))
+            .is_equal_to((100, capacity, 21));
+
+        packed.truncate(50);
+        packed.truncate(60);
+
+        asserting!("truncating keeps the allocation and clears the unused bits")
+            .that(&(
+                packed.len(),
+                packed.capacity(),
+                packed.as_words().len(),
+                packed.as_words()[10] >> (50 * 13 % 64),
+                packed.get(50),
+            ))
+            .is_equal_to((50, capacity, 11, 0, None));
+
+        packed.clear();
+
+        asserting!("clearing empties the list")
+            .that(&(
+                packed.len(),
+                packed.as_words().len(),
+                packed.capacity(),
+                packed.pop(),
+            ))
+            .is_equal_to((0, 0, capacity, None));
+
+        asserting!("new lists don't allocate")
+            .that(&PackedVec::<13>::new().capacity())
+            .is_equal_to(0);
+    }
+
+    #[test]
+    fn formatting() {
+        let packed: PackedVec<4> = (1..4).map(UInt::new).collect();
+
+        asserting!("lists format like their elements")
+            .that(&format!("{:?}", packed))
+            .is_equal_to(format!(
+                "{:?}",
+                vec![UInt::<4>::new(1), UInt::new(2), UInt::new(3)]
+            ));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn setting_out_of_bounds() {
+        let mut packed: PackedVec<13> = (0..2).map(UInt::new).collect();
+        packed.set(2, UInt::MAX);
+    }
+
+    #[test]
+    fn fixed_arrays_at_awkward_widths() {
+        fn check<const BITS: usize, const LEN: usize, const WORDS: usize>() {
+            let mut packed = PackedArray::<BITS, LEN, WORDS>::new();
+            let mut model = [UInt::<BITS>::ZERO; LEN];
+
+            for seed in random_u128s().take(200) {
+                let index = (seed >> 64) as usize % LEN;
+                let value = UInt::masked(seed);
+                model[index] = value;
+
+                if seed % 2 == 0 {
+                    packed.set(index, value);
+                } else {
+                    packed = packed.with(index, value);
+                }
+            }
+
+            asserting!("every element reads back")
+                .that(&(
+                    packed.iter().collect::<Vec<_>>(),
+                    packed.iter().rev().collect::<Vec<_>>(),
+                    packed.get(LEN),
+                ))
+                .is_equal_to((model.to_vec(), model.iter().rev().copied().collect(), None));
+
+            asserting!("arrays with the same elements are equal")
+                .that(&PackedArray::<BITS, LEN, WORDS>::from_array(model))
+                .is_equal_to(packed);
+
+            let used = BITS * LEN % 64;
+            asserting!("bits past the last element stay zero")
+                .that(&(used == 0 || packed.as_words()[WORDS - 1] >> used == 0))
+                .is_true();
+
+            let mut filled = PackedArray::<BITS, LEN, WORDS>::new();
+            for i in 0..LEN {
+                filled.set(i, UInt::MAX);
+            }
+
+            asserting!("filling every element sets exactly the used bits")
+                .that(
+                    &filled
+                        .as_words()
+                        .iter()
+                        .map(|word| word.count_ones())
+                        .sum::<u32>(),
+                )
+                .is_equal_to((BITS * LEN) as u32);
+        }
+
+        check::<1, 70, { packed_words(1, 70) }>();
+        check::<13, 10, { packed_words(13, 10) }>();
+        check::<13, 64, { packed_words(13, 64) }>();
+        check::<63, 3, { packed_words(63, 3) }>();
+        check::<100, 5, { packed_words(100, 5) }>();
+        check::<128, 2, { packed_words(128, 2) }>();
+    }
+
+    #[test]
+    fn building_arrays_in_constants() {
+        const TABLE: PackedArray<13, 5, 2> = PackedArray::new()
+            .with(0, UInt::new(0x1abc))
+            .with(4, UInt::new(0x1fff));
+        const FOURTH: Option<UInt<13>> = TABLE.get(4);
+
+        asserting!("constant tables hold their elements")
+            .that(&(
+                TABLE.get(0),
+                FOURTH,
+                TABLE.get(1),
+                TABLE.len(),
+                TABLE.is_empty(),
+            ))
+            .is_equal_to((
+                Some(UInt::new(0x1abc)),
+                Some(UInt::MAX),
+                Some(UInt::ZERO),
+                5,
+                false,
+            ));
+
+        asserting!("the element straddling the words is split between them")
+            .that(TABLE.as_words())
+            .is_equal_to([0x1abc | 0xfff << 52, 1]);
+
+        asserting!("arrays format like their elements")
+            .that(&format!("{:?}", PackedArray::<4, 2, 1>::default()))
+            .is_equal_to("[UInt<4>(0), UInt<4>(0)]".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 5 but the index is 5")]
+    fn setting_past_the_end_of_an_array() {
+        let mut packed = PackedArray::<13, 5, 2>::new();
+        packed.set(5, UInt::MAX);
+    }
+}
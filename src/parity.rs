@@ -0,0 +1,300 @@
+use crate::BitIndex;
+use std::ops::RangeBounds;
+
+/// Computes, inserts, and checks parity bits.
+///
+/// The parity of some bits is whether an odd number of them are set. A parity bit is set or
+/// cleared so that it and the bits it covers have an even number of ones, for even parity, or an
+/// odd number, for odd parity, which catches any single flipped bit. UARTs and many simple
+/// protocols send a parity bit after the data bits, so the parity bit at a given index covers the
+/// bits below it. Signed values are checked by their bit patterns.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Parity;
+///
+/// // Seven data bits with an even parity bit above them
+/// let frame = 0b101_0011_u8.with_even_parity_at(7);
+/// assert_eq!(frame, 0b0101_0011);
+/// assert!(frame.check_even_parity(7));
+/// assert!(!(frame ^ 0b100).check_even_parity(7));
+/// ```
+pub trait Parity {
+    /// Returns whether an odd number of bits of a value are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Parity;
+    ///
+    /// assert!(0b0111_u8.parity());
+    /// assert!(!0b0110_u8.parity());
+    /// assert!(!(-1_i32).parity());
+    /// ```
+    fn parity(&self) -> bool;
+
+    /// Returns whether an odd number of the bits in a range are set.
+    ///
+    /// An empty range has even parity, and bits of the range past the end of the type are read
+    /// the same way as by [`bits`](BitIndex::bits).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Parity;
+    ///
+    /// assert!(0b1011_0000_u8.parity_in(4..8));
+    /// assert!(!0b1011_0000_u8.parity_in(4..6));
+    /// assert!(!0xff_u8.parity_in(3..3));
+    /// ```
+    fn parity_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> bool;
+
+    /// Sets or clears the bit at an index so that it and the bits below it have even parity.
+    ///
+    /// The bits above the index are left alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is past the end of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Parity;
+    ///
+    /// assert_eq!(0x0_07_u16.with_even_parity_at(8), 0x1_07);
+    /// assert_eq!(0xf_06_u16.with_even_parity_at(8), 0xe_06);
+    /// ```
+    fn with_even_parity_at(&self, index: usize) -> Self;
+
+    /// Sets or clears the bit at an index so that it and the bits below it have odd parity.
+    ///
+    /// The bits above the index are left alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is past the end of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Parity;
+    ///
+    /// assert_eq!(0x0_07_u16.with_odd_parity_at(8), 0x0_07);
+    /// assert_eq!(0xf_06_u16.with_odd_parity_at(8), 0xf_06);
+    /// ```
+    fn with_odd_parity_at(&self, index: usize) -> Self;
+
+    /// Returns whether the bit at an index and the bits below it have even parity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is past the end of the type.
+    fn check_even_parity(&self, index: usize) -> bool;
+
+    /// Returns whether the bit at an index and the bits below it have odd parity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is past the end of the type.
+    fn check_odd_parity(&self, index: usize) -> bool;
+}
+
+macro_rules! parity_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Parity for $type {
+                fn parity(&self) -> bool {
+                    // Each step folds the upper half of the remaining bits onto the lower half, so
+                    // the lowest bit ends up as the XOR of every bit
+                    let mut value = *self;
+                    let mut shift = Self::BITS / 2;
+                    while shift > 0 {
+                        value ^= value >> shift;
+                        shift /= 2;
+                    }
+                    value & 1 == 1
+                }
+
+                fn parity_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> bool {
+                    self.bits(range).parity()
+                }
+
+                fn with_even_parity_at(&self, index: usize) -> Self {
+                    assert!(index < Self::BITS as usize, "the parity bit must lie within the type");
+                    self.with_bits(index..=index, self.bits(..index).parity() as Self)
+                }
+
+                fn with_odd_parity_at(&self, index: usize) -> Self {
+                    assert!(index < Self::BITS as usize, "the parity bit must lie within the type");
+                    self.with_bits(index..=index, !self.bits(..index).parity() as Self)
+                }
+
+                fn check_even_parity(&self, index: usize) -> bool {
+                    assert!(index < Self::BITS as usize, "the parity bit must lie within the type");
+                    !self.bits(..=index).parity()
+                }
+
+                fn check_odd_parity(&self, index: usize) -> bool {
+                    assert!(index < Self::BITS as usize, "the parity bit must lie within the type");
+                    self.bits(..=index).parity()
+                }
+            }
+        )*
+    };
+}
+
+parity_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed_parity_impl {
+    ($($type:ident as $unsigned:ident),*) => {
+        $(
+            impl Parity for $type {
+                fn parity(&self) -> bool {
+                    (*self as $unsigned).parity()
+                }
+
+                fn parity_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> bool {
+                    (*self as $unsigned).parity_in(range)
+                }
+
+                fn with_even_parity_at(&self, index: usize) -> Self {
+                    (*self as $unsigned).with_even_parity_at(index) as $type
+                }
+
+                fn with_odd_parity_at(&self, index: usize) -> Self {
+                    (*self as $unsigned).with_odd_parity_at(index) as $type
+                }
+
+                fn check_even_parity(&self, index: usize) -> bool {
+                    (*self as $unsigned).check_even_parity(index)
+                }
+
+                fn check_odd_parity(&self, index: usize) -> bool {
+                    (*self as $unsigned).check_odd_parity(index)
+                }
+            }
+        )*
+    };
+}
+
+signed_parity_impl!(
+    i8 as u8,
+    i16 as u16,
+    i32 as u32,
+    i64 as u64,
+    i128 as u128,
+    isize as usize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn parity_of_values_and_fields() {
+        asserting!("whole values have the parity of their ones")
+            .that(&(
+                0_u8.parity(),
+                1_u8.parity(),
+                u128::MAX.parity(),
+                (1_u128 << 127).parity(),
+                0x8000_0001_u32.parity(),
+                i64::MIN.parity(),
+                (-2_i16).parity(),
+            ))
+            .is_equal_to((false, true, false, true, false, true, true));
+
+        asserting!("fields have the parity of the ones inside them")
+            .that(&(
+                0xff_u8.parity_in(3..3),
+                0_u8.parity_in(..0),
+                0b1000_u8.parity_in(3..4),
+                0b0111_u8.parity_in(3..=3),
+                0b1011_0000_u8.parity_in(4..),
+                u64::MAX.parity_in(60..70),
+                (-1_i8).parity_in(..7),
+            ))
+            .is_equal_to((false, false, true, false, true, false, true));
+
+        for seed in random_u64s().take(1000) {
+            let wide = (seed as u128) << 64 | seed.rotate_left(7) as u128;
+            let (start, end) = ((seed >> 58) as usize, (seed >> 52) as usize % 64);
+
+            asserting!("parity is the lowest bit of the count of ones")
+                .that(&(
+                    seed.parity(),
+                    wide.parity(),
+                    (seed as u8).parity(),
+                    (seed as i32).parity(),
+                    seed.parity_in(start.min(end)..start.max(end)),
+                ))
+                .is_equal_to((
+                    seed.count_ones() % 2 == 1,
+                    wide.count_ones() % 2 == 1,
+                    (seed as u8).count_ones() % 2 == 1,
+                    (seed as u32).count_ones() % 2 == 1,
+                    (seed.bits(start.min(end)..start.max(end))).count_ones() % 2 == 1,
+                ));
+        }
+    }
+
+    #[test]
+    fn parity_bits() {
+        asserting!("parity bits at the ends of the value")
+            .that(&(
+                0xff_u8.with_even_parity_at(0),
+                0xfe_u8.with_odd_parity_at(0),
+                0x01_u8.with_even_parity_at(7),
+                0x81_u8.with_odd_parity_at(7),
+                u128::MAX.with_even_parity_at(127),
+                0_i8.with_odd_parity_at(7),
+            ))
+            .is_equal_to((0xfe, 0xff, 0x81, 0x01, u128::MAX, i8::MIN));
+
+        asserting!("single bits check against their parity")
+            .that(&(
+                0_u8.check_even_parity(0),
+                0_u8.check_odd_parity(0),
+                1_u8.check_even_parity(0),
+                1_u8.check_odd_parity(0),
+            ))
+            .is_equal_to((true, false, false, true));
+
+        for seed in random_u64s().take(1000) {
+            let index = (seed >> 58) as usize;
+
+            let (even, odd) = (
+                seed.with_even_parity_at(index),
+                seed.with_odd_parity_at(index),
+            );
+            asserting!("inserted parity bits check, and only touch their own bit")
+                .that(&(
+                    even.check_even_parity(index),
+                    even.check_odd_parity(index),
+                    odd.check_odd_parity(index),
+                    odd.check_even_parity(index),
+                    even ^ odd,
+                    (even ^ seed) & !(1 << index),
+                ))
+                .is_equal_to((true, false, true, false, 1 << index, 0));
+
+            let flipped = even ^ 1 << (seed as usize % (index + 1));
+            asserting!("a flipped bit fails the check")
+                .that(&(
+                    flipped.check_even_parity(index),
+                    (odd as i64).check_odd_parity(index),
+                ))
+                .is_equal_to((false, true));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the parity bit must lie within the type")]
+    fn parity_bit_past_the_end() {
+        0_u16.with_even_parity_at(16);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{Int, ParseError, ParseErrorKind, UInt};
+use std::str::FromStr;
+
+impl<const BITS: usize> UInt<BITS> {
+    /// Parses a value from a string of digits in the given radix.
+    ///
+    /// Like `from_str_radix` on the primitives, the string can start with a `+`, and the digits
+    /// can be upper or lower case. Digits can also be separated with underscores, as in Rust
+    /// literals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` isn't between 2 and 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<9>::from_str_radix("1_1111_1111", 2), Ok(UInt::MAX));
+    /// assert!(UInt::<9>::from_str_radix("200", 16).is_err());
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        let (negative, digits) = split_sign(src);
+        Self::parse(src, negative, digits, radix)
+    }
+
+    fn parse(src: &str, negative: bool, digits: &str, radix: u32) -> Result<Self, ParseError> {
+        let magnitude = if negative {
+            Err(ParseErrorKind::InvalidDigit)
+        } else {
+            parse_magnitude(src, digits, radix)
+        };
+
+        magnitude
+            .and_then(|magnitude| Self::checked_new(magnitude).ok_or(ParseErrorKind::OutOfRange))
+            .map_err(|kind| ParseError::new(kind, BITS, false))
+    }
+}
+
+impl<const BITS: usize> Int<BITS> {
+    /// Parses a value from a string of digits in the given radix.
+    ///
+    /// Like `from_str_radix` on the primitives, the string can start with a `+` or a `-`, and the
+    /// digits can be upper or lower case. Digits can also be separated with underscores, as in
+    /// Rust literals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` isn't between 2 and 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// assert_eq!(Int::<9>::from_str_radix("-100", 16), Ok(Int::MIN));
+    /// assert!(Int::<9>::from_str_radix("100", 16).is_err());
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        let (negative, digits) = split_sign(src);
+        Self::parse(src, negative, digits, radix)
+    }
+
+    fn parse(src: &str, negative: bool, digits: &str, radix: u32) -> Result<Self, ParseError> {
+        parse_magnitude(src, digits, radix)
+            .and_then(|magnitude| {
+                // The magnitude of MIN is one more than MAX, and wraps around to MIN when negated.
+                let limit = Self::MAX.get() as u128 + negative as u128;
+
+                if magnitude > limit {
+                    Err(ParseErrorKind::OutOfRange)
+                } else if negative {
+                    Ok(Int::masked((magnitude as i128).wrapping_neg()))
+                } else {
+                    Ok(Int::masked(magnitude as i128))
+                }
+            })
+            .map_err(|kind| ParseError::new(kind, BITS, true))
+    }
+}
+
+/// Parses a value written in Rust's literal syntax: in decimal, or in hex, octal, or binary with
+/// a `0x`, `0o`, or `0b` prefix, with an optional sign and underscores between the digits.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{Int, UInt};
+///
+/// assert_eq!("0x1F".parse::<UInt<9>>(), Ok(UInt::new(0x1f)));
+/// assert_eq!("0b1010_0110".parse::<UInt<9>>(), Ok(UInt::new(0xa6)));
+/// assert_eq!("-0o400".parse::<Int<9>>(), Ok(Int::MIN));
+/// ```
+impl<const BITS: usize> FromStr for UInt<BITS> {
+    type Err = ParseError;
+
+    fn from_str(src: &str) -> Result<Self, ParseError> {
+        let (negative, rest) = split_sign(src);
+        let (radix, digits) = split_prefix(rest);
+        Self::parse(src, negative, digits, radix)
+    }
+}
+
+/// Parses a value written in Rust's literal syntax: in decimal, or in hex, octal, or binary with
+/// a `0x`, `0o`, or `0b` prefix, with an optional sign and underscores between the digits.
+impl<const BITS: usize> FromStr for Int<BITS> {
+    type Err = ParseError;
+
+    fn from_str(src: &str) -> Result<Self, ParseError> {
+        let (negative, rest) = split_sign(src);
+        let (radix, digits) = split_prefix(rest);
+        Self::parse(src, negative, digits, radix)
+    }
+}
+
+fn split_sign(src: &str) -> (bool, &str) {
+    if let Some(rest) = src.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, src.strip_prefix('+').unwrap_or(src))
+    }
+}
+
+fn split_prefix(src: &str) -> (u32, &str) {
+    let radix = match src.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => return (10, src),
+    };
+
+    (radix, &src[2..])
+}
+
+fn parse_magnitude(src: &str, digits: &str, radix: u32) -> Result<u128, ParseErrorKind> {
+    assert!(
+        (2..=36).contains(&radix),
+        "from_str_radix: radix must be between 2 and 36, not {}",
+        radix
+    );
+
+    if src.is_empty() {
+        return Err(ParseErrorKind::Empty);
+    }
+
+    let digits = digits.chars().filter(|&digit| digit != '_');
+
+    if digits.clone().next().is_none() || digits.clone().any(|digit| !digit.is_digit(radix)) {
+        return Err(ParseErrorKind::InvalidDigit);
+    }
+
+    digits
+        .map(|digit| digit.to_digit(radix).unwrap() as u128)
+        .try_fold(0_u128, |value, digit| {
+            value.checked_mul(radix as u128)?.checked_add(digit)
+        })
+        .ok_or(ParseErrorKind::OutOfRange)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn unsigned() {
+        asserting!("every radix and separator is accepted")
+            .that(&[
+                "0x1F".parse::<UInt<9>>(),
+                "0X1f".parse::<UInt<9>>(),
+                "0o37".parse::<UInt<9>>(),
+                "0b1_1111".parse::<UInt<9>>(),
+                "+31".parse::<UInt<9>>(),
+                "0_031".parse::<UInt<9>>(),
+            ])
+            .is_equal_to([Ok(UInt::new(31)); 6]);
+
+        asserting!("limits are parsed exactly")
+            .that(&[
+                "511".parse::<UInt<9>>().map(UInt::get),
+                "0".parse::<UInt<9>>().map(UInt::get),
+                "1".parse::<UInt<1>>().map(UInt::get),
+                "340282366920938463463374607431768211455"
+                    .parse::<UInt<128>>()
+                    .map(UInt::get),
+            ])
+            .is_equal_to([Ok(511), Ok(0), Ok(1), Ok(u128::MAX)]);
+
+        let kind = |src: &str| src.parse::<UInt<9>>().map_err(|error| error.kind());
+
+        asserting!("malformed strings are rejected")
+            .that(&[
+                kind(""),
+                kind("+"),
+                kind("0x"),
+                kind("_"),
+                kind("12a"),
+                kind("0b102"),
+                kind("-1"),
+                kind("-0"),
+                kind(" 1"),
+            ])
+            .is_equal_to([
+                Err(ParseErrorKind::Empty),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+                Err(ParseErrorKind::InvalidDigit),
+            ]);
+
+        asserting!("values that don't fit are out of range")
+            .that(&[
+                kind("512"),
+                kind("0x200"),
+                kind("340282366920938463463374607431768211456"),
+                "2".parse::<UInt<1>>()
+                    .map(UInt::widen)
+                    .map_err(|error| error.kind()),
+            ])
+            .is_equal_to([Err(ParseErrorKind::OutOfRange); 4]);
+
+        asserting!("from_str_radix doesn't accept prefixes")
+            .that(&(
+                UInt::<12>::from_str_radix("abc", 16),
+                UInt::<12>::from_str_radix("z", 36),
+                UInt::<12>::from_str_radix("0xabc", 16).map_err(|error| error.kind()),
+            ))
+            .is_equal_to((
+                Ok(UInt::new(0xabc)),
+                Ok(UInt::new(35)),
+                Err(ParseErrorKind::InvalidDigit),
+            ));
+    }
+
+    #[test]
+    fn signed() {
+        let parse = |src: &str| src.parse::<Int<9>>().map(Int::get);
+
+        asserting!("the asymmetric range is enforced")
+            .that(&[
+                parse("-256"),
+                parse("255"),
+                parse("-0x100"),
+                parse("0xff"),
+                parse("-0"),
+                parse("-0b1"),
+            ])
+            .is_equal_to([Ok(-256), Ok(255), Ok(-256), Ok(255), Ok(0), Ok(-1)]);
+
+        asserting!("values just past the limits are out of range")
+            .that(&[
+                "-257".parse::<Int<9>>().map_err(|error| error.kind()),
+                "256".parse::<Int<9>>().map_err(|error| error.kind()),
+                "0x100".parse::<Int<9>>().map_err(|error| error.kind()),
+                "1".parse::<Int<1>>()
+                    .map(Int::widen)
+                    .map_err(|error| error.kind()),
+            ])
+            .is_equal_to([Err(ParseErrorKind::OutOfRange); 4]);
+
+        asserting!("the full-width limits are parsed exactly")
+            .that(&[
+                "-170141183460469231731687303715884105728".parse::<Int<128>>(),
+                "170141183460469231731687303715884105727".parse::<Int<128>>(),
+                "-1".parse::<Int<1>>().map(Int::widen),
+            ])
+            .is_equal_to([Ok(Int::MIN), Ok(Int::MAX), Ok(Int::new(-1))]);
+
+        asserting!("malformed strings are rejected")
+            .that(&[
+                "--1".parse::<Int<9>>().map_err(|error| error.kind()),
+                "+-1".parse::<Int<9>>().map_err(|error| error.kind()),
+                "-".parse::<Int<9>>().map_err(|error| error.kind()),
+                "0x-1".parse::<Int<9>>().map_err(|error| error.kind()),
+            ])
+            .is_equal_to([Err(ParseErrorKind::InvalidDigit); 4]);
+
+        asserting!("errors describe the type")
+            .that(&"256".parse::<Int<9>>().unwrap_err().to_string())
+            .is_equal_to("number doesn't fit in 9 bits as a signed integer".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be between 2 and 36")]
+    fn bad_radix() {
+        let _ = UInt::<8>::from_str_radix("1", 37);
+    }
+}
@@ -0,0 +1,889 @@
+use crate::bit_mask::range_bounds;
+use crate::BitIndex;
+use std::ops::RangeBounds;
+
+// The permutations are built from the usual mask-and-shift networks: each step swaps neighbouring
+// groups of bits picked out by a repeating mask, so a whole word is permuted in a few operations
+// rather than a loop over its bits. The masks repeat along the word, so one constant of each
+// pattern truncated to the type covers every width. Signed values are permuted by their bit
+// patterns.
+//
+// Interleaving uses the same masks the other way around: dilating a value spreads its bits out to
+// every other position by moving the upper half of each group up by half the group's width, from
+// the whole value down to single bits, and compacting runs the steps in reverse.
+
+// The repeating masks of the swap networks, truncated to each type.
+const EVEN_BITS: u128 = 0x5555_5555_5555_5555_5555_5555_5555_5555;
+const EVEN_PAIRS: u128 = 0x3333_3333_3333_3333_3333_3333_3333_3333;
+const EVEN_NIBBLES: u128 = 0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f;
+const EVEN_BYTES: u128 = 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff;
+const EVEN_HALFWORDS: u128 = 0x0000_ffff_0000_ffff_0000_ffff_0000_ffff;
+const EVEN_WORDS: u128 = 0x0000_0000_ffff_ffff_0000_0000_ffff_ffff;
+const EVEN_DOUBLEWORDS: u128 = 0x0000_0000_0000_0000_ffff_ffff_ffff_ffff;
+
+// The mask for groups of `1 << i` bits is at index `i`.
+const EVEN_GROUPS: [u128; 7] = [
+    EVEN_BITS,
+    EVEN_PAIRS,
+    EVEN_NIBBLES,
+    EVEN_BYTES,
+    EVEN_HALFWORDS,
+    EVEN_WORDS,
+    EVEN_DOUBLEWORDS,
+];
+
+/// Rearranges the bits of a value.
+///
+/// These are the bit permutations that serial protocols, checksums, and display drivers need
+/// beyond the `reverse_bits` and `swap_bytes` of the primitives.
+pub trait BitPermute {
+    /// Reverses the order of the bits within each byte, leaving the bytes where they are.
+    ///
+    /// This converts a word of bytes sent most significant bit first into one sent least
+    /// significant bit first, and back. It's half of `reverse_bits`: reversing the bits of each
+    /// byte and then swapping the bytes reverses the whole value, so
+    /// `x.reverse_bits() == x.reverse_bit_order_in_bytes().swap_bytes()`. For a `u8`, it's the
+    /// same as `reverse_bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0x01_80_u16.reverse_bit_order_in_bytes(), 0x80_01);
+    /// assert_eq!(0x12_34_u16.reverse_bit_order_in_bytes(), 0x48_2c);
+    ///
+    /// let value = 0x1234_5678_u32;
+    /// assert_eq!(value.reverse_bit_order_in_bytes().swap_bytes(), value.reverse_bits());
+    /// ```
+    fn reverse_bit_order_in_bytes(&self) -> Self;
+
+    /// Reverses the order of the bytes in a range of bits, leaving the other bits alone.
+    ///
+    /// This is `swap_bytes` for a field inside a larger word, like the byte-swapped fields of a
+    /// mixed-endian register. The bytes are counted from the start of the range, so the range
+    /// doesn't have to start on a byte boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range doesn't cover a whole number of bytes, or if it reaches past the end of
+    /// the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0x1122_3344_u32.swap_bytes_in(8..24), 0x1133_2244);
+    /// assert_eq!(0x1234_5678_u32.swap_bytes_in(16..), 0x3412_5678);
+    /// assert_eq!(0x000a_bcd0_u32.swap_bytes_in(4..20), 0x000c_dab0);
+    /// ```
+    fn swap_bytes_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> Self;
+
+    /// Reverses the order of the 16-bit halfwords of a value.
+    ///
+    /// This is `swap_bytes` with halfwords instead of bytes, so it swaps the halves of a `u32` and
+    /// reverses the four halfwords of a `u64`. Values of 16 bits or fewer are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0x1234_5678_u32.swap_halfwords(), 0x5678_1234);
+    /// assert_eq!(0x0001_0002_0003_0004_u64.swap_halfwords(), 0x0004_0003_0002_0001);
+    /// ```
+    fn swap_halfwords(&self) -> Self;
+
+    /// Swaps the high and low nibbles of each byte, leaving the bytes where they are.
+    ///
+    /// This turns `0xa5` into `0x5a` in every byte of the value, like swapping the two digits of
+    /// each byte of packed BCD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0xa5_u8.nibble_swap(), 0x5a);
+    /// assert_eq!(0x1234_5678_u32.nibble_swap(), 0x2143_6587);
+    /// ```
+    fn nibble_swap(&self) -> Self;
+
+    /// Reverses the order of the nibbles of a value.
+    ///
+    /// This is `swap_bytes` with nibbles instead of bytes, which is the same as swapping the
+    /// nibbles of each byte and then swapping the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0x1234_5678_u32.swap_nibbles_across(), 0x8765_4321);
+    /// assert_eq!(0xa5_u8.swap_nibbles_across(), 0x5a);
+    /// ```
+    fn swap_nibbles_across(&self) -> Self;
+
+    /// Splits a value into the bits in its even positions and the bits in its odd positions.
+    ///
+    /// This de-multiplexes two streams of bits captured alternately into one word. The bits of each
+    /// stream are gathered into the lower half of a value, in order, and returned as
+    /// `(even, odd)`. It's the inverse of [`interleave_with`](BitPermute::interleave_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0b1101_u8.deinterleave_bits(), (0b11, 0b10));
+    /// assert_eq!(0xaaaa_5555_u32.deinterleave_bits(), (0x00ff, 0xff00));
+    /// ```
+    fn deinterleave_bits(&self) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Interleaves the bits of two values, with `self` in the even positions and `other` in the
+    /// odd positions.
+    ///
+    /// Interleaving two values doubles their width, so the result is returned as `(low, high)`,
+    /// like a [`widening_mul`](crate::WideningMul::widening_mul). The low word interleaves the
+    /// lower halves of the values and the high word interleaves the upper halves, so when both
+    /// values fit in half the width, as the results of
+    /// [`deinterleave_bits`](BitPermute::deinterleave_bits) do, the high word is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(0b11_u8.interleave_with(0b10), (0b1101, 0));
+    /// assert_eq!(0x00ff_u16.interleave_with(0xff00), (0x5555, 0xaaaa));
+    ///
+    /// let (even, odd) = 0x1234_5678_u32.deinterleave_bits();
+    /// assert_eq!(even.interleave_with(odd), (0x1234_5678, 0));
+    /// ```
+    fn interleave_with(&self, other: Self) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Gathers the bits of a value selected by a mask into the low bits, in order.
+    ///
+    /// This is the `PEXT` instruction of x86's BMI2 extension. The bit of `self` at the lowest set
+    /// bit of `mask` becomes bit 0 of the result, the one at the next set bit becomes bit 1, and
+    /// so on; the rest of the result is zero. It's the inverse of
+    /// [`deposit_bits`](BitPermute::deposit_bits).
+    ///
+    /// On x86-64 processors that support BMI2, values up to 64 bits use the instruction, which is
+    /// checked for at runtime. Otherwise it takes a step for each set bit of the mask.
+    ///
+    /// The primitives have unstable methods of the same name in the standard library, so calling
+    /// this with method syntax gives an `unstable_name_collisions` warning. Calling it through the
+    /// trait avoids it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(BitPermute::extract_bits(&0b1011_0110_u8, 0b1111_0000), 0b1011);
+    /// assert_eq!(BitPermute::extract_bits(&0b1011_0110_u8, 0b0101_0101), 0b0110);
+    /// assert_eq!(BitPermute::extract_bits(&0x1234_5678_u32, 0xff00_00ff), 0x1278);
+    /// ```
+    fn extract_bits(&self, mask: Self) -> Self;
+
+    /// Scatters the low bits of a value into the positions of the set bits of a mask, in order.
+    ///
+    /// This is the `PDEP` instruction of x86's BMI2 extension. Bit 0 of `self` goes to the lowest
+    /// set bit of `mask`, bit 1 goes to the next set bit, and so on; the rest of the result is
+    /// zero. It's the inverse of [`extract_bits`](BitPermute::extract_bits), so
+    /// `x.extract_bits(mask).deposit_bits(mask) == x & mask`.
+    ///
+    /// On x86-64 processors that support BMI2, values up to 64 bits use the instruction, which is
+    /// checked for at runtime. Otherwise it takes a step for each set bit of the mask.
+    ///
+    /// The primitives have unstable methods of the same name in the standard library, so calling
+    /// this with method syntax gives an `unstable_name_collisions` warning. Calling it through the
+    /// trait avoids it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::BitPermute;
+    ///
+    /// assert_eq!(BitPermute::deposit_bits(&0b1011_u8, 0b1111_0000), 0b1011_0000);
+    /// assert_eq!(BitPermute::deposit_bits(&0b0110_u8, 0b0101_0101), 0b0001_0100);
+    /// assert_eq!(BitPermute::deposit_bits(&0x1278_u32, 0xff00_00ff), 0x1200_0078);
+    /// ```
+    fn deposit_bits(&self, mask: Self) -> Self;
+}
+
+macro_rules! bit_permute_impl {
+    ($($type:ident),*) => {
+        $(
+            impl BitPermute for $type {
+                fn reverse_bit_order_in_bytes(&self) -> Self {
+                    let value = *self;
+                    let value = value >> 1 & EVEN_BITS as $type | (value & EVEN_BITS as $type) << 1;
+                    let value =
+                        value >> 2 & EVEN_PAIRS as $type | (value & EVEN_PAIRS as $type) << 2;
+                    value.nibble_swap()
+                }
+
+                fn swap_bytes_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> Self {
+                    let bits = Self::BITS as usize;
+                    let (start, length) = range_bounds(&range);
+                    let length = length.unwrap_or(bits - start.min(bits));
+                    assert!(length % 8 == 0, "the range must cover a whole number of bytes");
+                    assert!(start + length <= bits, "the range must lie within the type");
+
+                    if length == 0 {
+                        return *self;
+                    }
+
+                    let range = start..start + length;
+                    let field = self.bits(range.clone()).swap_bytes() >> (bits - length);
+                    self.with_bits(range, field)
+                }
+
+                fn swap_halfwords(&self) -> Self {
+                    if Self::BITS <= 16 {
+                        return *self;
+                    }
+
+                    let value = self.swap_bytes();
+                    value >> 8 & EVEN_BYTES as $type | (value & EVEN_BYTES as $type) << 8
+                }
+
+                fn nibble_swap(&self) -> Self {
+                    *self >> 4 & EVEN_NIBBLES as $type | (*self & EVEN_NIBBLES as $type) << 4
+                }
+
+                fn swap_nibbles_across(&self) -> Self {
+                    self.nibble_swap().swap_bytes()
+                }
+
+                fn deinterleave_bits(&self) -> (Self, Self) {
+                    let compact = |value: $type| {
+                        let mut value = value & EVEN_BITS as $type;
+                        for level in 0..Self::BITS.trailing_zeros() - 1 {
+                            let mask = EVEN_GROUPS[level as usize + 1] as $type;
+                            value = (value | value >> (1 << level)) & mask;
+                        }
+                        value
+                    };
+
+                    (compact(*self), compact(*self >> 1))
+                }
+
+                fn interleave_with(&self, other: Self) -> (Self, Self) {
+                    let half = Self::BITS / 2;
+                    let dilate = |value: $type| {
+                        let mut value = value & EVEN_GROUPS[half.trailing_zeros() as usize] as $type;
+                        for level in (0..half.trailing_zeros()).rev() {
+                            let mask = EVEN_GROUPS[level as usize] as $type;
+                            value = (value | value << (1 << level)) & mask;
+                        }
+                        value
+                    };
+
+                    (
+                        dilate(*self) | dilate(other) << 1,
+                        dilate(*self >> half) | dilate(other >> half) << 1,
+                    )
+                }
+
+                fn extract_bits(&self, mask: Self) -> Self {
+                    #[cfg(target_arch = "x86_64")]
+                    {
+                        if Self::BITS <= 64 {
+                            if let Some(bits) = bmi2::extract(*self as u64, mask as u64) {
+                                return bits as $type;
+                            }
+                        }
+                    }
+
+                    extract(*self as u128, mask as u128) as $type
+                }
+
+                fn deposit_bits(&self, mask: Self) -> Self {
+                    #[cfg(target_arch = "x86_64")]
+                    {
+                        if Self::BITS <= 64 {
+                            if let Some(bits) = bmi2::deposit(*self as u64, mask as u64) {
+                                return bits as $type;
+                            }
+                        }
+                    }
+
+                    deposit(*self as u128, mask as u128) as $type
+                }
+            }
+        )*
+    };
+}
+
+bit_permute_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed_bit_permute_impl {
+    ($($type:ident as $unsigned:ident),*) => {
+        $(
+            impl BitPermute for $type {
+                fn reverse_bit_order_in_bytes(&self) -> Self {
+                    (*self as $unsigned).reverse_bit_order_in_bytes() as $type
+                }
+
+                fn swap_bytes_in<Idx: RangeBounds<usize>>(&self, range: Idx) -> Self {
+                    (*self as $unsigned).swap_bytes_in(range) as $type
+                }
+
+                fn swap_halfwords(&self) -> Self {
+                    (*self as $unsigned).swap_halfwords() as $type
+                }
+
+                fn nibble_swap(&self) -> Self {
+                    (*self as $unsigned).nibble_swap() as $type
+                }
+
+                fn swap_nibbles_across(&self) -> Self {
+                    (*self as $unsigned).swap_nibbles_across() as $type
+                }
+
+                fn deinterleave_bits(&self) -> (Self, Self) {
+                    let (even, odd) = (*self as $unsigned).deinterleave_bits();
+                    (even as $type, odd as $type)
+                }
+
+                fn interleave_with(&self, other: Self) -> (Self, Self) {
+                    let (low, high) = (*self as $unsigned).interleave_with(other as $unsigned);
+                    (low as $type, high as $type)
+                }
+
+                fn extract_bits(&self, mask: Self) -> Self {
+                    BitPermute::extract_bits(&(*self as $unsigned), mask as $unsigned) as $type
+                }
+
+                fn deposit_bits(&self, mask: Self) -> Self {
+                    BitPermute::deposit_bits(&(*self as $unsigned), mask as $unsigned) as $type
+                }
+            }
+        )*
+    };
+}
+
+signed_bit_permute_impl!(
+    i8 as u8,
+    i16 as u16,
+    i32 as u32,
+    i64 as u64,
+    i128 as u128,
+    isize as usize
+);
+
+/// Gathers the bits of `value` selected by `mask` into the low bits, one set bit of the mask at a
+/// time.
+fn extract(value: u128, mut mask: u128) -> u128 {
+    let mut bits = 0;
+    let mut index = 0;
+    while mask != 0 {
+        let lowest = mask & mask.wrapping_neg();
+        if value & lowest != 0 {
+            bits |= 1 << index;
+        }
+        mask ^= lowest;
+        index += 1;
+    }
+    bits
+}
+
+/// Scatters the low bits of `value` into the set bits of `mask`, one set bit of the mask at a time.
+fn deposit(value: u128, mut mask: u128) -> u128 {
+    let mut bits = 0;
+    let mut index = 0;
+    while mask != 0 {
+        let lowest = mask & mask.wrapping_neg();
+        if value >> index & 1 != 0 {
+            bits |= lowest;
+        }
+        mask ^= lowest;
+        index += 1;
+    }
+    bits
+}
+
+/// The `PEXT` and `PDEP` instructions, when the processor has them.
+///
+/// Smaller values zero-extended to 64 bits give the same results, so these serve every type up to
+/// 64 bits.
+#[cfg(target_arch = "x86_64")]
+mod bmi2 {
+    use std::arch::x86_64::{_pdep_u64, _pext_u64};
+
+    pub(super) fn extract(value: u64, mask: u64) -> Option<u64> {
+        if is_x86_feature_detected!("bmi2") {
+            // The processor supports BMI2, so the instruction is available
+            Some(unsafe { pext(value, mask) })
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn deposit(value: u64, mask: u64) -> Option<u64> {
+        if is_x86_feature_detected!("bmi2") {
+            // The processor supports BMI2, so the instruction is available
+            Some(unsafe { pdep(value, mask) })
+        } else {
+            None
+        }
+    }
+
+    #[target_feature(enable = "bmi2")]
+    unsafe fn pext(value: u64, mask: u64) -> u64 {
+        _pext_u64(value, mask)
+    }
+
+    #[target_feature(enable = "bmi2")]
+    unsafe fn pdep(value: u64, mask: u64) -> u64 {
+        _pdep_u64(value, mask)
+    }
+}
+
+/// Interleaves the bits of two values into a Morton code, and back.
+///
+/// A Morton code, or Z-order index, holds the bits of `x` in its even bits and the bits of `y` in
+/// its odd bits, so points that are close in two dimensions tend to be close in the code. It's
+/// used for spatial hashing, linearizing quadtrees, and swizzling textures. The code is twice as
+/// wide as the coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Morton;
+///
+/// assert_eq!(u32::interleave(0b11, 0b01), 0b0111);
+/// assert_eq!(u8::interleave(0xff, 0x00), 0x5555);
+/// assert_eq!(u32::deinterleave(0b0111), (0b11, 0b01));
+/// ```
+pub trait Morton: Sized {
+    /// The type of the code, twice as wide as the coordinates.
+    type Code;
+
+    /// Interleaves the bits of two values, with `x` in the even bits and `y` in the odd bits.
+    fn interleave(x: Self, y: Self) -> Self::Code;
+
+    /// Splits a code into the values in its even and odd bits, as `(x, y)`.
+    fn deinterleave(code: Self::Code) -> (Self, Self);
+}
+
+macro_rules! morton_impl {
+    ($($type:ident => $code:ident),*) => {
+        $(
+            impl Morton for $type {
+                type Code = $code;
+
+                fn interleave(x: $type, y: $type) -> $code {
+                    (x as $code).interleave_with(y as $code).0
+                }
+
+                fn deinterleave(code: $code) -> ($type, $type) {
+                    let (x, y) = code.deinterleave_bits();
+                    (x as $type, y as $type)
+                }
+            }
+        )*
+    };
+}
+
+morton_impl!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+
+#[cfg(test)]
+#[allow(unstable_name_collisions)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn reversing_bit_order_in_bytes() {
+        asserting!("bits reverse within each byte")
+            .that(&(
+                0x01_u8.reverse_bit_order_in_bytes(),
+                0x0f_01_u16.reverse_bit_order_in_bytes(),
+                0xa5_c3_1e_80_u32.reverse_bit_order_in_bytes(),
+                0x0102_0408_1020_4080_u64.reverse_bit_order_in_bytes(),
+                u128::MAX.reverse_bit_order_in_bytes(),
+                1_u128.reverse_bit_order_in_bytes(),
+                (-2_i16).reverse_bit_order_in_bytes(),
+            ))
+            .is_equal_to((
+                0x80,
+                0xf0_80,
+                0xa5_c3_78_01,
+                0x8040_2010_0804_0201,
+                u128::MAX,
+                0x80,
+                0xff_7f_u16 as i16,
+            ));
+
+        for seed in random_u64s().take(1000) {
+            let wide = (seed as u128) << 64 | seed.rotate_left(7) as u128;
+
+            asserting!("swapping the bytes afterwards reverses the whole value")
+                .that(&(
+                    (seed as u8).reverse_bit_order_in_bytes(),
+                    (seed as u16).reverse_bit_order_in_bytes().swap_bytes(),
+                    (seed as i32).reverse_bit_order_in_bytes().swap_bytes(),
+                    seed.reverse_bit_order_in_bytes().swap_bytes(),
+                    wide.reverse_bit_order_in_bytes().swap_bytes(),
+                    (seed as usize).reverse_bit_order_in_bytes().swap_bytes(),
+                ))
+                .is_equal_to((
+                    (seed as u8).reverse_bits(),
+                    (seed as u16).reverse_bits(),
+                    (seed as i32).reverse_bits(),
+                    seed.reverse_bits(),
+                    wide.reverse_bits(),
+                    (seed as usize).reverse_bits(),
+                ));
+
+            asserting!("reversing twice gives the value back")
+                .that(
+                    &seed
+                        .reverse_bit_order_in_bytes()
+                        .reverse_bit_order_in_bytes(),
+                )
+                .is_equal_to(seed);
+        }
+    }
+
+    #[test]
+    fn swapping_bytes_in_a_range() {
+        asserting!("fields in the middle and at the ends of the value swap in place")
+            .that(&(
+                0x1122_3344_u32.swap_bytes_in(8..24),
+                0x1122_3344_u32.swap_bytes_in(16..32),
+                0x1122_3344_u32.swap_bytes_in(..16),
+                0x1122_3344_5566_7788_u64.swap_bytes_in(8..=55),
+                0x00ab_cd00_u32.swap_bytes_in(8..24),
+                0x1234_u16.swap_bytes_in(..),
+                0x1234_u16.swap_bytes_in(8..8),
+                0x12_u8.swap_bytes_in(..8),
+                0x1122_3344_i32.swap_bytes_in(24..),
+                (-2_i16).swap_bytes_in(..),
+            ))
+            .is_equal_to((
+                0x1133_2244,
+                0x2211_3344,
+                0x1122_4433,
+                0x1177_6655_4433_2288,
+                0x00cd_ab00,
+                0x3412,
+                0x1234,
+                0x12,
+                0x1122_3344,
+                -257,
+            ));
+
+        asserting!("halfwords swap across the whole value")
+            .that(&(
+                0x12_u8.swap_halfwords(),
+                0x1234_u16.swap_halfwords(),
+                0x1234_5678_u32.swap_halfwords(),
+                0x1111_2222_3333_4444_u64.swap_halfwords(),
+                1_u128.swap_halfwords(),
+                0x0001_ffff_i32.swap_halfwords(),
+            ))
+            .is_equal_to((
+                0x12,
+                0x1234,
+                0x5678_1234,
+                0x4444_3333_2222_1111,
+                1 << 112,
+                0xffff_0001_u32 as i32,
+            ));
+
+        for seed in random_u64s().take(1000) {
+            let start = (seed >> 58) as usize;
+            let length = (seed >> 56) as usize % 4 * 8;
+
+            if start + length <= 64 {
+                asserting!("swapping twice gives the value back")
+                    .that(
+                        &seed
+                            .swap_bytes_in(start..start + length)
+                            .swap_bytes_in(start..start + length),
+                    )
+                    .is_equal_to(seed);
+            }
+
+            asserting!("the whole value swaps like swap_bytes")
+                .that(&(seed.swap_bytes_in(..), (seed as u32).swap_halfwords()))
+                .is_equal_to((seed.swap_bytes(), (seed as u32).rotate_left(16)));
+        }
+    }
+
+    #[test]
+    fn swapping_nibbles() {
+        asserting!("nibbles swap within each byte")
+            .that(&(
+                0xa5_u8.nibble_swap(),
+                0x0f_u8.nibble_swap(),
+                0x1234_5678_u32.nibble_swap(),
+                0x0123_4567_89ab_cdef_u64.nibble_swap(),
+                1_u128.nibble_swap(),
+                (-2_i16).nibble_swap(),
+            ))
+            .is_equal_to((
+                0x5a,
+                0xf0,
+                0x2143_6587,
+                0x1032_5476_98ba_dcfe,
+                0x10,
+                0xffef_u16 as i16,
+            ));
+
+        asserting!("nibbles reverse across the whole value")
+            .that(&(
+                0xa5_u8.swap_nibbles_across(),
+                0x1234_u16.swap_nibbles_across(),
+                0x0123_4567_89ab_cdef_u64.swap_nibbles_across(),
+                1_u128.swap_nibbles_across(),
+                0x1234_5678_i32.swap_nibbles_across(),
+            ))
+            .is_equal_to((
+                0x5a,
+                0x4321,
+                0xfedc_ba98_7654_3210,
+                1 << 124,
+                0x8765_4321_u32 as i32,
+            ));
+
+        for seed in random_u64s().take(1000) {
+            asserting!("swapping twice gives the value back")
+                .that(&(
+                    (seed as u8).nibble_swap().nibble_swap(),
+                    (seed as u32).nibble_swap().nibble_swap(),
+                    seed.swap_nibbles_across().swap_nibbles_across(),
+                ))
+                .is_equal_to((seed as u8, seed as u32, seed));
+
+            asserting!("each byte swaps like a u8")
+                .that(&seed.nibble_swap().to_le_bytes())
+                .is_equal_to(seed.to_le_bytes().map(|byte| byte.rotate_left(4)));
+        }
+    }
+    #[test]
+    #[should_panic(expected = "the range must cover a whole number of bytes")]
+    fn swapping_part_of_a_byte() {
+        0x1234_u16.swap_bytes_in(0..12);
+    }
+
+    #[test]
+    #[should_panic(expected = "the range must lie within the type")]
+    fn swapping_past_the_end() {
+        0x1234_u16.swap_bytes_in(8..24);
+    }
+
+    #[test]
+    fn splitting_even_and_odd_bits() {
+        asserting!("even and odd bits gather into the lower half")
+            .that(&(
+                0b1101_u8.deinterleave_bits(),
+                0x5555_u16.deinterleave_bits(),
+                0xaaaa_5555_u32.deinterleave_bits(),
+                u128::MAX.deinterleave_bits(),
+                (-1_i64).deinterleave_bits(),
+            ))
+            .is_equal_to((
+                (0b11, 0b10),
+                (0xff, 0),
+                (0x00ff, 0xff00),
+                (u64::MAX as u128, u64::MAX as u128),
+                (u32::MAX as i64, u32::MAX as i64),
+            ));
+
+        asserting!("the upper halves interleave into the high word")
+            .that(&(
+                0b11_u8.interleave_with(0b10),
+                0x00ff_u16.interleave_with(0xff00),
+                u128::MAX.interleave_with(0),
+                (-1_i8).interleave_with(0),
+            ))
+            .is_equal_to((
+                (0b1101, 0),
+                (0x5555, 0xaaaa),
+                (EVEN_BITS, EVEN_BITS),
+                (0x55, 0x55),
+            ));
+
+        for seed in random_u64s().take(1000) {
+            let other = seed.rotate_left(29);
+
+            // The bit-by-bit definitions
+            let even = (0..32).fold(0, |even, i| even | (seed >> (2 * i) & 1) << i);
+            let odd = (0..32).fold(0, |odd, i| odd | (seed >> (2 * i + 1) & 1) << i);
+            let interleaved = (0..64).fold(0_u128, |wide, i| {
+                wide | ((seed >> i & 1) as u128) << (2 * i)
+                    | ((other >> i & 1) as u128) << (2 * i + 1)
+            });
+            asserting!("splitting and interleaving match moving the bits one at a time")
+                .that(&(seed.deinterleave_bits(), seed.interleave_with(other)))
+                .is_equal_to((
+                    (even, odd),
+                    (interleaved as u64, (interleaved >> 64) as u64),
+                ));
+
+            let (even, odd) = seed.deinterleave_bits();
+            let (low, high) = seed.interleave_with(other);
+            asserting!("values round-trip through both directions")
+                .that(&(
+                    even.interleave_with(odd),
+                    (low.deinterleave_bits(), high.deinterleave_bits()),
+                    (seed as u8)
+                        .deinterleave_bits()
+                        .0
+                        .interleave_with((seed as u8).deinterleave_bits().1),
+                ))
+                .is_equal_to((
+                    (seed, 0),
+                    (
+                        (seed & 0xffff_ffff, other & 0xffff_ffff),
+                        (seed >> 32, other >> 32),
+                    ),
+                    (seed as u8, 0),
+                ));
+        }
+    }
+
+    #[test]
+    fn extracting_and_depositing_bits() {
+        asserting!("selected bits gather into the low bits")
+            .that(&(
+                0b1011_0110_u8.extract_bits(0b0101_0101),
+                0x1234_5678_u32.extract_bits(0xff00_00ff),
+                u64::MAX.extract_bits(1 << 63 | 1),
+                (1_u128 << 127).extract_bits(u128::MAX << 100),
+                0x1234_u16.extract_bits(0),
+                (-1_i16).extract_bits(0x0ff0),
+            ))
+            .is_equal_to((0b0110, 0x1278, 0b11, 1 << 27, 0, 0xff));
+
+        asserting!("low bits scatter into the selected bits")
+            .that(&(
+                0b0110_u8.deposit_bits(0b0101_0101),
+                0x1278_u32.deposit_bits(0xff00_00ff),
+                0b10_u64.deposit_bits(1 << 63 | 1),
+                (1_u128 << 27).deposit_bits(u128::MAX << 100),
+                u16::MAX.deposit_bits(0),
+                (-1_i16).deposit_bits(i16::MIN),
+            ))
+            .is_equal_to((0b0001_0100, 0x1200_0078, 1 << 63, 1 << 127, 0, i16::MIN));
+
+        for seed in random_u64s().take(1000) {
+            let mask = seed.rotate_left(23) & seed.rotate_left(41);
+
+            // The bit-by-bit definitions, walking every bit of the value
+            let positions = (0..64).filter(|&i| mask >> i & 1 != 0);
+            let extracted = positions
+                .clone()
+                .enumerate()
+                .fold(0, |bits, (index, i)| bits | (seed >> i & 1) << index);
+            let deposited = positions
+                .enumerate()
+                .fold(0, |bits, (index, i)| bits | (seed >> index & 1) << i);
+            asserting!("bits move like they do one at a time")
+                .that(&(
+                    seed.extract_bits(mask),
+                    seed.deposit_bits(mask),
+                    extract(seed as u128, mask as u128),
+                    deposit(seed as u128, mask as u128),
+                ))
+                .is_equal_to((extracted, deposited, extracted as u128, deposited as u128));
+
+            let wide = (seed as u128) << 64 | seed.rotate_left(7) as u128;
+            let wide_mask = (mask as u128) << 64 | seed as u128;
+            asserting!("extracted bits deposit back where they came from")
+                .that(&(
+                    seed.extract_bits(mask).deposit_bits(mask),
+                    (seed as u16)
+                        .extract_bits(mask as u16)
+                        .deposit_bits(mask as u16),
+                    wide.extract_bits(wide_mask).deposit_bits(wide_mask),
+                    wide.deposit_bits(wide_mask).extract_bits(wide_mask),
+                ))
+                .is_equal_to((
+                    seed & mask,
+                    seed as u16 & mask as u16,
+                    wide & wide_mask,
+                    wide & u128::MAX >> ((128 - wide_mask.count_ones()) % 128),
+                ));
+
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("bmi2") {
+                    asserting!("the instructions agree with the portable version")
+                        .that(&(bmi2::extract(seed, mask), bmi2::deposit(seed, mask)))
+                        .is_equal_to((
+                            Some(extract(seed as u128, mask as u128) as u64),
+                            Some(deposit(seed as u128, mask as u128) as u64),
+                        ));
+                }
+            }
+        }
+    }
+    #[test]
+    fn morton_codes() {
+        asserting!("coordinates go in the even and odd bits")
+            .that(&(
+                u32::interleave(0b11, 0b01),
+                u8::interleave(0xff, 0),
+                u8::interleave(0, 0xff),
+                u16::interleave(0x8000, 1),
+                u32::interleave(u32::MAX, u32::MAX),
+                u64::interleave(1 << 63, 0),
+            ))
+            .is_equal_to((0b0111, 0x5555, 0xaaaa, 1 << 30 | 2, u64::MAX, 1 << 126));
+
+        asserting!("codes split back into coordinates")
+            .that(&(
+                u32::deinterleave(0b0111),
+                u8::deinterleave(0xaaaa),
+                u16::deinterleave(0x4000_0002),
+                u64::deinterleave(u128::MAX),
+            ))
+            .is_equal_to(((0b11, 0b01), (0, 0xff), (0x8000, 1), (u64::MAX, u64::MAX)));
+
+        for seed in random_u64s().take(1000) {
+            let (x, y) = (seed as u32, (seed >> 32) as u32);
+
+            // The bit-by-bit definition
+            let expected = (0..32).fold(0_u64, |code, i| {
+                code | (x as u64 >> i & 1) << (2 * i) | (y as u64 >> i & 1) << (2 * i + 1)
+            });
+            asserting!("interleaving matches placing the bits one at a time")
+                .that(&u32::interleave(x, y))
+                .is_equal_to(expected);
+
+            asserting!("coordinates round-trip through the code")
+                .that(&(
+                    u8::deinterleave(u8::interleave(x as u8, y as u8)),
+                    u16::deinterleave(u16::interleave(x as u16, y as u16)),
+                    u32::deinterleave(u32::interleave(x, y)),
+                    u64::deinterleave(u64::interleave(seed, !seed)),
+                ))
+                .is_equal_to((
+                    (x as u8, y as u8),
+                    (x as u16, y as u16),
+                    (x, y),
+                    (seed, !seed),
+                ));
+
+            asserting!("codes round-trip through the coordinates")
+                .that(&u32::interleave(
+                    u32::deinterleave(seed).0,
+                    u32::deinterleave(seed).1,
+                ))
+                .is_equal_to(seed);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{Int, UInt};
+
+// Powers are computed on the full 128 bits the value is stored in and then fitted to the width.
+// Wrapping at 128 bits and then at the width gives the same result as wrapping at the width all
+// along, and a power that overflows 128 bits can't fit in the width either, so the checked version
+// only needs to check the result. Checking the storage alone isn't enough: 2^12 fits in a `u128`
+// but not in a `UInt<12>`.
+
+macro_rules! pow_impl {
+    ($($type:ident, $base:literal, $fits:literal, $power:literal, $overflows:literal;)*) => {
+        $(
+            impl<const BITS: usize> $type<BITS> {
+                #[doc = concat!(
+                    "Raises the value to the power of `exp`, wrapping around at the width of the ",
+                    "type.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let base = ", stringify!($type), "::<12>::new(", $base, ");\n",
+                    "assert_eq!(base.wrapping_pow(", $fits, ").get(), ", $power, ");\n",
+                    "assert_eq!(base.wrapping_pow(", $overflows, ").get(), 0);\n",
+                    "```",
+                )]
+                pub const fn wrapping_pow(self, exp: u32) -> Self {
+                    Self::masked(self.get().wrapping_pow(exp))
+                }
+
+                #[doc = concat!(
+                    "Raises the value to the power of `exp`, returning `None` if the result ",
+                    "doesn't fit in the width of the type.\n",
+                    "\n",
+                    "# Examples\n",
+                    "\n",
+                    "```\n",
+                    "use quark::", stringify!($type), ";\n",
+                    "\n",
+                    "let base = ", stringify!($type), "::<12>::new(", $base, ");\n",
+                    "assert_eq!(base.checked_pow(", $fits, ").map(", stringify!($type),
+                    "::get), Some(", $power, "));\n",
+                    "assert_eq!(base.checked_pow(", $overflows, "), None);\n",
+                    "```",
+                )]
+                pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+                    match self.get().checked_pow(exp) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Raises the value to the power of `exp`, returning the wrapped result and whether
+                /// it overflowed the width of the type.
+                pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                    (self.wrapping_pow(exp), self.checked_pow(exp).is_none())
+                }
+
+                /// Raises the value to the power of `exp`.
+                ///
+                /// Like `pow` on the primitives, this panics if the result doesn't fit in the width
+                /// when debug assertions are enabled, and wraps around otherwise.
+                pub const fn pow(self, exp: u32) -> Self {
+                    if cfg!(debug_assertions) {
+                        match self.checked_pow(exp) {
+                            Some(value) => value,
+                            None => panic!("attempt to multiply with overflow"),
+                        }
+                    } else {
+                        self.wrapping_pow(exp)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+pow_impl! {
+    UInt, "2", "11", "0x800", "12";
+    Int, "-2", "11", "-0x800", "12";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn powers_of_two() {
+        asserting!("powers of two up to the top bit fit")
+            .that(&(
+                UInt::<12>::new(2).checked_pow(11),
+                UInt::<128>::new(2).checked_pow(127),
+                Int::<12>::new(2).checked_pow(10),
+                Int::<12>::new(-2).checked_pow(11),
+                Int::<128>::new(-2).checked_pow(127),
+            ))
+            .is_equal_to((
+                Some(UInt::new(0x800)),
+                Some(UInt::new(1 << 127)),
+                Some(Int::new(0x400)),
+                Some(Int::MIN),
+                Some(Int::MIN),
+            ));
+
+        asserting!("powers of two one bit past the width overflow")
+            .that(&(
+                UInt::<12>::new(2).checked_pow(12),
+                UInt::<128>::new(2).checked_pow(128),
+                Int::<12>::new(2).checked_pow(11),
+                Int::<12>::new(-2).checked_pow(12),
+                Int::<128>::new(2).checked_pow(127),
+            ))
+            .is_equal_to((None, None, None, None, None));
+
+        asserting!("wrapping keeps the bits inside the width")
+            .that(&(
+                UInt::<12>::new(2).wrapping_pow(12),
+                UInt::<12>::new(3).wrapping_pow(8),
+                Int::<12>::new(2).wrapping_pow(11),
+                Int::<12>::new(3).overflowing_pow(8),
+            ))
+            .is_equal_to((
+                UInt::ZERO,
+                UInt::new(6561 % 4096),
+                Int::MIN,
+                (Int::new(6561 % 4096 - 4096), true),
+            ));
+    }
+
+    #[test]
+    fn zero_exponents() {
+        asserting!("anything to the power of zero is one")
+            .that(&(
+                UInt::<1>::ZERO.pow(0),
+                UInt::<12>::MAX.pow(0),
+                Int::<12>::MIN.pow(0),
+            ))
+            .is_equal_to((UInt::ONE, UInt::ONE, Int::ONE));
+
+        asserting!("one doesn't fit in Int<1>")
+            .that(&(
+                Int::<1>::new(-1).checked_pow(0),
+                Int::<1>::ZERO.overflowing_pow(0),
+            ))
+            .is_equal_to((None, (Int::new(-1), true)));
+    }
+
+    #[test]
+    fn matching_a_wider_model() {
+        for base in -32_i128..32 {
+            for exp in 0..8 {
+                let expected = base.pow(exp);
+                let fits = (-32..32).contains(&expected);
+
+                asserting!("signed powers match the model")
+                    .that(&(
+                        Int::<6>::new(base).checked_pow(exp).map(Int::get),
+                        Int::<6>::new(base).wrapping_pow(exp).get(),
+                    ))
+                    .is_equal_to((
+                        if fits { Some(expected) } else { None },
+                        Int::<6>::masked(expected).get(),
+                    ));
+            }
+        }
+
+        for base in 0_u128..64 {
+            for exp in 0..8 {
+                let expected = base.pow(exp);
+
+                asserting!("unsigned powers match the model")
+                    .that(&(
+                        UInt::<6>::new(base).checked_pow(exp).map(UInt::get),
+                        UInt::<6>::new(base).wrapping_pow(exp).get(),
+                    ))
+                    .is_equal_to((
+                        if expected < 64 { Some(expected) } else { None },
+                        expected % 64,
+                    ));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to multiply with overflow")
+    )]
+    fn overflowing_powers_panic() {
+        assert_eq!(UInt::<12>::new(2).pow(12), UInt::MIN);
+    }
+}
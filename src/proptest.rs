@@ -0,0 +1,182 @@
+//! Strategies for testing code built on `quark` with [`proptest`](https://docs.rs/proptest).
+//!
+//! This module is only available with the `proptest` feature. It provides strategies for the
+//! narrow integer types, for bit ranges and fields that fit in a type, and for values paired with
+//! a bit range to pass to [`BitIndex::bits`](../trait.BitIndex.html#tymethod.bits). The integer
+//! strategies shrink towards zero, and the range strategies shrink towards empty ranges at the
+//! bottom of the value.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use quark::proptest::{any_uint, field_within};
+//!
+//! proptest! {
+//!     fn extract_then_insert_is_identity(value in any::<u32>(), field in field_within(32)) {
+//!         prop_assert_eq!(field.insert(value, field.extract(value)), value);
+//!     }
+//!
+//!     fn narrow_values_fit(value in any_uint::<12>()) {
+//!         prop_assert!(value.get() < 0x1000);
+//!     }
+//! }
+//! # extract_then_insert_is_identity();
+//! # narrow_values_fit();
+//! ```
+
+use crate::{Field, Int, UInt};
+use ::proptest::strategy::{Just, Strategy};
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+
+/// Returns a strategy for every value of a `UInt`.
+pub fn any_uint<const BITS: usize>() -> impl Strategy<Value = UInt<BITS>> {
+    uint_in(UInt::MIN..=UInt::MAX)
+}
+
+/// Returns a strategy for every value of an `Int`.
+pub fn any_int<const BITS: usize>() -> impl Strategy<Value = Int<BITS>> {
+    int_in(Int::MIN..=Int::MAX)
+}
+
+/// Returns a strategy for the values of a `UInt` in a range.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+pub fn uint_in<const BITS: usize>(
+    range: RangeInclusive<UInt<BITS>>,
+) -> impl Strategy<Value = UInt<BITS>> {
+    (range.start().get()..=range.end().get()).prop_map(UInt::masked)
+}
+
+/// Returns a strategy for the values of an `Int` in a range.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+pub fn int_in<const BITS: usize>(
+    range: RangeInclusive<Int<BITS>>,
+) -> impl Strategy<Value = Int<BITS>> {
+    (range.start().get()..=range.end().get()).prop_map(Int::masked)
+}
+
+/// Returns a strategy for ranges of bit indices within the lowest `width` bits, including empty
+/// ranges and the whole width.
+pub fn bit_range_within(width: usize) -> impl Strategy<Value = Range<usize>> {
+    (0..=width)
+        .prop_flat_map(move |start| (Just(start), 0..=width - start))
+        .prop_map(|(start, len)| start..start + len)
+}
+
+/// Returns a strategy for fields that lie within the lowest `width` bits, including empty fields
+/// and the whole width.
+pub fn field_within(width: usize) -> impl Strategy<Value = Field> {
+    bit_range_within(width).prop_map(|range| Field::new(range.start, range.len()))
+}
+
+/// Returns a strategy for values paired with a range of bits within the lowest `width` bits,
+/// ready to pass to [`BitIndex::bits`](../trait.BitIndex.html#tymethod.bits) or
+/// [`BitIndex::with_bits`](../trait.BitIndex.html#method.with_bits).
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use quark::proptest::{any_uint, value_and_range};
+/// use quark::BitIndex;
+///
+/// proptest! {
+///     fn reading_fewer_bits(
+///         (value, range) in value_and_range(any_uint::<20>(), 20),
+///     ) {
+///         let len = range.len();
+///         prop_assert!(value.bits(range).get() < 1 << len);
+///     }
+/// }
+/// # reading_fewer_bits();
+/// ```
+pub fn value_and_range<S>(
+    values: S,
+    width: usize,
+) -> impl Strategy<Value = (S::Value, Range<usize>)>
+where
+    S: Strategy,
+    S::Value: Debug,
+{
+    (values, bit_range_within(width))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BitIndex, BitMask};
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn narrow_values_are_in_range(
+            unsigned in any_uint::<12>(),
+            signed in any_int::<12>(),
+            ranged in uint_in(UInt::<12>::new(0x100)..=UInt::new(0x1ff)),
+            negative in int_in(Int::<12>::MIN..=Int::new(-1)),
+        ) {
+            prop_assert_eq!(UInt::<12>::try_new(unsigned.get()), Ok(unsigned));
+            prop_assert_eq!(Int::<12>::try_new(signed.get()), Ok(signed));
+            prop_assert!(ranged.get() >> 8 == 1);
+            prop_assert!(negative.get() < 0);
+        }
+
+        #[test]
+        fn fields_fit_in_the_width(field in field_within(12), range in bit_range_within(12)) {
+            prop_assert!(field.offset() + field.width() <= 12);
+            prop_assert!(range.start <= range.end && range.end <= 12);
+        }
+
+        #[test]
+        fn extracting_then_inserting_bits_is_identity(
+            (value, range) in value_and_range(any::<u64>(), 64),
+            (narrow, narrow_range) in value_and_range(any_uint::<20>(), 20),
+            (signed, signed_range) in value_and_range(any_int::<20>(), 20),
+        ) {
+            prop_assert_eq!(value.with_bits(range.clone(), value.bits(range)), value);
+            prop_assert_eq!(
+                narrow.with_bits(narrow_range.clone(), narrow.bits(narrow_range)),
+                narrow
+            );
+            prop_assert_eq!(
+                signed.with_bits(signed_range.clone(), signed.bits(signed_range)),
+                signed
+            );
+        }
+
+        #[test]
+        fn inserting_then_extracting_bits_masks_the_value(
+            (value, range) in value_and_range(any_uint::<20>(), 20),
+            bits in any_uint::<20>(),
+        ) {
+            prop_assert_eq!(
+                value.with_bits(range.clone(), bits).bits(range.clone()),
+                bits.mask_to(range.len())
+            );
+        }
+
+        #[test]
+        fn masks_keep_the_bits_of_the_range(
+            (value, range) in value_and_range(any::<u32>(), 32),
+            narrow in any_uint::<20>(),
+            field in field_within(20),
+        ) {
+            let mask = u32::mask_range(range.clone());
+            prop_assert_eq!(value & mask, value.bits(range.clone()) << (range.start % 32));
+            prop_assert_eq!(mask.count_ones() as usize, range.len());
+
+            prop_assert_eq!(field.insert(narrow, field.extract(narrow)), narrow);
+            prop_assert_eq!(
+                narrow & field.mask::<UInt<20>>(),
+                field.extract(narrow) << field.offset() as u32
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::signs::sealed::Sealed;
+use crate::{BitConsts, BitIndex, BitMask, Signs};
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+/// Combines the bit operation traits with the operators needed to use them generically.
+///
+/// Writing a function that's generic over the primitives takes more than the traits in this
+/// crate: it also needs the bitwise operators, shifts, comparisons, and copying. This trait
+/// gathers all of them into a single bound, and adds constants for zero and one. It's sealed and
+/// implemented for every primitive, and for [`u256`](struct.u256.html) and
+/// [`i256`](struct.i256.html).
+///
+/// # Examples
+///
+/// ```
+/// use quark::Quark;
+///
+/// fn swap_nibbles<T: Quark>(value: T) -> T {
+///     let low = T::mask(4);
+///     value & !(low | low << 4) | (value & low) << 4 | value >> 4 & low
+/// }
+///
+/// assert_eq!(swap_nibbles(0x1234_u16), 0x1243);
+/// assert_eq!(swap_nibbles(0xab_u8), 0xba);
+/// ```
+pub trait Quark:
+    Sealed
+    + BitConsts
+    + BitIndex
+    + BitMask
+    + Signs
+    + Copy
+    + Eq
+    + Ord
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+{
+    /// The value zero.
+    const ZERO: Self;
+    /// The value one.
+    const ONE: Self;
+}
+
+macro_rules! quark_impl {
+    ($($type:ty),*) => {
+        $(
+            impl Quark for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+quark_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{i256, u256, BitSize, SignCast};
+    use std::ops::Range;
+
+    fn crc<T: Quark>(data: &[u8], poly: T, init: T) -> T {
+        let mut crc = init;
+        for byte in data {
+            for bit in (0..8).rev() {
+                let feedback = crc.sign_bit() != byte.bit(bit);
+                crc = crc << 1;
+                if feedback {
+                    crc = crc ^ poly;
+                }
+            }
+        }
+        crc
+    }
+
+    fn swap_fields<T: Quark>(value: T, a: Range<usize>, b: Range<usize>) -> T {
+        let (field_a, field_b) = (value.bits(a.clone()), value.bits(b.clone()));
+        value.with_bits(a, field_b).with_bits(b, field_a)
+    }
+
+    fn count_ones<T: Quark>(mut value: T) -> usize {
+        let mut count = 0;
+        while value != T::ZERO {
+            if value & T::ONE == T::ONE {
+                count += 1;
+            }
+            value = value.logical_shr(1);
+        }
+        count
+    }
+
+    #[test]
+    fn generic_crc() {
+        const CHECK: &[u8] = b"123456789";
+
+        assert_eq!(crc(CHECK, 0x07_u8, 0), 0xf4);
+        assert_eq!(crc(CHECK, 0x07_i8, 0), 0xf4_u8 as i8);
+        assert_eq!(crc(CHECK, 0x1021_u16, 0), 0x31c3);
+        assert_eq!(crc(CHECK, 0x1021_i16, 0), 0x31c3);
+        assert_eq!(crc(CHECK, 0x04c1_1db7_u32, !0), 0x0376_e6e7);
+        assert_eq!(crc(CHECK, 0x04c1_1db7_i32, !0), 0x0376_e6e7);
+        assert_eq!(
+            crc(CHECK, 0x42f0_e1eb_a9ea_3693_u64, 0),
+            0x6c40_df5f_0b49_7347
+        );
+        assert_eq!(
+            crc(CHECK, 0x42f0_e1eb_a9ea_3693_u64 as i64, 0),
+            0x6c40_df5f_0b49_7347
+        );
+
+        let wide = crc(CHECK, 0x42f0_e1eb_a9ea_3693_u128 << 64, 0);
+        assert_eq!(wide >> 64, 0x6c40_df5f_0b49_7347);
+
+        let poly = u256::from(0x42f0_e1eb_a9ea_3693_u64) << 192_u32;
+        assert_eq!(
+            crc(CHECK, poly, u256::ZERO) >> 192_u32,
+            u256::from(0x6c40_df5f_0b49_7347_u64)
+        );
+        assert_eq!(
+            crc(CHECK, poly.cast_signed(), i256::ZERO).cast_unsigned() >> 192_u32,
+            u256::from(0x6c40_df5f_0b49_7347_u64)
+        );
+    }
+
+    #[test]
+    fn generic_field_swap() {
+        macro_rules! check {
+            ($($type:ty),*) => {
+                $(
+                    let value: $type = 0x35;
+                    assert_eq!(swap_fields(value, 0..3, 4..7), 0x53);
+                    assert_eq!(swap_fields(value, 0..2, 4..6), 0x17);
+                    assert_eq!(swap_fields(swap_fields(value, 0..3, 5..8), 0..3, 5..8), value);
+                    assert_eq!(count_ones(value), 4);
+                    assert_eq!(count_ones(<$type>::ALL_SET), <$type>::BIT_SIZE);
+                    assert_eq!(count_ones(<$type>::ZERO), 0);
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+        let value = u256::from(0x35_u8) << 120_u32;
+        assert_eq!(
+            swap_fields(value, 120..123, 124..127),
+            u256::from(0x53_u8) << 120_u32
+        );
+        assert_eq!(count_ones(value), 4);
+        assert_eq!(count_ones(i256::ALL_SET), 256);
+    }
+}
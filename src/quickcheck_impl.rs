@@ -0,0 +1,143 @@
+use crate::{i24, u24, u48, Field, Int, UInt};
+use quickcheck::{Arbitrary, Gen};
+
+// Values are generated by masking a random primitive, which keeps the primitive's bias towards
+// zero, the extremes, and the other problem values. Shrinking borrows the primitive's shrinker,
+// which moves towards zero by halving the distance, and drops any candidate that doesn't fit in
+// the width, like the absolute value of the minimum of a signed type.
+
+impl<const BITS: usize> Arbitrary for UInt<BITS> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        UInt::masked(u128::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().filter_map(UInt::checked_new))
+    }
+}
+
+impl<const BITS: usize> Arbitrary for Int<BITS> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Int::masked(i128::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().filter_map(Int::checked_new))
+    }
+}
+
+macro_rules! quickcheck_fixed_impl {
+    ($($type:ident, $primitive:ident;)*) => {
+        $(
+            impl Arbitrary for $type {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    $type::masked($primitive::arbitrary(g))
+                }
+
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    Box::new(self.get().shrink().filter_map(|value| $type::try_new(value).ok()))
+                }
+            }
+        )*
+    };
+}
+
+quickcheck_fixed_impl! {
+    u24, u32;
+    i24, i32;
+    u48, u64;
+}
+
+/// Fields are generated within the bits of a `u128`, like the `arbitrary` feature's fields, and
+/// shrink towards the empty field at bit 0 by shrinking the offset and width separately.
+impl Arbitrary for Field {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let offset = (u8::arbitrary(g) & 0x7f) as usize;
+        Field::new(offset, u8::arbitrary(g) as usize % (129 - offset))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.offset(), self.width())
+                .shrink()
+                .map(|(offset, width)| Field::new(offset, width)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck::{quickcheck, TestResult};
+    use spectral::prelude::*;
+
+    quickcheck! {
+        fn masked_values_are_in_range(value: u128) -> bool {
+            UInt::<12>::masked(value).get() <= UInt::<12>::MAX.get()
+        }
+
+        fn shrinking_stays_in_range(unsigned: UInt<12>, signed: Int<12>, field: Field) -> TestResult {
+            let unsigned = unsigned.shrink().all(|value| value < unsigned);
+            let signed = signed
+                .shrink()
+                .all(|value| value.get().unsigned_abs() <= signed.get().unsigned_abs());
+            let fields = field
+                .shrink()
+                .all(|shrunk| shrunk.offset() + shrunk.width() <= 128 && shrunk != field);
+
+            TestResult::from_bool(unsigned && signed && fields)
+        }
+    }
+
+    #[test]
+    fn shrinking_moves_towards_zero() {
+        asserting!("unsigned values shrink towards zero")
+            .that(&UInt::<12>::new(100).shrink().collect::<Vec<_>>())
+            .is_equal_to(
+                [0, 50, 75, 88, 94, 97, 99]
+                    .iter()
+                    .map(|&value| UInt::new(value))
+                    .collect::<Vec<_>>(),
+            );
+
+        asserting!("the minimum never shrinks to a value past the maximum")
+            .that(&Int::<12>::MIN.shrink().collect::<Vec<_>>())
+            .is_equal_to(
+                [
+                    0, -1024, -1536, -1792, -1920, -1984, -2016, -2032, -2040, -2044, -2046, -2047,
+                ]
+                .iter()
+                .map(|&value| Int::new(value))
+                .collect::<Vec<_>>(),
+            );
+
+        asserting!("negative values shrink through their magnitude")
+            .that(&i24::new(-4).shrink().collect::<Vec<_>>())
+            .is_equal_to(vec![i24::new(0), i24::new(4), i24::new(-2), i24::new(-3)]);
+
+        asserting!("fields shrink towards the bottom of the value")
+            .that(&Field::new(2, 1).shrink().collect::<Vec<_>>())
+            .is_equal_to(vec![Field::new(0, 1), Field::new(1, 1), Field::new(2, 0)]);
+
+        asserting!("zeros don't shrink")
+            .that(&(UInt::<1>::ZERO.shrink().count(), u48::MIN.shrink().count()))
+            .is_equal_to((0, 0));
+    }
+
+    #[test]
+    fn generated_values_are_in_range() {
+        let mut g = Gen::new(100);
+
+        for _ in 0..1000 {
+            let values: (UInt<3>, Int<3>, u24, Field) = Arbitrary::arbitrary(&mut g);
+            asserting!("values fit in the width")
+                .that(&(
+                    values.0.get() < 8,
+                    (-4..4).contains(&values.1.get()),
+                    values.2.get() < 1 << 24,
+                    values.3.offset() + values.3.width() <= 128,
+                ))
+                .is_equal_to((true, true, true, true));
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{consts, i24, u24, u48, Int, UInt};
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+// Every width is a whole number of bits, so masking a random `u128` gives a uniform value over the
+// whole type. Ranges are sampled as an offset from their low end: the offset is drawn from just
+// enough random bits to cover the range, and drawn again if it lands past the end, which happens
+// less than half the time. Reducing a random number modulo the range would favour the low values.
+
+/// Returns a uniform random number below `range`, or any `u128` if `range` is 0.
+fn sample_below<R: Rng + ?Sized>(rng: &mut R, range: u128) -> u128 {
+    if range == 0 {
+        return rng.gen();
+    }
+
+    let mask = consts::mask_u128(128 - (range - 1).leading_zeros() as usize);
+    loop {
+        let offset = if mask <= u64::MAX as u128 {
+            rng.gen::<u64>() as u128 & mask
+        } else {
+            rng.gen::<u128>() & mask
+        };
+
+        if offset < range {
+            return offset;
+        }
+    }
+}
+
+impl<const BITS: usize> Distribution<UInt<BITS>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UInt<BITS> {
+        UInt::masked(rng.gen())
+    }
+}
+
+impl<const BITS: usize> Distribution<Int<BITS>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int<BITS> {
+        Int::masked(rng.gen())
+    }
+}
+
+macro_rules! standard_fixed_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Distribution<$type> for Standard {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                    $type::masked(rng.gen())
+                }
+            }
+        )*
+    };
+}
+
+standard_fixed_impl!(u24, i24, u48);
+
+/// Samples values of a [`UInt`](struct.UInt.html) uniformly from a range.
+///
+/// This is the sampler behind `gen_range` and `Uniform` for the unsigned narrow types, and is only
+/// available with the `rand` feature.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformUInt<const BITS: usize> {
+    low: u128,
+    range: u128,
+}
+
+/// Samples values of an [`Int`](struct.Int.html) uniformly from a range.
+///
+/// This is the sampler behind `gen_range` and `Uniform` for the signed narrow types, and is only
+/// available with the `rand` feature.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformInt<const BITS: usize> {
+    low: i128,
+    range: u128,
+}
+
+impl<const BITS: usize> UniformSampler for UniformUInt<BITS> {
+    type X = UInt<BITS>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<UInt<BITS>> + Sized,
+        B2: SampleBorrow<UInt<BITS>> + Sized,
+    {
+        let (low, high) = (low.borrow().get(), high.borrow().get());
+        assert!(low < high, "UniformSampler::new called with `low >= high`");
+        UniformUInt {
+            low,
+            range: high - low,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<UInt<BITS>> + Sized,
+        B2: SampleBorrow<UInt<BITS>> + Sized,
+    {
+        let (low, high) = (low.borrow().get(), high.borrow().get());
+        assert!(
+            low <= high,
+            "UniformSampler::new_inclusive called with `low > high`"
+        );
+        UniformUInt {
+            low,
+            range: (high - low).wrapping_add(1),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UInt<BITS> {
+        UInt::masked(self.low.wrapping_add(sample_below(rng, self.range)))
+    }
+}
+
+impl<const BITS: usize> UniformSampler for UniformInt<BITS> {
+    type X = Int<BITS>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Int<BITS>> + Sized,
+        B2: SampleBorrow<Int<BITS>> + Sized,
+    {
+        let (low, high) = (low.borrow().get(), high.borrow().get());
+        assert!(low < high, "UniformSampler::new called with `low >= high`");
+        UniformInt {
+            low,
+            range: high.wrapping_sub(low) as u128,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Int<BITS>> + Sized,
+        B2: SampleBorrow<Int<BITS>> + Sized,
+    {
+        let (low, high) = (low.borrow().get(), high.borrow().get());
+        assert!(
+            low <= high,
+            "UniformSampler::new_inclusive called with `low > high`"
+        );
+        UniformInt {
+            low,
+            range: (high.wrapping_sub(low) as u128).wrapping_add(1),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int<BITS> {
+        Int::masked(self.low.wrapping_add(sample_below(rng, self.range) as i128))
+    }
+}
+
+impl<const BITS: usize> SampleUniform for UInt<BITS> {
+    type Sampler = UniformUInt<BITS>;
+}
+
+impl<const BITS: usize> SampleUniform for Int<BITS> {
+    type Sampler = UniformInt<BITS>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::distributions::Uniform;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use spectral::prelude::*;
+
+    /// Returns the chi-square statistic of the counts against a uniform distribution.
+    fn chi_square(counts: &[u32]) -> f64 {
+        let total: u32 = counts.iter().sum();
+        let expected = total as f64 / counts.len() as f64;
+
+        counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    #[test]
+    fn standard_values_are_uniform() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut unsigned = [0; 8];
+        let mut signed = [0; 8];
+
+        for _ in 0..80_000 {
+            unsigned[rng.gen::<UInt<3>>().get() as usize] += 1;
+            signed[(rng.gen::<Int<3>>().get() + 4) as usize] += 1;
+        }
+
+        // The 99.9th percentile of the chi-square distribution with 7 degrees of freedom.
+        asserting!("every value of the width is as likely")
+            .that(&(chi_square(&unsigned) < 24.3, chi_square(&signed) < 24.3))
+            .is_equal_to((true, true));
+
+        let values: Vec<(u24, i24, u48)> = (0..1000).map(|_| rng.gen()).collect();
+        asserting!("fixed-size values cover their whole range")
+            .that(&(
+                values.iter().any(|(value, _, _)| value.get() >= 1 << 23),
+                values.iter().any(|(_, value, _)| value.get() < 0),
+                values.iter().any(|(_, _, value)| value.get() >= 1 << 47),
+            ))
+            .is_equal_to((true, true, true));
+    }
+
+    #[test]
+    fn ranges_are_uniform() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut unsigned = [0; 6];
+        let mut signed = [0; 11];
+
+        for _ in 0..66_000 {
+            let value = rng.gen_range(UInt::<3>::new(1)..UInt::new(7));
+            unsigned[value.get() as usize - 1] += 1;
+
+            let value = rng.gen_range(Int::<4>::new(-5)..=Int::new(5));
+            signed[(value.get() + 5) as usize] += 1;
+        }
+
+        // The 99.9th percentiles with 5 and 10 degrees of freedom. A modulo of a 3-bit value would
+        // make 1 and 2 twice as likely as the rest, far past these.
+        asserting!("every value in the range is as likely")
+            .that(&(chi_square(&unsigned) < 20.5, chi_square(&signed) < 29.6))
+            .is_equal_to((true, true));
+    }
+
+    #[test]
+    fn ranges_stay_in_bounds() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+
+        for _ in 0..1000 {
+            let value = rng.gen_range(UInt::<12>::new(0xffd)..=UInt::MAX);
+            assert!(value >= UInt::new(0xffd));
+
+            let value = rng.gen_range(Int::<12>::MIN..Int::new(-2046));
+            assert!(value < Int::new(-2046));
+
+            let value = rng.gen_range(Int::<12>::MIN..=Int::MAX);
+            assert!(value >= Int::MIN);
+
+            let value = rng.gen_range(UInt::<128>::MIN..=UInt::MAX);
+            assert!(value <= UInt::MAX);
+
+            let value = rng.gen_range(Int::<128>::new(-1)..=Int::ZERO);
+            assert!(value.get() == -1 || value.get() == 0);
+        }
+
+        let dice = Uniform::new_inclusive(UInt::<3>::new(1), UInt::new(6));
+        let rolls: Vec<_> = (&mut rng).sample_iter(dice).take(1000).collect();
+        asserting!("samplers built once cover the range")
+            .that(&(rolls.iter().min().copied(), rolls.iter().max().copied()))
+            .is_equal_to((Some(UInt::new(1)), Some(UInt::new(6))));
+    }
+
+    #[test]
+    #[should_panic(expected = "low >= high")]
+    fn empty_ranges_panic() {
+        Uniform::new(Int::<12>::new(3), Int::new(3));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::error::{integer_kind, Value};
+use crate::{i24, u24, u48, Int, TryFromIntError, UInt};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::{self, Formatter};
+
+// Values serialize as the smallest primitive that holds every value of the type, so compact
+// formats spend no more bytes on a `UInt<12>` than on a `u16`. Deserializing accepts a number of
+// any integer type, since self-describing formats like JSON don't keep the type that was written,
+// and fails with the same message as `TryFrom` if the number doesn't fit in the width.
+
+/// Deserializes an integer of any type and fits it into a narrow type.
+struct NarrowVisitor<T> {
+    bits: usize,
+    signed: bool,
+    fit: fn(Value) -> Option<T>,
+}
+
+impl<'de, T> Visitor<'de> for NarrowVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} integer that fits in {} bits",
+            integer_kind(self.signed, false),
+            self.bits
+        )
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        self.visit_u128(value.into())
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        self.visit_i128(value.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<T, E> {
+        self.fit(Value::Unsigned(value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<T, E> {
+        self.fit(Value::Signed(value))
+    }
+}
+
+impl<T> NarrowVisitor<T> {
+    fn fit<E: de::Error>(&self, value: Value) -> Result<T, E> {
+        (self.fit)(value).ok_or_else(|| {
+            E::custom(if self.signed {
+                TryFromIntError::signed(value, self.bits)
+            } else {
+                TryFromIntError::unsigned(value, self.bits)
+            })
+        })
+    }
+}
+
+fn to_u128(value: Value) -> Option<u128> {
+    match value {
+        Value::Unsigned(value) => Some(value),
+        Value::Signed(value) => u128::try_from(value).ok(),
+    }
+}
+
+fn to_i128(value: Value) -> Option<i128> {
+    match value {
+        Value::Unsigned(value) => i128::try_from(value).ok(),
+        Value::Signed(value) => Some(value),
+    }
+}
+
+impl<const BITS: usize> Serialize for UInt<BITS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match BITS {
+            0..=8 => serializer.serialize_u8(self.get() as u8),
+            9..=16 => serializer.serialize_u16(self.get() as u16),
+            17..=32 => serializer.serialize_u32(self.get() as u32),
+            33..=64 => serializer.serialize_u64(self.get() as u64),
+            _ => serializer.serialize_u128(self.get()),
+        }
+    }
+}
+
+impl<'de, const BITS: usize> Deserialize<'de> for UInt<BITS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = NarrowVisitor {
+            bits: BITS,
+            signed: false,
+            fit: |value| to_u128(value).and_then(UInt::checked_new),
+        };
+
+        match BITS {
+            0..=8 => deserializer.deserialize_u8(visitor),
+            9..=16 => deserializer.deserialize_u16(visitor),
+            17..=32 => deserializer.deserialize_u32(visitor),
+            33..=64 => deserializer.deserialize_u64(visitor),
+            _ => deserializer.deserialize_u128(visitor),
+        }
+    }
+}
+
+impl<const BITS: usize> Serialize for Int<BITS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match BITS {
+            0..=8 => serializer.serialize_i8(self.get() as i8),
+            9..=16 => serializer.serialize_i16(self.get() as i16),
+            17..=32 => serializer.serialize_i32(self.get() as i32),
+            33..=64 => serializer.serialize_i64(self.get() as i64),
+            _ => serializer.serialize_i128(self.get()),
+        }
+    }
+}
+
+impl<'de, const BITS: usize> Deserialize<'de> for Int<BITS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = NarrowVisitor {
+            bits: BITS,
+            signed: true,
+            fit: |value| to_i128(value).and_then(Int::checked_new),
+        };
+
+        match BITS {
+            0..=8 => deserializer.deserialize_i8(visitor),
+            9..=16 => deserializer.deserialize_i16(visitor),
+            17..=32 => deserializer.deserialize_i32(visitor),
+            33..=64 => deserializer.deserialize_i64(visitor),
+            _ => deserializer.deserialize_i128(visitor),
+        }
+    }
+}
+
+macro_rules! serde_fixed_impl {
+    ($(
+        $type:ident, $bits:literal, $signed:literal, $convert:ident, $primitive:ident,
+        $serialize:ident, $deserialize:ident;
+    )*) => {
+        $(
+            impl Serialize for $type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.$serialize(self.get())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.$deserialize(NarrowVisitor {
+                        bits: $bits,
+                        signed: $signed,
+                        fit: |value| {
+                            $convert(value)
+                                .and_then(|value| $primitive::try_from(value).ok())
+                                .and_then(|value| $type::try_new(value).ok())
+                        },
+                    })
+                }
+            }
+        )*
+    };
+}
+
+serde_fixed_impl! {
+    u24, 24, false, to_u128, u32, serialize_u32, deserialize_u32;
+    i24, 24, true, to_i128, i32, serialize_i32, deserialize_i32;
+    u48, 48, false, to_u128, u64, serialize_u64, deserialize_u64;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    fn json_error<T: for<'de> Deserialize<'de>>(json: &str) -> String {
+        match serde_json::from_str::<T>(json) {
+            Ok(_) => panic!("{} deserialized", json),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn json_numbers() {
+        asserting!("values serialize as plain numbers")
+            .that(&(
+                serde_json::to_string(&UInt::<12>::MAX).unwrap(),
+                serde_json::to_string(&Int::<12>::MIN).unwrap(),
+                serde_json::to_string(&UInt::<100>::MAX).unwrap(),
+                serde_json::to_string(&vec![u24::MAX.get(), 0]).unwrap(),
+                serde_json::to_string(&(i24::MIN, u48::MAX)).unwrap(),
+            ))
+            .is_equal_to((
+                "4095".to_string(),
+                "-2048".to_string(),
+                "1267650600228229401496703205375".to_string(),
+                "[16777215,0]".to_string(),
+                "[-8388608,281474976710655]".to_string(),
+            ));
+
+        asserting!("numbers in range deserialize")
+            .that(&(
+                serde_json::from_str::<UInt<12>>("4095").ok(),
+                serde_json::from_str::<Int<12>>("-2048").ok(),
+                serde_json::from_str::<Int<100>>("-1").ok(),
+                serde_json::from_str::<UInt<128>>("340282366920938463463374607431768211455").ok(),
+                serde_json::from_str::<(u24, i24, u48)>("[1, -1, 281474976710655]").ok(),
+            ))
+            .is_equal_to((
+                Some(UInt::MAX),
+                Some(Int::MIN),
+                Some(Int::new(-1)),
+                Some(UInt::MAX),
+                Some((u24::new(1), i24::new(-1), u48::MAX)),
+            ));
+    }
+
+    #[test]
+    fn json_numbers_out_of_range() {
+        asserting!("numbers that don't fit are rejected with the width")
+            .that(&(
+                json_error::<UInt<12>>("4096"),
+                json_error::<UInt<12>>("-1"),
+                json_error::<Int<12>>("2048"),
+                json_error::<Int<12>>("-2049"),
+                json_error::<u24>("16777216"),
+                json_error::<i24>("-8388609"),
+                json_error::<u48>("281474976710656"),
+            ))
+            .is_equal_to((
+                "4096 doesn't fit in 12 bits as an unsigned integer at line 1 column 4".to_string(),
+                "-1 doesn't fit in 12 bits as an unsigned integer at line 1 column 2".to_string(),
+                "2048 doesn't fit in 12 bits as a signed integer at line 1 column 4".to_string(),
+                "-2049 doesn't fit in 12 bits as a signed integer at line 1 column 5".to_string(),
+                "16777216 doesn't fit in 24 bits as an unsigned integer at line 1 column 8"
+                    .to_string(),
+                "-8388609 doesn't fit in 24 bits as a signed integer at line 1 column 8"
+                    .to_string(),
+                "281474976710656 doesn't fit in 48 bits as an unsigned integer at line 1 column 15"
+                    .to_string(),
+            ));
+
+        asserting!("other kinds of values are rejected")
+            .that(&(json_error::<UInt<12>>("1.5"), json_error::<Int<3>>("\"1\"")))
+            .is_equal_to((
+                "invalid type: floating point `1.5`, expected an unsigned integer that fits in 12 bits at line 1 column 3"
+                    .to_string(),
+                "invalid type: string \"1\", expected a signed integer that fits in 3 bits at line 1 column 3"
+                    .to_string(),
+            ));
+    }
+
+    #[test]
+    fn compact_binary() {
+        asserting!("values take the bytes of the smallest primitive that holds them")
+            .that(&(
+                bincode::serialize(&UInt::<3>::MAX).unwrap(),
+                bincode::serialize(&UInt::<12>::new(0xabc)).unwrap(),
+                bincode::serialize(&Int::<12>::new(-2)).unwrap(),
+                bincode::serialize(&Int::<33>::new(-2)).unwrap().len(),
+                bincode::serialize(&UInt::<65>::MAX).unwrap().len(),
+                bincode::serialize(&u24::new(0x12_3456)).unwrap(),
+                bincode::serialize(&u48::MAX).unwrap().len(),
+            ))
+            .is_equal_to((
+                vec![7],
+                vec![0xbc, 0x0a],
+                vec![0xfe, 0xff],
+                8,
+                16,
+                vec![0x56, 0x34, 0x12, 0],
+                8,
+            ));
+
+        let values = (
+            UInt::<12>::MAX,
+            Int::<100>::MIN,
+            i24::MIN,
+            u48::new(0x1234_5678_9abc),
+        );
+        asserting!("values round trip")
+            .that(&bincode::deserialize(&bincode::serialize(&values).unwrap()).ok())
+            .is_equal_to(Some(values));
+
+        asserting!("bytes that don't fit are rejected")
+            .that(&(
+                bincode::deserialize::<UInt<12>>(&[0x00, 0x10]).map_err(|error| error.to_string()),
+                bincode::deserialize::<Int<3>>(&[0x04]).map_err(|error| error.to_string()),
+            ))
+            .is_equal_to((
+                Err("4096 doesn't fit in 12 bits as an unsigned integer".to_string()),
+                Err("4 doesn't fit in 3 bits as a signed integer".to_string()),
+            ));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::BitSize;
+use crate::{i256, u256, BitConsts, BitIndex, BitMask, BitSize, Int, UInt};
+use std::ops::{BitAnd, BitOr, Not, Shr};
 
 /// Provides operations based on signs
 ///
@@ -15,15 +16,761 @@ use crate::BitSize;
 ///
 /// let value: u32 = 0x0000_8000;
 /// assert_eq!(value.sign_extend(16), 0xffff_8000);
+/// assert_eq!(value.sign_extend_from(16), 0xffff_8000);
 /// ```
+///
+/// Only [`sign_bit`](#tymethod.sign_bit) and [`sign_extend`](#tymethod.sign_extend) have to be
+/// implemented. The other methods are built on them and [`BitMask`](trait.BitMask.html) for any
+/// type with the operators they need, and the types in this crate override them with faster
+/// versions.
 pub trait Signs: BitSize {
     /// Returns whether the sign bit is set.
     fn sign_bit(&self) -> bool;
 
+    /// Returns whether the sign bit of the lowest N bits is set.
+    ///
+    /// This is bit N - 1, the sign bit of the value read as an N-bit two's complement number. A
+    /// width of 0 has no sign bit and always gives `false`, and widths at least as large as the type
+    /// give the [`sign_bit`](#tymethod.sign_bit) of the whole value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert!(0x0000_0800_u32.sign_bit_at(12));
+    /// assert!(!0x0000_0800_u32.sign_bit_at(13));
+    /// assert!((-1_i32).sign_bit_at(40));
+    /// ```
+    fn sign_bit_at(&self, width: usize) -> bool
+    where
+        Self: Sized + Copy + BitMask,
+    {
+        self.sign_extend_from(width).sign_bit()
+    }
+
+    /// Returns whether the lowest N bits are negative when read as a two's complement number.
+    ///
+    /// This is the same as [`sign_bit_at`](#method.sign_bit_at), for code that reads better asking
+    /// about the value than about the bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert!(0xfff_u16.is_negative_in(12));
+    /// assert!(!0x7ff_u16.is_negative_in(12));
+    /// ```
+    fn is_negative_in(&self, width: usize) -> bool
+    where
+        Self: Sized + Copy + BitMask,
+    {
+        self.sign_bit_at(width)
+    }
+
+    /// Returns the number of bits below the sign bit that are equal to it.
+    ///
+    /// These are the redundant sign bits, so this is how far the value can be shifted left without
+    /// changing its sign, like the CLS instruction on ARM. Unsigned values treat their top bit as
+    /// the sign bit, like [`sign_bit`](#tymethod.sign_bit) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x0000_1234_i32.leading_sign_bits(), 18);
+    /// assert_eq!((-1_i32).leading_sign_bits(), 31);
+    /// assert_eq!(0xffff_8000_u32.leading_sign_bits(), 16);
+    /// ```
+    fn leading_sign_bits(&self) -> u32
+    where
+        Self: Sized + Copy + PartialEq + BitMask,
+    {
+        let sign = self.sign_bit();
+        (0..Self::BIT_SIZE - 1)
+            .rev()
+            .take_while(|&index| bit_set(*self, index) == sign)
+            .count() as u32
+    }
+
     /// Fills the upper N bits of a value with the next bit down.
+    ///
+    /// Filling the whole value (or more) has no next bit down to copy, so it fills every bit with
+    /// the lowest bit instead, which is where the fill ends up as N approaches the bit size. This
+    /// returns all ones when the lowest bit is set and 0 otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x0000_8000_u32.sign_extend(16), 0xffff_8000);
+    /// assert_eq!(0x0000_0001_u32.sign_extend(31), 0xffff_ffff);
+    /// assert_eq!(0x0000_0001_u32.sign_extend(32), 0xffff_ffff);
+    /// assert_eq!(0x0000_0002_u32.sign_extend(32), 0x0000_0000);
+    /// ```
     fn sign_extend(&self, bits: usize) -> Self;
+
+    /// Sign-extends a value from its lowest N bits.
+    ///
+    /// This is the same operation as [`sign_extend`](#tymethod.sign_extend), but it takes the
+    /// width of the field being extended instead of the number of bits being filled, so it doesn't
+    /// depend on the size of the type. Extending from a width of 0 returns 0 since the field has
+    /// no bits, and extending from a width of at least the bit size returns the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x800_u32.sign_extend_from(12), 0xffff_f800);
+    /// assert_eq!(0x800_u64.sign_extend_from(12), 0xffff_ffff_ffff_f800);
+    /// assert_eq!(0x7ff_i16.sign_extend_from(12), 0x7ff);
+    /// ```
+    fn sign_extend_from(&self, width: usize) -> Self
+    where
+        Self: Sized + Copy + BitMask,
+    {
+        match width {
+            0 => Self::mask(0),
+            w if w < Self::BIT_SIZE => self.sign_extend(Self::BIT_SIZE - w),
+            _ => *self,
+        }
+    }
+
+    /// Sign-extends the upper N bits of a value, returning `None` if that changes the value.
+    ///
+    /// The value is unchanged exactly when the upper N bits were already a sign extension of the
+    /// remaining bits, so this checks whether the value fits in the remaining bits as a signed
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0xffff_f800_u32.checked_sign_extend(20), Some(0xffff_f800));
+    /// assert_eq!(0x0000_0800_u32.checked_sign_extend(20), None);
+    /// ```
+    fn checked_sign_extend(&self, bits: usize) -> Option<Self>
+    where
+        Self: Sized + PartialEq,
+    {
+        Some(self.sign_extend(bits)).filter(|extended| extended == self)
+    }
+
+    /// Sign-extends a value from its lowest N bits, returning `None` if that changes the value.
+    ///
+    /// This checks whether the value fits in a signed field N bits wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!((-256_i32).checked_sign_extend_from(9), Some(-256));
+    /// assert_eq!((-300_i32).checked_sign_extend_from(9), None);
+    /// ```
+    fn checked_sign_extend_from(&self, width: usize) -> Option<Self>
+    where
+        Self: Sized + Copy + PartialEq + BitMask,
+    {
+        Some(self.sign_extend_from(width)).filter(|extended| extended == self)
+    }
+
+    /// Returns whether the value fits in an unsigned field N bits wide.
+    ///
+    /// Values are interpreted using the signedness of the type, so negative values never fit. Only
+    /// 0 fits in a field with no bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert!(255_u32.fits_in_unsigned(8));
+    /// assert!(!256_u32.fits_in_unsigned(8));
+    /// assert!(!(-1_i32).fits_in_unsigned(8));
+    /// ```
+    fn fits_in_unsigned(&self, bits: usize) -> bool
+    where
+        Self: Sized + Copy + PartialOrd + BitMask,
+    {
+        !is_negative(*self) && self.mask_to(bits) == *self
+    }
+
+    /// Returns whether the value fits in a two's complement field N bits wide.
+    ///
+    /// Values are interpreted using the signedness of the type, so unsigned values are always
+    /// treated as non-negative and only fit in fields wide enough to leave the sign bit clear. Only
+    /// 0 fits in a field with no bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert!((-4096_i32).fits_in_signed(13));
+    /// assert!(!4096_i32.fits_in_signed(13));
+    /// assert!(!0x8000_0000_u32.fits_in_signed(32));
+    /// ```
+    fn fits_in_signed(&self, bits: usize) -> bool
+    where
+        Self: Sized + Copy + PartialOrd + BitMask,
+    {
+        match bits {
+            0 => *self == Self::mask(0),
+            b if is_signed::<Self>() => self.sign_extend_from(b) == *self,
+            b => self.fits_in_unsigned(b - 1),
+        }
+    }
+
+    /// Returns the fewest bits that can hold the value as an unsigned number.
+    ///
+    /// This is the index of the highest set bit plus one, or 0 for 0. Negative values have their
+    /// sign bit set, so they return the bit size of the type even though they don't fit in any
+    /// unsigned field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0_u32.min_unsigned_bits(), 0);
+    /// assert_eq!(255_u32.min_unsigned_bits(), 8);
+    /// assert_eq!(256_u32.min_unsigned_bits(), 9);
+    /// ```
+    fn min_unsigned_bits(&self) -> usize
+    where
+        Self: Sized + Copy + PartialEq + BitMask,
+    {
+        (0..Self::BIT_SIZE)
+            .find(|&bits| self.mask_to(bits) == *self)
+            .unwrap_or(Self::BIT_SIZE)
+    }
+
+    /// Returns the fewest bits that can hold the value as a two's complement number.
+    ///
+    /// This is 0 for 0. Unsigned values are treated as non-negative, so they need one more bit
+    /// than [`min_unsigned_bits`](#method.min_unsigned_bits), which can be more than the bit size
+    /// of the type. The value always [fits](#method.fits_in_signed) in the returned number of
+    /// bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!((-1_i32).min_signed_bits(), 1);
+    /// assert_eq!(127_i32.min_signed_bits(), 8);
+    /// assert_eq!((-128_i32).min_signed_bits(), 8);
+    /// assert_eq!(0x8000_0000_u32.min_signed_bits(), 33);
+    /// ```
+    fn min_signed_bits(&self) -> usize
+    where
+        Self: Sized + Copy + PartialOrd + BitMask,
+    {
+        (0..=Self::BIT_SIZE)
+            .find(|&bits| self.fits_in_signed(bits))
+            .unwrap_or(Self::BIT_SIZE + 1)
+    }
+
+    /// Clamps a value into the range of an N-bit unsigned number.
+    ///
+    /// Values above 2<sup>N</sup> - 1 saturate to it and negative values saturate to 0, while
+    /// values already in range are returned unchanged. A width of 0 always gives 0, and widths at
+    /// least as large as the type leave the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(300_u32.saturate_to_bits_unsigned(8), 255);
+    /// assert_eq!(100_u32.saturate_to_bits_unsigned(8), 100);
+    /// assert_eq!((-20_i32).saturate_to_bits_unsigned(8), 0);
+    /// ```
+    fn saturate_to_bits_unsigned(&self, width: usize) -> Self
+    where
+        Self: Sized + Copy + PartialOrd + BitMask,
+    {
+        if width >= Self::BIT_SIZE || self.fits_in_unsigned(width) {
+            *self
+        } else if is_negative(*self) {
+            Self::mask(0)
+        } else {
+            Self::mask(width)
+        }
+    }
+
+    /// Clamps a value into the range of an N-bit two's complement number.
+    ///
+    /// Values outside -2<sup>N-1</sup> to 2<sup>N-1</sup> - 1 saturate to the nearest end of the
+    /// range, while values already in range are returned unchanged. Unsigned values are treated as
+    /// non-negative. A width of 0 always gives 0, and widths at least as large as the type leave the
+    /// value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(40_000_i32.saturate_to_bits_signed(16), 32_767);
+    /// assert_eq!((-40_000_i32).saturate_to_bits_signed(16), -32_768);
+    /// assert_eq!((-20_i32).saturate_to_bits_signed(16), -20);
+    /// assert_eq!(200_u8.saturate_to_bits_signed(4), 7);
+    /// ```
+    fn saturate_to_bits_signed(&self, width: usize) -> Self
+    where
+        Self: Sized + Copy + PartialOrd + BitMask + Not<Output = Self>,
+    {
+        if width == 0 {
+            Self::mask(0)
+        } else if width >= Self::BIT_SIZE || self.fits_in_signed(width) {
+            *self
+        } else if is_negative(*self) {
+            !Self::mask(width - 1)
+        } else {
+            Self::mask(width - 1)
+        }
+    }
+
+    /// Wraps a value into N bits, keeping the signedness of the type.
+    ///
+    /// Signed types are sign-extended from bit N - 1, like
+    /// [`sign_extend_from`](#method.sign_extend_from), while unsigned types have the bits above
+    /// the width cleared. This emulates an N-bit register held in a wider type: the result is the
+    /// value the register would hold after an operation that overflowed it. A width of 0 always
+    /// gives 0, and widths at least as large as the type leave the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!((127_i32 + 1).wrapping_truncate_to_bits(8), -128);
+    /// assert_eq!((255_u32 + 1).wrapping_truncate_to_bits(8), 0);
+    /// assert_eq!((-129_i32).wrapping_truncate_to_bits(8), 127);
+    /// ```
+    fn wrapping_truncate_to_bits(&self, width: usize) -> Self
+    where
+        Self: Sized + Copy + PartialOrd + BitMask,
+    {
+        if is_signed::<Self>() {
+            self.sign_extend_from(width)
+        } else {
+            self.mask_to(width)
+        }
+    }
+
+    /// Converts the lowest N bits of a value from two's complement to sign-magnitude.
+    ///
+    /// The result holds the sign in bit N - 1 and the magnitude in the bits below it, with the
+    /// bits above the field cleared. The most negative N-bit value has no sign-magnitude
+    /// representation, so it saturates to the largest negative magnitude; use
+    /// [`try_to_sign_magnitude`](#method.try_to_sign_magnitude) to detect it instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x05_u8.to_sign_magnitude(8), 0x05);
+    /// assert_eq!(0xfb_u8.to_sign_magnitude(8), 0x85);
+    /// assert_eq!(0x80_u8.to_sign_magnitude(8), 0xff);
+    /// assert_eq!((-5_i32).to_sign_magnitude(12), 0x805);
+    /// ```
+    fn to_sign_magnitude(&self, width: usize) -> Self
+    where
+        Self: Sized
+            + Copy
+            + PartialEq
+            + BitMask
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        self.try_to_sign_magnitude(width)
+            .unwrap_or_else(|| Self::mask(width.min(Self::BIT_SIZE)))
+    }
+
+    /// Converts the lowest N bits of a value from two's complement to sign-magnitude, returning
+    /// `None` for the most negative N-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0xfb_u8.try_to_sign_magnitude(8), Some(0x85));
+    /// assert_eq!(0x80_u8.try_to_sign_magnitude(8), None);
+    /// ```
+    fn try_to_sign_magnitude(&self, width: usize) -> Option<Self>
+    where
+        Self: Sized
+            + Copy
+            + PartialEq
+            + BitMask
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        let width = width.min(Self::BIT_SIZE);
+        if width == 0 {
+            return Some(Self::mask(0));
+        }
+
+        let value = self.mask_to(width);
+        if !bit_set(value, width - 1) {
+            return Some(value);
+        }
+
+        let magnitude = negate_in(value, width);
+        if bit_set(magnitude, width - 1) {
+            None
+        } else {
+            Some(magnitude | Self::mask_range(width - 1..width))
+        }
+    }
+
+    /// Converts the lowest N bits of a value from sign-magnitude to two's complement.
+    ///
+    /// The result is the N-bit two's complement pattern with the bits above the field cleared,
+    /// which can be sign-extended with [`sign_extend_from`](#method.sign_extend_from). Negative
+    /// zero converts to 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x85_u8.from_sign_magnitude(8), 0xfb);
+    /// assert_eq!(0x80_u8.from_sign_magnitude(8), 0x00);
+    /// assert_eq!(0x805_i32.from_sign_magnitude(12).sign_extend_from(12), -5);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_sign_magnitude(&self, width: usize) -> Self
+    where
+        Self: Sized
+            + Copy
+            + PartialEq
+            + BitMask
+            + Not<Output = Self>
+            + BitAnd<Output = Self>
+            + BitOr<Output = Self>,
+    {
+        let width = width.min(Self::BIT_SIZE);
+        if width == 0 {
+            return Self::mask(0);
+        }
+
+        let magnitude = self.mask_to(width - 1);
+        if bit_set(*self, width - 1) {
+            negate_in(magnitude, width)
+        } else {
+            magnitude
+        }
+    }
+
+    /// Sign-extends a value from its lowest N bits into a type at least as wide.
+    ///
+    /// The lowest `width` bits are treated as a two's complement number, which is extended to fill
+    /// the target type regardless of the signedness of either type. Widths follow the same rules as
+    /// [`sign_extend_from`](#method.sign_extend_from).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0xfff_u16.sign_extend_cast::<i32>(12), -1);
+    /// assert_eq!(0x7ff_u16.sign_extend_cast::<i64>(12), 0x7ff);
+    /// assert_eq!(0x800_u16.sign_extend_cast::<u32>(12), 0xffff_f800);
+    /// ```
+    fn sign_extend_cast<W>(&self, width: usize) -> W
+    where
+        Self: Widen<W> + Sized + Copy + BitMask,
+    {
+        self.sign_extend_from(width).widen()
+    }
+
+    /// Clears the upper N bits of a value.
+    ///
+    /// This takes the same argument as [`sign_extend`](#tymethod.sign_extend), so the two can be
+    /// swapped for each other when a field changes between signed and unsigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// let value: u32 = 0xffff_8000;
+    /// assert_eq!(value.zero_extend(16), 0x0000_8000);
+    ///
+    /// let value: i32 = -1;
+    /// assert_eq!(value.zero_extend(24), 0xff);
+    /// ```
+    fn zero_extend(&self, bits: usize) -> Self
+    where
+        Self: Sized + BitMask,
+    {
+        self.mask_to(Self::BIT_SIZE.saturating_sub(bits))
+    }
+
+    /// Shifts a value right, filling the upper bits with copies of the sign bit.
+    ///
+    /// This is the `>>` operator on signed primitives, but it works the same on unsigned ones.
+    /// Shifting by the bit size of the type or more doesn't panic: it gives all ones if the sign
+    /// bit is set and zero otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!(0x8000_0000_u32.arithmetic_shr(4), 0xf800_0000);
+    /// assert_eq!(0x4000_0000_u32.arithmetic_shr(4), 0x0400_0000);
+    /// assert_eq!(0x8000_0000_u32.arithmetic_shr(40), 0xffff_ffff);
+    /// ```
+    fn arithmetic_shr(&self, n: usize) -> Self
+    where
+        Self: Sized + Copy + BitMask + Shr<usize, Output = Self>,
+    {
+        if n < Self::BIT_SIZE {
+            self.logical_shr(n).sign_extend(n)
+        } else if self.sign_bit() {
+            Self::mask(Self::BIT_SIZE)
+        } else {
+            Self::mask(0)
+        }
+    }
+
+    /// Shifts a value right, filling the upper bits with zeros.
+    ///
+    /// This is the `>>` operator on unsigned primitives, but it works the same on signed ones.
+    /// Shifting by the bit size of the type or more doesn't panic: it gives zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Signs;
+    ///
+    /// assert_eq!((-1_i32).logical_shr(4), 0x0fff_ffff);
+    /// assert_eq!(0x4000_0000_i32.logical_shr(4), 0x0400_0000);
+    /// assert_eq!((-1_i32).logical_shr(40), 0);
+    /// ```
+    fn logical_shr(&self, n: usize) -> Self
+    where
+        Self: Sized + Copy + BitMask + Shr<usize, Output = Self>,
+    {
+        if n < Self::BIT_SIZE {
+            (*self >> n).zero_extend(n)
+        } else {
+            Self::mask(0)
+        }
+    }
+}
+
+/// Returns whether the type is signed, which is when all ones is less than zero.
+fn is_signed<T: BitMask + PartialOrd>() -> bool {
+    T::mask(T::BIT_SIZE) < T::mask(0)
+}
+
+/// Returns whether the value is negative, using the signedness of its type.
+fn is_negative<T: Signs + BitMask + PartialOrd>(value: T) -> bool {
+    is_signed::<T>() && value.sign_bit()
+}
+
+/// Returns whether bit `index` of the value is set.
+fn bit_set<T: BitMask + PartialEq>(value: T, index: usize) -> bool {
+    value.mask_to(index + 1) != value.mask_to(index)
+}
+
+/// Negates the lowest `width` bits of a value as a two's complement number, clearing the bits
+/// above them.
+///
+/// The negation keeps every bit up to the lowest set bit and inverts the bits above it, so it only
+/// needs the bitwise operators.
+fn negate_in<T>(value: T, width: usize) -> T
+where
+    T: Copy + PartialEq + BitMask + Not<Output = T> + BitAnd<Output = T> + BitOr<Output = T>,
+{
+    match (0..width).find(|&index| bit_set(value, index)) {
+        Some(lowest) => {
+            let kept = T::mask(lowest + 1);
+            (value & kept | !value & !kept).mask_to(width)
+        }
+        None => value.mask_to(width),
+    }
+}
+
+/// Converts a primitive to a type at least as wide by sign-extending it.
+///
+/// This is implemented for every pair of primitives where the target is at least as wide as the
+/// source, and is used to bound [`Signs::sign_extend_cast`](trait.Signs.html#method.sign_extend_cast).
+pub trait Widen<W>: sealed::Sealed {
+    /// Sign-extends the value into the wider type.
+    fn widen(self) -> W;
+}
+
+/// Reinterprets a value as its same-width twin of the other signedness.
+///
+/// Every primitive has a signed and an unsigned type of the same size, and this trait names both
+/// so generic code can move between them. The bit pattern is never changed: `cast_signed` on
+/// `u8::MAX` gives `-1_i8`, and casting back gives `u8::MAX` again.
+///
+/// Both associated types have the same bit size as the type and implement the bit operation
+/// traits, and they name each other as their twins, so casts can be chained in generic code.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{SignCast, Signs};
+///
+/// fn decode_imm<T: SignCast>(word: T, width: usize) -> T::Signed {
+///     word.cast_signed().sign_extend_from(width)
+/// }
+///
+/// assert_eq!(decode_imm(0x0000_0fff_u32, 12), -1_i32);
+/// assert_eq!(decode_imm(0x07ff_u16, 12), 0x07ff_i16);
+/// ```
+pub trait SignCast: BitSize {
+    /// The signed type of the same size.
+    type Signed: SignCast<Signed = Self::Signed, Unsigned = Self::Unsigned>
+        + BitIndex
+        + BitMask
+        + Signs
+        + Copy
+        + PartialOrd
+        + Not<Output = Self::Signed>
+        + BitAnd<Output = Self::Signed>
+        + BitOr<Output = Self::Signed>
+        + Shr<usize, Output = Self::Signed>;
+    /// The unsigned type of the same size.
+    type Unsigned: SignCast<Signed = Self::Signed, Unsigned = Self::Unsigned>
+        + BitIndex
+        + BitMask
+        + Signs
+        + Copy
+        + PartialOrd
+        + Not<Output = Self::Unsigned>
+        + BitAnd<Output = Self::Unsigned>
+        + BitOr<Output = Self::Unsigned>
+        + Shr<usize, Output = Self::Unsigned>;
+
+    /// Reinterprets the bits of the value as the signed type.
+    fn cast_signed(self) -> Self::Signed;
+
+    /// Reinterprets the bits of the value as the unsigned type.
+    fn cast_unsigned(self) -> Self::Unsigned;
+}
+
+macro_rules! sign_cast_impl {
+    ($($type:ty => $u_type:ty, $s_type:ty);*) => {
+        $(
+            impl SignCast for $type {
+                type Signed = $s_type;
+                type Unsigned = $u_type;
+
+                fn cast_signed(self) -> $s_type {
+                    self as $s_type
+                }
+
+                fn cast_unsigned(self) -> $u_type {
+                    self as $u_type
+                }
+            }
+        )*
+    };
+}
+
+sign_cast_impl!(
+    u8 => u8, i8;
+    u16 => u16, i16;
+    u32 => u32, i32;
+    u64 => u64, i64;
+    u128 => u128, i128;
+    usize => usize, isize;
+    i8 => u8, i8;
+    i16 => u16, i16;
+    i32 => u32, i32;
+    i64 => u64, i64;
+    i128 => u128, i128;
+    isize => usize, isize
+);
+
+impl SignCast for u256 {
+    type Signed = i256;
+    type Unsigned = u256;
+
+    fn cast_signed(self) -> i256 {
+        i256::from_limbs(self.to_limbs())
+    }
+
+    fn cast_unsigned(self) -> u256 {
+        self
+    }
+}
+
+impl SignCast for i256 {
+    type Signed = i256;
+    type Unsigned = u256;
+
+    fn cast_signed(self) -> i256 {
+        self
+    }
+
+    fn cast_unsigned(self) -> u256 {
+        u256::from_limbs(self.to_limbs())
+    }
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for usize {}
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+    impl Sealed for i128 {}
+    impl Sealed for isize {}
+    impl Sealed for crate::u256 {}
+    impl Sealed for crate::i256 {}
+}
+
+macro_rules! widen_impl {
+    ($type:ty as $s_type:ty => $($wide:ty),*) => {
+        $(
+            impl Widen<$wide> for $type {
+                fn widen(self) -> $wide {
+                    self as $s_type as $wide
+                }
+            }
+        )*
+    };
 }
 
+widen_impl!(u8 as i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+widen_impl!(u16 as i16 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+widen_impl!(u32 as i32 => u32, u64, u128, usize, i32, i64, i128, isize);
+widen_impl!(u64 as i64 => u64, u128, i64, i128);
+widen_impl!(u128 as i128 => u128, i128);
+widen_impl!(usize as isize => usize, u64, u128, isize, i64, i128);
+widen_impl!(i8 as i8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+widen_impl!(i16 as i16 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+widen_impl!(i32 as i32 => u32, u64, u128, usize, i32, i64, i128, isize);
+widen_impl!(i64 as i64 => u64, u128, i64, i128);
+widen_impl!(i128 as i128 => u128, i128);
+widen_impl!(isize as isize => usize, u64, u128, isize, i64, i128);
+
 macro_rules! signs_impl {
     ($s_type:ty) => {
         impl Signs for $s_type {
@@ -32,12 +779,50 @@ macro_rules! signs_impl {
             }
 
             fn sign_extend(&self, bits: usize) -> Self {
-                if bits >= Self::BIT_SIZE {
+                let bits = bits.min(Self::BIT_SIZE - 1);
+                self << bits >> bits
+            }
+
+            fn zero_extend(&self, bits: usize) -> Self {
+                match bits {
+                    0 => *self,
+                    b if b < Self::BIT_SIZE => self & !(-1 << (Self::BIT_SIZE - b)),
+                    _ => 0,
+                }
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                *self >= 0 && self.zero_extend(Self::BIT_SIZE.saturating_sub(bits)) == *self
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                self.checked_sign_extend_from(bits).is_some()
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                if *self == 0 {
                     0
                 } else {
-                    self << bits >> bits
+                    Self::BIT_SIZE - self.leading_sign_bits() as usize
+                }
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                self.sign_extend_from(width)
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> Self {
+                match width {
+                    0 => 0,
+                    w if w < Self::BIT_SIZE => {
+                        let min: Self = -1 << (w - 1);
+                        (*self).clamp(min, !min)
+                    }
+                    _ => *self,
                 }
             }
+
+            signs_impl!(@shared);
         }
     };
     ($u_type:ty, $s_type:ty) => {
@@ -47,13 +832,157 @@ macro_rules! signs_impl {
             }
 
             fn sign_extend(&self, bits: usize) -> Self {
+                let bits = bits.min(Self::BIT_SIZE - 1);
+                (((self << bits) as $s_type) >> bits) as $u_type
+            }
+
+            fn zero_extend(&self, bits: usize) -> Self {
                 if bits >= Self::BIT_SIZE {
                     0
                 } else {
-                    (((self << bits) as $s_type) >> bits) as $u_type
+                    self << bits >> bits
+                }
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                self.zero_extend(Self::BIT_SIZE.saturating_sub(bits)) == *self
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                if bits == 0 {
+                    *self == 0
+                } else {
+                    self.fits_in_unsigned(bits - 1)
+                }
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                if *self == 0 {
+                    0
+                } else {
+                    self.min_unsigned_bits() + 1
+                }
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                self.zero_extend(Self::BIT_SIZE.saturating_sub(width))
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> Self {
+                match width {
+                    0 => 0,
+                    w if w < Self::BIT_SIZE => {
+                        let max: Self = 1 << (w - 1);
+                        (*self).min(max - 1)
+                    }
+                    _ => *self,
+                }
+            }
+
+            signs_impl!(@shared);
+        }
+    };
+    (@shared) => {
+        fn leading_sign_bits(&self) -> u32 {
+            if self.sign_bit() {
+                self.leading_ones() - 1
+            } else {
+                self.leading_zeros() - 1
+            }
+        }
+
+        fn min_unsigned_bits(&self) -> usize {
+            Self::BIT_SIZE - self.leading_zeros() as usize
+        }
+
+        fn arithmetic_shr(&self, n: usize) -> Self {
+            if n < Self::BIT_SIZE {
+                self.logical_shr(n).sign_extend(n)
+            } else if self.sign_bit() {
+                !0
+            } else {
+                0
+            }
+        }
+
+        fn logical_shr(&self, n: usize) -> Self {
+            if n < Self::BIT_SIZE {
+                (self >> n).zero_extend(n)
+            } else {
+                0
+            }
+        }
+
+        fn sign_extend_from(&self, width: usize) -> Self {
+            match width {
+                0 => 0,
+                w if w < Self::BIT_SIZE => self.sign_extend(Self::BIT_SIZE - w),
+                _ => *self,
+            }
+        }
+
+        fn saturate_to_bits_unsigned(&self, width: usize) -> Self {
+            match width {
+                0 => 0,
+                w if w < Self::BIT_SIZE => {
+                    let max: Self = 1 << w;
+                    (*self).max(0).min(max.wrapping_sub(1))
+                }
+                _ => *self,
+            }
+        }
+
+        fn checked_sign_extend(&self, bits: usize) -> Option<Self> {
+            Some(self.sign_extend(bits)).filter(|extended| extended == self)
+        }
+
+        fn checked_sign_extend_from(&self, width: usize) -> Option<Self> {
+            Some(self.sign_extend_from(width)).filter(|extended| extended == self)
+        }
+
+        fn to_sign_magnitude(&self, width: usize) -> Self {
+            self.try_to_sign_magnitude(width).unwrap_or_else(|| {
+                let sign: Self = 1 << (width.min(Self::BIT_SIZE) - 1);
+                sign | sign.wrapping_sub(1)
+            })
+        }
+
+        fn try_to_sign_magnitude(&self, width: usize) -> Option<Self> {
+            if width == 0 {
+                return Some(0);
+            }
+
+            let value = self.sign_extend_from(width);
+            let sign: Self = 1 << (width.min(Self::BIT_SIZE) - 1);
+            let magnitude_mask = sign.wrapping_sub(1);
+
+            if value & sign == 0 {
+                Some(value & magnitude_mask)
+            } else {
+                let magnitude = value.wrapping_neg();
+                if magnitude & !magnitude_mask == 0 {
+                    Some(sign | magnitude)
+                } else {
+                    None
                 }
             }
         }
+
+        fn from_sign_magnitude(&self, width: usize) -> Self {
+            if width == 0 {
+                return 0;
+            }
+
+            let sign: Self = 1 << (width.min(Self::BIT_SIZE) - 1);
+            let magnitude = self & sign.wrapping_sub(1);
+            let value = if self & sign == 0 {
+                magnitude
+            } else {
+                magnitude.wrapping_neg()
+            };
+
+            value & (sign << 1).wrapping_sub(1)
+        }
     };
 }
 
@@ -70,38 +999,1431 @@ signs_impl!(u64, i64);
 signs_impl!(u128, i128);
 signs_impl!(usize, isize);
 
-#[cfg(test)]
-mod test {
-    use super::*;
+// The narrow types treat bit `BITS - 1` as their sign bit and clamp widths to `BITS`, the same way
+// the primitives clamp them to their bit size. Most of the work is done by the `u128` and `i128`
+// implementations on the stored value, which already reads the bits past the width correctly, and
+// the result is masked back into the width.
+macro_rules! narrow_signs_impl {
+    (UInt) => {
+        impl<const BITS: usize> Signs for UInt<BITS> {
+            fn sign_bit(&self) -> bool {
+                self.get() >> (BITS - 1) == 1
+            }
 
-    #[test]
-    fn unsigned() {
-        let value: u32 = 0x0000_8000;
-        assert_eq!(value.sign_bit(), false);
-        assert_eq!(value.sign_extend(15), 0x0000_8000);
-        assert_eq!(value.sign_extend(16), 0xffff_8000);
-        assert_eq!(value.sign_extend(17), 0);
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                UInt::masked(self.get().mask_to(width))
+            }
 
-        let value: u32 = 0x8000_8000;
-        assert_eq!(value.sign_bit(), true);
-        assert_eq!(value.sign_extend(15), 0x0000_8000);
-        assert_eq!(value.sign_extend(16), 0xffff_8000);
-        assert_eq!(value.sign_extend(17), 0);
-    }
+            narrow_signs_impl!(@shared);
+        }
+    };
+    (Int) => {
+        impl<const BITS: usize> Signs for Int<BITS> {
+            fn sign_bit(&self) -> bool {
+                self.get() < 0
+            }
 
-    #[test]
-    fn signed() {
-        let value: i32 = -65536; // 0xffff_0000
-        assert_eq!(value.sign_bit(), true);
-        assert_eq!(value.sign_extend(15), -65536);
-        assert_eq!(value.sign_extend(16), 0);
-        assert_eq!(value.sign_extend(17), 0);
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                self.sign_extend_from(width)
+            }
 
-        let value: i32 = 0x7fff_0000;
-        assert_eq!(value.sign_bit(), false);
-        assert_eq!(value.sign_extend(1), -65536);
-        assert_eq!(value.sign_extend(15), -65536);
-        assert_eq!(value.sign_extend(16), 0);
-        assert_eq!(value.sign_extend(17), 0);
+            narrow_signs_impl!(@shared);
+        }
+    };
+    (@shared) => {
+        fn leading_sign_bits(&self) -> u32 {
+            (self.to_bits() << (128 - BITS))
+                .leading_sign_bits()
+                .min(BITS as u32 - 1)
+        }
+
+        fn sign_extend(&self, bits: usize) -> Self {
+            Self::masked(self.get().sign_extend_from(BITS - bits.min(BITS - 1)))
+        }
+
+        fn sign_extend_from(&self, width: usize) -> Self {
+            if width >= BITS {
+                *self
+            } else {
+                Self::masked(self.get().sign_extend_from(width))
+            }
+        }
+
+        fn checked_sign_extend(&self, bits: usize) -> Option<Self> {
+            Some(self.sign_extend(bits)).filter(|extended| extended == self)
+        }
+
+        fn checked_sign_extend_from(&self, width: usize) -> Option<Self> {
+            Some(self.sign_extend_from(width)).filter(|extended| extended == self)
+        }
+
+        fn zero_extend(&self, bits: usize) -> Self {
+            if bits >= BITS {
+                Self::ZERO
+            } else {
+                Self::from_bits(self.to_bits().mask_to(BITS - bits))
+            }
+        }
+
+        fn fits_in_unsigned(&self, bits: usize) -> bool {
+            self.get().fits_in_unsigned(bits)
+        }
+
+        fn fits_in_signed(&self, bits: usize) -> bool {
+            self.get().fits_in_signed(bits)
+        }
+
+        fn min_unsigned_bits(&self) -> usize {
+            self.to_bits().min_unsigned_bits()
+        }
+
+        fn min_signed_bits(&self) -> usize {
+            self.get().min_signed_bits()
+        }
+
+        fn saturate_to_bits_unsigned(&self, width: usize) -> Self {
+            if width >= BITS {
+                *self
+            } else {
+                Self::masked(self.get().saturate_to_bits_unsigned(width))
+            }
+        }
+
+        fn saturate_to_bits_signed(&self, width: usize) -> Self {
+            if width >= BITS {
+                *self
+            } else {
+                Self::masked(self.get().saturate_to_bits_signed(width))
+            }
+        }
+
+        fn to_sign_magnitude(&self, width: usize) -> Self {
+            self.try_to_sign_magnitude(width).unwrap_or_else(|| {
+                let sign = Self::from_bits(1) << (width.min(BITS) - 1);
+                sign | sign.wrapping_sub(Self::from_bits(1))
+            })
+        }
+
+        fn try_to_sign_magnitude(&self, width: usize) -> Option<Self> {
+            if width == 0 {
+                return Some(Self::ZERO);
+            }
+
+            let value = self.sign_extend_from(width);
+            let sign = Self::from_bits(1) << (width.min(BITS) - 1);
+            let magnitude_mask = sign.wrapping_sub(Self::from_bits(1));
+
+            if value & sign == Self::ZERO {
+                Some(value & magnitude_mask)
+            } else {
+                let magnitude = value.wrapping_neg();
+                if magnitude & !magnitude_mask == Self::ZERO {
+                    Some(sign | magnitude)
+                } else {
+                    None
+                }
+            }
+        }
+
+        fn from_sign_magnitude(&self, width: usize) -> Self {
+            if width == 0 {
+                return Self::ZERO;
+            }
+
+            let sign = Self::from_bits(1) << (width.min(BITS) - 1);
+            let magnitude = *self & sign.wrapping_sub(Self::from_bits(1));
+            let value = if *self & sign == Self::ZERO {
+                magnitude
+            } else {
+                magnitude.wrapping_neg()
+            };
+
+            value & (sign << 1_usize).wrapping_sub(Self::from_bits(1))
+        }
+
+        fn arithmetic_shr(&self, n: usize) -> Self {
+            if n < BITS {
+                self.logical_shr(n).sign_extend(n)
+            } else if self.sign_bit() {
+                Self::ALL_SET
+            } else {
+                Self::ZERO
+            }
+        }
+
+        fn logical_shr(&self, n: usize) -> Self {
+            Self::from_bits(self.to_bits().logical_shr(n))
+        }
+    };
+}
+
+narrow_signs_impl!(UInt);
+narrow_signs_impl!(Int);
+
+// The 256-bit types follow the primitive of the same signedness. Arithmetic shifts come from
+// shifting the signed type, whichever type the value is.
+macro_rules! wide_signs_impl {
+    (u256) => {
+        impl Signs for u256 {
+            fn sign_bit(&self) -> bool {
+                self.cast_signed().is_negative()
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                self.zero_extend(256_usize.saturating_sub(bits)) == *self
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                if bits == 0 {
+                    *self == u256::ZERO
+                } else {
+                    self.fits_in_unsigned(bits - 1)
+                }
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                if *self == u256::ZERO {
+                    0
+                } else {
+                    self.min_unsigned_bits() + 1
+                }
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                self.zero_extend(256_usize.saturating_sub(width))
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> Self {
+                match width {
+                    0 => u256::ZERO,
+                    w if w < 256 => (*self).min(u256::mask(w - 1)),
+                    _ => *self,
+                }
+            }
+
+            wide_signs_impl!(@shared u256);
+        }
+    };
+    (i256) => {
+        impl Signs for i256 {
+            fn sign_bit(&self) -> bool {
+                self.is_negative()
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                !self.is_negative() && self.zero_extend(256_usize.saturating_sub(bits)) == *self
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                self.checked_sign_extend_from(bits).is_some()
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                if *self == i256::ZERO {
+                    0
+                } else {
+                    256 - self.leading_sign_bits() as usize
+                }
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                self.sign_extend_from(width)
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> Self {
+                match width {
+                    0 => i256::ZERO,
+                    w if w < 256 => {
+                        let min = i256::ALL_SET << (w - 1);
+                        (*self).clamp(min, !min)
+                    }
+                    _ => *self,
+                }
+            }
+
+            wide_signs_impl!(@shared i256);
+        }
+    };
+    (@shared $type:ident) => {
+        fn leading_sign_bits(&self) -> u32 {
+            if self.sign_bit() {
+                self.leading_ones() - 1
+            } else {
+                self.leading_zeros() - 1
+            }
+        }
+
+        fn sign_extend(&self, bits: usize) -> Self {
+            let bits = bits.min(255);
+            $type::from_limbs((self.cast_signed() << bits >> bits).to_limbs())
+        }
+
+        fn sign_extend_from(&self, width: usize) -> Self {
+            match width {
+                0 => $type::ZERO,
+                w if w < 256 => self.sign_extend(256 - w),
+                _ => *self,
+            }
+        }
+
+        fn checked_sign_extend(&self, bits: usize) -> Option<Self> {
+            Some(self.sign_extend(bits)).filter(|extended| extended == self)
+        }
+
+        fn checked_sign_extend_from(&self, width: usize) -> Option<Self> {
+            Some(self.sign_extend_from(width)).filter(|extended| extended == self)
+        }
+
+        fn zero_extend(&self, bits: usize) -> Self {
+            if bits >= 256 {
+                $type::ZERO
+            } else {
+                $type::from_limbs((self.cast_unsigned() << bits >> bits).to_limbs())
+            }
+        }
+
+        fn min_unsigned_bits(&self) -> usize {
+            256 - self.leading_zeros() as usize
+        }
+
+        fn saturate_to_bits_unsigned(&self, width: usize) -> Self {
+            match width {
+                0 => $type::ZERO,
+                w if w < 256 => (*self).max($type::ZERO).min($type::mask(w)),
+                _ => *self,
+            }
+        }
+
+        fn to_sign_magnitude(&self, width: usize) -> Self {
+            self.try_to_sign_magnitude(width).unwrap_or_else(|| {
+                let sign = $type::ONE << (width.min(256) - 1);
+                sign | sign.wrapping_sub($type::ONE)
+            })
+        }
+
+        fn try_to_sign_magnitude(&self, width: usize) -> Option<Self> {
+            if width == 0 {
+                return Some($type::ZERO);
+            }
+
+            let value = self.sign_extend_from(width);
+            let sign = $type::ONE << (width.min(256) - 1);
+            let magnitude_mask = sign.wrapping_sub($type::ONE);
+
+            if value & sign == $type::ZERO {
+                Some(value & magnitude_mask)
+            } else {
+                let magnitude = value.wrapping_neg();
+                if magnitude & !magnitude_mask == $type::ZERO {
+                    Some(sign | magnitude)
+                } else {
+                    None
+                }
+            }
+        }
+
+        fn from_sign_magnitude(&self, width: usize) -> Self {
+            if width == 0 {
+                return $type::ZERO;
+            }
+
+            let sign = $type::ONE << (width.min(256) - 1);
+            let magnitude = *self & sign.wrapping_sub($type::ONE);
+            let value = if *self & sign == $type::ZERO {
+                magnitude
+            } else {
+                magnitude.wrapping_neg()
+            };
+
+            value & (sign << 1_u32).wrapping_sub($type::ONE)
+        }
+
+        fn arithmetic_shr(&self, n: usize) -> Self {
+            $type::from_limbs((self.cast_signed() >> n).to_limbs())
+        }
+
+        fn logical_shr(&self, n: usize) -> Self {
+            $type::from_limbs((self.cast_unsigned() >> n).to_limbs())
+        }
+    };
+}
+
+wide_signs_impl!(u256);
+wide_signs_impl!(i256);
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+
+    #[test]
+    fn unsigned() {
+        let value: u32 = 0x0000_8000;
+        assert_eq!(value.sign_bit(), false);
+        assert_eq!(value.sign_extend(15), 0x0000_8000);
+        assert_eq!(value.sign_extend(16), 0xffff_8000);
+        assert_eq!(value.sign_extend(17), 0);
+
+        let value: u32 = 0x8000_8000;
+        assert_eq!(value.sign_bit(), true);
+        assert_eq!(value.sign_extend(15), 0x0000_8000);
+        assert_eq!(value.sign_extend(16), 0xffff_8000);
+        assert_eq!(value.sign_extend(17), 0);
+    }
+
+    #[test]
+    fn unsigned_zero_extend() {
+        let value: u32 = 0x0000_8000;
+        assert_eq!(value.zero_extend(0), 0x0000_8000);
+        assert_eq!(value.zero_extend(15), 0x0000_8000);
+        assert_eq!(value.zero_extend(16), 0x0000_8000);
+        assert_eq!(value.zero_extend(17), 0);
+        assert_eq!(value.zero_extend(32), 0);
+        assert_eq!(value.zero_extend(33), 0);
+
+        let value: u32 = 0xffff_8000;
+        assert_eq!(value.zero_extend(0), 0xffff_8000);
+        assert_eq!(value.zero_extend(15), 0x0001_8000);
+        assert_eq!(value.zero_extend(16), 0x0000_8000);
+        assert_eq!(value.zero_extend(17), 0);
+        assert_eq!(value.zero_extend(32), 0);
+    }
+
+    #[test]
+    fn signed() {
+        let value: i32 = -65536; // 0xffff_0000
+        assert_eq!(value.sign_bit(), true);
+        assert_eq!(value.sign_extend(15), -65536);
+        assert_eq!(value.sign_extend(16), 0);
+        assert_eq!(value.sign_extend(17), 0);
+
+        let value: i32 = 0x7fff_0000;
+        assert_eq!(value.sign_bit(), false);
+        assert_eq!(value.sign_extend(1), -65536);
+        assert_eq!(value.sign_extend(15), -65536);
+        assert_eq!(value.sign_extend(16), 0);
+        assert_eq!(value.sign_extend(17), 0);
+    }
+
+    #[test]
+    fn signed_zero_extend() {
+        let value: i32 = -65536; // 0xffff_0000
+        assert_eq!(value.zero_extend(0), -65536);
+        assert_eq!(value.zero_extend(15), 0x0001_0000);
+        assert_eq!(value.zero_extend(16), 0);
+        assert_eq!(value.zero_extend(17), 0);
+        assert_eq!(value.zero_extend(32), 0);
+
+        let value: i32 = 0x7fff_0000;
+        assert_eq!(value.zero_extend(1), 0x7fff_0000);
+        assert_eq!(value.zero_extend(15), 0x0001_0000);
+        assert_eq!(value.zero_extend(16), 0);
+        assert_eq!(value.zero_extend(33), 0);
+    }
+
+    #[test]
+    fn leading_sign_bits() {
+        assert_eq!(0_i32.leading_sign_bits(), 31);
+        assert_eq!((-1_i32).leading_sign_bits(), 31);
+        assert_eq!(i32::MIN.leading_sign_bits(), 0);
+        assert_eq!(i32::MAX.leading_sign_bits(), 0);
+        assert_eq!(0x0000_1234_i32.leading_sign_bits(), 18);
+        assert_eq!((-0x1234_i32).leading_sign_bits(), 18);
+        assert_eq!(1_i32.leading_sign_bits(), 30);
+        assert_eq!((-2_i32).leading_sign_bits(), 30);
+
+        assert_eq!(0_i8.leading_sign_bits(), 7);
+        assert_eq!((-1_i8).leading_sign_bits(), 7);
+        assert_eq!(i8::MIN.leading_sign_bits(), 0);
+        assert_eq!(i8::MAX.leading_sign_bits(), 0);
+        assert_eq!(0x0f_i8.leading_sign_bits(), 3);
+
+        assert_eq!(0_i64.leading_sign_bits(), 63);
+        assert_eq!(i64::MIN.leading_sign_bits(), 0);
+        assert_eq!((-1_i128).leading_sign_bits(), 127);
+        assert_eq!(i128::MAX.leading_sign_bits(), 0);
+        assert_eq!((1_i128 << 100).leading_sign_bits(), 26);
+    }
+
+    #[test]
+    fn unsigned_leading_sign_bits() {
+        assert_eq!(0_u32.leading_sign_bits(), 31);
+        assert_eq!(u32::MAX.leading_sign_bits(), 31);
+        assert_eq!(0x8000_0000_u32.leading_sign_bits(), 0);
+        assert_eq!(0x7fff_ffff_u32.leading_sign_bits(), 0);
+        assert_eq!(0x0000_1234_u32.leading_sign_bits(), 18);
+        assert_eq!(0xffff_8000_u32.leading_sign_bits(), 16);
+
+        assert_eq!(0_u8.leading_sign_bits(), 7);
+        assert_eq!(0xf0_u8.leading_sign_bits(), 3);
+        assert_eq!(u16::MAX.leading_sign_bits(), 15);
+        assert_eq!(1_u64.leading_sign_bits(), 62);
+        assert_eq!(u128::MAX.leading_sign_bits(), 127);
+    }
+
+    #[test]
+    fn unsigned_fits_in() {
+        assert!(0_u32.fits_in_unsigned(0));
+        assert!(!1_u32.fits_in_unsigned(0));
+        assert!(255_u32.fits_in_unsigned(8));
+        assert!(!256_u32.fits_in_unsigned(8));
+        assert!(u32::MAX.fits_in_unsigned(32));
+        assert!(u32::MAX.fits_in_unsigned(33));
+        assert!(!u32::MAX.fits_in_unsigned(31));
+
+        assert!(0_u32.fits_in_signed(0));
+        assert!(!1_u32.fits_in_signed(0));
+        assert!(!1_u32.fits_in_signed(1));
+        assert!(1_u32.fits_in_signed(2));
+        assert!(127_u32.fits_in_signed(8));
+        assert!(!128_u32.fits_in_signed(8));
+        assert!(0x7fff_ffff_u32.fits_in_signed(32));
+        assert!(!0x8000_0000_u32.fits_in_signed(32));
+        assert!(0x8000_0000_u32.fits_in_signed(33));
+        assert!(u32::MAX.fits_in_signed(usize::MAX));
+    }
+
+    #[test]
+    fn signed_fits_in() {
+        assert!(0_i32.fits_in_unsigned(0));
+        assert!(!1_i32.fits_in_unsigned(0));
+        assert!(255_i32.fits_in_unsigned(8));
+        assert!(!256_i32.fits_in_unsigned(8));
+        assert!(!(-1_i32).fits_in_unsigned(8));
+        assert!(!(-1_i32).fits_in_unsigned(32));
+        assert!(i32::MAX.fits_in_unsigned(31));
+        assert!(i32::MAX.fits_in_unsigned(32));
+        assert!(!i32::MIN.fits_in_unsigned(usize::MAX));
+
+        // 13-bit signed displacements hold -4096 to 4095
+        assert!((-4096_i32).fits_in_signed(13));
+        assert!(!(-4097_i32).fits_in_signed(13));
+        assert!(4095_i32.fits_in_signed(13));
+        assert!(!4096_i32.fits_in_signed(13));
+
+        assert!(0_i32.fits_in_signed(0));
+        assert!(!(-1_i32).fits_in_signed(0));
+        assert!((-1_i32).fits_in_signed(1));
+        assert!(!1_i32.fits_in_signed(1));
+        assert!(i32::MIN.fits_in_signed(32));
+        assert!(i32::MAX.fits_in_signed(32));
+        assert!(!i32::MIN.fits_in_signed(31));
+        assert!(!i32::MAX.fits_in_signed(31));
+        assert!(i32::MIN.fits_in_signed(33));
+        assert!(i64::MIN.fits_in_signed(usize::MAX));
+    }
+
+    #[test]
+    fn min_bits() {
+        assert_eq!(0_u8.min_unsigned_bits(), 0);
+        assert_eq!(1_u8.min_unsigned_bits(), 1);
+        assert_eq!(0x80_u8.min_unsigned_bits(), 8);
+        assert_eq!(0x0001_0000_u32.min_unsigned_bits(), 17);
+        assert_eq!(u128::MAX.min_unsigned_bits(), 128);
+        assert_eq!(1_i32.min_unsigned_bits(), 1);
+        assert_eq!((-1_i32).min_unsigned_bits(), 32);
+
+        assert_eq!(0_u8.min_signed_bits(), 0);
+        assert_eq!(1_u8.min_signed_bits(), 2);
+        assert_eq!(0x7f_u8.min_signed_bits(), 8);
+        assert_eq!(0x80_u8.min_signed_bits(), 9);
+        assert_eq!(u128::MAX.min_signed_bits(), 129);
+
+        assert_eq!(0_i8.min_signed_bits(), 0);
+        assert_eq!((-1_i8).min_signed_bits(), 1);
+        assert_eq!(1_i8.min_signed_bits(), 2);
+        assert_eq!((-2_i8).min_signed_bits(), 2);
+        assert_eq!(i8::MIN.min_signed_bits(), 8);
+        assert_eq!(i8::MAX.min_signed_bits(), 8);
+        assert_eq!(i128::MIN.min_signed_bits(), 128);
+        assert_eq!((-4096_i64).min_signed_bits(), 13);
+        assert_eq!(4096_i64.min_signed_bits(), 14);
+    }
+
+    #[test]
+    fn min_bits_are_consistent_with_fits_in() {
+        for value in 0..=u8::MAX {
+            let bits = value.min_unsigned_bits();
+            assert!(value.fits_in_unsigned(bits));
+            assert!(bits == 0 || !value.fits_in_unsigned(bits - 1));
+
+            let bits = value.min_signed_bits();
+            assert!(value.fits_in_signed(bits));
+            assert!(bits == 0 || !value.fits_in_signed(bits - 1));
+
+            let value = value as i8;
+            let bits = value.min_signed_bits();
+            assert!(value.fits_in_signed(bits));
+            assert!(bits == 0 || !value.fits_in_signed(bits - 1));
+        }
+
+        for value in random_u64s().take(1000) {
+            let bits = value.min_signed_bits();
+            assert!(value.fits_in_signed(bits) && !value.fits_in_signed(bits - 1));
+
+            for &signed in &[value as i64, value as i64 >> 20, value as i64 >> 50] {
+                let bits = signed.min_signed_bits();
+                assert!(signed.fits_in_signed(bits));
+                assert!(bits == 0 || !signed.fits_in_signed(bits - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned_saturate_to_bits() {
+        assert_eq!(300_u16.saturate_to_bits_unsigned(8), 0xff);
+        assert_eq!(0xff_u16.saturate_to_bits_unsigned(8), 0xff);
+        assert_eq!(12_u16.saturate_to_bits_unsigned(8), 12);
+        assert_eq!(u16::MAX.saturate_to_bits_unsigned(15), 0x7fff);
+        assert_eq!(u16::MAX.saturate_to_bits_unsigned(16), u16::MAX);
+        assert_eq!(u16::MAX.saturate_to_bits_unsigned(0), 0);
+
+        assert_eq!(300_u16.saturate_to_bits_signed(8), 0x7f);
+        assert_eq!(0x7f_u16.saturate_to_bits_signed(8), 0x7f);
+        assert_eq!(12_u16.saturate_to_bits_signed(8), 12);
+        assert_eq!(u16::MAX.saturate_to_bits_signed(1), 0);
+        assert_eq!(u16::MAX.saturate_to_bits_signed(15), 0x3fff);
+        assert_eq!(u16::MAX.saturate_to_bits_signed(16), u16::MAX);
+        assert_eq!(u16::MAX.saturate_to_bits_signed(0), 0);
+    }
+
+    #[test]
+    fn signed_saturate_to_bits() {
+        assert_eq!(300_i16.saturate_to_bits_unsigned(8), 0xff);
+        assert_eq!((-300_i16).saturate_to_bits_unsigned(8), 0);
+        assert_eq!(12_i16.saturate_to_bits_unsigned(8), 12);
+        assert_eq!(i16::MAX.saturate_to_bits_unsigned(15), i16::MAX);
+        assert_eq!(i16::MIN.saturate_to_bits_unsigned(15), 0);
+        assert_eq!((-1_i16).saturate_to_bits_unsigned(16), -1);
+        assert_eq!(300_i16.saturate_to_bits_unsigned(0), 0);
+
+        assert_eq!(300_i16.saturate_to_bits_signed(8), 127);
+        assert_eq!((-300_i16).saturate_to_bits_signed(8), -128);
+        assert_eq!((-128_i16).saturate_to_bits_signed(8), -128);
+        assert_eq!((-12_i16).saturate_to_bits_signed(8), -12);
+        assert_eq!((-12_i16).saturate_to_bits_signed(1), -1);
+        assert_eq!(12_i16.saturate_to_bits_signed(1), 0);
+        assert_eq!(i16::MIN.saturate_to_bits_signed(15), -0x4000);
+        assert_eq!(i16::MIN.saturate_to_bits_signed(16), i16::MIN);
+        assert_eq!((-300_i16).saturate_to_bits_signed(0), 0);
+    }
+
+    #[test]
+    fn saturate_to_bits_fits() {
+        for value in i8::MIN..=i8::MAX {
+            for width in 0..8 {
+                let saturated = value.saturate_to_bits_signed(width);
+                assert!(saturated.fits_in_signed(width));
+                assert!(saturated == value || !value.fits_in_signed(width));
+
+                let saturated = value.saturate_to_bits_unsigned(width);
+                assert!(saturated.fits_in_unsigned(width));
+                assert!(saturated == value || !value.fits_in_unsigned(width));
+
+                let value = value as u8;
+                let saturated = value.saturate_to_bits_unsigned(width);
+                assert!(saturated.fits_in_unsigned(width));
+                assert!(saturated == value || !value.fits_in_unsigned(width));
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_truncate_to_bits() {
+        assert_eq!(0x1234_u16.wrapping_truncate_to_bits(8), 0x34);
+        assert_eq!(0x12b4_u16.wrapping_truncate_to_bits(8), 0xb4);
+        assert_eq!(0x12b4_u16.wrapping_truncate_to_bits(0), 0);
+        assert_eq!(0x12b4_u16.wrapping_truncate_to_bits(16), 0x12b4);
+        assert_eq!(0x12b4_u16.wrapping_truncate_to_bits(20), 0x12b4);
+
+        assert_eq!(0x1234_i16.wrapping_truncate_to_bits(8), 0x34);
+        assert_eq!(0x12b4_i16.wrapping_truncate_to_bits(8), -0x4c);
+        assert_eq!(0x12b4_i16.wrapping_truncate_to_bits(0), 0);
+        assert_eq!(0x12b4_i16.wrapping_truncate_to_bits(16), 0x12b4);
+        assert_eq!((-1_i16).wrapping_truncate_to_bits(20), -1);
+    }
+
+    #[test]
+    fn wrapping_truncate_emulates_accumulator() {
+        let mut accumulator = 100_i32;
+        for expected in &[
+            120, -116, -96, -76, -56, -36, -16, 4, 24, 44, 64, 84, 104, 124, -112,
+        ] {
+            accumulator = (accumulator + 20).wrapping_truncate_to_bits(8);
+            assert_eq!(accumulator, *expected);
+            assert_eq!(accumulator, (accumulator as i8) as i32);
+        }
+
+        accumulator = (accumulator - 20).wrapping_truncate_to_bits(8);
+        assert_eq!(accumulator, 124);
+    }
+
+    #[test]
+    fn unsigned_shifts() {
+        for &value in &[0x8000_0001_u32, 0x4000_0001] {
+            let fill = if value.sign_bit() { u32::MAX } else { 0 };
+
+            assert_eq!(value.arithmetic_shr(0), value);
+            assert_eq!(value.arithmetic_shr(31), fill);
+            assert_eq!(value.arithmetic_shr(32), fill);
+            assert_eq!(value.arithmetic_shr(usize::MAX), fill);
+
+            assert_eq!(value.logical_shr(0), value);
+            assert_eq!(value.logical_shr(31), value >> 31);
+            assert_eq!(value.logical_shr(32), 0);
+            assert_eq!(value.logical_shr(usize::MAX), 0);
+        }
+
+        assert_eq!(0x8000_0001_u32.arithmetic_shr(1), 0xc000_0000);
+        assert_eq!(0x4000_0001_u32.arithmetic_shr(1), 0x2000_0000);
+    }
+
+    #[test]
+    fn signed_shifts() {
+        for &value in &[i16::MIN + 1, i16::MAX - 1] {
+            let fill = if value < 0 { -1 } else { 0 };
+
+            assert_eq!(value.arithmetic_shr(0), value);
+            assert_eq!(value.arithmetic_shr(15), fill);
+            assert_eq!(value.arithmetic_shr(16), fill);
+            assert_eq!(value.arithmetic_shr(usize::MAX), fill);
+
+            assert_eq!(value.logical_shr(0), value);
+            assert_eq!(value.logical_shr(15), (value as u16 >> 15) as i16);
+            assert_eq!(value.logical_shr(16), 0);
+            assert_eq!(value.logical_shr(usize::MAX), 0);
+        }
+
+        assert_eq!((-2_i16).logical_shr(1), i16::MAX);
+        assert_eq!((-2_i16).arithmetic_shr(1), -1);
+    }
+
+    #[test]
+    fn shifts_match_primitives() {
+        for value in 0..=u8::MAX {
+            for n in 0..8 {
+                assert_eq!(value.arithmetic_shr(n), ((value as i8) >> n) as u8);
+                assert_eq!(value.logical_shr(n), value >> n);
+                assert_eq!((value as i8).arithmetic_shr(n), (value as i8) >> n);
+                assert_eq!((value as i8).logical_shr(n), (value >> n) as i8);
+            }
+        }
+    }
+
+    #[test]
+    fn sign_bit_at() {
+        assert!(!0xffff_u16.sign_bit_at(0));
+        assert!(0x0001_u16.sign_bit_at(1));
+        assert!(!0x0002_u16.sign_bit_at(1));
+        assert!(0x0800_u16.sign_bit_at(12));
+        assert!(!0x0800_u16.sign_bit_at(13));
+        assert!(0x8000_u16.sign_bit_at(16));
+        assert!(0x8000_u16.sign_bit_at(20));
+        assert!(!0x7fff_u16.sign_bit_at(20));
+
+        assert!(!(-1_i64).sign_bit_at(0));
+        assert!((-1_i64).sign_bit_at(1));
+        assert!(!0x0fff_i64.sign_bit_at(13));
+        assert!(i64::MIN.sign_bit_at(64));
+        assert!(!i64::MAX.sign_bit_at(100));
+        assert!(i64::MAX.is_negative_in(63));
+    }
+
+    #[test]
+    fn sign_bit_at_agrees_with_extension() {
+        for value in 0..=u8::MAX {
+            for width in 0..=10 {
+                let negative = value.sign_bit_at(width);
+                assert_eq!(value.is_negative_in(width), negative);
+                assert_eq!(value.sign_extend_from(width).sign_bit(), negative);
+                assert_eq!((value as i8).sign_bit_at(width), negative);
+
+                if width > 0 && width <= 8 && value.fits_in_unsigned(width) {
+                    assert_eq!(value.fits_in_unsigned(width - 1), !negative);
+                }
+                if width < 8 && value.fits_in_signed(width) {
+                    assert!(!negative);
+                }
+                if (value as i8).fits_in_signed(width) {
+                    assert_eq!(negative, (value as i8) < 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sign_cast() {
+        assert_eq!(u8::MAX.cast_signed(), -1_i8);
+        assert_eq!(u8::MAX.cast_signed().cast_unsigned(), u8::MAX);
+        assert_eq!(0x80_u8.cast_signed(), i8::MIN);
+        assert_eq!(i8::MIN.cast_unsigned(), 0x80);
+        assert_eq!(i8::MIN.cast_unsigned().cast_signed(), i8::MIN);
+        assert_eq!(i8::MAX.cast_unsigned().cast_signed(), i8::MAX);
+        assert_eq!(u128::MAX.cast_signed(), -1);
+        assert_eq!(i128::MIN.cast_unsigned(), 1 << 127);
+        assert_eq!(usize::MAX.cast_signed(), -1_isize);
+        assert_eq!(isize::MIN.cast_unsigned().cast_signed(), isize::MIN);
+
+        assert_eq!(SignCast::cast_unsigned(0x1234_u16), 0x1234_u16);
+        assert_eq!(SignCast::cast_signed(-0x1234_i16), -0x1234_i16);
+    }
+
+    #[test]
+    fn sign_cast_types() {
+        fn same_type<T: 'static, U: 'static>() -> bool {
+            std::any::TypeId::of::<T>() == std::any::TypeId::of::<U>()
+        }
+
+        macro_rules! check {
+            ($($type:ty => $u_type:ty, $s_type:ty);*) => {
+                $(
+                    assert!(same_type::<<$type as SignCast>::Signed, $s_type>());
+                    assert!(same_type::<<$type as SignCast>::Unsigned, $u_type>());
+                )*
+            };
+        }
+
+        check!(
+            u8 => u8, i8;
+            u16 => u16, i16;
+            u32 => u32, i32;
+            u64 => u64, i64;
+            u128 => u128, i128;
+            usize => usize, isize;
+            i8 => u8, i8;
+            i16 => u16, i16;
+            i32 => u32, i32;
+            i64 => u64, i64;
+            i128 => u128, i128;
+            isize => usize, isize
+        );
+    }
+
+    #[test]
+    fn generic_sign_cast() {
+        fn decode_imm<T: SignCast>(word: T, field: std::ops::Range<usize>) -> T::Signed {
+            let width = field.end - field.start;
+            word.cast_unsigned()
+                .bits(field)
+                .cast_signed()
+                .sign_extend_from(width)
+        }
+
+        fn magnitude_bits<T: SignCast>(value: T) -> usize {
+            T::Signed::BIT_SIZE - value.cast_signed().leading_sign_bits() as usize - 1
+        }
+
+        fn round_trip<T: SignCast>(value: T) -> T::Unsigned {
+            value
+                .cast_signed()
+                .cast_unsigned()
+                .cast_signed()
+                .cast_unsigned()
+        }
+
+        macro_rules! check {
+            ($($type:ty),*) => {
+                $(
+                    let word = <$type>::mask_range(2..4) | <$type>::mask_range(6..7);
+                    assert_eq!(decode_imm(word, 2..4), -1);
+                    assert_eq!(decode_imm(word, 2..5), 3);
+                    assert_eq!(decode_imm(word, 4..7), -4);
+                    assert_eq!(
+                        decode_imm(word, 0..<$type>::BIT_SIZE),
+                        word.cast_signed()
+                    );
+                    assert_eq!(round_trip(<$type>::MIN), <$type>::MIN.cast_unsigned());
+                    assert_eq!(round_trip(<$type>::MAX), <$type>::MAX.cast_unsigned());
+
+                    assert_eq!(magnitude_bits(word), 7);
+                    assert_eq!(magnitude_bits(<$type>::ALL_SET), 0);
+                    assert_eq!(magnitude_bits(<$type>::ALL_SET << 3), 3);
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn sign_magnitude_round_trip() {
+        for value in 0..=u8::MAX {
+            let negative_zero = value == 0x80;
+
+            let twos = value.from_sign_magnitude(8);
+            if negative_zero {
+                assert_eq!(twos, 0);
+            } else {
+                assert_eq!(twos.to_sign_magnitude(8), value);
+                assert_eq!(twos.try_to_sign_magnitude(8), Some(value));
+            }
+
+            let magnitude = value & 0x7f;
+            let expected = if value & 0x80 == 0 {
+                magnitude as i8
+            } else {
+                -(magnitude as i8)
+            };
+            assert_eq!(twos as i8, expected);
+            assert_eq!((value as i8).from_sign_magnitude(8), expected);
+        }
+
+        for value in i8::MIN..=i8::MAX {
+            match value.try_to_sign_magnitude(8) {
+                Some(magnitude) => assert_eq!(magnitude.from_sign_magnitude(8), value),
+                None => assert_eq!(value, i8::MIN),
+            }
+        }
+    }
+
+    #[test]
+    fn sign_magnitude_narrow_fields() {
+        assert_eq!((-5_i32).to_sign_magnitude(12), 0x805);
+        assert_eq!(5_i32.to_sign_magnitude(12), 0x005);
+        assert_eq!(0xffb_u32.to_sign_magnitude(12), 0x805);
+        assert_eq!(0x805_u32.from_sign_magnitude(12), 0xffb);
+        assert_eq!(0x805_i32.from_sign_magnitude(12), 0xffb);
+        assert_eq!(0xf805_u32.from_sign_magnitude(12), 0xffb);
+        assert_eq!(0x800_u32.from_sign_magnitude(12), 0);
+
+        // the most negative field value saturates
+        assert_eq!(0x800_u32.to_sign_magnitude(12), 0xfff);
+        assert_eq!(0x800_u32.try_to_sign_magnitude(12), None);
+        assert_eq!(i32::MIN.to_sign_magnitude(32), -1);
+        assert_eq!(i32::MIN.try_to_sign_magnitude(32), None);
+
+        assert_eq!(0x1_u8.to_sign_magnitude(1), 0x1);
+        assert_eq!(0x1_u8.try_to_sign_magnitude(1), None);
+        assert_eq!(0x1_u8.from_sign_magnitude(1), 0x0);
+        assert_eq!(0xff_u8.to_sign_magnitude(0), 0);
+        assert_eq!(0xff_u8.from_sign_magnitude(0), 0);
+
+        assert_eq!((-5_i64).to_sign_magnitude(64), i64::MIN | 5);
+        assert_eq!((i64::MIN | 5).from_sign_magnitude(65), -5);
+    }
+
+    #[test]
+    fn sign_extend_whole_value() {
+        macro_rules! check {
+            ($type:ty) => {
+                let bits = <$type>::BIT_SIZE;
+                for &n in &[bits, bits + 1, usize::MAX] {
+                    assert_eq!((0b01 as $type).sign_extend(n), !0);
+                    assert_eq!((0b10 as $type).sign_extend(n), 0);
+                    assert_eq!((0b11 as $type).sign_extend(n), !0);
+                    assert_eq!(<$type>::MAX.sign_extend(n), !0);
+                    assert_eq!(<$type>::MIN.sign_extend(n), 0);
+                    assert_eq!((0 as $type).sign_extend(n), 0);
+                }
+                assert_eq!((0b01 as $type).sign_extend(bits - 1), !0);
+                assert_eq!((0b10 as $type).sign_extend(bits - 1), 0);
+            };
+        }
+
+        check!(u8);
+        check!(u16);
+        check!(u32);
+        check!(u64);
+        check!(u128);
+        check!(usize);
+        check!(i8);
+        check!(i16);
+        check!(i32);
+        check!(i64);
+        check!(i128);
+        check!(isize);
+    }
+
+    #[test]
+    fn sign_extend_from_width() {
+        assert_eq!(0x80_u8.sign_extend_from(8), 0x80);
+        assert_eq!(0x80_u8.sign_extend_from(7), 0x00);
+        assert_eq!(0x40_u8.sign_extend_from(7), 0xc0);
+        assert_eq!(0x0800_u16.sign_extend_from(12), 0xf800);
+        assert_eq!(0x0800_u32.sign_extend_from(12), 0xffff_f800);
+        assert_eq!(0x0800_u64.sign_extend_from(12), 0xffff_ffff_ffff_f800);
+        assert_eq!(0x0800_u128.sign_extend_from(12), !0x7ff);
+        assert_eq!(0x0800_usize.sign_extend_from(12), !0x7ff);
+        assert_eq!(0x07ff_u32.sign_extend_from(12), 0x07ff);
+        assert_eq!(0x1800_u32.sign_extend_from(12), 0xffff_f800);
+
+        assert_eq!(0x40_i8.sign_extend_from(7), -64);
+        assert_eq!(0x0800_i16.sign_extend_from(12), -2048);
+        assert_eq!(0x0800_i32.sign_extend_from(12), -2048);
+        assert_eq!(0x0800_i64.sign_extend_from(12), -2048);
+        assert_eq!(0x0800_i128.sign_extend_from(12), -2048);
+        assert_eq!(0x0800_isize.sign_extend_from(12), -2048);
+        assert_eq!(0x07ff_i32.sign_extend_from(12), 0x07ff);
+        assert_eq!((-1_i32).sign_extend_from(1), -1);
+        assert_eq!(0x0001_i32.sign_extend_from(1), -1);
+        assert_eq!(0x0002_i32.sign_extend_from(1), 0);
+    }
+
+    #[test]
+    fn sign_extend_from_edge_widths() {
+        assert_eq!(0xffff_ffff_u32.sign_extend_from(0), 0);
+        assert_eq!((-1_i32).sign_extend_from(0), 0);
+
+        assert_eq!(0x8000_0000_u32.sign_extend_from(32), 0x8000_0000);
+        assert_eq!(0x8000_0000_u32.sign_extend_from(33), 0x8000_0000);
+        assert_eq!(i64::MIN.sign_extend_from(64), i64::MIN);
+        assert_eq!(i64::MIN.sign_extend_from(usize::MAX), i64::MIN);
+    }
+
+    #[test]
+    fn sign_extend_from_matches_sign_extend() {
+        for width in 1..32 {
+            let value: u32 = 0x9abc_def0;
+            assert_eq!(value.sign_extend_from(width), value.sign_extend(32 - width));
+
+            let value: i32 = 0x1234_5678;
+            assert_eq!(value.sign_extend_from(width), value.sign_extend(32 - width));
+        }
+    }
+
+    #[test]
+    fn checked_sign_extend() {
+        assert_eq!(0x0000_8000_u32.checked_sign_extend(0), Some(0x0000_8000));
+        assert_eq!(0x0000_8000_u32.checked_sign_extend(15), Some(0x0000_8000));
+        assert_eq!(0x0000_8000_u32.checked_sign_extend(16), None);
+        assert_eq!(0xffff_8000_u32.checked_sign_extend(16), Some(0xffff_8000));
+        assert_eq!(0xffff_8000_u32.checked_sign_extend(17), None);
+
+        assert_eq!((-32768_i32).checked_sign_extend(16), Some(-32768));
+        assert_eq!((-32769_i32).checked_sign_extend(16), None);
+        assert_eq!(32767_i32.checked_sign_extend(16), Some(32767));
+        assert_eq!(32768_i32.checked_sign_extend(16), None);
+
+        assert_eq!(0_u32.checked_sign_extend(32), Some(0));
+        assert_eq!(u32::MAX.checked_sign_extend(32), Some(u32::MAX));
+        assert_eq!(1_u32.checked_sign_extend(32), None);
+        assert_eq!((-1_i32).checked_sign_extend(33), Some(-1));
+        assert_eq!((-2_i32).checked_sign_extend(33), None);
+    }
+
+    #[test]
+    fn checked_sign_extend_from() {
+        // 9-bit signed immediates hold -256 to 255
+        assert_eq!((-256_i32).checked_sign_extend_from(9), Some(-256));
+        assert_eq!((-257_i32).checked_sign_extend_from(9), None);
+        assert_eq!((-300_i32).checked_sign_extend_from(9), None);
+        assert_eq!(255_i32.checked_sign_extend_from(9), Some(255));
+        assert_eq!(256_i32.checked_sign_extend_from(9), None);
+        assert_eq!(0_i32.checked_sign_extend_from(9), Some(0));
+        assert_eq!((-1_i32).checked_sign_extend_from(9), Some(-1));
+
+        for k in 1..16 {
+            let limit = 1_i16 << (k - 1);
+            assert_eq!((-limit).checked_sign_extend_from(k), Some(-limit));
+            assert_eq!((-limit - 1).checked_sign_extend_from(k), None);
+            assert_eq!((limit - 1).checked_sign_extend_from(k), Some(limit - 1));
+            assert_eq!(limit.checked_sign_extend_from(k), None);
+        }
+
+        // unsigned values with the top kept bit set don't fit unless they're already extended
+        assert_eq!(0x0000_0100_u32.checked_sign_extend_from(9), None);
+        assert_eq!(
+            0xffff_ff00_u32.checked_sign_extend_from(9),
+            Some(0xffff_ff00)
+        );
+        assert_eq!(
+            0x0000_00ff_u32.checked_sign_extend_from(9),
+            Some(0x0000_00ff)
+        );
+        assert_eq!(0x80_u8.checked_sign_extend_from(8), Some(0x80));
+
+        assert_eq!(0_u8.checked_sign_extend_from(0), Some(0));
+        assert_eq!(1_u8.checked_sign_extend_from(0), None);
+        assert_eq!(i8::MIN.checked_sign_extend_from(9), Some(i8::MIN));
+    }
+
+    #[test]
+    fn sign_extend_cast() {
+        assert_eq!(0xfff_u16.sign_extend_cast::<i32>(12), -1);
+        assert_eq!(0x800_u16.sign_extend_cast::<i32>(12), -2048);
+        assert_eq!(0x7ff_u16.sign_extend_cast::<i32>(12), 2047);
+        assert_eq!(0xf7ff_u16.sign_extend_cast::<i32>(12), 2047);
+        assert_eq!(0x800_u16.sign_extend_cast::<u64>(12), 0xffff_ffff_ffff_f800);
+        assert_eq!(0x800_u16.sign_extend_cast::<u16>(12), 0xf800);
+
+        assert_eq!(0x8000_u16.sign_extend_cast::<i64>(16), -32768);
+        assert_eq!(0x7fff_u16.sign_extend_cast::<i64>(16), 32767);
+        assert_eq!(0x8000_u16.sign_extend_cast::<u32>(16), 0xffff_8000);
+        assert_eq!(0x8000_0000_u32.sign_extend_cast::<i128>(32), -(1 << 31));
+        assert_eq!(u64::MAX.sign_extend_cast::<i128>(64), -1);
+        assert_eq!(u8::MAX.sign_extend_cast::<isize>(8), -1);
+
+        assert_eq!((-1_i8).sign_extend_cast::<u32>(8), u32::MAX);
+        assert_eq!((-1_i8).sign_extend_cast::<u32>(4), u32::MAX);
+        assert_eq!(0x08_i8.sign_extend_cast::<i16>(4), -8);
+        assert_eq!(0x07_i8.sign_extend_cast::<i16>(4), 7);
+        assert_eq!(i32::MIN.sign_extend_cast::<i64>(32), i32::MIN as i64);
+        assert_eq!(i32::MAX.sign_extend_cast::<i64>(32), i32::MAX as i64);
+
+        assert_eq!(0xff_u8.sign_extend_cast::<i32>(0), 0);
+    }
+
+    #[test]
+    fn extensions_are_interchangeable() {
+        for &bits in &[0, 1, 8, 12, 31] {
+            let value: u32 = 0x0000_0f00;
+            assert_eq!(value.zero_extend(bits), value.sign_extend(bits));
+
+            let value: i32 = 0x7fff_ffff_i32 >> bits;
+            assert_eq!(value.zero_extend(bits), value.sign_extend(bits));
+        }
+    }
+
+    #[test]
+    fn narrow_sign_extend() {
+        let value = UInt::<12>::new(0x080);
+        assert!(!value.sign_bit());
+        assert!(UInt::<12>::new(0x800).sign_bit());
+        assert_eq!(value.sign_extend(4).get(), 0xf80);
+        assert_eq!(value.sign_extend(5).get(), 0);
+        assert_eq!(value.sign_extend_from(8).get(), 0xf80);
+        assert_eq!(value.sign_extend_from(12), value);
+        assert_eq!(value.zero_extend(4).get(), 0x080);
+        assert_eq!(value.zero_extend(12).get(), 0);
+
+        let value = Int::<20>::new(0x0_0010);
+        assert_eq!(value.sign_extend(15).get(), -0x10);
+        assert_eq!(value.sign_extend_from(5).get(), -0x10);
+        assert_eq!(value.sign_extend_from(6), value);
+        assert_eq!(value.sign_extend(30).get(), 0);
+        assert_eq!(Int::<20>::new(-1).zero_extend(4).get(), 0xffff);
+        assert_eq!(Int::<20>::new(-1).leading_sign_bits(), 19);
+        assert_eq!(Int::<20>::new(1).leading_sign_bits(), 18);
+        assert_eq!(UInt::<20>::new(0).leading_sign_bits(), 19);
+    }
+
+    #[test]
+    fn narrow_shifts() {
+        assert_eq!(Int::<20>::MIN.arithmetic_shr(4).get(), -0x8000);
+        assert_eq!(Int::<20>::MIN.arithmetic_shr(20).get(), -1);
+        assert_eq!(Int::<20>::MIN.logical_shr(4).get(), 0x8000);
+        assert_eq!(Int::<20>::new(-1).logical_shr(19).get(), 1);
+        assert_eq!(Int::<20>::new(-1).logical_shr(20).get(), 0);
+        assert_eq!(UInt::<20>::MAX.arithmetic_shr(200), UInt::MAX);
+        assert_eq!(UInt::<20>::MAX.logical_shr(16).get(), 0xf);
+    }
+
+    #[test]
+    fn narrow_widths_clamp_to_the_type() {
+        assert_eq!(Int::<20>::MIN.saturate_to_bits_unsigned(20), Int::MIN);
+        assert_eq!(Int::<20>::MIN.saturate_to_bits_unsigned(19).get(), 0);
+        assert_eq!(UInt::<20>::MAX.saturate_to_bits_signed(20), UInt::MAX);
+        assert_eq!(UInt::<20>::MAX.saturate_to_bits_signed(19).get(), 0x3_ffff);
+        assert_eq!(UInt::<20>::MAX.wrapping_truncate_to_bits(8).get(), 0xff);
+        assert_eq!(Int::<20>::new(0xff).wrapping_truncate_to_bits(8).get(), -1);
+        assert_eq!(Int::<20>::new(-1).min_unsigned_bits(), 20);
+        assert_eq!(Int::<20>::new(-1).min_signed_bits(), 1);
+        assert!(!Int::<20>::new(-1).fits_in_unsigned(64));
+        assert!(UInt::<20>::MAX.fits_in_signed(21));
+        assert!(!UInt::<20>::MAX.fits_in_signed(20));
+    }
+
+    #[test]
+    fn narrow_signs_match_primitives() {
+        macro_rules! check {
+            ($narrow:ty, $prim:ty) => {
+                for value in <$prim>::MIN..=<$prim>::MAX {
+                    let narrow = <$narrow>::new(value as _);
+                    let prim = |narrow: $narrow| narrow.get() as $prim;
+
+                    assert_eq!(narrow.sign_bit(), value.sign_bit());
+                    assert_eq!(narrow.leading_sign_bits(), value.leading_sign_bits());
+                    assert_eq!(narrow.min_unsigned_bits(), value.min_unsigned_bits());
+                    assert_eq!(narrow.min_signed_bits(), value.min_signed_bits());
+
+                    for n in [0, 1, 3, 7, 8, 9, 200] {
+                        assert_eq!(prim(narrow.sign_extend(n)), value.sign_extend(n));
+                        assert_eq!(prim(narrow.sign_extend_from(n)), value.sign_extend_from(n));
+                        assert_eq!(
+                            narrow.checked_sign_extend_from(n).map(prim),
+                            value.checked_sign_extend_from(n)
+                        );
+                        assert_eq!(prim(narrow.zero_extend(n)), value.zero_extend(n));
+                        assert_eq!(narrow.fits_in_unsigned(n), value.fits_in_unsigned(n));
+                        assert_eq!(narrow.fits_in_signed(n), value.fits_in_signed(n));
+                        assert_eq!(
+                            prim(narrow.saturate_to_bits_unsigned(n)),
+                            value.saturate_to_bits_unsigned(n)
+                        );
+                        assert_eq!(
+                            prim(narrow.saturate_to_bits_signed(n)),
+                            value.saturate_to_bits_signed(n)
+                        );
+                        assert_eq!(
+                            prim(narrow.wrapping_truncate_to_bits(n)),
+                            value.wrapping_truncate_to_bits(n)
+                        );
+                        assert_eq!(prim(narrow.arithmetic_shr(n)), value.arithmetic_shr(n));
+                        assert_eq!(prim(narrow.logical_shr(n)), value.logical_shr(n));
+                        assert_eq!(narrow.sign_bit_at(n), value.sign_bit_at(n));
+                    }
+
+                    for width in [1, 3, 7, 8, 9] {
+                        assert_eq!(
+                            narrow.try_to_sign_magnitude(width).map(prim),
+                            value.try_to_sign_magnitude(width)
+                        );
+                        assert_eq!(
+                            prim(narrow.to_sign_magnitude(width)),
+                            value.to_sign_magnitude(width)
+                        );
+                        assert_eq!(
+                            prim(narrow.from_sign_magnitude(width)),
+                            value.from_sign_magnitude(width)
+                        );
+                    }
+                }
+            };
+        }
+
+        check!(UInt<8>, u8);
+        check!(Int<8>, i8);
+        check!(UInt<16>, u16);
+        check!(Int<16>, i16);
+    }
+
+    #[test]
+    fn wide_sign_extend_crosses_limbs() {
+        let value = u256::from_limbs([0, 0x8, 0, 0]);
+        assert!(!value.sign_bit());
+        assert!(value.sign_bit_at(68));
+        assert_eq!(value.sign_extend_from(68).to_limbs(), [0, !0x7, !0, !0]);
+        assert_eq!(value.sign_extend(188), value.sign_extend_from(68));
+        assert_eq!(value.sign_extend_from(69), value);
+        assert_eq!(value.checked_sign_extend_from(68), None);
+        assert_eq!(
+            u256::MAX.zero_extend(100).to_limbs(),
+            [!0, !0, 0xfff_ffff, 0]
+        );
+        assert_eq!(u256::MAX.leading_sign_bits(), 255);
+        assert_eq!(value.min_unsigned_bits(), 68);
+        assert_eq!(value.min_signed_bits(), 69);
+
+        let value = i256::from(1_u64 << 63);
+        assert_eq!(value.sign_extend_from(64).to_limbs(), [1 << 63, !0, !0, !0]);
+        assert_eq!(value.sign_extend_from(64), i256::from(i64::MIN));
+        assert_eq!(
+            i256::MIN.arithmetic_shr(130).to_limbs(),
+            [0, !0 << 61, !0, !0]
+        );
+        assert_eq!(i256::MIN.logical_shr(130).to_limbs(), [0, 1 << 61, 0, 0]);
+        assert_eq!(i256::MIN.arithmetic_shr(256), i256::from(-1));
+        assert_eq!(i256::from(-1).logical_shr(256), i256::ZERO);
+        assert_eq!(i256::from(-1).zero_extend(192).to_limbs(), [!0, 0, 0, 0]);
+        assert_eq!(i256::MIN.leading_sign_bits(), 0);
+        assert_eq!(u256::MAX.cast_signed(), i256::from(-1));
+        assert_eq!(i256::MIN.cast_unsigned().to_limbs(), [0, 0, 0, 1 << 63]);
+    }
+
+    #[test]
+    fn wide_signs_match_primitives() {
+        let values = [
+            0,
+            1,
+            -1,
+            0x7f,
+            -0x80,
+            0x1234_5678_9abc,
+            i128::MIN,
+            i128::MAX,
+        ];
+
+        for &value in values.iter() {
+            let wide = i256::from(value);
+
+            assert_eq!(wide.sign_bit(), value.sign_bit());
+            assert_eq!(wide.leading_sign_bits(), value.leading_sign_bits() + 128);
+            assert_eq!(wide.min_signed_bits(), value.min_signed_bits());
+
+            for n in [0, 1, 7, 8, 63, 64, 65, 127, 128, 200] {
+                assert_eq!(
+                    wide.sign_extend_from(n),
+                    i256::from(value.sign_extend_from(n))
+                );
+                assert_eq!(wide.arithmetic_shr(n), i256::from(value.arithmetic_shr(n)));
+                assert_eq!(wide.fits_in_signed(n), value.fits_in_signed(n));
+                assert_eq!(
+                    wide.saturate_to_bits_signed(n),
+                    i256::from(value.saturate_to_bits_signed(n))
+                );
+                assert_eq!(
+                    wide.wrapping_truncate_to_bits(n),
+                    i256::from(value.wrapping_truncate_to_bits(n))
+                );
+                assert_eq!(wide.sign_bit_at(n), value.sign_bit_at(n));
+            }
+
+            for width in [1, 8, 64, 100] {
+                assert_eq!(
+                    wide.try_to_sign_magnitude(width),
+                    value.try_to_sign_magnitude(width).map(i256::from)
+                );
+                assert_eq!(
+                    wide.from_sign_magnitude(width),
+                    i256::from(value.from_sign_magnitude(width))
+                );
+            }
+
+            let unsigned = value as u128;
+            let wide = u256::from(unsigned);
+
+            assert_eq!(wide.min_unsigned_bits(), unsigned.min_unsigned_bits());
+
+            for n in [0, 1, 7, 8, 63, 64, 65, 127, 128, 200] {
+                assert_eq!(wide.logical_shr(n), u256::from(unsigned.logical_shr(n)));
+                assert_eq!(wide.fits_in_unsigned(n), unsigned.fits_in_unsigned(n));
+                assert_eq!(
+                    wide.saturate_to_bits_unsigned(n),
+                    u256::from(unsigned.saturate_to_bits_unsigned(n))
+                );
+                assert_eq!(
+                    wide.wrapping_truncate_to_bits(n),
+                    u256::from(unsigned.wrapping_truncate_to_bits(n))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn provided_methods_match_primitives() {
+        // Only `sign_bit` and `sign_extend` are implemented, so everything else uses the provided
+        // methods.
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct Reg<T>(T);
+
+        impl<T: BitSize> BitSize for Reg<T> {
+            const BIT_SIZE: usize = T::BIT_SIZE;
+        }
+
+        impl<T: BitMask> BitMask for Reg<T> {
+            fn mask(size: usize) -> Self {
+                Reg(T::mask(size))
+            }
+
+            fn mask_to(&self, size: usize) -> Self {
+                Reg(self.0.mask_to(size))
+            }
+
+            fn mask_range<Idx: std::ops::RangeBounds<usize>>(range: Idx) -> Self {
+                Reg(T::mask_range(range))
+            }
+        }
+
+        impl<T: Signs> Signs for Reg<T> {
+            fn sign_bit(&self) -> bool {
+                self.0.sign_bit()
+            }
+
+            fn sign_extend(&self, bits: usize) -> Self {
+                Reg(self.0.sign_extend(bits))
+            }
+        }
+
+        impl<T: Not<Output = T>> Not for Reg<T> {
+            type Output = Reg<T>;
+
+            fn not(self) -> Reg<T> {
+                Reg(!self.0)
+            }
+        }
+
+        impl<T: BitAnd<Output = T>> BitAnd for Reg<T> {
+            type Output = Reg<T>;
+
+            fn bitand(self, rhs: Reg<T>) -> Reg<T> {
+                Reg(self.0 & rhs.0)
+            }
+        }
+
+        impl<T: BitOr<Output = T>> BitOr for Reg<T> {
+            type Output = Reg<T>;
+
+            fn bitor(self, rhs: Reg<T>) -> Reg<T> {
+                Reg(self.0 | rhs.0)
+            }
+        }
+
+        impl<T: Shr<usize, Output = T>> Shr<usize> for Reg<T> {
+            type Output = Reg<T>;
+
+            fn shr(self, rhs: usize) -> Reg<T> {
+                Reg(self.0 >> rhs)
+            }
+        }
+
+        macro_rules! check {
+            ($($type:ty),*) => {
+                $(
+                    let edges = vec![0, 1, <$type>::MAX, <$type>::MIN, <$type>::MAX / 2 + 1];
+                    let random = random_u64s().take(200).map(|value| value as $type);
+                    for value in edges.into_iter().chain(random) {
+                        let reg = Reg(value);
+                        assert_eq!(
+                            (
+                                reg.leading_sign_bits(),
+                                reg.min_unsigned_bits(),
+                                reg.min_signed_bits(),
+                            ),
+                            (
+                                value.leading_sign_bits(),
+                                value.min_unsigned_bits(),
+                                value.min_signed_bits(),
+                            )
+                        );
+
+                        for n in 0..20 {
+                            assert_eq!(
+                                (
+                                    reg.sign_extend_from(n).0,
+                                    reg.checked_sign_extend(n).map(|reg| reg.0),
+                                    reg.checked_sign_extend_from(n).map(|reg| reg.0),
+                                    reg.fits_in_unsigned(n),
+                                    reg.fits_in_signed(n),
+                                    reg.zero_extend(n).0,
+                                    reg.arithmetic_shr(n).0,
+                                    reg.logical_shr(n).0,
+                                ),
+                                (
+                                    value.sign_extend_from(n),
+                                    value.checked_sign_extend(n),
+                                    value.checked_sign_extend_from(n),
+                                    value.fits_in_unsigned(n),
+                                    value.fits_in_signed(n),
+                                    value.zero_extend(n),
+                                    value.arithmetic_shr(n),
+                                    value.logical_shr(n),
+                                )
+                            );
+                            assert_eq!(
+                                (
+                                    reg.saturate_to_bits_unsigned(n).0,
+                                    reg.saturate_to_bits_signed(n).0,
+                                    reg.wrapping_truncate_to_bits(n).0,
+                                    reg.to_sign_magnitude(n).0,
+                                    reg.try_to_sign_magnitude(n).map(|reg| reg.0),
+                                    reg.from_sign_magnitude(n).0,
+                                ),
+                                (
+                                    value.saturate_to_bits_unsigned(n),
+                                    value.saturate_to_bits_signed(n),
+                                    value.wrapping_truncate_to_bits(n),
+                                    value.to_sign_magnitude(n),
+                                    value.try_to_sign_magnitude(n),
+                                    value.from_sign_magnitude(n),
+                                )
+                            );
+                        }
+                    }
+                )*
+            };
+        }
+
+        check!(i16, u16);
     }
 }
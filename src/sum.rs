@@ -0,0 +1,144 @@
+use crate::{Int, UInt};
+use std::iter::{Product, Sum};
+
+// Sums and products follow the operators: they panic on overflow when debug assertions are
+// enabled and wrap otherwise. Narrow types overflow after only a few values, so there are checked
+// versions as well that return `None` instead. The empty product is one, so `Product` and
+// `checked_product` fail to compile for `Int<1>`, which can't hold it.
+
+macro_rules! sum_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<const BITS: usize> Sum for $type<BITS> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ZERO, |sum, value| sum + value)
+                }
+            }
+
+            impl<'a, const BITS: usize> Sum<&'a $type<BITS>> for $type<BITS> {
+                fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+
+            impl<const BITS: usize> Product for $type<BITS> {
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::ONE, |product, value| product * value)
+                }
+            }
+
+            impl<'a, const BITS: usize> Product<&'a $type<BITS>> for $type<BITS> {
+                fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+
+            impl<const BITS: usize> $type<BITS> {
+                /// Adds up the values, returning `None` if the sum overflows at any point.
+                ///
+                /// The sum of no values is zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use quark::{Int, UInt};
+                ///
+                /// assert_eq!(UInt::checked_sum([7, 8].map(UInt::<4>::new)), Some(UInt::MAX));
+                /// assert_eq!(UInt::checked_sum([8, 8, 0].map(UInt::<4>::new)), None);
+                /// assert_eq!(Int::checked_sum([7, -8].map(Int::<4>::new)), Some(Int::new(-1)));
+                /// ```
+                pub fn checked_sum<I: IntoIterator<Item = Self>>(values: I) -> Option<Self> {
+                    values
+                        .into_iter()
+                        .try_fold(Self::ZERO, |sum, value| sum.checked_add(value))
+                }
+
+                /// Multiplies the values together, returning `None` if the product overflows at
+                /// any point.
+                ///
+                /// The product of no values is one.
+                pub fn checked_product<I: IntoIterator<Item = Self>>(values: I) -> Option<Self> {
+                    values
+                        .into_iter()
+                        .try_fold(Self::ONE, |product, value| product.checked_mul(value))
+                }
+            }
+        )*
+    };
+}
+
+sum_impl!(UInt, Int);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn sums() {
+        let values = vec![UInt::<12>::new(1000), UInt::new(2000), UInt::new(1000)];
+
+        asserting!("values add up")
+            .that(&(
+                values.iter().sum::<UInt<12>>(),
+                values.clone().into_iter().sum::<UInt<12>>(),
+                [-3, 7, -2].map(Int::<5>::new).iter().sum::<Int<5>>(),
+            ))
+            .is_equal_to((UInt::new(4000), UInt::new(4000), Int::new(2)));
+
+        asserting!("empty sums are zero")
+            .that(&(
+                Vec::<UInt<12>>::new().into_iter().sum::<UInt<12>>(),
+                Int::<1>::checked_sum(None),
+            ))
+            .is_equal_to((UInt::ZERO, Some(Int::ZERO)));
+
+        asserting!("sums past the width overflow, even though they fit in the storage")
+            .that(&(
+                UInt::checked_sum(values.iter().copied().chain(Some(UInt::new(96)))),
+                UInt::checked_sum(values.iter().copied().chain(Some(UInt::new(95)))),
+                Int::checked_sum([15, 1, -1].map(Int::<5>::new)),
+                Int::checked_sum([15, -1, 1].map(Int::<5>::new)),
+            ))
+            .is_equal_to((None, Some(UInt::MAX), None, Some(Int::MAX)));
+    }
+
+    #[test]
+    fn products() {
+        asserting!("values multiply together")
+            .that(&(
+                [3, 5, 7].map(UInt::<8>::new).iter().product::<UInt<8>>(),
+                vec![-2, 3, -1]
+                    .into_iter()
+                    .map(Int::<4>::new)
+                    .product::<Int<4>>(),
+            ))
+            .is_equal_to((UInt::new(105), Int::new(6)));
+
+        asserting!("empty products are one")
+            .that(&(
+                Vec::<UInt<1>>::new().into_iter().product::<UInt<1>>(),
+                Int::<2>::checked_product(None),
+            ))
+            .is_equal_to((UInt::ONE, Some(Int::ONE)));
+
+        asserting!("products past the width overflow")
+            .that(&(
+                UInt::checked_product([16, 16].map(UInt::<8>::new)),
+                UInt::checked_product([15, 17].map(UInt::<8>::new)),
+                Int::checked_product([-8, -1].map(Int::<4>::new)),
+                Int::checked_product([-4, 2].map(Int::<4>::new)),
+            ))
+            .is_equal_to((None, Some(UInt::MAX), None, Some(Int::MIN)));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to add with overflow")
+    )]
+    fn overflowing_sums_panic() {
+        let sum: UInt<12> = [UInt::MAX, UInt::new(1)].iter().sum();
+        assert_eq!(sum, UInt::MIN);
+    }
+}
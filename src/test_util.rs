@@ -0,0 +1,20 @@
+//! Helpers shared by the tests.
+
+/// Returns an endless stream of pseudo-random numbers from a xorshift generator with a fixed seed,
+/// so the randomized tests check the same values on every run.
+pub(crate) fn random_u64s() -> impl Iterator<Item = u64> {
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    std::iter::from_fn(move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        Some(seed)
+    })
+}
+
+/// Returns an endless stream of pseudo-random `u128`s, each made of two numbers from
+/// [`random_u64s`].
+pub(crate) fn random_u128s() -> impl Iterator<Item = u128> {
+    let mut halves = random_u64s();
+    std::iter::from_fn(move || Some(u128::from(halves.next()?) << 64 | u128::from(halves.next()?)))
+}
@@ -0,0 +1,1318 @@
+use crate::{consts, BitConsts, BitSize, TryFromIntError};
+use std::convert::TryFrom;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// An unsigned integer with an arbitrary number of bits.
+///
+/// `BITS` can be anything from 1 to 128. Values are held in a `u128` with every bit above the
+/// width clear, so two values are equal exactly when their numbers are, and they order the same
+/// way their numbers do. `Default` is zero and `Hash` agrees with `Eq`, so values work as keys in
+/// any of the standard collections. Using a width outside the supported range fails to compile.
+///
+/// Arithmetic wraps around at the width of the type. The `wrapping_*` methods always wrap, and the
+/// operators follow the primitives: they panic on overflow when debug assertions are enabled, and
+/// wrap otherwise.
+///
+/// Every primitive converts to and from every width. The conversion is `From` when it can't fail
+/// and `TryFrom` when it can, so `u8` converts into `UInt<12>` with `From` but `u16` needs
+/// `TryFrom`. Values also compare with every primitive by their numbers, in either order, so
+/// `UInt::<4>::MAX < 16_u32` and `UInt::<4>::ZERO > -1_i32` both hold.
+///
+/// Values format as their numbers, with every flag the primitives support. `Debug` adds the type,
+/// as in `UInt<12>(2748)`. The radix traits also pad to the number of digits the width needs when
+/// zero padding is asked for without a width, so `{:#0x}` prints a `UInt<12>` as `0x005`.
+///
+/// `BitIndex`, `BitMask`, and `Signs` treat the type as `BITS` bits wide, the same way they treat
+/// the primitives as their bit size: indices and widths past the top bit read as zeros, and bit
+/// `BITS - 1` is the sign bit.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{BitIndex, Signs, UInt};
+/// use std::convert::TryFrom;
+///
+/// let value = UInt::<12>::new(0xfff);
+/// assert_eq!(value.get(), 0xfff);
+/// assert!(UInt::<12>::try_new(0x1000).is_err());
+/// assert_eq!(UInt::<12>::masked(0x1234).get(), 0x234);
+///
+/// assert_eq!(u16::from(UInt::<12>::from(0xab_u8)), 0xab);
+/// assert!(UInt::<12>::try_from(0x1000_u16).is_err());
+///
+/// assert_eq!(value.bits(4..8).get(), 0xf);
+/// assert_eq!(UInt::<12>::new(0x080).sign_extend_from(8).get(), 0xf80);
+/// ```
+///
+/// ```compile_fail
+/// use quark::UInt;
+///
+/// let empty = UInt::<0>::masked(0);
+/// ```
+///
+/// ```compile_fail
+/// use quark::UInt;
+///
+/// let one = UInt::<0>::ONE;
+/// ```
+///
+/// ```compile_fail
+/// use quark::UInt;
+///
+/// let zero = UInt::<0>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UInt<const BITS: usize> {
+    value: u128,
+}
+
+impl<const BITS: usize> UInt<BITS> {
+    pub(crate) const VALID_WIDTH: () = assert!(
+        BITS > 0 && BITS <= 128,
+        "UInt must have between 1 and 128 bits"
+    );
+
+    /// The smallest value of the type, 0.
+    pub const MIN: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        UInt { value: 0 }
+    };
+
+    /// The largest value of the type, 2<sup>BITS</sup> - 1.
+    pub const MAX: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        UInt {
+            value: consts::mask_u128(BITS),
+        }
+    };
+
+    /// The value 0.
+    pub const ZERO: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        UInt { value: 0 }
+    };
+
+    /// The value 1.
+    pub const ONE: Self = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        UInt { value: 1 }
+    };
+
+    /// The number of bits in the type, as a `u32` like the primitives' `BITS`.
+    pub const BITS: u32 = BITS as u32;
+
+    /// Creates a value, panicking if it doesn't fit in `BITS` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is larger than [`MAX`](#associatedconstant.MAX). The message names the
+    /// value and the width, the same as the error [`try_new`](#method.try_new) returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<4>::new(9).get(), 9);
+    ///
+    /// const FIELD: UInt<7> = UInt::new(0x5a);
+    /// assert_eq!(FIELD.get(), 0x5a);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use quark::UInt;
+    ///
+    /// // Panics with "16 doesn't fit in 4 bits as an unsigned integer".
+    /// UInt::<4>::new(16);
+    /// ```
+    ///
+    /// In a constant, a value that doesn't fit is a compile error:
+    ///
+    /// ```compile_fail
+    /// use quark::UInt;
+    ///
+    /// const FIELD: UInt<7> = UInt::new(0x80);
+    /// ```
+    #[track_caller]
+    pub const fn new(value: u128) -> Self {
+        match Self::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it doesn't fit in `BITS` bits.
+    ///
+    /// This checks the value in the same way as converting with `TryFrom`.
+    pub const fn try_new(value: u128) -> Result<Self, TryFromIntError> {
+        match Self::checked_new(value) {
+            Some(value) => Ok(value),
+            None => Err(TryFromIntError::from_u128(value, BITS)),
+        }
+    }
+
+    /// Creates a value, returning `None` if it doesn't fit in `BITS` bits.
+    pub(crate) const fn checked_new(value: u128) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        if value <= Self::MAX.value {
+            Some(UInt { value })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a value from the lowest `BITS` bits of `value`, discarding the rest.
+    pub const fn masked(value: u128) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        UInt {
+            value: value & Self::MAX.value,
+        }
+    }
+
+    /// Returns the value as a `u128`.
+    pub const fn get(self) -> u128 {
+        self.value
+    }
+
+    /// Returns the value as a `u128`, the same as [`get`](#method.get).
+    pub const fn value(self) -> u128 {
+        self.value
+    }
+
+    /// Consumes the wrapper and returns the value as a `u128`, the same as
+    /// [`get`](#method.get).
+    pub const fn into_inner(self) -> u128 {
+        self.value
+    }
+
+    /// Converts the value to a type that's at least as wide, filling the new bits with zeros.
+    ///
+    /// Widening to a narrower type fails to compile; use [`try_resize`](#method.try_resize) or
+    /// [`masked_resize`](#method.masked_resize) to narrow a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// let nibble = UInt::<4>::new(0xa);
+    /// assert_eq!(nibble.widen::<12>(), UInt::<12>::new(0xa));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use quark::UInt;
+    ///
+    /// let narrowed = UInt::<12>::MAX.widen::<4>();
+    /// ```
+    pub const fn widen<const TO: usize>(self) -> UInt<TO> {
+        #[allow(clippy::let_unit_value)]
+        let () = Widening::<BITS, TO>::VALID;
+
+        UInt { value: self.value }
+    }
+
+    /// Converts the value to a type of any width, returning `None` if it doesn't fit.
+    pub const fn try_resize<const TO: usize>(self) -> Option<UInt<TO>> {
+        UInt::checked_new(self.value)
+    }
+
+    /// Converts the value to a type of any width, discarding the bits that don't fit.
+    pub const fn masked_resize<const TO: usize>(self) -> UInt<TO> {
+        UInt::masked(self.value)
+    }
+
+    /// Returns whether the value is held the way the type's invariant requires.
+    const fn is_canonical(self) -> bool {
+        self.value <= Self::MAX.value
+    }
+
+    /// Returns the bits of the value within its width.
+    pub(crate) const fn to_bits(self) -> u128 {
+        self.value
+    }
+
+    /// Creates a value from the lowest `BITS` bits of `bits`.
+    pub(crate) const fn from_bits(bits: u128) -> Self {
+        Self::masked(bits)
+    }
+
+    /// Adds two values, wrapping around at 2<sup>BITS</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<12>::MAX.wrapping_add(UInt::new(1)), UInt::MIN);
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_add(rhs.value))
+    }
+
+    /// Subtracts `rhs` from the value, wrapping around at 2<sup>BITS</sup>.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_sub(rhs.value))
+    }
+
+    /// Multiplies two values, wrapping around at 2<sup>BITS</sup>.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::masked(self.value.wrapping_mul(rhs.value))
+    }
+
+    /// Divides the value by `rhs`. Unsigned division can't overflow, so this is the same as `/`,
+    /// and is only here to match [`Int::wrapping_div`](struct.Int.html#method.wrapping_div).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        UInt {
+            value: self.value / rhs.value,
+        }
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, the same as `%`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        UInt {
+            value: self.value % rhs.value,
+        }
+    }
+
+    /// Divides the value by `rhs`. For unsigned numbers this is the same as `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`. For unsigned numbers this is the same
+    /// as `%`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        self.wrapping_rem(rhs)
+    }
+
+    /// Adds two values, returning `None` if the sum doesn't fit in `BITS` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<12>::new(0xffe).checked_add(UInt::new(1)), Some(UInt::MAX));
+    /// assert_eq!(UInt::<12>::MAX.checked_add(UInt::new(1)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_add(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from the value, returning `None` if the result is negative.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_sub(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Multiplies two values, returning `None` if the product doesn't fit in `BITS` bits.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_mul(rhs.value) {
+            Some(value) => Self::checked_new(value),
+            None => None,
+        }
+    }
+
+    /// Divides the value by `rhs`, returning `None` if `rhs` is zero.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_div(rhs.value) {
+            Some(value) => Some(UInt { value }),
+            None => None,
+        }
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, or `None` if `rhs` is zero.
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        match self.value.checked_rem(rhs.value) {
+            Some(value) => Some(UInt { value }),
+            None => None,
+        }
+    }
+
+    /// Divides the value by `rhs`, returning `None` if `rhs` is zero. For unsigned numbers this is
+    /// the same as [`checked_div`](#method.checked_div).
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    /// Returns the remainder of dividing the value by `rhs`, or `None` if `rhs` is zero. For
+    /// unsigned numbers this is the same as [`checked_rem`](#method.checked_rem).
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+
+    /// Negates the value, returning `None` unless it's zero.
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.value == 0 {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Shifts the value left by `rhs` bits, returning `None` if `rhs` is at least `BITS`.
+    ///
+    /// As with the primitives, only the shift amount is checked: bits shifted past the top of the
+    /// value are discarded.
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if (rhs as usize) < BITS {
+            Some(Self::masked(self.value << rhs))
+        } else {
+            None
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, returning `None` if `rhs` is at least `BITS`.
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if (rhs as usize) < BITS {
+            Some(UInt {
+                value: self.value >> rhs,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Adds two values, clamping the sum to [`MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// assert_eq!(UInt::<12>::new(0xff0).saturating_add(UInt::new(0x20)), UInt::MAX);
+    /// assert_eq!(UInt::<12>::new(0x10).saturating_sub(UInt::new(0x20)), UInt::MIN);
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Subtracts `rhs` from the value, clamping the result to zero.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(value) => value,
+            None => Self::MIN,
+        }
+    }
+
+    /// Multiplies two values, clamping the product to [`MAX`](#associatedconstant.MAX).
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(value) => value,
+            None => Self::MAX,
+        }
+    }
+
+    /// Negates the value, clamping the result to zero.
+    ///
+    /// Every value but zero has a negative negation, so this always returns zero.
+    pub const fn saturating_neg(self) -> Self {
+        Self::MIN
+    }
+
+    /// Adds two values, returning the sum wrapped at 2<sup>BITS</sup> and whether it carried out
+    /// of the top bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// // A 12-bit accumulator like the PDP-8's, with its link bit.
+    /// let (acc, link) = UInt::<12>::new(0o7777).overflowing_add(UInt::new(0o0002));
+    /// assert_eq!((acc.get(), link), (0o0001, true));
+    /// ```
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_add(rhs), self.checked_add(rhs).is_none())
+    }
+
+    /// Subtracts `rhs` from the value, returning the result wrapped at 2<sup>BITS</sup> and
+    /// whether it borrowed.
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_sub(rhs), self.checked_sub(rhs).is_none())
+    }
+
+    /// Multiplies two values, returning the product wrapped at 2<sup>BITS</sup> and whether it
+    /// overflowed.
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_mul(rhs), self.checked_mul(rhs).is_none())
+    }
+
+    /// Negates the value, returning the result wrapped at 2<sup>BITS</sup> and whether it
+    /// overflowed, which is the case for everything but zero.
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), self.value != 0)
+    }
+
+    /// Adds two values and a carry bit, returning the sum wrapped at 2<sup>BITS</sup> and the
+    /// carry out of the top bit.
+    ///
+    /// Passing the carry from one call into the next chains several values into a wider number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// // 0x000fff + 0x000001, as two 12-bit halves.
+    /// let (low, carry) = UInt::<12>::new(0xfff).carrying_add(UInt::new(0x001), false);
+    /// let (high, carry) = UInt::<12>::new(0x000).carrying_add(UInt::new(0x000), carry);
+    /// assert_eq!((high.get(), low.get(), carry), (0x001, 0x000, false));
+    /// ```
+    pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let (sum, carried) = self.value.overflowing_add(rhs.value);
+        let (sum, carried_again) = sum.overflowing_add(carry as u128);
+        (
+            Self::masked(sum),
+            carried || carried_again || sum > Self::MAX.value,
+        )
+    }
+
+    /// Subtracts `rhs` and a borrow bit from the value, returning the result wrapped at
+    /// 2<sup>BITS</sup> and whether it borrowed.
+    pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let (difference, borrowed) = self.value.overflowing_sub(rhs.value);
+        let (difference, borrowed_again) = difference.overflowing_sub(borrow as u128);
+        (Self::masked(difference), borrowed || borrowed_again)
+    }
+
+    /// Shifts the value left by `rhs` bits, giving zero if `rhs` is at least `BITS`.
+    ///
+    /// Bits shifted past the top of the width are discarded. This is also what the `<<` operator
+    /// does, so unlike the primitives, shifting by the width or more never panics.
+    pub const fn unbounded_shl(self, rhs: u32) -> Self {
+        if (rhs as usize) < BITS {
+            Self::masked(self.value << rhs)
+        } else {
+            Self::NONE_SET
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, giving zero if `rhs` is at least `BITS`.
+    pub const fn unbounded_shr(self, rhs: u32) -> Self {
+        if (rhs as usize) < BITS {
+            UInt {
+                value: self.value >> rhs,
+            }
+        } else {
+            Self::MIN
+        }
+    }
+
+    /// Shifts the value left by `rhs` modulo `BITS` bits, the way `wrapping_shl` does on the
+    /// primitives.
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        self.unbounded_shl(rhs % BITS as u32)
+    }
+
+    /// Shifts the value right by `rhs` modulo `BITS` bits, the way `wrapping_shr` does on the
+    /// primitives.
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.unbounded_shr(rhs % BITS as u32)
+    }
+
+    /// Rotates the bits of the value left by `n` places within the width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// let value = UInt::<12>::new(0x9ab);
+    /// assert_eq!(value.rotate_left(4).get(), 0xab9);
+    /// assert_eq!(value.rotate_right(4).get(), 0xb9a);
+    /// ```
+    pub const fn rotate_left(self, n: u32) -> Self {
+        let n = n as usize % BITS;
+        let bits = self.value;
+
+        if n == 0 {
+            self
+        } else {
+            Self::masked(bits << n | bits >> (BITS - n))
+        }
+    }
+
+    /// Rotates the bits of the value right by `n` places within the width of the type.
+    pub const fn rotate_right(self, n: u32) -> Self {
+        let n = n as usize % BITS;
+        let bits = self.value;
+
+        if n == 0 {
+            self
+        } else {
+            Self::masked(bits >> n | bits << (BITS - n))
+        }
+    }
+
+    /// Negates the value, wrapping around at 2<sup>BITS</sup>.
+    ///
+    /// This is the two's complement of the value, so it's zero for zero and
+    /// 2<sup>BITS</sup> - `self` for everything else.
+    pub const fn wrapping_neg(self) -> Self {
+        Self::masked(self.value.wrapping_neg())
+    }
+}
+
+impl<const BITS: usize> BitSize for UInt<BITS> {
+    const BIT_SIZE: usize = BITS;
+}
+
+impl<const BITS: usize> BitConsts for UInt<BITS> {
+    const ALL_SET: Self = Self::MAX;
+    const NONE_SET: Self = Self::MIN;
+}
+
+impl<const BITS: usize> Default for UInt<BITS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Checks at compile time that a conversion from `FROM` bits to `TO` bits is a widening one.
+pub(crate) struct Widening<const FROM: usize, const TO: usize>;
+
+impl<const FROM: usize, const TO: usize> Widening<FROM, TO> {
+    pub(crate) const VALID: () = assert!(
+        FROM <= TO && TO <= 128,
+        "can only widen to a type with at least as many bits"
+    );
+}
+
+macro_rules! uint_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $wrapping:ident, $message:literal;)*) => {
+        $(
+            /// Panics if the result doesn't fit in `BITS` bits when debug assertions are enabled,
+            /// and wraps around otherwise, like the operators on the primitives.
+            impl<const BITS: usize> $trait for UInt<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    if cfg!(debug_assertions) {
+                        match self.$checked(rhs) {
+                            Some(value) => value,
+                            None => panic!($message),
+                        }
+                    } else {
+                        self.$wrapping(rhs)
+                    }
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for UInt<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+uint_op_impl! {
+    Add, add, AddAssign, add_assign, checked_add, wrapping_add, "attempt to add with overflow";
+    Sub, sub, SubAssign, sub_assign, checked_sub, wrapping_sub, "attempt to subtract with overflow";
+    Mul, mul, MulAssign, mul_assign, checked_mul, wrapping_mul, "attempt to multiply with overflow";
+}
+
+macro_rules! uint_div_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $message:literal;)*) => {
+        $(
+            /// Panics if `rhs` is zero, like the operators on the primitives.
+            impl<const BITS: usize> $trait for UInt<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    match self.$checked(rhs) {
+                        Some(value) => value,
+                        None => panic!($message),
+                    }
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for UInt<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+uint_div_impl! {
+    Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero";
+    Rem, rem, RemAssign, rem_assign, checked_rem,
+    "attempt to calculate the remainder with a divisor of zero";
+}
+
+macro_rules! uint_bit_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt;)*) => {
+        $(
+            impl<const BITS: usize> $trait for UInt<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    debug_assert!(self.is_canonical() && rhs.is_canonical());
+                    Self::masked(self.value $op rhs.value)
+                }
+            }
+
+            /// Masks `rhs` to `BITS` bits first, so the bits above the width are ignored.
+            impl<const BITS: usize> $trait<u128> for UInt<BITS> {
+                type Output = Self;
+
+                fn $method(self, rhs: u128) -> Self {
+                    self $op Self::masked(rhs)
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait for UInt<BITS> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+
+            impl<const BITS: usize> $assign_trait<u128> for UInt<BITS> {
+                fn $assign_method(&mut self, rhs: u128) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+uint_bit_op_impl! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &;
+    BitOr, bitor, BitOrAssign, bitor_assign, |;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^;
+}
+
+impl<const BITS: usize> Not for UInt<BITS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::masked(!self.value)
+    }
+}
+
+macro_rules! uint_shift_impl {
+    ($($type:ty),*) => {
+        $(
+            /// Shifts left by the amount, discarding the bits past the top of the width. Shifting
+            /// by `BITS` or more gives zero.
+            impl<const BITS: usize> Shl<$type> for UInt<BITS> {
+                type Output = Self;
+
+                fn shl(self, rhs: $type) -> Self {
+                    self.unbounded_shl(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            /// Shifts right by the amount. Shifting by `BITS` or more gives zero.
+            impl<const BITS: usize> Shr<$type> for UInt<BITS> {
+                type Output = Self;
+
+                fn shr(self, rhs: $type) -> Self {
+                    self.unbounded_shr(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            impl<const BITS: usize> ShlAssign<$type> for UInt<BITS> {
+                fn shl_assign(&mut self, rhs: $type) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl<const BITS: usize> ShrAssign<$type> for UInt<BITS> {
+                fn shr_assign(&mut self, rhs: $type) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+uint_shift_impl!(u32, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u128s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn constructing() {
+        asserting!("values that fit can be created")
+            .that(&(UInt::<12>::new(0xfff).get(), UInt::<12>::new(0).get()))
+            .is_equal_to((0xfff, 0));
+
+        asserting!("values that don't fit are rejected")
+            .that(&(UInt::<12>::try_new(0x1000), UInt::<1>::try_new(2)))
+            .is_equal_to((
+                Err(TryFromIntError::from_u128(0x1000, 12)),
+                Err(TryFromIntError::from_u128(2, 1)),
+            ));
+
+        asserting!("values that fit are accepted")
+            .that(&UInt::<12>::try_new(0xabc).map(UInt::get))
+            .is_equal_to(Ok(0xabc));
+
+        asserting!("masking discards the upper bits")
+            .that(&(
+                UInt::<12>::masked(0xf_1234).get(),
+                UInt::<1>::masked(0b10).get(),
+                UInt::<128>::masked(u128::MAX).get(),
+            ))
+            .is_equal_to((0x234, 0, u128::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "4096 doesn't fit in 12 bits as an unsigned integer")]
+    fn new_panics_when_out_of_range() {
+        UInt::<12>::new(0x1000);
+    }
+
+    #[test]
+    fn limits() {
+        asserting!("limits cover the width")
+            .that(&(
+                UInt::<1>::MAX.get(),
+                UInt::<12>::MAX.get(),
+                UInt::<64>::MAX.get(),
+                UInt::<127>::MAX.get(),
+                UInt::<128>::MAX.get(),
+            ))
+            .is_equal_to((1, 0xfff, u64::MAX as u128, u128::MAX >> 1, u128::MAX));
+
+        asserting!("the minimum is zero")
+            .that(&(UInt::<7>::MIN.get(), UInt::<128>::MIN.get()))
+            .is_equal_to((0, 0));
+
+        asserting!("the bit size is the width")
+            .that(&(
+                UInt::<12>::BIT_SIZE,
+                UInt::<12>::ALL_SET,
+                UInt::<12>::NONE_SET,
+            ))
+            .is_equal_to((12, UInt::<12>::MAX, UInt::<12>::MIN));
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        type U5 = UInt<5>;
+
+        for a in 0..32 {
+            for b in 0..32 {
+                let (x, y) = (U5::new(a), U5::new(b));
+
+                asserting!("results are reduced modulo 2^5")
+                    .that(&(
+                        x.wrapping_add(y).get(),
+                        x.wrapping_sub(y).get(),
+                        x.wrapping_mul(y).get(),
+                    ))
+                    .is_equal_to((
+                        (a as i32 + b as i32).rem_euclid(32) as u128,
+                        (a as i32 - b as i32).rem_euclid(32) as u128,
+                        (a as i32 * b as i32).rem_euclid(32) as u128,
+                    ));
+            }
+
+            asserting!("negation is reduced modulo 2^5")
+                .that(&U5::new(a).wrapping_neg().get())
+                .is_equal_to((-(a as i32)).rem_euclid(32) as u128);
+        }
+
+        asserting!("full-width values wrap at 2^128")
+            .that(&(
+                UInt::<128>::MAX.wrapping_add(UInt::new(2)).get(),
+                UInt::<128>::MAX.wrapping_mul(UInt::MAX).get(),
+            ))
+            .is_equal_to((1, 1));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        type U5 = UInt<5>;
+
+        fn fit(value: i32) -> Option<u128> {
+            if (0..32).contains(&value) {
+                Some(value as u128)
+            } else {
+                None
+            }
+        }
+
+        for a in 0..32 {
+            for b in 0..32 {
+                let (x, y) = (U5::new(a as u128), U5::new(b as u128));
+
+                asserting!("results are None exactly when they don't fit in 5 bits")
+                    .that(&(
+                        x.checked_add(y).map(U5::get),
+                        x.checked_sub(y).map(U5::get),
+                        x.checked_mul(y).map(U5::get),
+                        x.checked_div(y).map(U5::get),
+                    ))
+                    .is_equal_to((
+                        fit(a + b),
+                        fit(a - b),
+                        fit(a * b),
+                        a.checked_div(b).map(|value| value as u128),
+                    ));
+            }
+
+            for shift in 0..8 {
+                asserting!("shifts are None exactly when the amount is at least 5")
+                    .that(&(
+                        U5::new(a as u128).checked_shl(shift).map(U5::get),
+                        U5::new(a as u128).checked_shr(shift).map(U5::get),
+                    ))
+                    .is_equal_to(if shift < 5 {
+                        (
+                            Some(((a << shift) & 31) as u128),
+                            Some((a >> shift) as u128),
+                        )
+                    } else {
+                        (None, None)
+                    });
+            }
+
+            asserting!("only zero can be negated")
+                .that(&U5::new(a as u128).checked_neg().map(U5::get))
+                .is_equal_to(fit(-a));
+        }
+
+        asserting!("full-width results are checked against u128")
+            .that(&(
+                UInt::<128>::MAX.checked_add(UInt::new(1)),
+                UInt::<128>::MAX.checked_mul(UInt::new(2)),
+                UInt::<128>::MAX.checked_shl(127).map(UInt::get),
+            ))
+            .is_equal_to((None, None, Some(1 << 127)));
+    }
+
+    #[test]
+    fn division() {
+        type U6 = UInt<6>;
+
+        for a in 0..64_u32 {
+            for b in 0..64_u32 {
+                let (x, y) = (U6::new(a as u128), U6::new(b as u128));
+
+                asserting!("checked division matches a wider model")
+                    .that(&(
+                        x.checked_div(y).map(U6::get),
+                        x.checked_rem(y).map(U6::get),
+                        x.checked_div_euclid(y).map(U6::get),
+                        x.checked_rem_euclid(y).map(U6::get),
+                    ))
+                    .is_equal_to((
+                        a.checked_div(b).map(u128::from),
+                        a.checked_rem(b).map(u128::from),
+                        a.checked_div_euclid(b).map(u128::from),
+                        a.checked_rem_euclid(b).map(u128::from),
+                    ));
+
+                if let (Some(quotient), Some(remainder)) = (a.checked_div(b), a.checked_rem(b)) {
+                    let (quotient, remainder) = (quotient as u128, remainder as u128);
+
+                    asserting!("operators match the model")
+                        .that(&(
+                            (x / y).get(),
+                            (x % y).get(),
+                            x.div_euclid(y).get(),
+                            x.rem_euclid(y).get(),
+                            x.wrapping_div(y).get(),
+                            x.wrapping_rem(y).get(),
+                        ))
+                        .is_equal_to((
+                            quotient, remainder, quotient, remainder, quotient, remainder,
+                        ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn remainder_by_zero_panics() {
+        let mut value = UInt::<6>::MAX;
+        value %= UInt::ZERO;
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        type U5 = UInt<5>;
+
+        for a in 0..32 {
+            for b in 0..32 {
+                let (x, y) = (U5::new(a as u128), U5::new(b as u128));
+
+                asserting!("results are clamped to the limits of 5 bits")
+                    .that(&(
+                        x.saturating_add(y).get(),
+                        x.saturating_sub(y).get(),
+                        x.saturating_mul(y).get(),
+                    ))
+                    .is_equal_to((
+                        (a + b).clamp(0, 31) as u128,
+                        (a - b).clamp(0, 31) as u128,
+                        (a * b).clamp(0, 31) as u128,
+                    ));
+            }
+
+            asserting!("negation clamps to zero")
+                .that(&U5::new(a as u128).saturating_neg())
+                .is_equal_to(U5::MIN);
+        }
+
+        asserting!("full-width results clamp to the u128 limits")
+            .that(&(
+                UInt::<128>::MAX.saturating_add(UInt::MAX),
+                UInt::<128>::MAX.saturating_mul(UInt::MAX),
+            ))
+            .is_equal_to((UInt::MAX, UInt::MAX));
+    }
+
+    #[test]
+    fn overflowing_arithmetic() {
+        type U5 = UInt<5>;
+
+        for a in 0..32 {
+            for b in 0..32 {
+                let (x, y) = (U5::new(a as u128), U5::new(b as u128));
+
+                asserting!("flags are measured at 5 bits")
+                    .that(&(
+                        x.overflowing_add(y),
+                        x.overflowing_sub(y),
+                        x.overflowing_mul(y),
+                    ))
+                    .is_equal_to((
+                        (x.wrapping_add(y), a + b > 31),
+                        (x.wrapping_sub(y), a < b),
+                        (x.wrapping_mul(y), a * b > 31),
+                    ));
+
+                for carry in [false, true] {
+                    asserting!("carries and borrows are measured at 5 bits")
+                        .that(&(x.carrying_add(y, carry), x.borrowing_sub(y, carry)))
+                        .is_equal_to((
+                            (
+                                U5::masked((a + b + carry as i32) as u128),
+                                a + b + carry as i32 > 31,
+                            ),
+                            (
+                                U5::masked((a - b - carry as i32).rem_euclid(32) as u128),
+                                a - b - (carry as i32) < 0,
+                            ),
+                        ));
+                }
+            }
+        }
+
+        asserting!("full-width flags are measured at 128 bits")
+            .that(&(
+                UInt::<128>::MAX.carrying_add(UInt::MIN, true),
+                UInt::<128>::MAX.carrying_add(UInt::MAX, true),
+                UInt::<128>::MIN.borrowing_sub(UInt::MAX, true),
+            ))
+            .is_equal_to(((UInt::MIN, true), (UInt::MAX, true), (UInt::MIN, true)));
+    }
+
+    #[test]
+    fn chained_carries() {
+        type U12 = UInt<12>;
+
+        fn add_24(a: u32, b: u32) -> (u32, bool) {
+            let (a_low, a_high) = (U12::masked(a as u128), U12::masked((a >> 12) as u128));
+            let (b_low, b_high) = (U12::masked(b as u128), U12::masked((b >> 12) as u128));
+            let (low, carry) = a_low.carrying_add(b_low, false);
+            let (high, carry) = a_high.carrying_add(b_high, carry);
+            ((high.get() << 12 | low.get()) as u32, carry)
+        }
+
+        fn sub_24(a: u32, b: u32) -> (u32, bool) {
+            let (a_low, a_high) = (U12::masked(a as u128), U12::masked((a >> 12) as u128));
+            let (b_low, b_high) = (U12::masked(b as u128), U12::masked((b >> 12) as u128));
+            let (low, borrow) = a_low.borrowing_sub(b_low, false);
+            let (high, borrow) = a_high.borrowing_sub(b_high, borrow);
+            ((high.get() << 12 | low.get()) as u32, borrow)
+        }
+
+        let values = [
+            0, 1, 0xfff, 0x1000, 0x7f_ffff, 0x80_0000, 0xab_cdef, 0xff_f000, 0xff_ffff,
+        ];
+
+        for &a in &values {
+            for &b in &values {
+                asserting!("two 12-bit adds make a 24-bit add")
+                    .that(&add_24(a, b))
+                    .is_equal_to(((a + b) & 0xff_ffff, a + b > 0xff_ffff));
+
+                asserting!("two 12-bit subtractions make a 24-bit subtraction")
+                    .that(&sub_24(a, b))
+                    .is_equal_to((a.wrapping_sub(b) & 0xff_ffff, a < b));
+            }
+        }
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        fn check<const BITS: usize>() {
+            for seed in random_u128s().take(100) {
+                let (x, y) = (UInt::<BITS>::masked(seed), UInt::<BITS>::masked(seed >> 64));
+
+                asserting!("negation flips only the bits inside the width")
+                    .that(&(!x).get())
+                    .is_equal_to(x.get() ^ UInt::<BITS>::MAX.get());
+
+                asserting!("binary operators match the operators on the values")
+                    .that(&((x & y).get(), (x | y).get(), (x ^ y).get()))
+                    .is_equal_to((x.get() & y.get(), x.get() | y.get(), x.get() ^ y.get()));
+
+                asserting!("primitive operands are masked first")
+                    .that(&(x | seed, x ^ seed, x & seed))
+                    .is_equal_to((
+                        x | UInt::masked(seed),
+                        x ^ UInt::masked(seed),
+                        x & UInt::masked(seed),
+                    ));
+            }
+        }
+
+        check::<1>();
+        check::<5>();
+        check::<12>();
+        check::<64>();
+        check::<128>();
+
+        let mut value = UInt::<12>::new(0xf0f);
+        value &= UInt::new(0xff0);
+        value |= 0xf000_0001;
+        value ^= UInt::new(0x100);
+
+        asserting!("assignment operators stay within the width")
+            .that(&value.get())
+            .is_equal_to(0xe01);
+    }
+
+    #[test]
+    fn shifts_and_rotates() {
+        type U5 = UInt<5>;
+
+        for a in 0..32_u32 {
+            let x = U5::new(a as u128);
+
+            for n in 0..12_u32 {
+                let wide = (a << 5 | a) as u128;
+
+                asserting!("shifts and rotates stay within 5 bits")
+                    .that(&(
+                        (x << n).get(),
+                        (x >> n as usize).get(),
+                        x.wrapping_shl(n).get(),
+                        x.rotate_left(n).get(),
+                        x.rotate_right(n).get(),
+                    ))
+                    .is_equal_to((
+                        if n < 5 { (a << n & 31) as u128 } else { 0 },
+                        if n < 5 { (a >> n) as u128 } else { 0 },
+                        (a << (n % 5) & 31) as u128,
+                        wide >> (5 - n % 5) & 31,
+                        wide >> (n % 5) & 31,
+                    ));
+            }
+        }
+
+        let mut value = UInt::<128>::MAX;
+        value <<= 127_u32;
+        value >>= 1_usize;
+
+        asserting!("full-width shifts and rotates use all 128 bits")
+            .that(&(
+                value.get(),
+                (UInt::<128>::MAX << 128_u32).get(),
+                UInt::<128>::new(1).rotate_right(1).get(),
+            ))
+            .is_equal_to((1 << 126, 0, 1 << 127));
+    }
+
+    #[test]
+    fn resizing() {
+        asserting!("widening fills the new bits with zeros")
+            .that(&(
+                UInt::<4>::MAX.widen::<12>(),
+                UInt::<1>::MAX.widen::<128>().get(),
+                UInt::<12>::new(0x800).widen::<12>().get(),
+            ))
+            .is_equal_to((UInt::new(0xf), 1, 0x800));
+
+        asserting!("narrowing checks or masks the value")
+            .that(&(
+                UInt::<12>::new(0xf).try_resize::<4>(),
+                UInt::<12>::new(0x1f).try_resize::<4>(),
+                UInt::<12>::new(0xabc).masked_resize::<4>(),
+                UInt::<12>::new(0xabc).masked_resize::<16>(),
+            ))
+            .is_equal_to((Some(UInt::MAX), None, UInt::new(0xc), UInt::new(0xabc)));
+    }
+
+    #[test]
+    fn operators() {
+        let mut value = UInt::<12>::new(0x800);
+        value += UInt::new(0x7ff);
+        value -= UInt::new(0xf);
+        value *= UInt::new(1);
+
+        asserting!("operators compute the result when it fits")
+            .that(&(value.get(), (UInt::<12>::new(3) * UInt::new(5)).get()))
+            .is_equal_to((0xff0, 15));
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "attempt to add with overflow")
+    )]
+    fn operators_overflow() {
+        assert_eq!(UInt::<12>::MAX + UInt::new(1), UInt::MIN);
+    }
+
+    #[test]
+    fn constants() {
+        macro_rules! check {
+            ($($bits:literal: $max:expr),*) => {
+                $(
+                    asserting!("constants match the width")
+                        .that(&(
+                            UInt::<$bits>::MIN.get(),
+                            UInt::<$bits>::MAX.get(),
+                            UInt::<$bits>::ZERO.get(),
+                            UInt::<$bits>::ONE.get(),
+                            UInt::<$bits>::BITS,
+                            UInt::<$bits>::BIT_SIZE,
+                        ))
+                        .is_equal_to((0, $max, 0, 1, $bits, $bits));
+                )*
+            };
+        }
+
+        check!(
+            1: 1,
+            7: 0x7f,
+            8: u8::MAX as u128,
+            12: 0xfff,
+            16: u16::MAX as u128,
+            32: u32::MAX as u128,
+            63: i64::MAX as u128,
+            64: u64::MAX as u128,
+            127: u128::MAX >> 1,
+            128: u128::MAX
+        );
+    }
+
+    #[test]
+    fn constructing_in_constants() {
+        const FIELD: UInt<7> = UInt::new(0x5a);
+
+        asserting!("every constructor agrees on values that fit")
+            .that(&(
+                UInt::<7>::new(0x5a),
+                UInt::<7>::try_new(0x5a).ok(),
+                UInt::<7>::masked(0x5a),
+                UInt::<7>::try_from(0x5a_u128).ok(),
+            ))
+            .is_equal_to((FIELD, Some(FIELD), FIELD, Some(FIELD)));
+
+        asserting!("the accessors agree with get")
+            .that(&(FIELD.value(), FIELD.into_inner()))
+            .is_equal_to((FIELD.get(), FIELD.get()));
+
+        asserting!("values that don't fit are rejected or masked")
+            .that(&(
+                UInt::<7>::try_new(0xda).map_err(|error| error.to_string()),
+                UInt::<7>::masked(0xda),
+                UInt::<7>::try_from(0xda_u128).map_err(|error| error.to_string()),
+            ))
+            .is_equal_to((
+                Err("218 doesn't fit in 7 bits as an unsigned integer".to_string()),
+                FIELD,
+                Err("218 doesn't fit in 7 bits as an unsigned integer".to_string()),
+            ));
+    }
+
+    #[test]
+    fn comparing() {
+        let small = UInt::<20>::new(0x0_ffff);
+        let large = UInt::<20>::new(0xf_0000);
+
+        asserting!("values order by their numbers")
+            .that(&(small < large, small.max(large)))
+            .is_equal_to((true, large));
+
+        asserting!("masked values equal the values they were masked to")
+            .that(&UInt::<20>::masked(0xfff_ffff))
+            .is_equal_to(UInt::<20>::MAX);
+    }
+
+    #[test]
+    fn collections() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let keys = [0, 1, 0x7ff, 0x800, 0xfff].map(UInt::<12>::new);
+
+        let map: HashMap<_, _> = keys.iter().map(|&key| (key, key.get())).collect();
+        asserting!("every key finds its own value")
+            .that(&keys.iter().all(|key| map[key] == key.get()))
+            .is_true();
+
+        asserting!("masked keys find the values they were masked to")
+            .that(&map.get(&UInt::masked(0xffff)))
+            .is_equal_to(Some(&0xfff));
+
+        let tree: BTreeSet<_> = keys.iter().rev().copied().collect();
+        asserting!("tree sets iterate in numeric order")
+            .that(&tree.into_iter().collect::<Vec<_>>())
+            .is_equal_to(keys.to_vec());
+
+        asserting!("the default is zero")
+            .that(&UInt::<12>::default())
+            .is_equal_to(UInt::ZERO);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{BitConsts, BitSize, TryFromIntError, UInt};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// A one-bit unsigned integer, either 0 or 1.
+///
+/// This is for modelling single signals and flag bits as numbers rather than as `bool`, so they
+/// convert to and from the other integer types and take part in the same traits. It converts to
+/// and from `bool` as well, with 1 being `true`.
+///
+/// The bitwise operators are implemented, but the arithmetic ones aren't: a one-bit sum either
+/// wraps, in which case it's just `^`, or it overflows on almost every input, and neither makes
+/// for a useful `+`. Use `^` for addition modulo 2 and `&` for the carry.
+///
+/// # Examples
+///
+/// ```
+/// use quark::u1;
+///
+/// let (a, b) = (u1::ONE, u1::from(true));
+/// assert_eq!(a ^ b, u1::ZERO);
+/// assert_eq!(a & b, u1::ONE);
+/// assert!(bool::from(!u1::ZERO));
+/// assert_eq!(u8::from(u1::ONE), 1);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct u1(bool);
+
+impl u1 {
+    /// The value 0.
+    pub const ZERO: u1 = u1(false);
+
+    /// The value 1.
+    pub const ONE: u1 = u1(true);
+
+    /// The smallest value of the type, 0.
+    pub const MIN: u1 = u1::ZERO;
+
+    /// The largest value of the type, 1.
+    pub const MAX: u1 = u1::ONE;
+
+    /// Creates a value, panicking if it isn't 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is larger than 1.
+    #[track_caller]
+    pub const fn new(value: u8) -> u1 {
+        match u1::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it isn't 0 or 1.
+    pub const fn try_new(value: u8) -> Result<u1, TryFromIntError> {
+        if value <= 1 {
+            Ok(u1(value == 1))
+        } else {
+            Err(TryFromIntError::from_u128(value as u128, 1))
+        }
+    }
+
+    /// Creates a value from the lowest bit of `value`, discarding the rest.
+    pub const fn masked(value: u8) -> u1 {
+        u1(value & 1 == 1)
+    }
+
+    /// Returns the value as a `u8`.
+    pub const fn get(self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Returns the value as a `u8`, the same as [`get`](#method.get).
+    pub const fn value(self) -> u8 {
+        self.get()
+    }
+
+    /// Consumes the wrapper and returns the value as a `u8`, the same as [`get`](#method.get).
+    pub const fn into_inner(self) -> u8 {
+        self.get()
+    }
+}
+
+impl BitSize for u1 {
+    const BIT_SIZE: usize = 1;
+}
+
+impl BitConsts for u1 {
+    const ALL_SET: u1 = u1::ONE;
+    const NONE_SET: u1 = u1::ZERO;
+}
+
+impl Debug for u1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for u1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl Not for u1 {
+    type Output = u1;
+
+    fn not(self) -> u1 {
+        u1(!self.0)
+    }
+}
+
+macro_rules! u1_op_impl {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt;)*) => {
+        $(
+            impl $trait for u1 {
+                type Output = u1;
+
+                fn $method(self, rhs: u1) -> u1 {
+                    u1(self.0 $op rhs.0)
+                }
+            }
+
+            impl $assign_trait for u1 {
+                fn $assign_method(&mut self, rhs: u1) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+u1_op_impl! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &;
+    BitOr, bitor, BitOrAssign, bitor_assign, |;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^;
+}
+
+impl From<bool> for u1 {
+    fn from(value: bool) -> u1 {
+        u1(value)
+    }
+}
+
+impl From<u1> for bool {
+    fn from(value: u1) -> bool {
+        value.0
+    }
+}
+
+impl TryFrom<u8> for u1 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u8) -> Result<u1, TryFromIntError> {
+        u1::try_new(value)
+    }
+}
+
+impl From<UInt<1>> for u1 {
+    fn from(value: UInt<1>) -> u1 {
+        u1(value.get() == 1)
+    }
+}
+
+impl From<u1> for UInt<1> {
+    fn from(value: u1) -> UInt<1> {
+        UInt::masked(value.get().into())
+    }
+}
+
+macro_rules! from_u1_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<u1> for $type {
+                fn from(value: u1) -> $type {
+                    value.get() as $type
+                }
+            }
+        )*
+    };
+}
+
+from_u1_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{u24, BitIndex};
+    use spectral::prelude::*;
+
+    #[test]
+    fn bool_interop() {
+        asserting!("true is one and false is zero")
+            .that(&(
+                u1::from(true),
+                u1::from(false),
+                bool::from(u1::ONE),
+                bool::from(u1::ZERO),
+            ))
+            .is_equal_to((u1::ONE, u1::ZERO, true, false));
+
+        for &(a, b) in &[(false, false), (false, true), (true, false), (true, true)] {
+            let (x, y) = (u1::from(a), u1::from(b));
+
+            asserting!("operators match the bool operators")
+                .that(&(
+                    bool::from(x & y),
+                    bool::from(x | y),
+                    bool::from(x ^ y),
+                    bool::from(!x),
+                ))
+                .is_equal_to((a & b, a | b, a ^ b, !a));
+        }
+
+        let mut value = u1::ONE;
+        value ^= u1::ONE;
+        value |= u1::ONE;
+        value &= u1::ZERO;
+
+        asserting!("compound assignment works")
+            .that(&value)
+            .is_equal_to(u1::ZERO);
+    }
+
+    #[test]
+    fn integer_interop() {
+        asserting!("only zero and one are accepted")
+            .that(&(
+                u1::try_from(0_u8).ok(),
+                u1::try_from(1_u8).ok(),
+                u1::try_from(2_u8).ok(),
+            ))
+            .is_equal_to((Some(u1::ZERO), Some(u1::ONE), None));
+
+        asserting!("errors report the value and width")
+            .that(&u1::try_from(2_u8).unwrap_err().to_string())
+            .is_equal_to("2 doesn't fit in 1 bits as an unsigned integer".to_string());
+
+        asserting!("masking keeps the lowest bit")
+            .that(&(u1::masked(0xfe), u1::masked(0xff)))
+            .is_equal_to((u1::ZERO, u1::ONE));
+
+        asserting!("values convert to the primitives")
+            .that(&(u8::from(u1::ONE), i8::from(u1::ONE), u128::from(u1::ZERO)))
+            .is_equal_to((1, 1, 0));
+
+        asserting!("values convert to and from UInt<1>")
+            .that(&(UInt::<1>::from(u1::ONE), u1::from(UInt::<1>::MAX)))
+            .is_equal_to((UInt::MAX, u1::MAX));
+
+        asserting!("bits of other values convert to u1")
+            .that(&u1::from(u24::new(0x80_0000).get().bit(23)))
+            .is_equal_to(u1::ONE);
+
+        asserting!("the bit size is one")
+            .that(&(u1::BIT_SIZE, u1::ALL_SET, u1::ZERO < u1::ONE))
+            .is_equal_to((1, u1::ONE, true));
+    }
+
+    #[test]
+    #[should_panic(expected = "2 doesn't fit in 1 bits as an unsigned integer")]
+    fn new_panics_when_out_of_range() {
+        u1::new(2);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{BitConsts, BitSize, TryFromIntError, UInt};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A 24-bit unsigned integer that takes exactly three bytes.
+///
+/// Unlike [`UInt<24>`](struct.UInt.html), this type is stored as three bytes, which matches the
+/// 24-bit fields of file formats and buses. It converts to and from bytes in either order, and to
+/// and from `UInt<24>` and the primitives.
+///
+/// In memory, a value is its three bytes in little-endian order on every target, with no padding
+/// and an alignment of 1. With the `bytemuck` feature, a byte buffer of three-byte values can be
+/// cast to a slice of `u24` without copying, and with the `zerocopy` feature a `u24` can be a field
+/// of a header struct. Wire formats in big-endian order can use `BigEndian<u24>` instead.
+///
+/// # Examples
+///
+/// ```
+/// use quark::u24;
+///
+/// let value = u24::from_be_bytes([0x12, 0x34, 0x56]);
+/// assert_eq!(value.get(), 0x12_3456);
+/// assert_eq!(value.to_le_bytes(), [0x56, 0x34, 0x12]);
+/// assert_eq!(value.to_string(), "1193046");
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[repr(transparent)]
+pub struct u24([u8; 3]);
+
+impl u24 {
+    /// The smallest value of the type, 0.
+    pub const MIN: u24 = u24([0x00; 3]);
+
+    /// The largest value of the type, 2<sup>24</sup> - 1.
+    pub const MAX: u24 = u24([0xff; 3]);
+
+    /// Creates a value, panicking if it doesn't fit in 24 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is larger than [`MAX`](#associatedconstant.MAX).
+    #[track_caller]
+    pub const fn new(value: u32) -> u24 {
+        match u24::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it doesn't fit in 24 bits.
+    pub const fn try_new(value: u32) -> Result<u24, TryFromIntError> {
+        if value <= 0x00ff_ffff {
+            Ok(u24::masked(value))
+        } else {
+            Err(TryFromIntError::from_u128(value as u128, 24))
+        }
+    }
+
+    /// Creates a value from the lowest 24 bits of `value`, discarding the rest.
+    pub const fn masked(value: u32) -> u24 {
+        let [b0, b1, b2, _] = value.to_le_bytes();
+        u24([b0, b1, b2])
+    }
+
+    /// Returns the value as a `u32`.
+    pub const fn get(self) -> u32 {
+        let [b0, b1, b2] = self.0;
+        u32::from_le_bytes([b0, b1, b2, 0])
+    }
+
+    /// Returns the value as a `u32`, the same as [`get`](#method.get).
+    pub const fn value(self) -> u32 {
+        self.get()
+    }
+
+    /// Consumes the wrapper and returns the value as a `u32`, the same as [`get`](#method.get).
+    pub const fn into_inner(self) -> u32 {
+        self.get()
+    }
+
+    /// Creates a value from its bytes in little-endian order.
+    pub const fn from_le_bytes(bytes: [u8; 3]) -> u24 {
+        u24(bytes)
+    }
+
+    /// Creates a value from its bytes in big-endian order.
+    pub const fn from_be_bytes(bytes: [u8; 3]) -> u24 {
+        let [b0, b1, b2] = bytes;
+        u24([b2, b1, b0])
+    }
+
+    /// Returns the bytes of the value in little-endian order.
+    pub const fn to_le_bytes(self) -> [u8; 3] {
+        self.0
+    }
+
+    /// Returns the bytes of the value in big-endian order.
+    pub const fn to_be_bytes(self) -> [u8; 3] {
+        let [b0, b1, b2] = self.0;
+        [b2, b1, b0]
+    }
+}
+
+impl BitSize for u24 {
+    const BIT_SIZE: usize = 24;
+}
+
+impl BitConsts for u24 {
+    const ALL_SET: u24 = u24::MAX;
+    const NONE_SET: u24 = u24::MIN;
+}
+
+impl PartialOrd for u24 {
+    fn partial_cmp(&self, other: &u24) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for u24 {
+    fn cmp(&self, other: &u24) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl Debug for u24 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for u24 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl From<u8> for u24 {
+    fn from(value: u8) -> u24 {
+        u24::masked(value.into())
+    }
+}
+
+impl From<u16> for u24 {
+    fn from(value: u16) -> u24 {
+        u24::masked(value.into())
+    }
+}
+
+impl TryFrom<u32> for u24 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u32) -> Result<u24, TryFromIntError> {
+        u24::try_new(value)
+    }
+}
+
+impl From<UInt<24>> for u24 {
+    fn from(value: UInt<24>) -> u24 {
+        u24::masked(value.get() as u32)
+    }
+}
+
+impl From<u24> for UInt<24> {
+    fn from(value: u24) -> UInt<24> {
+        UInt::masked(value.get().into())
+    }
+}
+
+macro_rules! from_u24_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<u24> for $type {
+                fn from(value: u24) -> $type {
+                    value.get() as $type
+                }
+            }
+        )*
+    };
+}
+
+from_u24_impl!(u32, u64, u128, i32, i64, i128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn byte_round_trips() {
+        let cases = [
+            ([0x00, 0x00, 0x00], 0x00_0000),
+            ([0xff, 0xff, 0xff], 0xff_ffff),
+            ([0x01, 0x00, 0x00], 0x00_0001),
+            ([0x00, 0x00, 0x01], 0x01_0000),
+            ([0x12, 0x34, 0x56], 0x56_3412),
+            ([0x80, 0x00, 0x7f], 0x7f_0080),
+        ];
+
+        for &(bytes, value) in &cases {
+            let from_le = u24::from_le_bytes(bytes);
+            let mut reversed = bytes;
+            reversed.reverse();
+            let from_be = u24::from_be_bytes(reversed);
+
+            asserting!("bytes decode in either order")
+                .that(&(from_le.get(), from_be.get()))
+                .is_equal_to((value, value));
+
+            asserting!("bytes encode in either order")
+                .that(&(from_le.to_le_bytes(), from_le.to_be_bytes()))
+                .is_equal_to((bytes, reversed));
+        }
+
+        asserting!("endianness matters for asymmetric values")
+            .that(&(
+                u24::from_le_bytes([0x01, 0x02, 0x03]).get(),
+                u24::from_be_bytes([0x01, 0x02, 0x03]).get(),
+            ))
+            .is_equal_to((0x03_0201, 0x01_0203));
+    }
+
+    #[test]
+    fn bounds() {
+        asserting!("limits cover 24 bits")
+            .that(&(u24::MIN.get(), u24::MAX.get(), u24::BIT_SIZE))
+            .is_equal_to((0, 0xff_ffff, 24));
+
+        asserting!("values that fit are accepted")
+            .that(&(u24::try_new(0xff_ffff), u24::try_from(0xab_cdef_u32).ok()))
+            .is_equal_to((Ok(u24::MAX), Some(u24::from_be_bytes([0xab, 0xcd, 0xef]))));
+
+        asserting!("values that don't fit are rejected")
+            .that(&(u24::try_new(0x100_0000).ok(), u24::try_from(u32::MAX).ok()))
+            .is_equal_to((None, None));
+
+        asserting!("errors report the value and width")
+            .that(&u24::try_from(0x100_0000_u32).unwrap_err().to_string())
+            .is_equal_to("16777216 doesn't fit in 24 bits as an unsigned integer".to_string());
+
+        asserting!("masking discards the upper byte")
+            .that(&u24::masked(0xab12_3456).get())
+            .is_equal_to(0x12_3456);
+    }
+
+    #[test]
+    #[should_panic(expected = "16777216 doesn't fit in 24 bits as an unsigned integer")]
+    fn new_panics_when_out_of_range() {
+        u24::new(0x100_0000);
+    }
+
+    #[test]
+    fn conversions() {
+        asserting!("smaller primitives convert losslessly")
+            .that(&(u24::from(0xff_u8).get(), u24::from(0xffff_u16).get()))
+            .is_equal_to((0xff, 0xffff));
+
+        asserting!("values convert to wider primitives")
+            .that(&(
+                u32::from(u24::MAX),
+                i32::from(u24::MAX),
+                u64::from(u24::MAX),
+            ))
+            .is_equal_to((0xff_ffff, 0xff_ffff, 0xff_ffff));
+
+        asserting!("values convert to and from UInt<24>")
+            .that(&(
+                UInt::<24>::from(u24::MAX),
+                u24::from(UInt::<24>::new(0x12_3456)),
+            ))
+            .is_equal_to((UInt::MAX, u24::new(0x12_3456)));
+    }
+
+    #[test]
+    fn ordering_and_formatting() {
+        let mut values = [0x01_0000, 0x00_00ff, 0xff_0000, 0x00_ff00].map(u24::new);
+        values.sort();
+
+        asserting!("values order by their numbers")
+            .that(&values.map(u24::get))
+            .is_equal_to([0x00_00ff, 0x00_ff00, 0x01_0000, 0xff_0000]);
+
+        asserting!("values format as numbers")
+            .that(&(
+                u24::MAX.to_string(),
+                format!("{:?}", u24::new(42)),
+                format!("{:>6}", u24::new(42)),
+            ))
+            .is_equal_to((
+                "16777215".to_string(),
+                "42".to_string(),
+                "    42".to_string(),
+            ));
+    }
+}
@@ -0,0 +1,285 @@
+use crate::wide::{self, Limbs};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A 256-bit unsigned integer.
+///
+/// Hashes like SHA-256 and the words of the Ethereum virtual machine are 256 bits wide. This type
+/// holds them as four `u64` limbs and supports the bit operation traits across the whole width,
+/// so bit ranges and sign extension work the same way they do on the primitives even where they
+/// cross from one limb to the next. It converts to and from bytes in either order and from every
+/// unsigned primitive.
+///
+/// Only the bitwise operators and wrapping addition and subtraction are provided, not the full
+/// set of arithmetic. Shifts by 256 or more give zero rather than panicking, as they do for
+/// [`UInt`](struct.UInt.html).
+///
+/// Formatting with `{}` prints the number in decimal, and the hex and binary traits print its
+/// bits.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{u256, BitIndex};
+///
+/// let mut bytes = [0; 32];
+/// bytes[24..].copy_from_slice(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+/// let hash = u256::from_be_bytes(bytes);
+///
+/// assert_eq!(hash, u256::from(0x0123_4567_89ab_cdef_u64));
+/// assert_eq!(hash.bits(4..12), u256::from(0xde_u8));
+/// assert_eq!(format!("{:#x}", hash), "0x123456789abcdef");
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct u256(Limbs);
+
+impl u256 {
+    /// The smallest value of the type, 0.
+    pub const MIN: u256 = u256([0; 4]);
+
+    /// The largest value of the type, 2<sup>256</sup> - 1.
+    pub const MAX: u256 = u256([u64::MAX; 4]);
+
+    /// Creates a value from its `u64` limbs, least significant first.
+    pub const fn from_limbs(limbs: [u64; 4]) -> u256 {
+        u256(limbs)
+    }
+
+    /// Returns the `u64` limbs of the value, least significant first.
+    pub const fn to_limbs(self) -> [u64; 4] {
+        self.0
+    }
+
+    /// Adds two values, returning `None` if the sum overflows.
+    pub const fn checked_add(self, rhs: u256) -> Option<u256> {
+        match wide::carrying_add(self.0, rhs.0) {
+            (sum, false) => Some(u256(sum)),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtracts `rhs` from the value, returning `None` if the difference is negative.
+    pub const fn checked_sub(self, rhs: u256) -> Option<u256> {
+        match wide::borrowing_sub(self.0, rhs.0) {
+            (difference, false) => Some(u256(difference)),
+            (_, true) => None,
+        }
+    }
+
+    /// Shifts the value right by `rhs` bits, filling the upper bits with zeros. Shifting by 256 or
+    /// more gives zero.
+    pub const fn unbounded_shr(self, rhs: u32) -> u256 {
+        u256(wide::shr(self.0, rhs, 0))
+    }
+}
+
+impl PartialOrd for u256 {
+    fn partial_cmp(&self, other: &u256) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for u256 {
+    fn cmp(&self, other: &u256) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else if wide::less_than(self.0, other.0) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl Debug for u256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for u256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad_integral(true, "", &wide::to_decimal(self.0))
+    }
+}
+
+macro_rules! u256_from_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for u256 {
+                fn from(value: $type) -> u256 {
+                    let value = value as u128;
+                    u256([value as u64, (value >> 64) as u64, 0, 0])
+                }
+            }
+        )*
+    };
+}
+
+u256_from_impl!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn bytes() {
+        let bytes: Vec<u8> = (1..=32).collect();
+        let mut be = [0; 32];
+        be.copy_from_slice(&bytes);
+
+        let value = u256::from_be_bytes(be);
+
+        asserting!("big-endian bytes put the first byte at the top")
+            .that(&value.to_limbs())
+            .is_equal_to([
+                0x191a_1b1c_1d1e_1f20,
+                0x1112_1314_1516_1718,
+                0x090a_0b0c_0d0e_0f10,
+                0x0102_0304_0506_0708,
+            ]);
+
+        asserting!("bytes round trip in either order")
+            .that(&(
+                u256::from_be_bytes(value.to_be_bytes()),
+                u256::from_le_bytes(value.to_le_bytes()),
+                u256::from_le_bytes(be).to_le_bytes(),
+            ))
+            .is_equal_to((value, value, be));
+
+        let mut reversed = be;
+        reversed.reverse();
+        asserting!("the orders are each other's reverse")
+            .that(&value.to_le_bytes())
+            .is_equal_to(reversed);
+    }
+
+    #[test]
+    fn conversions() {
+        asserting!("primitives fill the low limbs")
+            .that(&(
+                u256::from(0xab_u8).to_limbs(),
+                u256::from(u64::MAX).to_limbs(),
+                u256::from(u128::MAX - 1).to_limbs(),
+            ))
+            .is_equal_to((
+                [0xab, 0, 0, 0],
+                [u64::MAX, 0, 0, 0],
+                [u64::MAX - 1, u64::MAX, 0, 0],
+            ));
+    }
+
+    #[test]
+    fn ordering() {
+        let values = [
+            u256::MIN,
+            u256::ONE,
+            u256::from(u64::MAX),
+            u256::from_limbs([0, 1, 0, 0]),
+            u256::from_limbs([0, 0, 0, 1]),
+            u256::from_limbs([0, 0, 0, 1 << 63]),
+            u256::MAX,
+        ];
+
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                asserting!("values order by their numbers")
+                    .that(&a.cmp(b))
+                    .is_equal_to(i.cmp(&j));
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        asserting!("addition carries across limbs and wraps at 256 bits")
+            .that(&(
+                u256::from(u64::MAX).wrapping_add(u256::ONE),
+                u256::MAX.wrapping_add(u256::ONE),
+                u256::MAX.checked_add(u256::ONE),
+                u256::from_limbs([0, 0, 1, 0]).checked_sub(u256::ONE),
+                u256::ZERO.checked_sub(u256::ONE),
+                u256::ONE.wrapping_neg(),
+            ))
+            .is_equal_to((
+                u256::from_limbs([0, 1, 0, 0]),
+                u256::ZERO,
+                None,
+                Some(u256::from_limbs([u64::MAX, u64::MAX, 0, 0])),
+                None,
+                u256::MAX,
+            ));
+    }
+
+    #[test]
+    fn operators() {
+        let value = u256::from_limbs([0xff, 0, 0, 1 << 63]);
+
+        asserting!("shifts move bits across limbs")
+            .that(&(
+                value << 60_u32,
+                value >> 200_usize,
+                value << 256_u32,
+                value >> 1000_u32,
+            ))
+            .is_equal_to((
+                u256::from_limbs([0xf000_0000_0000_0000, 0xf, 0, 0]),
+                u256::from_limbs([1 << 55, 0, 0, 0]),
+                u256::ZERO,
+                u256::ZERO,
+            ));
+
+        asserting!("bitwise operators work on every limb")
+            .that(&(
+                !value,
+                value & u256::from(0xf0_u8),
+                value ^ value,
+                value | u256::ONE,
+            ))
+            .is_equal_to((
+                u256::from_limbs([!0xff, !0, !0, !(1 << 63)]),
+                u256::from(0xf0_u8),
+                u256::ZERO,
+                u256::from_limbs([0xff, 0, 0, 1 << 63]),
+            ));
+
+        asserting!("counts cover the whole width")
+            .that(&(
+                value.count_ones(),
+                value.leading_zeros(),
+                value.trailing_zeros(),
+                u256::ZERO.leading_zeros(),
+                u256::ZERO.trailing_zeros(),
+                u256::MAX.trailing_ones(),
+                (value >> 1_u32).leading_zeros(),
+            ))
+            .is_equal_to((9, 0, 0, 256, 256, 256, 1));
+    }
+
+    #[test]
+    fn formatting() {
+        let value = u256::from_limbs([0xdead_beef, 0, 0, 0x0123_4567_89ab_cdef]);
+
+        asserting!("values format as numbers")
+            .that(&(
+                format!("{}", u256::from(u128::MAX)),
+                format!("{:?}", u256::ZERO),
+                format!("{:>5}", u256::from(42_u8)),
+                format!("{:x}", value),
+                format!("{:#X}", value),
+                format!("{:#066x}", u256::ONE),
+                format!("{:b}", u256::from_limbs([1, 1, 0, 0])),
+            ))
+            .is_equal_to((
+                u128::MAX.to_string(),
+                "0".to_string(),
+                "   42".to_string(),
+                format!("123456789abcdef{:048x}", 0xdead_beef_u32),
+                format!("0x123456789ABCDEF{:048X}", 0xdead_beef_u32),
+                format!("0x{:064x}", 1),
+                format!("1{:064b}", 1),
+            ));
+    }
+}
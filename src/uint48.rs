@@ -0,0 +1,347 @@
+use crate::{BitConsts, BitSize, TryFromIntError, UInt};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A 48-bit unsigned integer that takes exactly six bytes.
+///
+/// MAC addresses, many hardware counters, and several timestamp formats are 48 bits wide. This
+/// type holds them in six bytes, converts to and from bytes in either order, and wraps around at
+/// 2<sup>48</sup> when used as a counter.
+///
+/// Formatting with `{}` prints the number in decimal, and the alternate form `{:#}` prints the
+/// bytes in big-endian order as colon-separated hex, the usual way of writing a MAC address.
+///
+/// In memory, a value is its six bytes in little-endian order on every target, with no padding and
+/// an alignment of 1. With the `bytemuck` feature, a byte buffer of six-byte values can be cast to
+/// a slice of `u48` without copying, and with the `zerocopy` feature a `u48` can be a field of a
+/// header struct. Wire formats in big-endian order can use `BigEndian<u48>` instead.
+///
+/// # Examples
+///
+/// ```
+/// use quark::u48;
+///
+/// let mac = u48::from_be_bytes([0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6]);
+/// assert_eq!(format!("{:#}", mac), "00:1b:63:84:45:e6");
+/// assert_eq!(format!("{}", mac), "117633730022");
+///
+/// assert_eq!(u48::MAX.wrapping_add(u48::new(2)), u48::new(1));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[repr(transparent)]
+pub struct u48([u8; 6]);
+
+impl u48 {
+    /// The smallest value of the type, 0.
+    pub const MIN: u48 = u48([0x00; 6]);
+
+    /// The largest value of the type, 2<sup>48</sup> - 1.
+    pub const MAX: u48 = u48([0xff; 6]);
+
+    /// Creates a value, panicking if it doesn't fit in 48 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is larger than [`MAX`](#associatedconstant.MAX).
+    #[track_caller]
+    pub const fn new(value: u64) -> u48 {
+        match u48::try_new(value) {
+            Ok(value) => value,
+            Err(error) => error.panic(),
+        }
+    }
+
+    /// Creates a value, returning an error if it doesn't fit in 48 bits.
+    pub const fn try_new(value: u64) -> Result<u48, TryFromIntError> {
+        if value <= 0xffff_ffff_ffff {
+            Ok(u48::masked(value))
+        } else {
+            Err(TryFromIntError::from_u128(value as u128, 48))
+        }
+    }
+
+    /// Creates a value from the lowest 48 bits of `value`, discarding the rest.
+    pub const fn masked(value: u64) -> u48 {
+        let [b0, b1, b2, b3, b4, b5, _, _] = value.to_le_bytes();
+        u48([b0, b1, b2, b3, b4, b5])
+    }
+
+    /// Returns the value as a `u64`.
+    pub const fn get(self) -> u64 {
+        let [b0, b1, b2, b3, b4, b5] = self.0;
+        u64::from_le_bytes([b0, b1, b2, b3, b4, b5, 0, 0])
+    }
+
+    /// Returns the value as a `u64`, the same as [`get`](#method.get).
+    pub const fn value(self) -> u64 {
+        self.get()
+    }
+
+    /// Consumes the wrapper and returns the value as a `u64`, the same as [`get`](#method.get).
+    pub const fn into_inner(self) -> u64 {
+        self.get()
+    }
+
+    /// Adds two values, wrapping around at 2<sup>48</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::u48;
+    ///
+    /// let counter = u48::new(0xffff_ffff_fff0);
+    /// assert_eq!(counter.wrapping_add(u48::new(0x20)), u48::new(0x10));
+    /// ```
+    pub const fn wrapping_add(self, rhs: u48) -> u48 {
+        u48::masked(self.get().wrapping_add(rhs.get()))
+    }
+
+    /// Subtracts `rhs` from the value, wrapping around at 2<sup>48</sup>.
+    ///
+    /// This gives the number of ticks between two readings of a 48-bit counter even if it
+    /// rolled over in between.
+    pub const fn wrapping_sub(self, rhs: u48) -> u48 {
+        u48::masked(self.get().wrapping_sub(rhs.get()))
+    }
+
+    /// Creates a value from its bytes in little-endian order.
+    pub const fn from_le_bytes(bytes: [u8; 6]) -> u48 {
+        u48(bytes)
+    }
+
+    /// Creates a value from its bytes in big-endian order.
+    pub const fn from_be_bytes(bytes: [u8; 6]) -> u48 {
+        let [b0, b1, b2, b3, b4, b5] = bytes;
+        u48([b5, b4, b3, b2, b1, b0])
+    }
+
+    /// Returns the bytes of the value in little-endian order.
+    pub const fn to_le_bytes(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Returns the bytes of the value in big-endian order.
+    pub const fn to_be_bytes(self) -> [u8; 6] {
+        let [b0, b1, b2, b3, b4, b5] = self.0;
+        [b5, b4, b3, b2, b1, b0]
+    }
+}
+
+impl BitSize for u48 {
+    const BIT_SIZE: usize = 48;
+}
+
+impl BitConsts for u48 {
+    const ALL_SET: u48 = u48::MAX;
+    const NONE_SET: u48 = u48::MIN;
+}
+
+impl PartialOrd for u48 {
+    fn partial_cmp(&self, other: &u48) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for u48 {
+    fn cmp(&self, other: &u48) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl Debug for u48 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for u48 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            let [b0, b1, b2, b3, b4, b5] = self.to_be_bytes();
+            write!(
+                f,
+                "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                b0, b1, b2, b3, b4, b5
+            )
+        } else {
+            Display::fmt(&self.get(), f)
+        }
+    }
+}
+
+macro_rules! u48_from_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for u48 {
+                fn from(value: $type) -> u48 {
+                    u48::masked(value.into())
+                }
+            }
+        )*
+    };
+}
+
+u48_from_impl!(u8, u16, u32);
+
+impl TryFrom<u64> for u48 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u64) -> Result<u48, TryFromIntError> {
+        u48::try_new(value)
+    }
+}
+
+impl From<UInt<48>> for u48 {
+    fn from(value: UInt<48>) -> u48 {
+        u48::masked(value.get() as u64)
+    }
+}
+
+impl From<u48> for UInt<48> {
+    fn from(value: u48) -> UInt<48> {
+        UInt::masked(value.get().into())
+    }
+}
+
+macro_rules! from_u48_impl {
+    ($($type:ty),*) => {
+        $(
+            impl From<u48> for $type {
+                fn from(value: u48) -> $type {
+                    value.get() as $type
+                }
+            }
+        )*
+    };
+}
+
+from_u48_impl!(u64, u128, i64, i128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn byte_round_trips() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab];
+
+        asserting!("bytes decode in either order")
+            .that(&(
+                u48::from_be_bytes(bytes).get(),
+                u48::from_le_bytes(bytes).get(),
+            ))
+            .is_equal_to((0x0123_4567_89ab, 0xab89_6745_2301));
+
+        for &value in &[0, 1, 0x8000_0000_0000, 0x0123_4567_89ab, 0xffff_ffff_ffff] {
+            let value = u48::new(value);
+
+            asserting!("bytes round-trip in either order")
+                .that(&(
+                    u48::from_be_bytes(value.to_be_bytes()),
+                    u48::from_le_bytes(value.to_le_bytes()),
+                ))
+                .is_equal_to((value, value));
+        }
+    }
+
+    #[test]
+    fn wrapping_at_48_bits() {
+        asserting!("adding past the maximum wraps to zero")
+            .that(&(
+                u48::MAX.wrapping_add(u48::new(1)),
+                u48::MAX.wrapping_add(u48::MAX),
+                u48::new(0x8000_0000_0000).wrapping_add(u48::new(0x8000_0000_0000)),
+            ))
+            .is_equal_to((u48::MIN, u48::new(0xffff_ffff_fffe), u48::MIN));
+
+        asserting!("subtracting past zero wraps to the maximum")
+            .that(&(
+                u48::MIN.wrapping_sub(u48::new(1)),
+                u48::new(5).wrapping_sub(u48::new(0xffff_ffff_fffb)),
+            ))
+            .is_equal_to((u48::MAX, u48::new(10)));
+
+        asserting!("the wrapped result isn't the 64-bit one")
+            .that(&u64::from(u48::MAX.wrapping_add(u48::new(1))))
+            .is_not_equal_to(u48::MAX.get() + 1);
+    }
+
+    #[test]
+    fn bounds() {
+        asserting!("values that don't fit are rejected")
+            .that(&(u48::try_new(1 << 48).ok(), u48::try_from(u64::MAX).ok()))
+            .is_equal_to((None, None));
+
+        asserting!("values that fit are accepted")
+            .that(&u48::try_from(0xffff_ffff_ffff_u64).ok())
+            .is_equal_to(Some(u48::MAX));
+
+        asserting!("errors report the value and width")
+            .that(&u48::try_from(1_u64 << 48).unwrap_err().to_string())
+            .is_equal_to(
+                "281474976710656 doesn't fit in 48 bits as an unsigned integer".to_string(),
+            );
+
+        asserting!("masking discards the upper bytes")
+            .that(&u48::masked(0xabcd_0123_4567_89ab).get())
+            .is_equal_to(0x0123_4567_89ab);
+    }
+
+    #[test]
+    #[should_panic(expected = "281474976710656 doesn't fit in 48 bits as an unsigned integer")]
+    fn new_panics_when_out_of_range() {
+        u48::new(1 << 48);
+    }
+
+    #[test]
+    fn conversions() {
+        asserting!("values convert to and from the primitives")
+            .that(&(
+                u48::from(u32::MAX).get(),
+                u64::from(u48::MAX),
+                i64::from(u48::MAX),
+            ))
+            .is_equal_to((0xffff_ffff, 0xffff_ffff_ffff, 0xffff_ffff_ffff));
+
+        asserting!("values convert to and from UInt<48>")
+            .that(&(UInt::<48>::from(u48::MAX), u48::from(UInt::<48>::new(7))))
+            .is_equal_to((UInt::MAX, u48::new(7)));
+    }
+
+    #[test]
+    fn formatting() {
+        let mac = u48::from_be_bytes([0xaa, 0xbb, 0xcc, 0x0d, 0x0e, 0x0f]);
+
+        asserting!("alternate formatting prints colon-separated hex")
+            .that(&(format!("{:#}", mac), format!("{:#}", u48::MIN)))
+            .is_equal_to((
+                "aa:bb:cc:0d:0e:0f".to_string(),
+                "00:00:00:00:00:00".to_string(),
+            ));
+
+        asserting!("plain formatting prints the number")
+            .that(&(
+                format!("{}", u48::MAX),
+                format!("{:?}", u48::new(42)),
+                format!("{:>4}", u48::new(42)),
+            ))
+            .is_equal_to((
+                "281474976710655".to_string(),
+                "42".to_string(),
+                "  42".to_string(),
+            ));
+    }
+}
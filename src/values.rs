@@ -0,0 +1,402 @@
+use crate::{Int, UInt};
+use std::convert::TryFrom;
+use std::iter::FusedIterator;
+
+/// An iterator over consecutive values of a narrow integer type, in ascending order.
+///
+/// This is returned by `all_values`, `range`, and `range_inclusive` on [`UInt`](struct.UInt.html)
+/// and [`Int`](struct.Int.html). It can be iterated from either end, and knows exactly how many
+/// values it has left. A range over more values than a `usize` can count has no upper bound in its
+/// `size_hint`, and calling `len` on it panics.
+///
+/// # Examples
+///
+/// ```
+/// use quark::UInt;
+///
+/// let mut values = UInt::<2>::all_values();
+/// assert_eq!(values.len(), 4);
+/// assert_eq!(values.next(), Some(UInt::new(0)));
+/// assert_eq!(values.next_back(), Some(UInt::new(3)));
+/// assert_eq!(values.map(UInt::get).collect::<Vec<_>>(), vec![1, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Values<T> {
+    front: T,
+    back: T,
+    exhausted: bool,
+}
+
+impl<T> Values<T> {
+    /// Creates an iterator from `front` to `back`, including both.
+    pub(crate) fn new(front: T, back: T) -> Values<T> {
+        Values {
+            front,
+            back,
+            exhausted: false,
+        }
+    }
+
+    /// Creates an iterator with no values.
+    pub(crate) fn empty(at: T) -> Values<T>
+    where
+        T: Copy,
+    {
+        Values {
+            front: at,
+            back: at,
+            exhausted: true,
+        }
+    }
+}
+
+impl<const BITS: usize> UInt<BITS> {
+    const ENUMERABLE: () = assert!(
+        BITS <= 32,
+        "all_values can only be used with types of up to 32 bits"
+    );
+
+    /// Returns an iterator over every value of the type, from `MIN` to `MAX`.
+    ///
+    /// There are 2<sup>BITS</sup> values, so this is only available for types of up to 32 bits.
+    /// Using it with a wider type fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// let squares: Vec<u128> = UInt::<3>::all_values().map(|x| x.get() * x.get()).collect();
+    /// assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    /// assert_eq!(UInt::<6>::all_values().rev().next(), Some(UInt::MAX));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use quark::UInt;
+    ///
+    /// let values = UInt::<33>::all_values();
+    /// ```
+    pub fn all_values() -> Values<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ENUMERABLE;
+
+        Values::new(Self::MIN, Self::MAX)
+    }
+
+    /// Returns an iterator over the values from `start` up to, but not including, `end`.
+    ///
+    /// This is the same as `start..end` for the primitives, which can't be written for `UInt`
+    /// because the trait it relies on isn't stable. The range is empty if `end` isn't above
+    /// `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// let values = UInt::range(UInt::<10>::new(5), UInt::new(8));
+    /// assert_eq!(values.map(UInt::get).collect::<Vec<_>>(), vec![5, 6, 7]);
+    /// assert_eq!(UInt::range(UInt::<10>::new(8), UInt::new(8)).next(), None);
+    /// ```
+    pub fn range(start: Self, end: Self) -> Values<Self> {
+        if start < end {
+            Values::new(start, Self::masked(end.get() - 1))
+        } else {
+            Values::empty(start)
+        }
+    }
+
+    /// Returns an iterator over the values from `start` up to and including `end`.
+    ///
+    /// This is the same as `start..=end` for the primitives. The range ends after yielding `end`,
+    /// even when `end` is `MAX`, and it's empty if `end` is below `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::UInt;
+    ///
+    /// let values = UInt::range_inclusive(UInt::<4>::new(13), UInt::MAX);
+    /// assert_eq!(values.map(UInt::get).collect::<Vec<_>>(), vec![13, 14, 15]);
+    /// ```
+    pub fn range_inclusive(start: Self, end: Self) -> Values<Self> {
+        if start <= end {
+            Values::new(start, end)
+        } else {
+            Values::empty(start)
+        }
+    }
+}
+
+impl<const BITS: usize> Int<BITS> {
+    const ENUMERABLE: () = assert!(
+        BITS <= 32,
+        "all_values can only be used with types of up to 32 bits"
+    );
+
+    /// Returns an iterator over every value of the type, from `MIN` to `MAX`.
+    ///
+    /// There are 2<sup>BITS</sup> values, so this is only available for types of up to 32 bits.
+    /// Using it with a wider type fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// let values: Vec<i128> = Int::<3>::all_values().map(Int::get).collect();
+    /// assert_eq!(values, vec![-4, -3, -2, -1, 0, 1, 2, 3]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use quark::Int;
+    ///
+    /// let values = Int::<33>::all_values();
+    /// ```
+    pub fn all_values() -> Values<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ENUMERABLE;
+
+        Values::new(Self::MIN, Self::MAX)
+    }
+
+    /// Returns an iterator over the values from `start` up to, but not including, `end`.
+    ///
+    /// This is the same as `start..end` for the primitives, which can't be written for `Int`
+    /// because the trait it relies on isn't stable. The range is empty if `end` isn't above
+    /// `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// let values = Int::range(Int::<10>::new(-2), Int::new(2));
+    /// assert_eq!(values.map(Int::get).collect::<Vec<_>>(), vec![-2, -1, 0, 1]);
+    /// assert_eq!(Int::range(Int::<10>::new(8), Int::new(-8)).next(), None);
+    /// ```
+    pub fn range(start: Self, end: Self) -> Values<Self> {
+        if start < end {
+            Values::new(start, Self::masked(end.get() - 1))
+        } else {
+            Values::empty(start)
+        }
+    }
+
+    /// Returns an iterator over the values from `start` up to and including `end`.
+    ///
+    /// This is the same as `start..=end` for the primitives. The range ends after yielding `end`,
+    /// even when `end` is `MAX`, and it's empty if `end` is below `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::Int;
+    ///
+    /// let values = Int::range_inclusive(Int::<4>::new(5), Int::MAX);
+    /// assert_eq!(values.map(Int::get).collect::<Vec<_>>(), vec![5, 6, 7]);
+    /// ```
+    pub fn range_inclusive(start: Self, end: Self) -> Values<Self> {
+        if start <= end {
+            Values::new(start, end)
+        } else {
+            Values::empty(start)
+        }
+    }
+}
+
+macro_rules! values_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<const BITS: usize> Values<$type<BITS>> {
+                /// Returns the number of values left, or `None` if there are more than a `u128`
+                /// can count.
+                fn remaining(&self) -> Option<u128> {
+                    if self.exhausted {
+                        Some(0)
+                    } else {
+                        (self.back.get().wrapping_sub(self.front.get()) as u128).checked_add(1)
+                    }
+                }
+            }
+
+            impl<const BITS: usize> Iterator for Values<$type<BITS>> {
+                type Item = $type<BITS>;
+
+                fn next(&mut self) -> Option<$type<BITS>> {
+                    if self.exhausted {
+                        return None;
+                    }
+
+                    let value = self.front;
+                    if value == self.back {
+                        self.exhausted = true;
+                    } else {
+                        self.front = $type::masked(value.get() + 1);
+                    }
+
+                    Some(value)
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    match self.remaining().map(usize::try_from) {
+                        Some(Ok(remaining)) => (remaining, Some(remaining)),
+                        _ => (usize::MAX, None),
+                    }
+                }
+            }
+
+            impl<const BITS: usize> DoubleEndedIterator for Values<$type<BITS>> {
+                fn next_back(&mut self) -> Option<$type<BITS>> {
+                    if self.exhausted {
+                        return None;
+                    }
+
+                    let value = self.back;
+                    if value == self.front {
+                        self.exhausted = true;
+                    } else {
+                        self.back = $type::masked(value.get() - 1);
+                    }
+
+                    Some(value)
+                }
+            }
+
+            impl<const BITS: usize> ExactSizeIterator for Values<$type<BITS>> {}
+
+            impl<const BITS: usize> FusedIterator for Values<$type<BITS>> {}
+        )*
+    };
+}
+
+values_impl!(UInt, Int);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn unsigned() {
+        let values: Vec<_> = UInt::<6>::all_values().collect();
+
+        asserting!("every value is produced once")
+            .that(&(
+                UInt::<6>::all_values().len(),
+                values.len(),
+                values.first().copied(),
+                values.last().copied(),
+            ))
+            .is_equal_to((64, 64, Some(UInt::MIN), Some(UInt::MAX)));
+
+        asserting!("values are strictly increasing")
+            .that(&values.windows(2).all(|pair| pair[0] < pair[1]))
+            .is_true();
+
+        asserting!("single-bit types have two values")
+            .that(&UInt::<1>::all_values().map(UInt::get).collect::<Vec<_>>())
+            .is_equal_to(vec![0, 1]);
+
+        asserting!("the widest type is counted exactly")
+            .that(&UInt::<32>::all_values().len())
+            .is_equal_to(1 << 32);
+    }
+
+    #[test]
+    fn signed() {
+        let values: Vec<_> = Int::<5>::all_values().collect();
+
+        asserting!("every value is produced once")
+            .that(&(
+                Int::<5>::all_values().len(),
+                values.len(),
+                values.first().map(|value| value.get()),
+                values.last().map(|value| value.get()),
+            ))
+            .is_equal_to((32, 32, Some(-16), Some(15)));
+
+        asserting!("values are strictly increasing")
+            .that(&values.windows(2).all(|pair| pair[0] < pair[1]))
+            .is_true();
+
+        asserting!("single-bit types have two values")
+            .that(&Int::<1>::all_values().map(Int::get).collect::<Vec<_>>())
+            .is_equal_to(vec![-1, 0]);
+    }
+
+    #[test]
+    fn double_ended() {
+        let mut values = Int::<3>::all_values();
+
+        asserting!("values are taken from both ends")
+            .that(&(
+                values.next().map(Int::get),
+                values.next_back().map(Int::get),
+                values.len(),
+            ))
+            .is_equal_to((Some(-4), Some(3), 6));
+
+        asserting!("the ends meet in the middle")
+            .that(&values.rev().map(Int::get).collect::<Vec<_>>())
+            .is_equal_to(vec![2, 1, 0, -1, -2, -3]);
+
+        let mut values = UInt::<1>::all_values();
+        values.next();
+        values.next_back();
+
+        asserting!("an iterator with nothing left stays empty")
+            .that(&(values.next(), values.next_back(), values.len()))
+            .is_equal_to((None, None, 0));
+    }
+
+    #[test]
+    fn ranges() {
+        let five = UInt::<4>::new(5);
+
+        asserting!("empty ranges have no values")
+            .that(&(
+                UInt::range(five, five).len(),
+                UInt::range(five, UInt::MIN).len(),
+                UInt::range_inclusive(five, UInt::MIN).len(),
+                Int::range(Int::<4>::MAX, Int::MIN).next(),
+            ))
+            .is_equal_to((0, 0, 0, None));
+
+        asserting!("single-element ranges have one value")
+            .that(&(
+                UInt::range(five, UInt::new(6)).collect::<Vec<_>>(),
+                UInt::range_inclusive(UInt::<4>::MAX, UInt::MAX).collect::<Vec<_>>(),
+                Int::range_inclusive(Int::<4>::MIN, Int::MIN)
+                    .rev()
+                    .collect::<Vec<_>>(),
+            ))
+            .is_equal_to((vec![five], vec![UInt::MAX], vec![Int::MIN]));
+
+        asserting!("inclusive ranges over the whole type stop at the maximum")
+            .that(&(
+                UInt::range_inclusive(UInt::<4>::MIN, UInt::MAX).collect::<Vec<_>>(),
+                Int::range_inclusive(Int::<4>::MIN, Int::MAX).collect::<Vec<_>>(),
+            ))
+            .is_equal_to((
+                UInt::<4>::all_values().collect(),
+                Int::<4>::all_values().collect(),
+            ));
+
+        asserting!("signed ranges cross zero")
+            .that(
+                &Int::range(Int::<8>::new(-3), Int::new(3))
+                    .map(Int::get)
+                    .collect::<Vec<_>>(),
+            )
+            .is_equal_to(vec![-3, -2, -1, 0, 1, 2]);
+
+        asserting!("ranges wider than a usize have no upper bound")
+            .that(&(
+                UInt::range_inclusive(UInt::<128>::MIN, UInt::MAX).size_hint(),
+                Int::range(Int::<128>::MIN, Int::MAX).size_hint(),
+                Int::range(Int::<128>::new(-1), Int::new(1)).size_hint(),
+            ))
+            .is_equal_to(((usize::MAX, None), (usize::MAX, None), (2, Some(2))));
+    }
+}
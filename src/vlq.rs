@@ -0,0 +1,229 @@
+use crate::VlqError;
+
+// A variable-length quantity stores a value seven bits at a time like LEB128, but most
+// significant group first. Decoding accepts leading zero groups, like `0x80 0x00` for zero, as
+// long as the encoding is no longer than the longest encoding of the type.
+
+/// Encodes and decodes unsigned values as variable-length quantities.
+///
+/// Variable-length quantities are the varints of MIDI files and some font and archive formats.
+/// They're [`Uleb128`](crate::Uleb128) with the groups in the opposite order: each byte holds
+/// seven bits of the value, most significant group first, with the top bit set on every byte but
+/// the last.
+///
+/// # Examples
+///
+/// ```
+/// use quark::Vlq;
+///
+/// let mut buf = [0; u32::MAX_VLQ_LEN];
+/// let len = 0x0fff_ffff_u32.encode_vlq(&mut buf);
+/// assert_eq!(&buf[..len], &[0xff, 0xff, 0xff, 0x7f]);
+/// assert_eq!(u32::decode_vlq(&buf), Ok((0x0fff_ffff, 4)));
+/// ```
+pub trait Vlq: Sized {
+    /// The length of the longest encoding of a value of the type.
+    const MAX_VLQ_LEN: usize;
+
+    /// Writes the encoding of a value to the start of a buffer, and returns its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoding is longer than the buffer. A buffer of
+    /// [`MAX_VLQ_LEN`](Vlq::MAX_VLQ_LEN) bytes is always long enough.
+    fn encode_vlq(&self, buf: &mut [u8]) -> usize;
+
+    /// Reads a value from the encoding at the start of some bytes, and returns it with the length
+    /// of the encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VlqError::Truncated`] if the bytes end before the encoding does, and
+    /// [`VlqError::Overflow`] if the value doesn't fit in the type or the encoding is longer than
+    /// [`MAX_VLQ_LEN`](Vlq::MAX_VLQ_LEN).
+    fn decode_vlq(bytes: &[u8]) -> Result<(Self, usize), VlqError>;
+}
+
+macro_rules! vlq_impl {
+    ($($type:ident),*) => {
+        $(
+            impl Vlq for $type {
+                const MAX_VLQ_LEN: usize = ($type::BITS as usize).div_ceil(7);
+
+                fn encode_vlq(&self, buf: &mut [u8]) -> usize {
+                    let bits = Self::BITS - self.leading_zeros();
+                    let len = (bits.max(1) as usize).div_ceil(7);
+                    for (index, byte) in buf[..len].iter_mut().enumerate() {
+                        let group = (*self >> ((len - 1 - index) * 7)) as u8 & 0x7f;
+                        *byte = if index == len - 1 { group } else { group | 0x80 };
+                    }
+                    len
+                }
+
+                fn decode_vlq(bytes: &[u8]) -> Result<(Self, usize), VlqError> {
+                    let mut value: Self = 0;
+                    for (index, &byte) in bytes.iter().take(Self::MAX_VLQ_LEN).enumerate() {
+                        if value >> (Self::BITS - 7) != 0 {
+                            return Err(VlqError::Overflow);
+                        }
+
+                        value = value << 7 | (byte & 0x7f) as Self;
+                        if byte & 0x80 == 0 {
+                            return Ok((value, index + 1));
+                        }
+                    }
+
+                    if bytes.len() >= Self::MAX_VLQ_LEN {
+                        Err(VlqError::Overflow)
+                    } else {
+                        Err(VlqError::Truncated)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+vlq_impl!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use crate::Uleb128;
+    use spectral::prelude::*;
+
+    fn vlq<T: Vlq>(value: T) -> Vec<u8> {
+        let mut buf = [0; 19];
+        let len = value.encode_vlq(&mut buf);
+        buf[..len].to_vec()
+    }
+
+    #[test]
+    fn midi_examples() {
+        // The examples from the Standard MIDI Files specification
+        let examples: [(u32, &[u8]); 12] = [
+            (0x0000_0000, &[0x00]),
+            (0x0000_0040, &[0x40]),
+            (0x0000_007f, &[0x7f]),
+            (0x0000_0080, &[0x81, 0x00]),
+            (0x0000_2000, &[0xc0, 0x00]),
+            (0x0000_3fff, &[0xff, 0x7f]),
+            (0x0000_4000, &[0x81, 0x80, 0x00]),
+            (0x0010_0000, &[0xc0, 0x80, 0x00]),
+            (0x001f_ffff, &[0xff, 0xff, 0x7f]),
+            (0x0020_0000, &[0x81, 0x80, 0x80, 0x00]),
+            (0x0800_0000, &[0xc0, 0x80, 0x80, 0x00]),
+            (0x0fff_ffff, &[0xff, 0xff, 0xff, 0x7f]),
+        ];
+
+        for &(value, bytes) in &examples {
+            asserting!("values encode like the specification")
+                .that(&(vlq(value), u32::decode_vlq(bytes)))
+                .is_equal_to((bytes.to_vec(), Ok((value, bytes.len()))));
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        let mut max = vec![0x83];
+        max.extend([0xff; 17]);
+        max.push(0x7f);
+        asserting!("the largest values take the longest encodings")
+            .that(&(
+                vlq(u8::MAX),
+                vlq(u32::MAX),
+                vlq(u64::MAX).len(),
+                vlq(u128::MAX),
+                (u8::MAX_VLQ_LEN, u64::MAX_VLQ_LEN, u128::MAX_VLQ_LEN),
+            ))
+            .is_equal_to((
+                vec![0x81, 0x7f],
+                vec![0x8f, 0xff, 0xff, 0xff, 0x7f],
+                10,
+                max,
+                (2, 10, 19),
+            ));
+
+        asserting!("decoding stops at the last byte and accepts leading zero groups")
+            .that(&(
+                u8::decode_vlq(&[0x7f, 0xff]),
+                u8::decode_vlq(&[0x81, 0x7f]),
+                u8::decode_vlq(&[0x80, 0x00]),
+                u32::decode_vlq(&[0x80, 0x80, 0x80, 0x80, 0x01]),
+                u64::decode_vlq(&[0x81, 0x00, 0x00]),
+            ))
+            .is_equal_to((
+                Ok((127, 1)),
+                Ok((u8::MAX, 2)),
+                Ok((0, 2)),
+                Ok((1, 5)),
+                Ok((128, 2)),
+            ));
+
+        asserting!("invalid encodings are rejected")
+            .that(&(
+                u32::decode_vlq(&[]),
+                u32::decode_vlq(&[0xff, 0xff, 0xff, 0xff]),
+                u8::decode_vlq(&[0x82, 0x00]),
+                u8::decode_vlq(&[0x80, 0x80, 0x00]),
+                u32::decode_vlq(&[0x90, 0x80, 0x80, 0x80, 0x00]),
+                u32::decode_vlq(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+            ))
+            .is_equal_to((
+                Err(VlqError::Truncated),
+                Err(VlqError::Truncated),
+                Err(VlqError::Overflow),
+                Err(VlqError::Overflow),
+                Err(VlqError::Overflow),
+                Err(VlqError::Overflow),
+            ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn encoding_into_a_short_buffer() {
+        0x4000_u16.encode_vlq(&mut [0; 2]);
+    }
+
+    #[test]
+    fn round_trips() {
+        for seed in random_u64s().take(1000) {
+            // Shift the values down so that every length of encoding comes up
+            let value = seed >> (seed % 64);
+            let wide = (value as u128) << (seed % 65);
+
+            let encoded = (
+                vlq(value),
+                vlq(wide),
+                vlq(value as u16),
+                vlq(value as usize),
+            );
+            asserting!("values come back from their encodings")
+                .that(&(
+                    u64::decode_vlq(&encoded.0),
+                    u128::decode_vlq(&encoded.1),
+                    u16::decode_vlq(&encoded.2),
+                    usize::decode_vlq(&encoded.3),
+                ))
+                .is_equal_to((
+                    Ok((value, encoded.0.len())),
+                    Ok((wide, encoded.1.len())),
+                    Ok((value as u16, encoded.2.len())),
+                    Ok((value as usize, encoded.3.len())),
+                ));
+
+            // The groups are the LEB128 groups in the opposite order
+            let mut leb128 = [0; 10];
+            let len = value.encode_uleb128(&mut leb128);
+            let mut groups = leb128[..len]
+                .iter()
+                .map(|byte| byte & 0x7f)
+                .collect::<Vec<_>>();
+            groups.reverse();
+            asserting!("the groups are the LEB128 groups reversed")
+                .that(&encoded.0.iter().map(|byte| byte & 0x7f).collect::<Vec<_>>())
+                .is_equal_to(groups);
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use crate::{i256, u256, BitConsts, BitSize, Quark};
+use std::convert::TryFrom;
+use std::fmt::{self, Binary, Formatter, LowerHex, UpperHex};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+// The 256-bit types are four `u64` limbs with the least significant limb first, in two's
+// complement for `i256`. The functions here work on the limbs directly and don't care about the
+// sign, except for right shifts, which are told what to fill the upper bits with.
+
+/// The limbs of a 256-bit value, least significant first.
+pub(crate) type Limbs = [u64; 4];
+
+/// Shifts the limbs left by `n` bits, giving zero if `n` is at least 256.
+pub(crate) const fn shl(value: Limbs, n: u32) -> Limbs {
+    if n >= 256 {
+        return [0; 4];
+    }
+
+    let (whole, bits) = ((n / 64) as usize, n % 64);
+    let mut result = [0; 4];
+    let mut index = whole;
+    while index < 4 {
+        result[index] = value[index - whole] << bits;
+        if bits > 0 && index > whole {
+            result[index] |= value[index - whole - 1] >> (64 - bits);
+        }
+        index += 1;
+    }
+
+    result
+}
+
+/// Shifts the limbs right by `n` bits, filling the upper bits with `fill`, which is either all
+/// zeros or all ones. Shifting by 256 bits or more gives `fill` in every limb.
+pub(crate) const fn shr(value: Limbs, n: u32, fill: u64) -> Limbs {
+    if n >= 256 {
+        return [fill; 4];
+    }
+
+    let (whole, bits) = ((n / 64) as usize, n % 64);
+    let mut result = [fill; 4];
+    let mut index = 0;
+    while index + whole < 4 {
+        let above = if index + whole + 1 < 4 {
+            value[index + whole + 1]
+        } else {
+            fill
+        };
+
+        result[index] = value[index + whole] >> bits;
+        if bits > 0 {
+            result[index] |= above << (64 - bits);
+        }
+        index += 1;
+    }
+
+    result
+}
+
+/// Adds the limbs, returning the sum modulo 2<sup>256</sup> and whether there was a carry out of
+/// the top limb.
+pub(crate) const fn carrying_add(a: Limbs, b: Limbs) -> (Limbs, bool) {
+    let mut result = [0; 4];
+    let mut carry = false;
+    let mut index = 0;
+    while index < 4 {
+        let (sum, first) = a[index].overflowing_add(b[index]);
+        let (sum, second) = sum.overflowing_add(carry as u64);
+        result[index] = sum;
+        carry = first || second;
+        index += 1;
+    }
+
+    (result, carry)
+}
+
+/// Subtracts the limbs, returning the difference modulo 2<sup>256</sup> and whether there was a
+/// borrow out of the top limb.
+pub(crate) const fn borrowing_sub(a: Limbs, b: Limbs) -> (Limbs, bool) {
+    let mut result = [0; 4];
+    let mut borrow = false;
+    let mut index = 0;
+    while index < 4 {
+        let (difference, first) = a[index].overflowing_sub(b[index]);
+        let (difference, second) = difference.overflowing_sub(borrow as u64);
+        result[index] = difference;
+        borrow = first || second;
+        index += 1;
+    }
+
+    (result, borrow)
+}
+
+/// Compares the limbs as unsigned numbers.
+pub(crate) const fn less_than(a: Limbs, b: Limbs) -> bool {
+    borrowing_sub(a, b).1
+}
+
+/// Divides the limbs by a small divisor, returning the quotient and the remainder.
+fn div_rem(value: Limbs, divisor: u64) -> (Limbs, u64) {
+    let mut quotient = [0; 4];
+    let mut remainder = 0_u128;
+    for index in (0..4).rev() {
+        let current = remainder << 64 | value[index] as u128;
+        quotient[index] = (current / divisor as u128) as u64;
+        remainder = current % divisor as u128;
+    }
+
+    (quotient, remainder as u64)
+}
+
+/// Returns the decimal digits of the limbs as an unsigned number.
+pub(crate) fn to_decimal(mut value: Limbs) -> String {
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+    let mut chunks = Vec::new();
+    loop {
+        let (quotient, remainder) = div_rem(value, CHUNK);
+        chunks.push(remainder);
+        value = quotient;
+        if value == [0; 4] {
+            break;
+        }
+    }
+
+    let mut digits = chunks.pop().unwrap_or(0).to_string();
+    for chunk in chunks.iter().rev() {
+        digits.push_str(&format!("{:019}", chunk));
+    }
+
+    digits
+}
+
+macro_rules! wide_impl {
+    ($($type:ident),*) => {
+        $(
+            impl $type {
+                /// The value 0.
+                pub const ZERO: $type = $type::from_limbs([0; 4]);
+
+                /// The value 1.
+                pub const ONE: $type = $type::from_limbs([1, 0, 0, 0]);
+
+                /// The number of bits in the type, as a `u32` like the primitives' `BITS`.
+                pub const BITS: u32 = 256;
+
+                /// Creates a value from its bytes in little-endian order.
+                pub const fn from_le_bytes(bytes: [u8; 32]) -> $type {
+                    let mut limbs = [0; 4];
+                    let mut index = 0;
+                    while index < 4 {
+                        let mut limb = [0; 8];
+                        let mut byte = 0;
+                        while byte < 8 {
+                            limb[byte] = bytes[index * 8 + byte];
+                            byte += 1;
+                        }
+                        limbs[index] = u64::from_le_bytes(limb);
+                        index += 1;
+                    }
+
+                    $type::from_limbs(limbs)
+                }
+
+                /// Creates a value from its bytes in big-endian order, the usual order for hashes
+                /// and for the words of the Ethereum virtual machine.
+                pub const fn from_be_bytes(bytes: [u8; 32]) -> $type {
+                    $type::from_le_bytes(reverse(bytes))
+                }
+
+                /// Returns the bytes of the value in little-endian order.
+                pub const fn to_le_bytes(self) -> [u8; 32] {
+                    let limbs = self.to_limbs();
+                    let mut bytes = [0; 32];
+                    let mut index = 0;
+                    while index < 4 {
+                        let limb = limbs[index].to_le_bytes();
+                        let mut byte = 0;
+                        while byte < 8 {
+                            bytes[index * 8 + byte] = limb[byte];
+                            byte += 1;
+                        }
+                        index += 1;
+                    }
+
+                    bytes
+                }
+
+                /// Returns the bytes of the value in big-endian order.
+                pub const fn to_be_bytes(self) -> [u8; 32] {
+                    reverse(self.to_le_bytes())
+                }
+
+                /// Returns the number of ones in the bits of the value.
+                pub const fn count_ones(self) -> u32 {
+                    let [a, b, c, d] = self.to_limbs();
+                    a.count_ones() + b.count_ones() + c.count_ones() + d.count_ones()
+                }
+
+                /// Returns the number of zeros in the bits of the value.
+                pub const fn count_zeros(self) -> u32 {
+                    256 - self.count_ones()
+                }
+
+                /// Returns the number of leading zeros in the bits of the value.
+                pub const fn leading_zeros(self) -> u32 {
+                    let limbs = self.to_limbs();
+                    let mut count = 0;
+                    let mut index = 4;
+                    while index > 0 {
+                        index -= 1;
+                        count += limbs[index].leading_zeros();
+                        if limbs[index] != 0 {
+                            break;
+                        }
+                    }
+
+                    count
+                }
+
+                /// Returns the number of trailing zeros in the bits of the value.
+                pub const fn trailing_zeros(self) -> u32 {
+                    let limbs = self.to_limbs();
+                    let mut count = 0;
+                    let mut index = 0;
+                    while index < 4 {
+                        count += limbs[index].trailing_zeros();
+                        if limbs[index] != 0 {
+                            break;
+                        }
+                        index += 1;
+                    }
+
+                    count
+                }
+
+                /// Returns the number of leading ones in the bits of the value.
+                pub const fn leading_ones(self) -> u32 {
+                    self.not().leading_zeros()
+                }
+
+                /// Returns the number of trailing ones in the bits of the value.
+                pub const fn trailing_ones(self) -> u32 {
+                    self.not().trailing_zeros()
+                }
+
+                /// Adds two values, wrapping around at the bounds of the type.
+                pub const fn wrapping_add(self, rhs: $type) -> $type {
+                    $type::from_limbs(carrying_add(self.to_limbs(), rhs.to_limbs()).0)
+                }
+
+                /// Subtracts `rhs` from the value, wrapping around at the bounds of the type.
+                pub const fn wrapping_sub(self, rhs: $type) -> $type {
+                    $type::from_limbs(borrowing_sub(self.to_limbs(), rhs.to_limbs()).0)
+                }
+
+                /// Negates the value, wrapping around at the bounds of the type.
+                pub const fn wrapping_neg(self) -> $type {
+                    $type::ZERO.wrapping_sub(self)
+                }
+
+                /// Shifts the value left by `rhs` bits, giving zero if `rhs` is at least 256.
+                pub const fn unbounded_shl(self, rhs: u32) -> $type {
+                    $type::from_limbs(shl(self.to_limbs(), rhs))
+                }
+
+                /// Returns the value with every bit flipped. This is `!` in constant expressions.
+                const fn not(self) -> $type {
+                    let [a, b, c, d] = self.to_limbs();
+                    $type::from_limbs([!a, !b, !c, !d])
+                }
+            }
+
+            impl BitSize for $type {
+                const BIT_SIZE: usize = 256;
+            }
+
+            impl BitConsts for $type {
+                const ALL_SET: $type = $type::from_limbs([u64::MAX; 4]);
+                const NONE_SET: $type = $type::ZERO;
+            }
+
+            impl Quark for $type {
+                const ZERO: $type = $type::ZERO;
+                const ONE: $type = $type::ONE;
+            }
+
+            impl Not for $type {
+                type Output = $type;
+
+                fn not(self) -> $type {
+                    $type::not(self)
+                }
+            }
+
+            wide_impl!(@bitwise $type, BitAnd, bitand, BitAndAssign, bitand_assign, &);
+            wide_impl!(@bitwise $type, BitOr, bitor, BitOrAssign, bitor_assign, |);
+            wide_impl!(@bitwise $type, BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+            wide_impl!(@shift $type, u32, usize);
+
+            impl LowerHex for $type {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    f.pad_integral(true, "0x", &hex(self.to_limbs()))
+                }
+            }
+
+            impl UpperHex for $type {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    f.pad_integral(true, "0x", &hex(self.to_limbs()).to_uppercase())
+                }
+            }
+
+            impl Binary for $type {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    let limbs = self.to_limbs();
+                    let top = limbs.iter().rposition(|&limb| limb != 0).unwrap_or(0);
+                    let mut digits = format!("{:b}", limbs[top]);
+                    for limb in limbs[..top].iter().rev() {
+                        digits.push_str(&format!("{:064b}", limb));
+                    }
+
+                    f.pad_integral(true, "0b", &digits)
+                }
+            }
+        )*
+    };
+    (@bitwise $type:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for $type {
+            type Output = $type;
+
+            fn $method(self, rhs: $type) -> $type {
+                let (a, b) = (self.to_limbs(), rhs.to_limbs());
+                $type::from_limbs([a[0] $op b[0], a[1] $op b[1], a[2] $op b[2], a[3] $op b[3]])
+            }
+        }
+
+        impl $assign_trait for $type {
+            fn $assign_method(&mut self, rhs: $type) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+    (@shift $type:ident, $($amount:ty),*) => {
+        $(
+            /// Shifts left by the amount. Shifting by 256 or more gives zero.
+            impl Shl<$amount> for $type {
+                type Output = $type;
+
+                fn shl(self, rhs: $amount) -> $type {
+                    self.unbounded_shl(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            /// Shifts right by the amount, the same way `>>` does on the primitive of the same
+            /// signedness. Shifting by 256 or more gives zero, or -1 for negative values.
+            impl Shr<$amount> for $type {
+                type Output = $type;
+
+                fn shr(self, rhs: $amount) -> $type {
+                    self.unbounded_shr(u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            impl ShlAssign<$amount> for $type {
+                fn shl_assign(&mut self, rhs: $amount) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl ShrAssign<$amount> for $type {
+                fn shr_assign(&mut self, rhs: $amount) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+wide_impl!(u256, i256);
+
+/// Reverses the order of 32 bytes.
+const fn reverse(bytes: [u8; 32]) -> [u8; 32] {
+    let mut reversed = [0; 32];
+    let mut index = 0;
+    while index < 32 {
+        reversed[index] = bytes[31 - index];
+        index += 1;
+    }
+
+    reversed
+}
+
+/// Returns the hex digits of the limbs, without leading zeros.
+fn hex(limbs: Limbs) -> String {
+    let top = limbs.iter().rposition(|&limb| limb != 0).unwrap_or(0);
+    let mut digits = format!("{:x}", limbs[top]);
+    for limb in limbs[..top].iter().rev() {
+        digits.push_str(&format!("{:016x}", limb));
+    }
+
+    digits
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    /// Splits a `u128` into the two limbs it fills.
+    fn split(value: u128) -> [u64; 2] {
+        [value as u64, (value >> 64) as u64]
+    }
+
+    #[test]
+    fn shifting_limbs() {
+        let value = [
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0,
+            0x8000_0000_0000_0001,
+        ];
+
+        asserting!("shifts within a limb carry into the next")
+            .that(&(shl(value, 4), shr(value, 4, 0), shr(value, 4, !0)))
+            .is_equal_to((
+                [
+                    0x1234_5678_9abc_def0,
+                    0xedcb_a987_6543_2100,
+                    0xf,
+                    0x0000_0000_0000_0010,
+                ],
+                [
+                    0x0012_3456_789a_bcde,
+                    0x0fed_cba9_8765_4321,
+                    0x1000_0000_0000_0000,
+                    0x0800_0000_0000_0000,
+                ],
+                [
+                    0x0012_3456_789a_bcde,
+                    0x0fed_cba9_8765_4321,
+                    0x1000_0000_0000_0000,
+                    0xf800_0000_0000_0000,
+                ],
+            ));
+
+        asserting!("shifts of whole limbs move the limbs")
+            .that(&(shl(value, 64), shr(value, 128, 0), shr(value, 192, !0)))
+            .is_equal_to((
+                [0, 0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210, 0],
+                [0, 0x8000_0000_0000_0001, 0, 0],
+                [0x8000_0000_0000_0001, !0, !0, !0],
+            ));
+
+        asserting!("shifts of the whole width fill every limb")
+            .that(&(shl(value, 256), shr(value, 256, 0), shr(value, 300, !0)))
+            .is_equal_to(([0; 4], [0; 4], [!0; 4]));
+
+        for n in 0..128 {
+            let wide = 0x8123_4567_89ab_cdef_0fed_cba9_8765_4321_u128;
+            let [low, high] = split(wide);
+
+            asserting!("shifts match the 128-bit ones")
+                .that(&(
+                    shl([low, high, 0, 0], n),
+                    shr([0, 0, low, high], n + 128, 0),
+                ))
+                .is_equal_to((
+                    {
+                        let [a, b] = split(wide << n);
+                        let [c, d] = split(if n == 0 { 0 } else { wide >> (128 - n) });
+                        [a, b, c, d]
+                    },
+                    {
+                        let [a, b] = split(wide >> n);
+                        [a, b, 0, 0]
+                    },
+                ));
+        }
+    }
+
+    #[test]
+    fn adding_limbs() {
+        asserting!("carries run through every limb")
+            .that(&(
+                carrying_add([!0, !0, !0, 0], [1, 0, 0, 0]),
+                carrying_add([!0; 4], [1, 0, 0, 0]),
+                borrowing_sub([0, 0, 0, 1], [1, 0, 0, 0]),
+                borrowing_sub([0; 4], [1, 0, 0, 0]),
+            ))
+            .is_equal_to((
+                ([0, 0, 0, 1], false),
+                ([0; 4], true),
+                ([!0, !0, !0, 0], false),
+                ([!0; 4], true),
+            ));
+
+        asserting!("comparisons are unsigned")
+            .that(&(
+                less_than([!0, 0, 0, 0], [0, 1, 0, 0]),
+                less_than([0, 0, 0, 1], [!0, !0, !0, 0]),
+                less_than([5, 0, 0, 0], [5, 0, 0, 0]),
+            ))
+            .is_equal_to((true, false, false));
+    }
+
+    #[test]
+    fn decimal_digits() {
+        asserting!("limbs print as decimal numbers")
+            .that(&(
+                to_decimal([0; 4]),
+                to_decimal([10_000_000_000_000_000_000, 0, 0, 0]),
+                to_decimal([0, 1, 0, 0]),
+                to_decimal([!0; 4]),
+            ))
+            .is_equal_to((
+                "0".to_string(),
+                "10000000000000000000".to_string(),
+                "18446744073709551616".to_string(),
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+                    .to_string(),
+            ));
+    }
+}
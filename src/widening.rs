@@ -0,0 +1,238 @@
+use crate::HalfBits;
+
+// The types up to 64 bits multiply in the type twice as wide and split the product. There's no
+// primitive twice as wide as 128 bits, so `u128` multiplies its 64-bit halves and adds up the
+// partial products like long multiplication, and `i128` corrects the unsigned product for the
+// signs: reading a negative operand as unsigned adds 2^128 times the other operand to the
+// product, which only changes the high word.
+
+/// Multiplies two values into a result twice as wide.
+///
+/// The product of two N-bit values always fits in 2N bits, so it never overflows. It's returned
+/// as `(low, high)`, the lower and upper N bits of the product. For signed values, the high word
+/// holds the sign of the product and the low word holds the raw lower bits, so it can be negative
+/// when the product isn't.
+///
+/// The primitives have unstable methods of the same name in the standard library, so calling this
+/// with method syntax gives an `unstable_name_collisions` warning. Calling it through the trait
+/// avoids it.
+///
+/// # Examples
+///
+/// ```
+/// use quark::WideningMul;
+///
+/// assert_eq!(WideningMul::widening_mul(0xff_u8, 0xff), (0x01, 0xfe));
+/// assert_eq!(WideningMul::widening_mul(u64::MAX, 2), (u64::MAX - 1, 1));
+/// assert_eq!(WideningMul::widening_mul(u128::MAX, u128::MAX), (1, u128::MAX - 1));
+///
+/// // -1 × 1 is -1 in both words
+/// assert_eq!(WideningMul::widening_mul(-1_i32, 1), (-1, -1));
+/// ```
+pub trait WideningMul: Sized {
+    /// Returns the lower and upper halves of the full product of two values.
+    fn widening_mul(self, rhs: Self) -> (Self, Self);
+}
+
+macro_rules! widening_mul_impl {
+    ($($type:ident => $wide:ident;)*) => {
+        $(
+            impl WideningMul for $type {
+                #[inline]
+                fn widening_mul(self, rhs: $type) -> ($type, $type) {
+                    let product = self as $wide * rhs as $wide;
+                    (product.low(), product.high())
+                }
+            }
+        )*
+    };
+}
+
+widening_mul_impl! {
+    u8 => u16;
+    u16 => u32;
+    u32 => u64;
+    u64 => u128;
+    i8 => i16;
+    i16 => i32;
+    i32 => i64;
+    i64 => i128;
+}
+
+impl WideningMul for usize {
+    #[inline]
+    fn widening_mul(self, rhs: usize) -> (usize, usize) {
+        let product = self as u128 * rhs as u128;
+        (product as usize, (product >> usize::BITS) as usize)
+    }
+}
+
+impl WideningMul for isize {
+    #[inline]
+    fn widening_mul(self, rhs: isize) -> (isize, isize) {
+        let product = self as i128 * rhs as i128;
+        (product as isize, (product >> isize::BITS) as isize)
+    }
+}
+
+impl WideningMul for u128 {
+    fn widening_mul(self, rhs: u128) -> (u128, u128) {
+        let (a_high, a_low) = (self.high() as u128, self.low() as u128);
+        let (b_high, b_low) = (rhs.high() as u128, rhs.low() as u128);
+
+        let low = a_low * b_low;
+        let cross_a = a_high * b_low;
+        let cross_b = a_low * b_high;
+        let high = a_high * b_high;
+
+        // Each term is below 2^64, so the sum can't overflow
+        let middle = (low >> 64) + (cross_a & u64::MAX as u128) + (cross_b & u64::MAX as u128);
+
+        (
+            low & u64::MAX as u128 | middle << 64,
+            high + (cross_a >> 64) + (cross_b >> 64) + (middle >> 64),
+        )
+    }
+}
+
+impl WideningMul for i128 {
+    fn widening_mul(self, rhs: i128) -> (i128, i128) {
+        let (low, mut high) = WideningMul::widening_mul(self as u128, rhs as u128);
+
+        if self < 0 {
+            high = high.wrapping_sub(rhs as u128);
+        }
+        if rhs < 0 {
+            high = high.wrapping_sub(self as u128);
+        }
+
+        (low as i128, high as i128)
+    }
+}
+
+#[cfg(test)]
+#[allow(unstable_name_collisions)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use crate::{BitConcat, HalfBits};
+    use spectral::prelude::*;
+
+    #[test]
+    fn corners() {
+        asserting!("the largest products fill both words")
+            .that(&(
+                u8::MAX.widening_mul(u8::MAX),
+                u32::MAX.widening_mul(u32::MAX),
+                u128::MAX.widening_mul(u128::MAX),
+                u128::MAX.widening_mul(0),
+                (1_u128 << 64).widening_mul(1 << 64),
+                usize::MAX.widening_mul(1),
+            ))
+            .is_equal_to((
+                (1, u8::MAX - 1),
+                (1, u32::MAX - 1),
+                (1, u128::MAX - 1),
+                (0, 0),
+                (0, 1),
+                (usize::MAX, 0),
+            ));
+
+        asserting!("signed products take their sign from the operands")
+            .that(&(
+                i8::MIN.widening_mul(i8::MIN),
+                i8::MIN.widening_mul(i8::MAX),
+                i64::MIN.widening_mul(i64::MIN),
+                i128::MIN.widening_mul(i128::MIN),
+                i128::MIN.widening_mul(i128::MAX),
+                i128::MIN.widening_mul(-1),
+                (-1_i128).widening_mul(-1),
+                isize::MIN.widening_mul(-1),
+            ))
+            .is_equal_to((
+                (0, 0x40),
+                (i8::MIN, -0x40),
+                (0, 1 << 62),
+                (0, 1 << 126),
+                (i128::MIN, -(1 << 126)),
+                (i128::MIN, 0),
+                (1, 0),
+                (isize::MIN, 0),
+            ));
+    }
+
+    #[test]
+    fn products_match_a_wider_multiplication() {
+        let mut random = random_u64s();
+        let mut next = || random.next().unwrap();
+
+        for _ in 0..1000 {
+            let (a, b) = (next(), next());
+
+            let product = a as u128 * b as u128;
+            asserting!("64-bit products split into words")
+                .that(&(a.widening_mul(b), (a as i64).widening_mul(b as i64)))
+                .is_equal_to((
+                    (product.low(), product.high()),
+                    (
+                        (a as i64 as i128 * b as i64 as i128).low(),
+                        (a as i64 as i128 * b as i64 as i128).high(),
+                    ),
+                ));
+
+            // 128-bit operands are two 64-bit digits, so the reference is long multiplication
+            // with the 64-bit products checked above
+            let (c, d) = (next(), next());
+            let (x, y) = (u64::concat(a, b), u64::concat(c, d));
+            let (low, high) = x.widening_mul(y);
+            let digits = long_multiply([b, a], [d, c]);
+            asserting!("128-bit products match long multiplication")
+                .that(&(low, high))
+                .is_equal_to((
+                    u64::concat(digits[1], digits[0]),
+                    u64::concat(digits[3], digits[2]),
+                ));
+
+            // Signed 128-bit products are the product of the magnitudes, negated when the signs
+            // differ
+            let (x, y) = (x as i128, y as i128);
+            let (low, high) = x.unsigned_abs().widening_mul(y.unsigned_abs());
+            let expected = if (x < 0) != (y < 0) {
+                let (low, carry) = (!low).overflowing_add(1);
+                (low, (!high).wrapping_add(carry as u128))
+            } else {
+                (low, high)
+            };
+            asserting!("signed 128-bit products match the product of the magnitudes")
+                .that(&x.widening_mul(y))
+                .is_equal_to((expected.0 as i128, expected.1 as i128));
+
+            let (small_a, small_b) = (a as i64 as i128, c as i64 as i128);
+            let product = (a as i64).widening_mul(c as i64);
+            asserting!("signed 128-bit products of small values sign-extend")
+                .that(&small_a.widening_mul(small_b))
+                .is_equal_to((
+                    i64::concat(product.1, product.0),
+                    if product.1 < 0 { -1 } else { 0 },
+                ));
+        }
+    }
+
+    /// Multiplies two numbers given as little-endian 64-bit digits.
+    fn long_multiply(a: [u64; 2], b: [u64; 2]) -> [u64; 4] {
+        let mut result = [0_u64; 4];
+
+        for (i, &a) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in b.iter().enumerate() {
+                let (low, high) = a.widening_mul(b);
+                let sum = result[i + j] as u128 + low as u128 + carry as u128;
+                result[i + j] = sum as u64;
+                carry = high + (sum >> 64) as u64;
+            }
+            result[i + 2] = carry;
+        }
+
+        result
+    }
+}
@@ -0,0 +1,850 @@
+use crate::{BitIndex, BitSize, Signs};
+
+/// The condition flags set by an arithmetic operation at a given width.
+///
+/// These are the flags an N-bit ALU would derive from the operation, as returned by methods like
+/// [`WidthArith::add_in`](trait.WidthArith.html#method.add_in).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AluFlags {
+    /// Whether the unsigned result didn't fit in the width.
+    pub carry: bool,
+    /// Whether the signed result didn't fit in the width.
+    pub overflow: bool,
+    /// Whether the result is zero.
+    pub zero: bool,
+    /// Whether the top bit of the result is set.
+    pub negative: bool,
+}
+
+/// Provides arithmetic, rotation, and reversal that wrap at an arbitrary bit width.
+///
+/// These methods treat the lowest N bits of a value as an N-bit register, which is useful for
+/// emulating machines whose word size doesn't match a primitive. Results are returned as the N-bit
+/// pattern with the bits above the width cleared, so they can be stored straight back into the
+/// register; use [`Signs::sign_extend_from`](trait.Signs.html#method.sign_extend_from) to read
+/// them as signed values. Bits of the operands above the width are ignored.
+///
+/// A width of 0 always gives 0, and widths at least as large as the type wrap at the full width of
+/// the type.
+pub trait WidthArith: Signs {
+    /// Negates the lowest N bits of a value, wrapping at N bits.
+    ///
+    /// Like [`wrapping_neg`](https://doc.rust-lang.org/std/primitive.i32.html#method.wrapping_neg),
+    /// the most negative N-bit value negates to itself. A width larger than the type negates the
+    /// whole value, the same as `wrapping_neg`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert_eq!(0x01_u32.negate_in(8), 0xff);
+    /// assert_eq!(0x80_u32.negate_in(8), 0x80);
+    /// assert_eq!(5_i32.negate_in(12), 0xffb);
+    /// ```
+    fn negate_in(&self, width: usize) -> Self;
+
+    /// Returns the absolute value of the lowest N bits of a value, wrapping at N bits.
+    ///
+    /// The lowest N bits are read as a two's complement number. Like
+    /// [`wrapping_abs`](https://doc.rust-lang.org/std/primitive.i32.html#method.wrapping_abs), the
+    /// most negative N-bit value is returned unchanged. A width larger than the type reads the
+    /// whole value, the same as `wrapping_abs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert_eq!(0xff_u32.abs_in(8), 0x01);
+    /// assert_eq!(0x7f_u32.abs_in(8), 0x7f);
+    /// assert_eq!(0x80_u32.abs_in(8), 0x80);
+    /// assert_eq!((-5_i32).abs_in(12), 5);
+    /// ```
+    fn abs_in(&self, width: usize) -> Self;
+
+    /// Adds the lowest N bits of two values, wrapping at N bits and returning the resulting flags.
+    ///
+    /// The carry flag is set when the unsigned sum carries out of bit N - 1, and the overflow flag
+    /// is set when adding two N-bit numbers of the same sign gives a result of the other sign. A
+    /// width of 0 always gives 0 with only the zero flag set, and a width larger than the type adds
+    /// at the full width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// let (sum, flags) = 0x7f_u32.add_in(0x01, 8);
+    /// assert_eq!(sum, 0x80);
+    /// assert!(flags.overflow && flags.negative && !flags.carry);
+    ///
+    /// let (sum, flags) = 0xff_u32.add_in(0x01, 8);
+    /// assert_eq!(sum, 0x00);
+    /// assert!(flags.carry && flags.zero && !flags.overflow);
+    /// ```
+    fn add_in(&self, rhs: Self, width: usize) -> (Self, AluFlags)
+    where
+        Self: Sized,
+    {
+        self.add_with_carry_in(rhs, false, width)
+    }
+
+    /// Subtracts the lowest N bits of two values, wrapping at N bits and returning the resulting
+    /// flags.
+    ///
+    /// The carry flag is set when the unsigned subtraction borrows, which is the convention used by
+    /// x86 and the Z80. ARM and the 6502 instead set carry when there's no borrow, so emulators of
+    /// those need to invert it. The overflow flag is set when subtracting two N-bit numbers of
+    /// different signs gives a result with the sign of the right-hand side. A width of 0 always
+    /// gives 0 with only the zero flag set, and a width larger than the type subtracts at the full
+    /// width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// let (difference, flags) = 0x10_u32.sub_in(0x20, 8);
+    /// assert_eq!(difference, 0xf0);
+    /// assert!(flags.carry && flags.negative && !flags.overflow);
+    ///
+    /// let (difference, flags) = 0x80_u32.sub_in(0x01, 8);
+    /// assert_eq!(difference, 0x7f);
+    /// assert!(flags.overflow && !flags.carry);
+    /// ```
+    fn sub_in(&self, rhs: Self, width: usize) -> (Self, AluFlags)
+    where
+        Self: Sized,
+    {
+        self.sub_with_borrow_in(rhs, false, width)
+    }
+
+    /// Compares the lowest N bits of two values, returning the flags of subtracting them.
+    ///
+    /// This is [`sub_in`](#tymethod.sub_in) without the difference, like a compare instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert!(0x42_u32.cmp_in(0x42, 8).zero);
+    /// assert!(0x41_u32.cmp_in(0x42, 8).carry);
+    /// ```
+    fn cmp_in(&self, rhs: Self, width: usize) -> AluFlags
+    where
+        Self: Sized,
+    {
+        self.sub_in(rhs, width).1
+    }
+
+    /// Adds the lowest N bits of two values and an incoming carry, wrapping at N bits and returning
+    /// the resulting flags.
+    ///
+    /// This is [`add_in`](#method.add_in) for add-with-carry instructions, where the carry flag from
+    /// a previous operation is added to the sum. Widths are handled the same as `add_in`, so a
+    /// width larger than the type adds at the full width of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// let (sum, flags) = 0xe1_u32.add_with_carry_in(0x1e, true, 8);
+    /// assert_eq!(sum, 0x00);
+    /// assert!(flags.carry && flags.zero);
+    /// ```
+    fn add_with_carry_in(&self, rhs: Self, carry: bool, width: usize) -> (Self, AluFlags)
+    where
+        Self: Sized;
+
+    /// Subtracts the lowest N bits of two values and an incoming borrow, wrapping at N bits and
+    /// returning the resulting flags.
+    ///
+    /// This is [`sub_in`](#method.sub_in) for subtract-with-borrow instructions, and uses the same
+    /// carry convention: both the incoming `borrow` and the returned carry flag are set when a
+    /// borrow occurs. Like `sub_in`, a width larger than the type subtracts at the full width of
+    /// the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// let (difference, flags) = 0x3b_u32.sub_with_borrow_in(0x3a, true, 8);
+    /// assert_eq!(difference, 0x00);
+    /// assert!(flags.zero && !flags.carry);
+    /// ```
+    fn sub_with_borrow_in(&self, rhs: Self, borrow: bool, width: usize) -> (Self, AluFlags)
+    where
+        Self: Sized;
+
+    /// Returns whether adding two values carries out of the given bit.
+    ///
+    /// This is the carry flag of [`add_in`](#method.add_in) at a width of `bit + 1`, so the
+    /// half-carry flag of an 8-bit addition is the carry out of bit 3. Use
+    /// [`add_with_carry_in`](#tymethod.add_with_carry_in) the same way to include an incoming
+    /// carry. Bits past the top of the type never carry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert!(0x0f_u8.carry_out_of_bit(0x01, 3));
+    /// assert!(!0x07_u8.carry_out_of_bit(0x08, 3));
+    /// assert!(0x0800_u16.carry_out_of_bit(0x0800, 11));
+    /// ```
+    fn carry_out_of_bit(&self, rhs: Self, bit: usize) -> bool
+    where
+        Self: Sized,
+    {
+        bit < Self::BIT_SIZE && self.add_in(rhs, bit + 1).1.carry
+    }
+
+    /// Returns whether subtracting two values borrows into the given bit from the bit above it.
+    ///
+    /// This is the carry flag of [`sub_in`](#method.sub_in) at a width of `bit + 1`, so the
+    /// half-carry flag of an 8-bit subtraction is the borrow out of bit 3. Use
+    /// [`sub_with_borrow_in`](#tymethod.sub_with_borrow_in) the same way to include an incoming
+    /// borrow. Bits past the top of the type never borrow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert!(0x10_u8.borrow_out_of_bit(0x01, 3));
+    /// assert!(!0x1f_u8.borrow_out_of_bit(0x0f, 3));
+    /// ```
+    fn borrow_out_of_bit(&self, rhs: Self, bit: usize) -> bool
+    where
+        Self: Sized,
+    {
+        bit < Self::BIT_SIZE && self.sub_in(rhs, bit + 1).1.carry
+    }
+
+    /// Rotates the lowest N bits of a value left by `n` bits, wrapping at N bits.
+    ///
+    /// Bits shifted out of the top of the width come back in at the bottom, like the rotate
+    /// instructions of an N-bit machine. The rotation is taken modulo the width, so rotating by a
+    /// multiple of it leaves the N-bit value unchanged. Like the rest of the trait, the bits above
+    /// the width are cleared in the result, and a width of 0 gives 0. A width larger than the type
+    /// rotates the whole value, the same as `rotate_left`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert_eq!(0b0001_1000_u8.rotate_left_in(5, 2), 0b0000_0011);
+    /// assert_eq!(0xf800_0fff_u32.rotate_left_in(12, 4), 0xfff);
+    /// assert_eq!(0x801_u16.rotate_left_in(12, 1), 0x003);
+    /// ```
+    fn rotate_left_in(&self, width: usize, n: usize) -> Self;
+
+    /// Rotates the lowest N bits of a value right by `n` bits, wrapping at N bits.
+    ///
+    /// This is the reverse of [`rotate_left_in`](#tymethod.rotate_left_in): bits shifted out of
+    /// the bottom come back in at the top of the width, and the bits above the width are cleared.
+    /// Wider widths than the type are likewise clamped to it, so they match `rotate_right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert_eq!(0b0000_0011_u8.rotate_right_in(5, 2), 0b0001_1000);
+    /// assert_eq!(0x003_u16.rotate_right_in(12, 1), 0x801);
+    /// ```
+    fn rotate_right_in(&self, width: usize, n: usize) -> Self;
+
+    /// Reverses the order of the lowest N bits of a value.
+    ///
+    /// Bit 0 swaps with bit N - 1, bit 1 with bit N - 2, and so on, which is the reflection used by
+    /// CRCs and the index permutation of an FFT. The bits above the width are cleared, so at the
+    /// full width of the type this matches `reverse_bits`, and a width of 0 gives 0. Widths larger
+    /// than the type are clamped to it, so they match `reverse_bits` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quark::WidthArith;
+    ///
+    /// assert_eq!(0b0000_0110_u8.reverse_bits_in(4), 0b0000_0110);
+    /// assert_eq!(0b0000_0001_u8.reverse_bits_in(4), 0b0000_1000);
+    /// assert_eq!(0xf0_u8.reverse_bits_in(4), 0);
+    /// assert_eq!(0x1234_u16.reverse_bits_in(16), 0x1234_u16.reverse_bits());
+    /// ```
+    fn reverse_bits_in(&self, width: usize) -> Self;
+}
+
+macro_rules! width_arith_impl {
+    ($type:ty) => {
+        impl WidthArith for $type {
+            fn negate_in(&self, width: usize) -> Self {
+                self.wrapping_neg()
+                    .zero_extend(Self::BIT_SIZE.saturating_sub(width))
+            }
+
+            fn abs_in(&self, width: usize) -> Self {
+                let value = self.sign_extend_from(width);
+                if value.sign_bit() {
+                    value.negate_in(width)
+                } else {
+                    value
+                }
+            }
+
+            fn add_with_carry_in(&self, rhs: Self, carry: bool, width: usize) -> (Self, AluFlags) {
+                if width == 0 {
+                    return (
+                        0,
+                        AluFlags {
+                            zero: true,
+                            ..AluFlags::default()
+                        },
+                    );
+                }
+
+                let top = width.min(Self::BIT_SIZE) - 1;
+                let truncate = |value: Self| value.zero_extend(Self::BIT_SIZE - top - 1);
+                let (lhs, rhs) = (truncate(*self), truncate(rhs));
+                let result = truncate(lhs.wrapping_add(rhs).wrapping_add(carry as Self));
+
+                let flags = AluFlags {
+                    carry: (lhs & rhs | (lhs | rhs) & !result).bit(top),
+                    overflow: ((lhs ^ result) & (rhs ^ result)).bit(top),
+                    zero: result == 0,
+                    negative: result.bit(top),
+                };
+
+                (result, flags)
+            }
+
+            fn sub_with_borrow_in(
+                &self,
+                rhs: Self,
+                borrow: bool,
+                width: usize,
+            ) -> (Self, AluFlags) {
+                if width == 0 {
+                    return (
+                        0,
+                        AluFlags {
+                            zero: true,
+                            ..AluFlags::default()
+                        },
+                    );
+                }
+
+                let top = width.min(Self::BIT_SIZE) - 1;
+                let truncate = |value: Self| value.zero_extend(Self::BIT_SIZE - top - 1);
+                let (lhs, rhs) = (truncate(*self), truncate(rhs));
+                let result = truncate(lhs.wrapping_sub(rhs).wrapping_sub(borrow as Self));
+
+                let flags = AluFlags {
+                    carry: (!lhs & rhs | (!lhs | rhs) & result).bit(top),
+                    overflow: ((lhs ^ rhs) & (lhs ^ result)).bit(top),
+                    zero: result == 0,
+                    negative: result.bit(top),
+                };
+
+                (result, flags)
+            }
+
+            fn rotate_left_in(&self, width: usize, n: usize) -> Self {
+                let width = width.min(Self::BIT_SIZE);
+                if width == 0 {
+                    return 0;
+                }
+
+                let truncate = |value: Self| value.zero_extend(Self::BIT_SIZE - width);
+                let (value, n) = (truncate(*self), n % width);
+                if n == 0 {
+                    return value;
+                }
+
+                truncate(value << n | value.logical_shr(width - n))
+            }
+
+            fn rotate_right_in(&self, width: usize, n: usize) -> Self {
+                let width = width.min(Self::BIT_SIZE);
+                if width == 0 {
+                    return 0;
+                }
+
+                self.rotate_left_in(width, width - n % width)
+            }
+
+            fn reverse_bits_in(&self, width: usize) -> Self {
+                let width = width.min(Self::BIT_SIZE);
+                if width == 0 {
+                    return 0;
+                }
+
+                self.reverse_bits().logical_shr(Self::BIT_SIZE - width)
+            }
+        }
+    };
+}
+
+width_arith_impl!(u8);
+width_arith_impl!(u16);
+width_arith_impl!(u32);
+width_arith_impl!(u64);
+width_arith_impl!(u128);
+width_arith_impl!(usize);
+width_arith_impl!(i8);
+width_arith_impl!(i16);
+width_arith_impl!(i32);
+width_arith_impl!(i64);
+width_arith_impl!(i128);
+width_arith_impl!(isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use crate::BitMask;
+
+    #[test]
+    fn negate_in() {
+        assert_eq!(0x00_u32.negate_in(8), 0x00);
+        assert_eq!(0x01_u32.negate_in(8), 0xff);
+        assert_eq!(0xff_u32.negate_in(8), 0x01);
+        assert_eq!(0x80_u32.negate_in(8), 0x80);
+        assert_eq!(0x7f_u32.negate_in(8), 0x81);
+        assert_eq!(0x1234_5601_u32.negate_in(8), 0xff);
+
+        assert_eq!(0x800_u16.negate_in(12), 0x800);
+        assert_eq!(0x000_u16.negate_in(12), 0x000);
+        assert_eq!(0x001_u16.negate_in(12), 0xfff);
+
+        assert_eq!(0x80_u8.negate_in(8), 0x80);
+        assert_eq!(0x80_u8.negate_in(20), 0x80);
+        assert_eq!(0x01_u8.negate_in(8), 0xff);
+        assert_eq!(0x01_u8.negate_in(0), 0x00);
+
+        assert_eq!(1_u64.negate_in(64), u64::MAX);
+        assert_eq!((1_u128 << 99).negate_in(100), 1 << 99);
+    }
+
+    #[test]
+    fn signed_negate_in() {
+        assert_eq!(0_i32.negate_in(8), 0);
+        assert_eq!(1_i32.negate_in(8), 0xff);
+        assert_eq!((-1_i32).negate_in(8), 1);
+        assert_eq!((-128_i32).negate_in(8), 0x80);
+        assert_eq!((-128_i32).negate_in(8).sign_extend_from(8), -128);
+
+        assert_eq!(i8::MIN.negate_in(8), i8::MIN);
+        assert_eq!(5_i8.negate_in(8), -5);
+        assert_eq!(5_i8.negate_in(0), 0);
+        assert_eq!(i64::MIN.negate_in(64), i64::MIN);
+        assert_eq!((-7_i64).negate_in(40), 7);
+    }
+
+    #[test]
+    fn abs_in() {
+        assert_eq!(0x00_u32.abs_in(8), 0x00);
+        assert_eq!(0x05_u32.abs_in(8), 0x05);
+        assert_eq!(0xfb_u32.abs_in(8), 0x05);
+        assert_eq!(0x80_u32.abs_in(8), 0x80);
+        assert_eq!(0xffff_ff80_u32.abs_in(8), 0x80);
+        assert_eq!(0x800_u16.abs_in(12), 0x800);
+        assert_eq!(0x801_u16.abs_in(12), 0x7ff);
+
+        assert_eq!(0xff_u8.abs_in(8), 0x01);
+        assert_eq!(0xff_u8.abs_in(0), 0x00);
+
+        assert_eq!((-5_i32).abs_in(8), 5);
+        assert_eq!((-128_i32).abs_in(8), 0x80);
+        assert_eq!(i16::MIN.abs_in(16), i16::MIN);
+        assert_eq!((-1_i16).abs_in(16), 1);
+        assert_eq!(0x7f_i16.abs_in(7), 0x01);
+    }
+
+    #[test]
+    fn negate_and_abs_in_wide_widths() {
+        for seed in random_u64s().take(1000) {
+            let width = 64 + (seed >> 58) as usize;
+
+            assert_eq!(seed.negate_in(width), seed.wrapping_neg());
+            assert_eq!((seed as i64).negate_in(width), (seed as i64).wrapping_neg());
+            assert_eq!((seed as i32).abs_in(width), (seed as i32).wrapping_abs());
+            assert_eq!((seed as i64).abs_in(width), (seed as i64).wrapping_abs());
+        }
+    }
+
+    fn flags(carry: bool, overflow: bool, zero: bool, negative: bool) -> AluFlags {
+        AluFlags {
+            carry,
+            overflow,
+            zero,
+            negative,
+        }
+    }
+
+    #[test]
+    fn add_in() {
+        let cases = [
+            (0x00, 0x00, 0x00, flags(false, false, true, false)),
+            (0x01, 0x01, 0x02, flags(false, false, false, false)),
+            (0x7f, 0x01, 0x80, flags(false, true, false, true)),
+            (0xff, 0x01, 0x00, flags(true, false, true, false)),
+            (0x80, 0x80, 0x00, flags(true, true, true, false)),
+            (0x80, 0xff, 0x7f, flags(true, true, false, false)),
+            (0xff, 0xff, 0xfe, flags(true, false, false, true)),
+            (0x50, 0x50, 0xa0, flags(false, true, false, true)),
+            (0xd0, 0x90, 0x60, flags(true, true, false, false)),
+            (0x50, 0xd0, 0x20, flags(true, false, false, false)),
+        ];
+
+        for &(lhs, rhs, sum, expected) in &cases {
+            assert_eq!((lhs as u8).add_in(rhs as u8, 8), (sum as u8, expected));
+            assert_eq!((lhs as i8).add_in(rhs as i8, 8), (sum as i8, expected));
+            assert_eq!(
+                (lhs as u32 | 0x1200).add_in(rhs as u32 | 0x3400, 8),
+                (sum as u32, expected),
+            );
+            assert_eq!(
+                (lhs as i8 as i64).add_in(rhs as i8 as i64, 8),
+                (sum as i64, expected),
+            );
+        }
+    }
+
+    #[test]
+    fn add_in_edge_widths() {
+        assert_eq!(
+            0xff_u8.add_in(0xff, 0),
+            (0, flags(false, false, true, false)),
+        );
+        assert_eq!(0x1_u16.add_in(0x1, 1), (0, flags(true, true, true, false)));
+        assert_eq!(
+            0x800_u16.add_in(0x800, 12),
+            (0, flags(true, true, true, false)),
+        );
+        assert_eq!(u64::MAX.add_in(1, 64), (0, flags(true, false, true, false)));
+        assert_eq!(
+            i64::MAX.add_in(1, 100),
+            (i64::MIN, flags(false, true, false, true)),
+        );
+    }
+
+    #[test]
+    fn add_in_wide_widths() {
+        for seed in random_u64s().take(1000) {
+            let (lhs, rhs) = (seed as u32, (seed >> 32) as u32);
+            let width = 32 + (seed >> 58) as usize;
+
+            assert_eq!(lhs.add_in(rhs, width), lhs.add_in(rhs, 32));
+            assert_eq!(
+                (lhs as i32).add_with_carry_in(rhs as i32, true, width),
+                (lhs as i32).add_with_carry_in(rhs as i32, true, 32),
+            );
+        }
+    }
+
+    #[test]
+    fn sub_in() {
+        let cases = [
+            (0x00, 0x00, 0x00, flags(false, false, true, false)),
+            (0x05, 0x03, 0x02, flags(false, false, false, false)),
+            (0x03, 0x05, 0xfe, flags(true, false, false, true)),
+            (0x10, 0x20, 0xf0, flags(true, false, false, true)),
+            (0x80, 0x01, 0x7f, flags(false, true, false, false)),
+            (0x7f, 0xff, 0x80, flags(true, true, false, true)),
+            (0x00, 0x80, 0x80, flags(true, true, false, true)),
+            (0xff, 0xff, 0x00, flags(false, false, true, false)),
+            (0xff, 0x7f, 0x80, flags(false, false, false, true)),
+            (0x00, 0x01, 0xff, flags(true, false, false, true)),
+        ];
+
+        for &(lhs, rhs, difference, expected) in &cases {
+            assert_eq!(
+                (lhs as u8).sub_in(rhs as u8, 8),
+                (difference as u8, expected),
+            );
+            assert_eq!(
+                (lhs as i8).sub_in(rhs as i8, 8),
+                (difference as i8, expected),
+            );
+            assert_eq!(
+                (lhs as u32 | 0x1200).sub_in(rhs as u32 | 0x3400, 8),
+                (difference as u32, expected),
+            );
+            assert_eq!((lhs as u16).cmp_in(rhs as u16, 8), expected);
+        }
+    }
+
+    #[test]
+    fn sub_in_wide_widths() {
+        for seed in random_u64s().take(1000) {
+            let (lhs, rhs) = (seed as u32, (seed >> 32) as u32);
+            let width = 32 + (seed >> 58) as usize;
+
+            assert_eq!(lhs.sub_in(rhs, width), lhs.sub_in(rhs, 32));
+            assert_eq!(lhs.cmp_in(rhs, width), lhs.cmp_in(rhs, 32));
+            assert_eq!(
+                (lhs as i32).sub_with_borrow_in(rhs as i32, true, width),
+                (lhs as i32).sub_with_borrow_in(rhs as i32, true, 32),
+            );
+        }
+    }
+
+    #[test]
+    fn cmp_in_orders_values() {
+        for lhs in 0..=u8::MAX {
+            for rhs in 0..=u8::MAX {
+                let flags = (lhs as u32).cmp_in(rhs as u32, 8);
+                assert_eq!(flags.zero, lhs == rhs);
+                assert_eq!(flags.carry, lhs < rhs);
+                assert_eq!(flags.negative != flags.overflow, (lhs as i8) < (rhs as i8));
+            }
+        }
+
+        assert_eq!(0x1_u16.cmp_in(0x1, 0), flags(false, false, true, false));
+        assert_eq!(0_u64.cmp_in(1, 64), flags(true, false, false, true));
+        assert_eq!(i64::MIN.cmp_in(1, 100), flags(false, true, false, false));
+    }
+
+    #[test]
+    fn game_boy_flags() {
+        // (a, operand, carry in, result, zero, half carry, carry) for ADD and ADC
+        let additions = [
+            (0x3a, 0xc6, false, 0x00, true, true, true),
+            (0x3c, 0xff, false, 0x3b, false, true, true),
+            (0x3c, 0x12, false, 0x4e, false, false, false),
+            (0x0f, 0x01, false, 0x10, false, true, false),
+            (0xf0, 0x10, false, 0x00, true, false, true),
+            (0xe1, 0x0f, true, 0xf1, false, true, false),
+            (0xe1, 0x3b, true, 0x1d, false, false, true),
+            (0xe1, 0x1e, true, 0x00, true, true, true),
+        ];
+
+        for &(a, operand, carry, result, zero, half_carry, full_carry) in &additions {
+            let (sum, flags) = (a as u8).add_with_carry_in(operand, carry, 8);
+            assert_eq!((sum, flags.zero, flags.carry), (result, zero, full_carry));
+            assert_eq!(
+                (a as u8).add_with_carry_in(operand, carry, 4).1.carry,
+                half_carry,
+            );
+            if !carry {
+                assert_eq!((a as u8).carry_out_of_bit(operand, 3), half_carry);
+            }
+        }
+
+        // (a, operand, carry in, result, zero, half carry, carry) for SUB, SBC, and CP
+        let subtractions = [
+            (0x3e, 0x3e, false, 0x00, true, false, false),
+            (0x3e, 0x0f, false, 0x2f, false, true, false),
+            (0x3e, 0x40, false, 0xfe, false, false, true),
+            (0x3b, 0x2a, true, 0x10, false, false, false),
+            (0x3b, 0x3a, true, 0x00, true, false, false),
+            (0x3b, 0x4f, true, 0xeb, false, true, true),
+            (0x3c, 0x2f, false, 0x0d, false, true, false),
+            (0x3c, 0x3c, false, 0x00, true, false, false),
+        ];
+
+        for &(a, operand, borrow, result, zero, half_carry, full_carry) in &subtractions {
+            let (difference, flags) = (a as u8).sub_with_borrow_in(operand, borrow, 8);
+            assert_eq!(
+                (difference, flags.zero, flags.carry),
+                (result, zero, full_carry),
+            );
+            assert_eq!(
+                (a as u8).sub_with_borrow_in(operand, borrow, 4).1.carry,
+                half_carry,
+            );
+            if !borrow {
+                assert_eq!((a as u8).borrow_out_of_bit(operand, 3), half_carry);
+            }
+        }
+
+        // ADD HL, rr sets the half carry from bit 11
+        let (hl, flags) = 0x8a23_u16.add_in(0x0605, 16);
+        assert_eq!((hl, flags.carry), (0x9028, false));
+        assert!(0x8a23_u16.carry_out_of_bit(0x0605, 11));
+
+        let (hl, flags) = 0x8a23_u16.add_in(0x8a23, 16);
+        assert_eq!((hl, flags.carry), (0x1446, true));
+        assert!(0x8a23_u16.carry_out_of_bit(0x8a23, 11));
+    }
+
+    #[test]
+    fn carry_out_of_bit() {
+        for lhs in 0..=u8::MAX {
+            for rhs in 0..=u8::MAX {
+                for bit in 0..8 {
+                    let mask = u8::mask(bit + 1) as u16;
+                    let (lhs, rhs) = (lhs as u16 & mask, rhs as u16 & mask);
+                    assert_eq!(
+                        (lhs as u8).carry_out_of_bit(rhs as u8, bit),
+                        lhs + rhs > mask,
+                    );
+                    assert_eq!((lhs as u8).borrow_out_of_bit(rhs as u8, bit), lhs < rhs);
+                }
+            }
+        }
+
+        assert!(!u8::MAX.carry_out_of_bit(u8::MAX, 8));
+        assert!(!u8::MAX.carry_out_of_bit(u8::MAX, usize::MAX));
+        assert!(!0_i32.borrow_out_of_bit(1, 40));
+        assert!(!0_i32.borrow_out_of_bit(1, usize::MAX));
+        assert!(i32::MIN.carry_out_of_bit(i32::MIN, 31));
+    }
+
+    #[test]
+    fn narrow_arithmetic_matches_primitives() {
+        for value in 0..=u8::MAX {
+            assert_eq!(value.negate_in(8), value.wrapping_neg());
+            assert_eq!((value as u32).negate_in(8), value.wrapping_neg() as u32);
+            assert_eq!((value as i64).negate_in(8), value.wrapping_neg() as i64);
+
+            assert_eq!(
+                (value as u16).abs_in(8),
+                (value as i8).wrapping_abs() as u8 as u16,
+            );
+            assert_eq!(
+                (value as i8 as i32).abs_in(8),
+                (value as i8).wrapping_abs() as u8 as i32,
+            );
+
+            for rhs in 0..=u8::MAX {
+                let (sum, carry) = value.overflowing_add(rhs);
+                let (_, overflow) = (value as i8).overflowing_add(rhs as i8);
+                assert_eq!(
+                    (value as u16).add_in(rhs as u16, 8),
+                    (sum as u16, flags(carry, overflow, sum == 0, sum >= 0x80)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_in() {
+        assert_eq!(0b0001_1000_u8.rotate_left_in(5, 2), 0b0000_0011);
+        assert_eq!(0b1111_1000_u8.rotate_left_in(5, 2), 0b0000_0011);
+        assert_eq!(0b0001_1000_u8.rotate_right_in(5, 2), 0b0000_0110);
+        assert_eq!(0x801_u16.rotate_left_in(12, 1), 0x003);
+        assert_eq!(0x801_u16.rotate_right_in(12, 1), 0xc00);
+
+        assert_eq!(0x1234_u16.rotate_left_in(12, 0), 0x234);
+        assert_eq!(0x1234_u16.rotate_left_in(12, 12), 0x234);
+        assert_eq!(0x1234_u16.rotate_right_in(12, 36), 0x234);
+        assert_eq!(0x1234_u16.rotate_left_in(12, 13), 0x468);
+        assert_eq!(0x1234_u16.rotate_right_in(12, 13), 0x11a);
+
+        assert_eq!(0xff_u8.rotate_left_in(0, 3), 0);
+        assert_eq!(0xff_u8.rotate_right_in(0, 3), 0);
+        assert_eq!(0x81_u8.rotate_left_in(1, 5), 0x01);
+
+        assert_eq!((-1_i16).rotate_left_in(12, 5), 0xfff);
+        assert_eq!(0x0800_i16.rotate_left_in(12, 1), 0x001);
+        assert_eq!(i32::MIN.rotate_right_in(32, 31), 1);
+    }
+
+    #[test]
+    fn rotate_in_matches_primitives() {
+        for value in 0..=u8::MAX {
+            for n in 0..20 {
+                assert_eq!(value.rotate_left_in(8, n), value.rotate_left(n as u32));
+                assert_eq!(value.rotate_right_in(8, n), value.rotate_right(n as u32));
+                assert_eq!(value.rotate_left_in(100, n), value.rotate_left(n as u32));
+                assert_eq!(
+                    (value as i8).rotate_left_in(8, n),
+                    (value as i8).rotate_left(n as u32),
+                );
+                assert_eq!(
+                    (value as u32 | 0xabcd_ef00).rotate_left_in(8, n),
+                    value.rotate_left(n as u32) as u32,
+                );
+                assert_eq!(
+                    (value as i64 - 0x100).rotate_right_in(8, n),
+                    value.rotate_right(n as u32) as i64,
+                );
+
+                let wide = (value as u128).wrapping_mul(0x0123_4567_89ab_cdef_fedc_ba98_7654_3211);
+                assert_eq!(
+                    wide.rotate_left_in(128, n * 7),
+                    wide.rotate_left(n as u32 * 7)
+                );
+                assert_eq!(
+                    wide.rotate_right_in(128, n * 7),
+                    wide.rotate_right(n as u32 * 7)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_in_wide_widths() {
+        for seed in random_u64s().take(1000) {
+            let width = 64 + (seed >> 57) as usize;
+            let n = (seed >> 50) as usize;
+
+            assert_eq!(seed.rotate_left_in(width, n), seed.rotate_left(n as u32));
+            assert_eq!(seed.rotate_right_in(width, n), seed.rotate_right(n as u32));
+            assert_eq!(
+                (seed as i16).rotate_left_in(width, n),
+                (seed as i16).rotate_left(n as u32),
+            );
+        }
+
+        assert_eq!(0x81_u8.rotate_left_in(usize::MAX, 1), 0x03);
+        assert_eq!(0x81_u8.rotate_right_in(usize::MAX, 1), 0xc0);
+    }
+
+    #[test]
+    fn reverse_bits_in() {
+        assert_eq!(0b0000_0110_u8.reverse_bits_in(4), 0b0000_0110);
+        assert_eq!(0b0000_0011_u8.reverse_bits_in(4), 0b0000_1100);
+        assert_eq!(0b1111_0001_u8.reverse_bits_in(4), 0b0000_1000);
+        assert_eq!(0x001_u16.reverse_bits_in(12), 0x800);
+        assert_eq!(0xabc_u16.reverse_bits_in(12), 0x3d5);
+
+        assert_eq!(0xff_u8.reverse_bits_in(0), 0);
+        assert_eq!(0xff_u8.reverse_bits_in(1), 1);
+        assert_eq!(0xfe_u8.reverse_bits_in(1), 0);
+        assert_eq!(0x01_u8.reverse_bits_in(9), 0x80);
+
+        assert_eq!((-1_i16).reverse_bits_in(12), 0xfff);
+        assert_eq!(1_i32.reverse_bits_in(32), i32::MIN);
+        assert_eq!(0x0123_u128.reverse_bits_in(128), 0x0123_u128.reverse_bits());
+    }
+
+    #[test]
+    fn reverse_bits_in_wide_widths() {
+        for seed in random_u64s().take(1000) {
+            let width = 64 + (seed >> 57) as usize;
+
+            assert_eq!(seed.reverse_bits_in(width), seed.reverse_bits());
+            assert_eq!(
+                (seed as i8).reverse_bits_in(width),
+                (seed as i8).reverse_bits()
+            );
+        }
+
+        assert_eq!(0x01_u8.reverse_bits_in(usize::MAX), 0x80);
+    }
+
+    #[test]
+    fn reversing_twice_is_identity() {
+        for seed in random_u64s().take(1000) {
+            let width = (seed >> 57) as usize;
+
+            assert_eq!(
+                seed.reverse_bits_in(width).reverse_bits_in(width),
+                seed.zero_extend(64_usize.saturating_sub(width)),
+            );
+            assert_eq!(
+                (seed as i32).reverse_bits_in(width).reverse_bits_in(width),
+                (seed as i32).zero_extend(32_usize.saturating_sub(width)),
+            );
+            assert_eq!(seed.reverse_bits_in(64), seed.reverse_bits());
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{BitConsts, BitIndex, BitMask, BitSize, MaskIndices, Signs};
+use std::num::{Saturating, Wrapping};
+use std::ops::{BitAnd, BitOr, Not, RangeBounds, Shr};
+
+macro_rules! wrapper_impl {
+    ($wrapper:ident) => {
+        impl<T: BitSize> BitSize for $wrapper<T> {
+            const BIT_SIZE: usize = T::BIT_SIZE;
+        }
+
+        impl<T: BitConsts> BitConsts for $wrapper<T> {
+            const ALL_SET: Self = $wrapper(T::ALL_SET);
+            const NONE_SET: Self = $wrapper(T::NONE_SET);
+        }
+
+        impl<T> BitIndex for $wrapper<T>
+        where
+            T: BitIndex + Copy + Not<Output = T> + BitAnd<Output = T> + BitOr<Output = T>,
+        {
+            fn bit(&self, index: usize) -> bool {
+                self.0.bit(index)
+            }
+
+            fn bits<Idx: RangeBounds<usize>>(&self, index: Idx) -> Self {
+                $wrapper(self.0.bits(index))
+            }
+
+            fn with_bits<Idx: RangeBounds<usize>>(&self, index: Idx, value: Self) -> Self {
+                $wrapper(self.0.with_bits(index, value.0))
+            }
+        }
+
+        impl<T: BitMask + Copy> BitMask for $wrapper<T> {
+            fn mask(size: usize) -> Self {
+                $wrapper(T::mask(size))
+            }
+
+            fn mask_to(&self, size: usize) -> Self {
+                $wrapper(self.0.mask_to(size))
+            }
+
+            fn mask_range<Idx: RangeBounds<usize>>(range: Idx) -> Self {
+                $wrapper(T::mask_range(range))
+            }
+        }
+
+        impl<T: Copy> Iterator for MaskIndices<$wrapper<T>>
+        where
+            MaskIndices<T>: Iterator<Item = usize>,
+        {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                let mut inner = MaskIndices {
+                    remaining: self.remaining.0,
+                };
+                let index = inner.next();
+                self.remaining = $wrapper(inner.remaining);
+                index
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                MaskIndices {
+                    remaining: self.remaining.0,
+                }
+                .size_hint()
+            }
+        }
+
+        impl<T: Copy> ExactSizeIterator for MaskIndices<$wrapper<T>> where
+            MaskIndices<T>: ExactSizeIterator<Item = usize>
+        {
+        }
+
+        impl<T> Signs for $wrapper<T>
+        where
+            T: Signs
+                + Copy
+                + PartialOrd
+                + BitMask
+                + Not<Output = T>
+                + BitAnd<Output = T>
+                + BitOr<Output = T>
+                + Shr<usize, Output = T>,
+        {
+            fn sign_bit(&self) -> bool {
+                self.0.sign_bit()
+            }
+
+            fn leading_sign_bits(&self) -> u32 {
+                self.0.leading_sign_bits()
+            }
+
+            fn sign_extend(&self, bits: usize) -> Self {
+                $wrapper(self.0.sign_extend(bits))
+            }
+
+            fn sign_extend_from(&self, width: usize) -> Self {
+                $wrapper(self.0.sign_extend_from(width))
+            }
+
+            fn checked_sign_extend(&self, bits: usize) -> Option<Self> {
+                self.0.checked_sign_extend(bits).map($wrapper)
+            }
+
+            fn checked_sign_extend_from(&self, width: usize) -> Option<Self> {
+                self.0.checked_sign_extend_from(width).map($wrapper)
+            }
+
+            fn fits_in_unsigned(&self, bits: usize) -> bool {
+                self.0.fits_in_unsigned(bits)
+            }
+
+            fn fits_in_signed(&self, bits: usize) -> bool {
+                self.0.fits_in_signed(bits)
+            }
+
+            fn min_unsigned_bits(&self) -> usize {
+                self.0.min_unsigned_bits()
+            }
+
+            fn min_signed_bits(&self) -> usize {
+                self.0.min_signed_bits()
+            }
+
+            fn saturate_to_bits_unsigned(&self, width: usize) -> Self {
+                $wrapper(self.0.saturate_to_bits_unsigned(width))
+            }
+
+            fn saturate_to_bits_signed(&self, width: usize) -> Self {
+                $wrapper(self.0.saturate_to_bits_signed(width))
+            }
+
+            fn wrapping_truncate_to_bits(&self, width: usize) -> Self {
+                $wrapper(self.0.wrapping_truncate_to_bits(width))
+            }
+
+            fn to_sign_magnitude(&self, width: usize) -> Self {
+                $wrapper(self.0.to_sign_magnitude(width))
+            }
+
+            fn try_to_sign_magnitude(&self, width: usize) -> Option<Self> {
+                self.0.try_to_sign_magnitude(width).map($wrapper)
+            }
+
+            fn from_sign_magnitude(&self, width: usize) -> Self {
+                $wrapper(self.0.from_sign_magnitude(width))
+            }
+
+            fn zero_extend(&self, bits: usize) -> Self {
+                $wrapper(self.0.zero_extend(bits))
+            }
+
+            fn arithmetic_shr(&self, n: usize) -> Self {
+                $wrapper(self.0.arithmetic_shr(n))
+            }
+
+            fn logical_shr(&self, n: usize) -> Self {
+                $wrapper(self.0.logical_shr(n))
+            }
+        }
+    };
+}
+
+wrapper_impl!(Wrapping);
+wrapper_impl!(Saturating);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn wrapping_register() {
+        let raw: u32 = 0xe01a_3497;
+        let register = Wrapping(raw);
+
+        asserting!("wrapped registers have the size of the primitive")
+            .that(&(Wrapping::<u32>::BIT_SIZE, Wrapping::<u32>::ALL_SET))
+            .is_equal_to((32, Wrapping(u32::MAX)));
+
+        asserting!("reading bits matches the primitive")
+            .that(&(register.bit(4), register.bits(16..20), register.mask_to(12)))
+            .is_equal_to((
+                raw.bit(4),
+                Wrapping(raw.bits(16..20)),
+                Wrapping(raw.mask_to(12)),
+            ));
+
+        asserting!("writing bits matches the primitive")
+            .that(&register.with_bits(16..20, Wrapping(0x5)))
+            .is_equal_to(Wrapping(raw.with_bits(16..20, 0x5)));
+
+        asserting!("masks can be created in the wrapper")
+            .that(&(Wrapping::<u32>::mask(8), Wrapping::<u32>::mask_range(4..8)))
+            .is_equal_to((Wrapping(0xff), Wrapping(0xf0)));
+
+        asserting!("sign extension matches the primitive")
+            .that(&(register.sign_extend_from(12), register.sign_bit()))
+            .is_equal_to((Wrapping(raw.sign_extend_from(12)), raw.sign_bit()));
+
+        asserting!("wrapped registers can still do wrapping arithmetic")
+            .that(&(register.bits(16..20) - Wrapping(0xb)))
+            .is_equal_to(Wrapping(u32::MAX));
+    }
+
+    #[test]
+    fn wrapping_mask_indices() {
+        let indices: Vec<_> = Wrapping(0x8088_u16).indices_of_mask().collect();
+        asserting!("mask indices match the primitive")
+            .that(&indices)
+            .is_equal_to(vec![3, 7, 15]);
+
+        asserting!("mask indices report their length")
+            .that(&Saturating(-1_i64).indices_of_mask().len())
+            .is_equal_to(64);
+    }
+
+    #[test]
+    fn saturating_register() {
+        let raw: i16 = -0x1234;
+        let register = Saturating(raw);
+
+        asserting!("reading bits matches the primitive")
+            .that(&(register.bits(4..12), register.leading_sign_bits()))
+            .is_equal_to((Saturating(raw.bits(4..12)), raw.leading_sign_bits()));
+
+        asserting!("signed conversions match the primitive")
+            .that(&(
+                register.saturate_to_bits_signed(8),
+                register.to_sign_magnitude(16),
+                register.checked_sign_extend_from(8),
+            ))
+            .is_equal_to((
+                Saturating(raw.saturate_to_bits_signed(8)),
+                Saturating(raw.to_sign_magnitude(16)),
+                None,
+            ));
+
+        asserting!("saturated registers can still do saturating arithmetic")
+            .that(&(Saturating::<i16>::mask(15) + Saturating(1)))
+            .is_equal_to(Saturating(i16::MAX));
+    }
+
+    #[test]
+    fn provided_methods() {
+        asserting!("provided methods work through the wrapper")
+            .that(&(
+                Wrapping(0x800_u32).sign_bit_at(12),
+                Saturating(0x800_u32).is_negative_in(13),
+            ))
+            .is_equal_to((true, false));
+    }
+}
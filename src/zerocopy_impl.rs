@@ -0,0 +1,174 @@
+use crate::{i24, u24, u48};
+use std::fmt::{self, Debug, Display, Formatter};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+// `u24`, `i24`, and `u48` derive the zerocopy traits where they're declared, since zerocopy only
+// accepts derived impls. They're little-endian in memory, which suits file formats, but network
+// protocols put the most significant byte first, so `BigEndian` holds a value with its bytes in
+// the other order and swaps them back when it's read.
+
+/// A byte-exact integer stored with its most significant byte first.
+///
+/// Network protocols and many hardware registers lay out their fields in big-endian order. This
+/// wrapper lets a [`u24`](struct.u24.html), [`i24`](struct.i24.html), or
+/// [`u48`](struct.u48.html) be a field of a header struct read with zerocopy, converting to and
+/// from the value when it's read or written. It's only available with the `zerocopy` feature.
+///
+/// # Examples
+///
+/// ```
+/// use quark::{u24, BigEndian};
+/// use zerocopy::FromBytes;
+///
+/// let length = BigEndian::<u24>::read_from_bytes(&[0x01, 0x02, 0x03][..]).unwrap();
+/// assert_eq!(length.get(), u24::new(0x01_0203));
+/// ```
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    FromBytes,
+    IntoBytes,
+    Unaligned,
+    KnownLayout,
+    Immutable,
+)]
+#[repr(transparent)]
+pub struct BigEndian<T>(T);
+
+macro_rules! big_endian_impl {
+    ($($type:ident),*) => {
+        $(
+            impl BigEndian<$type> {
+                /// Stores a value in big-endian order.
+                pub const fn new(value: $type) -> Self {
+                    BigEndian($type::from_le_bytes(value.to_be_bytes()))
+                }
+
+                /// Returns the value.
+                pub const fn get(self) -> $type {
+                    $type::from_be_bytes(self.0.to_le_bytes())
+                }
+            }
+
+            impl From<$type> for BigEndian<$type> {
+                fn from(value: $type) -> Self {
+                    BigEndian::<$type>::new(value)
+                }
+            }
+
+            impl From<BigEndian<$type>> for $type {
+                fn from(value: BigEndian<$type>) -> Self {
+                    value.get()
+                }
+            }
+
+            impl Debug for BigEndian<$type> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    f.debug_tuple("BigEndian").field(&self.get()).finish()
+                }
+            }
+
+            impl Display for BigEndian<$type> {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    Display::fmt(&self.get(), f)
+                }
+            }
+        )*
+    };
+}
+
+big_endian_impl!(u24, i24, u48);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    /// A made-up frame header in the style of Ethernet, with a big-endian length and a
+    /// little-endian sequence number.
+    #[derive(FromBytes, IntoBytes, Unaligned, KnownLayout, Immutable)]
+    #[repr(C)]
+    struct Header {
+        destination: BigEndian<u48>,
+        source: BigEndian<u48>,
+        length: BigEndian<u24>,
+        flags: u8,
+        sequence: u24,
+        offset: BigEndian<i24>,
+    }
+
+    const FRAME: [u8; 26] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // destination
+        0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6, // source
+        0x00, 0x05, 0xdc, // length
+        0x80, // flags
+        0x03, 0x02, 0x01, // sequence
+        0xff, 0xff, 0xfe, // offset
+        0xde, 0xad, 0xbe, 0xef, // payload
+    ];
+
+    #[test]
+    fn parsing_a_header() {
+        let (header, payload) = Header::ref_from_prefix(&FRAME[..]).unwrap();
+
+        asserting!("fields decode in their byte order")
+            .that(&(
+                header.destination.get(),
+                format!("{:#}", header.source.get()),
+                header.length.get(),
+                header.flags,
+                header.sequence,
+                header.offset.get(),
+                payload,
+            ))
+            .is_equal_to((
+                u48::MAX,
+                "00:1b:63:84:45:e6".to_string(),
+                u24::new(1500),
+                0x80,
+                u24::new(0x01_0203),
+                i24::new(-2),
+                &[0xde, 0xad, 0xbe, 0xef][..],
+            ));
+    }
+
+    #[test]
+    fn writing_a_header() {
+        let header = Header {
+            destination: u48::MAX.into(),
+            source: u48::from_be_bytes([0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6]).into(),
+            length: BigEndian::<u24>::new(u24::new(1500)),
+            flags: 0x80,
+            sequence: u24::new(0x01_0203),
+            offset: i24::new(-2).into(),
+        };
+
+        asserting!("fields encode in their byte order")
+            .that(&header.as_bytes())
+            .is_equal_to(&FRAME[..22]);
+
+        asserting!("big-endian values format as the value")
+            .that(&(
+                format!("{:?}", header.length),
+                header.offset.to_string(),
+                u48::from(header.destination),
+            ))
+            .is_equal_to(("BigEndian(1500)".to_string(), "-2".to_string(), u48::MAX));
+    }
+
+    #[test]
+    fn byte_exact_types_are_unaligned() {
+        let sequences = <[u24]>::ref_from_bytes(&FRAME[..6]).unwrap();
+        asserting!("slices of values read as little-endian")
+            .that(&sequences.to_vec())
+            .is_equal_to(vec![u24::new(0xff_ffff), u24::new(0xff_ffff)]);
+
+        asserting!("reads from buffers of the wrong length fail")
+            .that(&u48::read_from_bytes(&FRAME[..5]).is_err())
+            .is_true();
+    }
+}
@@ -0,0 +1,144 @@
+use crate::SignCast;
+
+/// Maps signed values to unsigned ones so that small magnitudes stay small, and back.
+///
+/// ZigZag encoding interleaves the signed values as 0, -1, 1, -2, 2, …, so a value near zero of
+/// either sign encodes as a small unsigned value. Protocol Buffers and other formats use it before
+/// a variable-length encoding, which would otherwise spend its longest form on every negative
+/// number.
+///
+/// Both methods work on either signedness, reading the value by its bit pattern: encoding reads
+/// it as signed and decoding reads it as unsigned.
+///
+/// # Examples
+///
+/// ```
+/// use quark::ZigZag;
+///
+/// assert_eq!(0_i32.zigzag_encode(), 0_u32);
+/// assert_eq!((-1_i32).zigzag_encode(), 1);
+/// assert_eq!(1_i32.zigzag_encode(), 2);
+/// assert_eq!((-2_i32).zigzag_encode(), 3);
+///
+/// assert_eq!(3_u32.zigzag_decode(), -2_i32);
+/// assert_eq!(u8::MAX.zigzag_decode(), i8::MIN);
+/// ```
+pub trait ZigZag: SignCast {
+    /// Returns the ZigZag encoding of a signed value.
+    fn zigzag_encode(&self) -> Self::Unsigned;
+
+    /// Returns the signed value of a ZigZag encoding.
+    fn zigzag_decode(&self) -> Self::Signed;
+}
+
+macro_rules! zigzag_impl {
+    ($($type:ident => $unsigned:ident, $signed:ident);*) => {
+        $(
+            impl ZigZag for $type {
+                #[inline]
+                fn zigzag_encode(&self) -> $unsigned {
+                    // The arithmetic shift fills the value with its sign, which flips the bits of
+                    // negative values. Shifting the sign bit out of the top is fine, and doesn't
+                    // panic for MIN.
+                    let value = *self as $signed;
+                    (value as $unsigned) << 1 ^ (value >> ($signed::BITS - 1)) as $unsigned
+                }
+
+                #[inline]
+                fn zigzag_decode(&self) -> $signed {
+                    let value = *self as $unsigned;
+                    (value >> 1 ^ (value & 1).wrapping_neg()) as $signed
+                }
+            }
+        )*
+    };
+}
+
+zigzag_impl!(
+    u8 => u8, i8;
+    u16 => u16, i16;
+    u32 => u32, i32;
+    u64 => u64, i64;
+    u128 => u128, i128;
+    usize => usize, isize;
+    i8 => u8, i8;
+    i16 => u16, i16;
+    i32 => u32, i32;
+    i64 => u64, i64;
+    i128 => u128, i128;
+    isize => usize, isize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::random_u64s;
+    use spectral::prelude::*;
+
+    #[test]
+    fn canonical_values() {
+        asserting!("small magnitudes encode as small values")
+            .that(&(
+                0_i8.zigzag_encode(),
+                (-1_i16).zigzag_encode(),
+                1_i32.zigzag_encode(),
+                (-2_i64).zigzag_encode(),
+                2_i128.zigzag_encode(),
+                (-3_isize).zigzag_encode(),
+            ))
+            .is_equal_to((0, 1, 2, 3, 4, 5));
+
+        asserting!("the extremes encode as the largest values")
+            .that(&(
+                i8::MAX.zigzag_encode(),
+                i8::MIN.zigzag_encode(),
+                i128::MAX.zigzag_encode(),
+                i128::MIN.zigzag_encode(),
+                u32::MAX.zigzag_encode(),
+            ))
+            .is_equal_to((u8::MAX - 1, u8::MAX, u128::MAX - 1, u128::MAX, 1));
+
+        asserting!("encodings decode to alternating signs")
+            .that(&(
+                0_u8.zigzag_decode(),
+                1_u16.zigzag_decode(),
+                2_u32.zigzag_decode(),
+                3_u64.zigzag_decode(),
+                u128::MAX.zigzag_decode(),
+                (u128::MAX - 1).zigzag_decode(),
+                (-1_i32).zigzag_decode(),
+            ))
+            .is_equal_to((0, -1, 1, -2, i128::MIN, i128::MAX, i32::MIN));
+    }
+
+    #[test]
+    fn round_trips() {
+        for value in i16::MIN..=i16::MAX {
+            let encoded = value.zigzag_encode();
+            asserting!("i16 values round-trip in order of magnitude")
+                .that(&(encoded.zigzag_decode(), encoded as u32))
+                .is_equal_to((
+                    value,
+                    if value < 0 {
+                        value.unsigned_abs() as u32 * 2 - 1
+                    } else {
+                        value as u32 * 2
+                    },
+                ));
+        }
+
+        for seed in random_u64s().take(1000) {
+            let wide = (seed as u128) << 64 | seed.rotate_left(7) as u128;
+
+            asserting!("values round-trip through both directions")
+                .that(&(
+                    (seed as i64).zigzag_encode().zigzag_decode(),
+                    seed.zigzag_decode().zigzag_encode(),
+                    (wide as i128).zigzag_encode().zigzag_decode(),
+                    wide.zigzag_decode().zigzag_encode(),
+                    (seed as isize).zigzag_encode().zigzag_decode(),
+                ))
+                .is_equal_to((seed as i64, seed, wide as i128, wide, seed as isize));
+        }
+    }
+}